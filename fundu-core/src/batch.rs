@@ -0,0 +1,264 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! The fast paths of [`Parser::parse_many`] to parse columns of source strings
+//!
+//! Columnar data (like a column of a csv file) often consists of plain numbers (`"123"`) or of a
+//! number directly followed by a time unit (`"123ms"`). For these common cases, the [`FastPath`]
+//! skips the full parser of the [`Parser`] and builds the [`DurationRepr`] directly. All other
+//! inputs and inputs which would result in an error are parsed with the full parser, so the
+//! results are always the same as the results of [`Parser::parse`].
+//!
+//! [`Parser`]: crate::parse::Parser
+//! [`Parser::parse_many`]: crate::parse::Parser::parse_many
+//! [`Parser::parse`]: crate::parse::Parser::parse
+
+use crate::config::Config;
+use crate::parse::{DurationRepr, Whole};
+use crate::time::{Duration, TimeUnitsLike};
+
+/// The fast paths which are possible with the [`Config`] and the time units of a
+/// [`crate::parse::Parser`]
+///
+/// The checks depending on the configuration are done only once in [`FastPath::new`], so the
+/// costs of [`FastPath::parse`] are independent of the configuration.
+#[derive(Clone, Copy)]
+pub struct FastPath<'c, 'a> {
+    config: &'c Config<'a>,
+    time_units: &'c dyn TimeUnitsLike,
+    /// True if a number directly followed by a time unit can be parsed with the fast path
+    with_time_unit: bool,
+}
+
+impl<'c, 'a> FastPath<'c, 'a> {
+    /// Create the fast paths for a parser with the `config` and the `time_units`
+    ///
+    /// A number directly followed by a time unit is only parsed with the fast path if there are
+    /// time units and no ascii letter is a delimiter, since a delimiter would end the time unit.
+    pub fn new(config: &'c Config<'a>, time_units: &'c dyn TimeUnitsLike) -> Self {
+        let is_delimiter =
+            |byte: u8| (config.inner_delimiter)(byte) || (config.outer_delimiter)(byte);
+        Self {
+            config,
+            time_units,
+            with_time_unit: !time_units.is_empty()
+                && !(b'a'..=b'z').chain(b'A'..=b'Z').any(is_delimiter),
+        }
+    }
+
+    /// Parse the `source` with the fast path
+    ///
+    /// Returns `None` if the `source` can't be parsed with the fast path. The `source` needs to be
+    /// parsed with the full parser in such a case.
+    #[inline]
+    pub fn parse(&self, source: &str) -> Option<Duration> {
+        let input = source.as_bytes();
+        let end = count_digits(input);
+        if end == 0 {
+            return None;
+        }
+
+        let mut duration_repr = DurationRepr {
+            default_unit: self.config.default_unit,
+            input,
+            ..Default::default()
+        };
+        let start = input[..end]
+            .iter()
+            .position(|byte| *byte != b'0')
+            .unwrap_or(end);
+        duration_repr.whole = Some(Whole(start, end));
+
+        if end < input.len() {
+            let identifier = &input[end..];
+            if !self.with_time_unit
+                // An `e` or `E` starts the exponent
+                || identifier[0].eq_ignore_ascii_case(&b'e')
+                || !identifier
+                    .iter()
+                    .all(|byte| byte.is_ascii_alphabetic() || !byte.is_ascii())
+            {
+                return None;
+            }
+            // The identifier starts at a char boundary after the ascii digits
+            let (unit, multiplier, constraints) =
+                self.time_units.get_with_constraints(&source[end..])?;
+            // The full parser reports the violation of constraints
+            if constraints.restricts_number() {
+                return None;
+            }
+            duration_repr.unit = Some(unit);
+            duration_repr.multiplier = multiplier;
+        }
+
+        duration_repr
+            .parse()
+            .ok()
+            .filter(|duration| self.config.allow_negative || !duration.is_negative())
+    }
+}
+
+/// Return the amount of ascii digits at the start of the `bytes`
+///
+/// On `x86_64` with `sse2`, the digits are counted in chunks of 16 bytes with SIMD instructions.
+/// Otherwise and for the remaining bytes, the digits are counted in chunks of 8 bytes with the
+/// same SWAR trick which is used by [`crate::parse::Bytes::parse_8_digits`].
+#[inline]
+pub fn count_digits(bytes: &[u8]) -> usize {
+    let mut count = 0;
+
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    while let Some(chunk) = bytes.get(count..count + 16) {
+        // SAFETY: The chunk has exactly 16 bytes and sse2 is enabled at compile time
+        let digits = unsafe { sse2::count_digits_16(chunk) };
+        count += digits;
+        if digits < 16 {
+            return count;
+        }
+    }
+
+    while let Some(chunk) = bytes.get(count..count + 8) {
+        if !is_8_digits(chunk) {
+            break;
+        }
+        count += 8;
+    }
+
+    count
+        + bytes[count..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count()
+}
+
+/// Return true if the first 8 bytes of `bytes` are all ascii digits
+///
+/// This method is based on the work of Daniel Lemire and his blog post
+/// <https://lemire.me/blog/2018/09/30/quickly-identifying-a-sequence-of-digits-in-a-string-of-characters/>
+#[inline]
+fn is_8_digits(bytes: &[u8]) -> bool {
+    debug_assert!(bytes.len() >= 8); // cov:excl-line
+
+    // This cast to a more strictly aligned type is safe since we're using ptr.read_unaligned
+    #[allow(clippy::cast_ptr_alignment)]
+    let ptr = bytes.as_ptr().cast::<u64>();
+    // SAFETY: There are at least 8 bytes
+    let num = u64::from_le(unsafe { ptr.read_unaligned() });
+    (num & (num.wrapping_add(0x0606_0606_0606_0606)) & 0xf0f0_f0f0_f0f0_f0f0)
+        == 0x3030_3030_3030_3030
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+mod sse2 {
+    use core::arch::x86_64::{
+        __m128i, _mm_cmpgt_epi8, _mm_cmplt_epi8, _mm_loadu_si128, _mm_movemask_epi8, _mm_or_si128,
+        _mm_set1_epi8,
+    };
+
+    /// Return the amount of ascii digits at the start of the first 16 bytes of `bytes`
+    ///
+    /// The comparisons are signed, so bytes above `0x7f` are below `b'0'` and are no digits.
+    #[inline]
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    pub unsafe fn count_digits_16(bytes: &[u8]) -> usize {
+        debug_assert!(bytes.len() >= 16); // cov:excl-line
+
+        // This cast to a more strictly aligned type is safe since _mm_loadu_si128 doesn't need
+        // an aligned pointer
+        #[allow(clippy::cast_ptr_alignment)]
+        let chunk = _mm_loadu_si128(bytes.as_ptr().cast::<__m128i>());
+        let below = _mm_cmplt_epi8(chunk, _mm_set1_epi8(b'0' as i8));
+        let above = _mm_cmpgt_epi8(chunk, _mm_set1_epi8(b'9' as i8));
+        let mask = _mm_movemask_epi8(_mm_or_si128(below, above)) as u32;
+        (mask | 1 << 16).trailing_zeros() as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::config::ConfigBuilder;
+    use crate::time::TimeUnit::*;
+    use crate::time::{Multiplier, TimeUnit};
+
+    struct TimeUnits {}
+    impl TimeUnitsLike for TimeUnits {
+        fn is_empty(&self) -> bool {
+            false
+        }
+
+        fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
+            match identifier {
+                "s" => Some((Second, Multiplier::default())),
+                "ms" => Some((MilliSecond, Multiplier::default())),
+                "µs" => Some((MicroSecond, Multiplier::default())),
+                "back" => Some((Second, Multiplier(-1, 0))),
+                _ => None,
+            }
+        }
+    }
+
+    #[rstest]
+    #[case::empty("", 0)]
+    #[case::no_digit("s", 0)]
+    #[case::one_digit("1", 1)]
+    #[case::digits_and_time_unit("123ms", 3)]
+    #[case::eight_digits("12345678", 8)]
+    #[case::nine_digits("123456789s", 9)]
+    #[case::sixteen_digits("1234567890123456", 16)]
+    #[case::seventeen_digits("12345678901234567s", 17)]
+    #[case::non_digit_in_first_chunk("1234567890123.456789", 13)]
+    #[case::non_digit_in_second_chunk("12345678901234567890.1234567", 20)]
+    #[case::non_ascii("1234567890123µs", 13)]
+    #[case::byte_after_nine("1234567890123:56", 13)]
+    #[case::byte_before_zero("1234567890123/56", 13)]
+    #[case::many_digits(&"9".repeat(100), 100)]
+    fn test_count_digits(#[case] source: &str, #[case] expected: usize) {
+        assert_eq!(count_digits(source.as_bytes()), expected);
+    }
+
+    #[rstest]
+    #[case::digits("123", Some(Duration::positive(123, 0)))]
+    #[case::leading_zeros("000123", Some(Duration::positive(123, 0)))]
+    #[case::only_zeros("000", Some(Duration::ZERO))]
+    #[case::time_unit("123ms", Some(Duration::positive(0, 123_000_000)))]
+    #[case::multibyte_time_unit("5µs", Some(Duration::positive(0, 5_000)))]
+    #[case::overflow(&"9".repeat(30), Some(Duration::MAX))]
+    #[case::invalid_time_unit("1x", None)]
+    #[case::exponent("1e3", None)]
+    #[case::fraction("1.5s", None)]
+    #[case::sign("+1s", None)]
+    #[case::delimiter("1 s", None)]
+    #[case::negative_multiplier("1back", None)]
+    #[case::no_number("s", None)]
+    fn test_fast_path_parse(#[case] source: &str, #[case] expected: Option<Duration>) {
+        let config = Config::new();
+        let fast_path = FastPath::new(&config, &TimeUnits {});
+        assert_eq!(fast_path.parse(source), expected);
+    }
+
+    #[test]
+    fn test_fast_path_parse_when_letter_is_delimiter_then_only_numbers() {
+        let config = ConfigBuilder::new()
+            .outer_delimiter(|byte| byte == b'm')
+            .build();
+        let fast_path = FastPath::new(&config, &TimeUnits {});
+        assert_eq!(fast_path.parse("1"), Some(Duration::positive(1, 0)));
+        assert_eq!(fast_path.parse("1ms"), None);
+    }
+
+    #[test]
+    fn test_fast_path_parse_with_default_unit_and_negative_multiplier() {
+        let config = ConfigBuilder::new()
+            .default_unit(MilliSecond)
+            .allow_negative()
+            .build();
+        let fast_path = FastPath::new(&config, &TimeUnits {});
+        assert_eq!(fast_path.parse("1"), Some(Duration::positive(0, 1_000_000)));
+        assert_eq!(fast_path.parse("1back"), Some(Duration::negative(1, 0)));
+    }
+}
@@ -163,6 +163,8 @@ pub struct Config<'a> {
     ///
     /// Usually, a time unit needs a number like in `"1second"`. With this setting set to `true` a
     /// time unit can occur without number like `"second"` and a number with value `1` is assumed.
+    /// Single time units can override this setting with
+    /// [`crate::time::TimeUnitConstraints::number_is_optional`].
     pub number_is_optional: bool,
 
     /// If `true`, this setting allows multiple `durations` in the input (Default: `false`)
@@ -180,6 +182,32 @@ pub struct Config<'a> {
     /// conjunctions can occur only once between two durations.
     pub conjunctions: Option<&'a [&'a str]>,
 
+    /// If parsing multiple durations, the time units must occur in descending order (Default:
+    /// `false`)
+    ///
+    /// For example, `"1h 30m 10s"` is accepted but `"10s 1h"` results in a
+    /// [`crate::error::ParseError::TimeUnit`] error pointing at the start of the offending
+    /// duration. The same time unit may occur multiple times unless
+    /// [`Config::disable_duplicate_units`] is set, too. Durations without a time unit count as
+    /// [`Config::default_unit`] and infinity is ignored. Time units are compared by their
+    /// [`TimeUnit`], so custom time units with the same base [`TimeUnit`] count as equal.
+    pub require_descending_order: bool,
+
+    /// If parsing multiple durations, each time unit may occur only once (Default: `false`)
+    ///
+    /// For example, `"1s 1h 1s"` results in a [`crate::error::ParseError::TimeUnit`] error
+    /// pointing at the start of the second `"1s"`. Like with [`Config::require_descending_order`],
+    /// time units are compared by their [`TimeUnit`].
+    pub disable_duplicate_units: bool,
+
+    /// If parsing multiple durations, all durations must have the same sign (Default: `false`)
+    ///
+    /// For example, `"1h -5m"` results in a [`crate::error::ParseError::Syntax`] error pointing at
+    /// the start of `"-5m"`. The sign is the sign of the parsed duration, so it considers the
+    /// `ago` keyword and negative [`Multiplier`]s, too. Durations which are zero don't have a
+    /// sign and are ignored.
+    pub disable_mixed_signs: bool,
+
     /// Allow parsing negative durations (Default: `false`)
     ///
     /// Negative durations usually start with a `-` sign like in `-1second` which would evaluate to
@@ -262,6 +290,9 @@ impl<'a> Config<'a> {
     /// assert_eq!(DEFAULT_CONFIG.disable_infinity, false);
     /// assert_eq!(DEFAULT_CONFIG.allow_multiple, false);
     /// assert_eq!(DEFAULT_CONFIG.conjunctions, None);
+    /// assert_eq!(DEFAULT_CONFIG.require_descending_order, false);
+    /// assert_eq!(DEFAULT_CONFIG.disable_duplicate_units, false);
+    /// assert_eq!(DEFAULT_CONFIG.disable_mixed_signs, false);
     /// assert_eq!(DEFAULT_CONFIG.allow_negative, false);
     /// assert_eq!(DEFAULT_CONFIG.allow_ago, false);
     /// ```
@@ -276,6 +307,9 @@ impl<'a> Config<'a> {
             disable_infinity: false,
             allow_multiple: false,
             conjunctions: None,
+            require_descending_order: false,
+            disable_duplicate_units: false,
+            disable_mixed_signs: false,
             allow_negative: false,
             allow_ago: false,
             allow_sign_delimiter: false,
@@ -530,6 +564,75 @@ impl<'a> ConfigBuilder<'a> {
         self
     }
 
+    /// If parsing multiple durations, require the time units to be in descending order (Default:
+    /// `false`)
+    ///
+    /// See also the documentation of [`Config::require_descending_order`]. This setting has no
+    /// effect unless parsing multiple durations with [`ConfigBuilder::parse_multiple`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_core::config::{Config, ConfigBuilder};
+    ///
+    /// const CONFIG: Config = ConfigBuilder::new()
+    ///     .parse_multiple(None)
+    ///     .require_descending_order()
+    ///     .build();
+    ///
+    /// assert!(CONFIG.require_descending_order);
+    /// ```
+    pub const fn require_descending_order(mut self) -> Self {
+        self.config.require_descending_order = true;
+        self
+    }
+
+    /// If parsing multiple durations, reject time units which occur more than once (Default:
+    /// `false`)
+    ///
+    /// See also the documentation of [`Config::disable_duplicate_units`]. This setting has no
+    /// effect unless parsing multiple durations with [`ConfigBuilder::parse_multiple`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_core::config::{Config, ConfigBuilder};
+    ///
+    /// const CONFIG: Config = ConfigBuilder::new()
+    ///     .parse_multiple(None)
+    ///     .disable_duplicate_units()
+    ///     .build();
+    ///
+    /// assert!(CONFIG.disable_duplicate_units);
+    /// ```
+    pub const fn disable_duplicate_units(mut self) -> Self {
+        self.config.disable_duplicate_units = true;
+        self
+    }
+
+    /// If parsing multiple durations, reject durations with different signs (Default: `false`)
+    ///
+    /// See also the documentation of [`Config::disable_mixed_signs`]. This setting has no effect
+    /// unless parsing multiple durations with [`ConfigBuilder::parse_multiple`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_core::config::{Config, ConfigBuilder};
+    ///
+    /// const CONFIG: Config = ConfigBuilder::new()
+    ///     .parse_multiple(None)
+    ///     .allow_negative()
+    ///     .disable_mixed_signs()
+    ///     .build();
+    ///
+    /// assert!(CONFIG.disable_mixed_signs);
+    /// ```
+    pub const fn disable_mixed_signs(mut self) -> Self {
+        self.config.disable_mixed_signs = true;
+        self
+    }
+
     /// Allow the ago keyword delimited by a [`Delimiter`] to indicate a negative duration
     /// (Default: `None`)
     ///
@@ -742,6 +845,39 @@ mod tests {
         assert_eq!(config, expected);
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_config_builder_require_descending_order() {
+        let config = ConfigBuilder::new().require_descending_order().build();
+
+        let mut expected = Config::new();
+        expected.require_descending_order = true;
+
+        assert_eq!(config, expected);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_config_builder_disable_duplicate_units() {
+        let config = ConfigBuilder::new().disable_duplicate_units().build();
+
+        let mut expected = Config::new();
+        expected.disable_duplicate_units = true;
+
+        assert_eq!(config, expected);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_config_builder_disable_mixed_signs() {
+        let config = ConfigBuilder::new().disable_mixed_signs().build();
+
+        let mut expected = Config::new();
+        expected.disable_mixed_signs = true;
+
+        assert_eq!(config, expected);
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn test_config_builder_parse_multiple_when_conjunctions() {
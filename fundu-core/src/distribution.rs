@@ -0,0 +1,736 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Parse and sample randomized durations like `30s ± 10%`, `1m~5s` or `rand(1s, 5s)`
+//!
+//! The [`DurationDistributionParser`] parses the durations with any parser implementing
+//! [`ParseDurationPrefix`] into a [`DurationDistribution`]. A distribution is sampled with a
+//! [`RandomSource`] supplied by the caller, so there's no dependency on a random number generator.
+
+use alloc::format;
+
+use crate::error::ParseError;
+use crate::expression::{from_nanos, multiply, number_length, parse_number};
+use crate::stream::ParseDurationPrefix;
+use crate::time::{Duration, Multiplier};
+use crate::util::{skip_whitespace, unexpected};
+
+/// The operators separating the base duration from the jitter
+const JITTER_OPERATORS: [&str; 3] = ["±", "+-", "~"];
+
+/// A source of uniformly distributed random numbers
+///
+/// This trait is implemented by the caller to sample a [`DurationDistribution`] with the random
+/// number generator of choice. For example, the `RngCore` of the `rand` crate can be used by
+/// forwarding to its `next_u64` method.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_core::distribution::RandomSource;
+///
+/// // A simple SplitMix64 generator
+/// struct SplitMix64(u64);
+///
+/// impl RandomSource for SplitMix64 {
+///     fn next_u64(&mut self) -> u64 {
+///         self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+///         let mut z = self.0;
+///         z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+///         z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+///         z ^ (z >> 31)
+///     }
+/// }
+/// ```
+pub trait RandomSource {
+    /// Return the next random `u64` with all bits uniformly distributed
+    fn next_u64(&mut self) -> u64;
+}
+
+impl<R: RandomSource + ?Sized> RandomSource for &mut R {
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+}
+
+/// A distribution of [`Duration`]s which can be sampled with a [`RandomSource`]
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_core::distribution::{DurationDistribution, RandomSource};
+/// use fundu_core::time::{Duration, Multiplier};
+///
+/// struct Counter(u64);
+///
+/// impl RandomSource for Counter {
+///     fn next_u64(&mut self) -> u64 {
+///         self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+///         self.0
+///     }
+/// }
+///
+/// // 30 seconds with a jitter of 10%
+/// let distribution = DurationDistribution::Jitter {
+///     base: Duration::positive(30, 0),
+///     percent: Multiplier(10, 0),
+/// };
+/// assert_eq!(distribution.min(), Duration::positive(27, 0));
+/// assert_eq!(distribution.max(), Duration::positive(33, 0));
+///
+/// let sample = distribution.sample(&mut Counter(0));
+/// assert!(distribution.min() <= sample && sample <= distribution.max());
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum DurationDistribution {
+    /// Always the same duration
+    Fixed(Duration),
+    /// A uniform distribution between `min` and `max` including both
+    Uniform {
+        /// The minimum duration
+        min: Duration,
+        /// The maximum duration
+        max: Duration,
+    },
+    /// A uniform distribution around `base` deviating at most `percent` percent of `base`
+    Jitter {
+        /// The duration in the middle of the distribution
+        base: Duration,
+        /// The maximum deviation in percent of the `base` like `Multiplier(10, 0)` for `10%`
+        percent: Multiplier,
+    },
+}
+
+impl DurationDistribution {
+    /// Return the minimum duration of this distribution
+    ///
+    /// The deviation of a [`DurationDistribution::Jitter`] is truncated to nanoseconds.
+    pub fn min(&self) -> Duration {
+        self.bounds().0
+    }
+
+    /// Return the maximum duration of this distribution
+    ///
+    /// The deviation of a [`DurationDistribution::Jitter`] is truncated to nanoseconds.
+    pub fn max(&self) -> Duration {
+        self.bounds().1
+    }
+
+    /// Return a random duration of this distribution using the random numbers of the `rng`
+    ///
+    /// All durations between [`DurationDistribution::min`] and [`DurationDistribution::max`] with
+    /// nanosecond precision are equally likely. A [`DurationDistribution::Fixed`] doesn't use the
+    /// `rng` and the same sequence of random numbers results in the same sequence of durations.
+    pub fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Duration {
+        let (min, max) = self.bounds();
+        if min == max {
+            return min;
+        }
+
+        // The span is at most 2 * Duration::MAX which is less than 2^96 nanoseconds
+        let min = min.as_nanos();
+        let span = (max.as_nanos() - min).unsigned_abs();
+        #[allow(clippy::cast_possible_wrap)]
+        let nanos = min + uniform(rng, span) as i128;
+        from_nanos(nanos.is_negative(), Some(nanos.unsigned_abs()))
+    }
+
+    /// Return the minimum and maximum duration with the minimum less than or equal to the maximum
+    fn bounds(&self) -> (Duration, Duration) {
+        match *self {
+            Self::Fixed(duration) => (duration, duration),
+            Self::Uniform { min, max } => (min.min(max), min.max(max)),
+            Self::Jitter {
+                base,
+                percent: Multiplier(coefficient, exponent),
+            } => {
+                let deviation =
+                    multiply(base, Multiplier(coefficient, exponent.saturating_sub(2))).abs();
+                (
+                    base.saturating_sub(deviation),
+                    base.saturating_add(deviation),
+                )
+            }
+        }
+    }
+}
+
+/// Return a uniformly distributed number between `0` and `span` including both
+fn uniform<R: RandomSource + ?Sized>(rng: &mut R, span: u128) -> u128 {
+    let mut next = || u128::from(rng.next_u64()) << 64u32 | u128::from(rng.next_u64());
+    let range = match span.checked_add(1) {
+        Some(range) => range,
+        None => return next(),
+    };
+    // Reject the numbers below 2^128 % range, so the remaining amount of numbers is a multiple of
+    // the range and the remainder is unbiased
+    let threshold = range.wrapping_neg() % range;
+    loop {
+        let number = next();
+        if number >= threshold {
+            return number % range;
+        }
+    }
+}
+
+/// A parser for randomized durations
+///
+/// The durations are parsed with the parser, so the grammar of the durations is exactly the
+/// grammar of the configured parser. The following forms are recognized:
+///
+/// * A duration without jitter like `30s` is a [`DurationDistribution::Fixed`]
+/// * A duration with a jitter in percent like `30s ± 10%` or `30s ~ 10%` is a
+///   [`DurationDistribution::Jitter`]. The percentage is a number like `10` or `2.5` followed by
+///   `%`.
+/// * A duration with a jitter duration like `1m~5s` or `1m ± 5s` is a
+///   [`DurationDistribution::Uniform`] between `55s` and `1m 5s`
+/// * `rand(min, max)` like in `rand(1s, 5s)` is a [`DurationDistribution::Uniform`] between `min`
+///   and `max`
+///
+/// Instead of `±`, the jitter can also be written with `+-` like in `30s +- 10%`. Whitespace may
+/// occur between all parts. The minimum of `rand(min, max)` must not be greater than the maximum
+/// and the jitter must not be negative. Negative durations of the distribution are an error unless
+/// [`DurationDistributionParser::allow_negative`] is set, so a jitter like `1s ± 200%` is an error
+/// by default.
+///
+/// # Problems
+///
+/// The parser must stop in front of the jitter operators. A parser which parses multiple durations
+/// consumes `1m +- 5s` as a single duration if signs can be separated from the number by a
+/// delimiter.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_core::distribution::{DurationDistribution, DurationDistributionParser};
+/// use fundu_core::error::ParseError;
+/// use fundu_core::stream::ParseDurationPrefix;
+/// use fundu_core::time::{Duration, Multiplier};
+///
+/// // A parser for whole seconds like `10s`
+/// struct Seconds;
+///
+/// impl ParseDurationPrefix for Seconds {
+///     fn parse_duration_prefix<'s>(
+///         &self,
+///         source: &'s str,
+///     ) -> Result<(Duration, &'s str), ParseError> {
+///         let end = source
+///             .find(|c: char| !c.is_ascii_digit())
+///             .unwrap_or(source.len());
+///         let seconds: u64 = source[..end]
+///             .parse()
+///             .map_err(|_| ParseError::Syntax(0, "Expected a number".to_string()))?;
+///         match source[end..].strip_prefix('s') {
+///             Some(rest) => Ok((Duration::positive(seconds, 0), rest)),
+///             None => Err(ParseError::TimeUnit(end, "Expected a time unit".to_string())),
+///         }
+///     }
+/// }
+///
+/// let parser = DurationDistributionParser::new(Seconds);
+/// assert_eq!(
+///     parser.parse("30s"),
+///     Ok(DurationDistribution::Fixed(Duration::positive(30, 0)))
+/// );
+/// assert_eq!(
+///     parser.parse("30s ± 10%"),
+///     Ok(DurationDistribution::Jitter {
+///         base: Duration::positive(30, 0),
+///         percent: Multiplier(1, 1)
+///     })
+/// );
+/// assert_eq!(
+///     parser.parse("60s~5s"),
+///     Ok(DurationDistribution::Uniform {
+///         min: Duration::positive(55, 0),
+///         max: Duration::positive(65, 0)
+///     })
+/// );
+/// assert_eq!(
+///     parser.parse("rand(1s, 5s)"),
+///     Ok(DurationDistribution::Uniform {
+///         min: Duration::positive(1, 0),
+///         max: Duration::positive(5, 0)
+///     })
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DurationDistributionParser<P> {
+    parser: P,
+    allow_negative: bool,
+}
+
+impl<P: ParseDurationPrefix> DurationDistributionParser<P> {
+    /// Create a new `DurationDistributionParser` which parses the durations with the `parser`
+    pub const fn new(parser: P) -> Self {
+        Self {
+            parser,
+            allow_negative: false,
+        }
+    }
+
+    /// Allow negative durations in the distribution (Default: `false`)
+    ///
+    /// Without this setting, a distribution which contains negative durations is a
+    /// [`ParseError::NegativeNumber`].
+    pub const fn allow_negative(mut self) -> Self {
+        self.allow_negative = true;
+        self
+    }
+
+    /// Return the parser of this `DurationDistributionParser`
+    pub const fn parser(&self) -> &P {
+        &self.parser
+    }
+
+    /// Parse the `source` string into a [`DurationDistribution`]
+    pub fn parse(&self, source: &str) -> Result<DurationDistribution, ParseError> {
+        if source.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let mut position = skip_whitespace(source, 0);
+        let distribution = if let Some(column) = strip_function(source, position) {
+            let (min, column) = self.parse_duration(source, column)?;
+            let column = expect(source, column, ",")?;
+            let (max, column) = self.parse_duration(source, column)?;
+            position = expect(source, column, ")")?;
+            if min > max {
+                return Err(ParseError::InvalidInput(format!(
+                    "The minimum {min} is greater than the maximum {max}"
+                )));
+            }
+            DurationDistribution::Uniform { min, max }
+        } else {
+            let (base, column) = self.parse_duration(source, position)?;
+            position = skip_whitespace(source, column);
+            match JITTER_OPERATORS
+                .iter()
+                .find(|operator| source[position..].starts_with(*operator))
+            {
+                Some(operator) => {
+                    let column = skip_whitespace(source, position + operator.len());
+                    let (distribution, column) = self.parse_jitter(source, column, base)?;
+                    position = column;
+                    distribution
+                }
+                None => DurationDistribution::Fixed(base),
+            }
+        };
+
+        let position = skip_whitespace(source, position);
+        if position < source.len() {
+            return Err(unexpected(source, position, "the end of input"));
+        }
+        if !self.allow_negative && distribution.min().is_negative() {
+            return Err(ParseError::NegativeNumber);
+        }
+        Ok(distribution)
+    }
+
+    /// Parse the jitter at `position` of the `base` duration
+    ///
+    /// Return the distribution and the position after the jitter.
+    fn parse_jitter(
+        &self,
+        source: &str,
+        position: usize,
+        base: Duration,
+    ) -> Result<(DurationDistribution, usize), ParseError> {
+        let rest = &source[position..];
+        let number = &rest[..number_length(rest)];
+        let percent_position = skip_whitespace(source, position + number.len());
+        if !number.is_empty() && source[percent_position..].starts_with('%') {
+            let percent = parse_number(number, position)?;
+            return Ok((
+                DurationDistribution::Jitter { base, percent },
+                percent_position + 1,
+            ));
+        }
+
+        let (jitter, column) = self.parse_duration(source, position)?;
+        if jitter.is_negative() {
+            return Err(ParseError::InvalidInput(format!(
+                "The jitter {jitter} must not be negative"
+            )));
+        }
+        Ok((
+            DurationDistribution::Uniform {
+                min: base.saturating_sub(jitter),
+                max: base.saturating_add(jitter),
+            },
+            column,
+        ))
+    }
+
+    /// Parse the duration at `position`
+    ///
+    /// Return the duration and the position after the duration.
+    fn parse_duration(
+        &self,
+        source: &str,
+        position: usize,
+    ) -> Result<(Duration, usize), ParseError> {
+        let position = skip_whitespace(source, position);
+        if position == source.len() {
+            return Err(unexpected(source, position, "a duration"));
+        }
+        self.parser
+            .parse_duration_prefix(&source[position..])
+            .map(|(duration, rest)| (duration, source.len() - rest.len()))
+            .map_err(|error| error.with_offset(position))
+    }
+}
+
+/// Return the position after `rand(` if the `source` continues with it at `position`
+fn strip_function(source: &str, position: usize) -> Option<usize> {
+    let rest = source[position..].strip_prefix("rand")?;
+    let column = skip_whitespace(source, source.len() - rest.len());
+    source[column..].starts_with('(').then_some(column + 1)
+}
+
+/// Skip the whitespace and return the position after the `token` or an error if it's missing
+fn expect(source: &str, position: usize, token: &str) -> Result<usize, ParseError> {
+    let position = skip_whitespace(source, position);
+    if source[position..].starts_with(token) {
+        Ok(position + token.len())
+    } else {
+        Err(unexpected(source, position, &format!("'{token}'")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration as StdDuration;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Parses numbers with an optional fraction and time unit `ms`, `s`, `m` or `h`
+    struct Simple;
+
+    impl ParseDurationPrefix for Simple {
+        fn parse_duration_prefix<'s>(
+            &self,
+            source: &'s str,
+        ) -> Result<(Duration, &'s str), ParseError> {
+            let (sign, digits) = match source.strip_prefix('-') {
+                Some(digits) => (true, digits),
+                None => (false, source),
+            };
+            let end = digits
+                .find(|c: char| !c.is_ascii_digit() && c != '.')
+                .unwrap_or(digits.len());
+            let number: f64 = digits[..end].parse().map_err(|_| {
+                ParseError::Syntax(usize::from(sign), "Expected a number".to_owned())
+            })?;
+            let rest = &digits[end..];
+            let unit_end = rest
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(rest.len());
+            let seconds: f64 = match &rest[..unit_end] {
+                "" | "s" => 1.0,
+                "ms" => 0.001,
+                "m" => 60.0,
+                "h" => 3600.0,
+                unit => {
+                    return Err(ParseError::TimeUnit(
+                        usize::from(sign) + end,
+                        format!("Invalid time unit: '{unit}'"),
+                    ))
+                }
+            };
+            let duration = StdDuration::from_secs_f64(number * seconds);
+            Ok((Duration::from_std(sign, duration), &rest[unit_end..]))
+        }
+    }
+
+    /// The `SplitMix64` generator which is deterministic for the same seed
+    struct SplitMix64(u64);
+
+    impl RandomSource for SplitMix64 {
+        fn next_u64(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = self.0;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        }
+    }
+
+    /// Returns the numbers in the given order and panics if there are no numbers left
+    struct Sequence<'a>(&'a [u64]);
+
+    impl RandomSource for Sequence<'_> {
+        fn next_u64(&mut self) -> u64 {
+            let (first, rest) = self.0.split_first().expect("No numbers left");
+            self.0 = rest;
+            *first
+        }
+    }
+
+    const fn secs(seconds: u64) -> Duration {
+        Duration::positive(seconds, 0)
+    }
+
+    const fn uniform_distribution(min: Duration, max: Duration) -> DurationDistribution {
+        DurationDistribution::Uniform { min, max }
+    }
+
+    const fn jitter(base: Duration, percent: Multiplier) -> DurationDistribution {
+        DurationDistribution::Jitter { base, percent }
+    }
+
+    #[rstest]
+    #[case::fixed("30s", DurationDistribution::Fixed(secs(30)))]
+    #[case::fixed_with_whitespace(" 30s ", DurationDistribution::Fixed(secs(30)))]
+    #[case::percent("30s ± 10%", jitter(secs(30), Multiplier(1, 1)))]
+    #[case::percent_without_whitespace("30s±10%", jitter(secs(30), Multiplier(1, 1)))]
+    #[case::percent_with_plus_minus("30s +- 10%", jitter(secs(30), Multiplier(1, 1)))]
+    #[case::percent_with_tilde("30s~10%", jitter(secs(30), Multiplier(1, 1)))]
+    #[case::percent_with_fraction("30s ± 2.5%", jitter(secs(30), Multiplier(25, -1)))]
+    #[case::percent_with_exponent("30s ± 1e1%", jitter(secs(30), Multiplier(1, 1)))]
+    #[case::percent_with_whitespace("30s ± 10 %", jitter(secs(30), Multiplier(1, 1)))]
+    #[case::zero_percent("30s ± 0%", jitter(secs(30), Multiplier(0, 0)))]
+    #[case::hundred_percent("30s ± 100%", jitter(secs(30), Multiplier(1, 2)))]
+    #[case::jitter_duration("1m~5s", uniform_distribution(secs(55), secs(65)))]
+    #[case::jitter_duration_with_plus_minus("1m ± 5s", uniform_distribution(secs(55), secs(65)))]
+    #[case::jitter_number("1m ~ 5", uniform_distribution(secs(55), secs(65)))]
+    #[case::jitter_fraction(
+        "1m ~ 0.5",
+        uniform_distribution(
+            Duration::positive(59, 500_000_000),
+            Duration::positive(60, 500_000_000)
+        )
+    )]
+    #[case::jitter_equal_to_base("5s ~ 5s", uniform_distribution(Duration::ZERO, secs(10)))]
+    #[case::rand("rand(1s, 5s)", uniform_distribution(secs(1), secs(5)))]
+    #[case::rand_with_whitespace(" rand ( 1s ,5s ) ", uniform_distribution(secs(1), secs(5)))]
+    #[case::rand_with_equal_bounds("rand(2s, 2s)", uniform_distribution(secs(2), secs(2)))]
+    fn test_duration_distribution_parser(
+        #[case] source: &str,
+        #[case] expected: DurationDistribution,
+    ) {
+        assert_eq!(
+            DurationDistributionParser::new(Simple).parse(source),
+            Ok(expected)
+        );
+    }
+
+    #[rstest]
+    #[case::empty("", ParseError::Empty)]
+    #[case::only_whitespace(
+        " ",
+        ParseError::Syntax(1, "Expected a duration but reached the end of input".to_owned())
+    )]
+    #[case::invalid_duration("x", ParseError::Syntax(0, "Expected a number".to_owned()))]
+    #[case::invalid_time_unit(
+        "1m ± 5x",
+        ParseError::TimeUnit(7, "Invalid time unit: 'x'".to_owned())
+    )]
+    #[case::missing_jitter(
+        "30s ± ",
+        ParseError::Syntax(7, "Expected a duration but reached the end of input".to_owned())
+    )]
+    #[case::missing_operator(
+        "30s 10%",
+        ParseError::Syntax(4, "Expected the end of input but found '1'".to_owned())
+    )]
+    #[case::trailing_input(
+        "30s ± 10% 1s",
+        ParseError::Syntax(11, "Expected the end of input but found '1'".to_owned())
+    )]
+    #[case::negative_percent(
+        "30s ± -10%",
+        ParseError::InvalidInput("The jitter -10s must not be negative".to_owned())
+    )]
+    #[case::percent_exponent_overflow("30s ± 1e99999%", ParseError::PositiveExponentOverflow)]
+    #[case::rand_without_comma(
+        "rand(1s 5s)",
+        ParseError::Syntax(8, "Expected ',' but found '5'".to_owned())
+    )]
+    #[case::rand_without_closing_parenthesis(
+        "rand(1s, 5s",
+        ParseError::Syntax(11, "Expected ')' but reached the end of input".to_owned())
+    )]
+    #[case::rand_with_one_argument(
+        "rand(1s)",
+        ParseError::Syntax(7, "Expected ',' but found ')'".to_owned())
+    )]
+    #[case::rand_without_parentheses(
+        "rand",
+        ParseError::Syntax(0, "Expected a number".to_owned())
+    )]
+    #[case::rand_min_greater_than_max(
+        "rand(5s, 1s)",
+        ParseError::InvalidInput("The minimum 5s is greater than the maximum 1s".to_owned())
+    )]
+    #[case::negative("-1s", ParseError::NegativeNumber)]
+    #[case::negative_by_percent("1s ± 200%", ParseError::NegativeNumber)]
+    #[case::negative_by_jitter("1s ~ 2s", ParseError::NegativeNumber)]
+    fn test_duration_distribution_parser_when_error(
+        #[case] source: &str,
+        #[case] expected: ParseError,
+    ) {
+        assert_eq!(
+            DurationDistributionParser::new(Simple).parse(source),
+            Err(expected)
+        );
+    }
+
+    #[rstest]
+    #[case::negative("-1s", DurationDistribution::Fixed(Duration::negative(1, 0)))]
+    #[case::negative_by_percent("1s ± 200%", jitter(secs(1), Multiplier(2, 2)))]
+    #[case::negative_by_jitter("1s ~ 2s", uniform_distribution(Duration::negative(1, 0), secs(3)))]
+    #[case::negative_rand(
+        "rand(-2s, -1s)",
+        uniform_distribution(Duration::negative(2, 0), Duration::negative(1, 0))
+    )]
+    fn test_duration_distribution_parser_with_allow_negative(
+        #[case] source: &str,
+        #[case] expected: DurationDistribution,
+    ) {
+        let parser = DurationDistributionParser::new(Simple).allow_negative();
+        assert_eq!(parser.parse(source), Ok(expected));
+    }
+
+    #[test]
+    fn test_duration_distribution_parser_when_jitter_is_negative() {
+        let parser = DurationDistributionParser::new(Simple).allow_negative();
+        assert_eq!(
+            parser.parse("1m ~ -5s"),
+            Err(ParseError::InvalidInput(
+                "The jitter -5s must not be negative".to_owned()
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case::fixed(DurationDistribution::Fixed(secs(1)), secs(1), secs(1))]
+    #[case::uniform(uniform_distribution(secs(1), secs(5)), secs(1), secs(5))]
+    #[case::uniform_swapped(uniform_distribution(secs(5), secs(1)), secs(1), secs(5))]
+    #[case::jitter(jitter(secs(30), Multiplier(1, 1)), secs(27), secs(33))]
+    #[case::jitter_zero(jitter(secs(30), Multiplier(0, 0)), secs(30), secs(30))]
+    #[case::jitter_negative_percent(jitter(secs(30), Multiplier(-1, 1)), secs(27), secs(33))]
+    #[case::jitter_negative_base(
+        jitter(Duration::negative(30, 0), Multiplier(1, 1)),
+        Duration::negative(33, 0),
+        Duration::negative(27, 0)
+    )]
+    #[case::jitter_truncates(
+        jitter(secs(1), Multiplier(333_333, -4)),
+        Duration::positive(0, 666_667_000),
+        Duration::positive(1, 333_333_000)
+    )]
+    #[case::jitter_saturates(
+        jitter(Duration::MAX, Multiplier(1, 1)),
+        Duration::MAX.saturating_sub(multiply(Duration::MAX, Multiplier(1, -1))),
+        Duration::MAX
+    )]
+    #[case::jitter_min_exponent(jitter(secs(1), Multiplier(1, i16::MIN)), secs(1), secs(1))]
+    fn test_duration_distribution_min_and_max(
+        #[case] distribution: DurationDistribution,
+        #[case] min: Duration,
+        #[case] max: Duration,
+    ) {
+        assert_eq!(distribution.min(), min);
+        assert_eq!(distribution.max(), max);
+    }
+
+    #[rstest]
+    #[case::fixed(DurationDistribution::Fixed(secs(1)))]
+    #[case::uniform_with_equal_bounds(uniform_distribution(secs(1), secs(1)))]
+    #[case::jitter_zero(jitter(secs(1), Multiplier(0, 0)))]
+    fn test_duration_distribution_sample_when_single_duration(
+        #[case] distribution: DurationDistribution,
+    ) {
+        assert_eq!(distribution.sample(&mut Sequence(&[])), secs(1));
+    }
+
+    #[rstest]
+    #[case::first(&[0, 6], Duration::positive(0, 6))]
+    #[case::last(&[0, 9], Duration::positive(0, 9))]
+    #[case::wraps(&[0, 15], Duration::positive(0, 5))]
+    #[case::rejected_below_threshold(&[0, 5, 0, 7], Duration::positive(0, 7))]
+    #[case::high_bits(&[1, 0], Duration::positive(0, 6))]
+    fn test_duration_distribution_sample_with_sequence(
+        #[case] numbers: &[u64],
+        #[case] expected: Duration,
+    ) {
+        // 2^128 % 10 = 6, so the numbers below 6 are rejected
+        let distribution = uniform_distribution(Duration::ZERO, Duration::positive(0, 9));
+        let mut rng = Sequence(numbers);
+        assert_eq!(distribution.sample(&mut rng), expected);
+        assert!(rng.0.is_empty());
+    }
+
+    #[rstest]
+    #[case::uniform(uniform_distribution(secs(1), secs(5)))]
+    #[case::jitter(jitter(secs(30), Multiplier(1, 1)))]
+    #[case::negative(uniform_distribution(Duration::negative(2, 0), secs(1)))]
+    #[case::full(uniform_distribution(Duration::MIN, Duration::MAX))]
+    #[case::nanoseconds(uniform_distribution(Duration::ZERO, Duration::positive(0, 1)))]
+    fn test_duration_distribution_sample_is_within_bounds(
+        #[case] distribution: DurationDistribution,
+    ) {
+        let mut rng = SplitMix64(42);
+        for _ in 0..1000_u32 {
+            let sample = distribution.sample(&mut rng);
+            assert!(distribution.min() <= sample && sample <= distribution.max());
+        }
+    }
+
+    #[test]
+    fn test_duration_distribution_sample_is_deterministic() {
+        let distribution = DurationDistributionParser::new(Simple)
+            .parse("30s ± 10%")
+            .unwrap();
+
+        let mut first = SplitMix64(7);
+        let mut second = SplitMix64(7);
+        for _ in 0..100_u32 {
+            assert_eq!(
+                distribution.sample(&mut first),
+                distribution.sample(&mut second)
+            );
+        }
+        assert_eq!(
+            distribution.sample(&mut SplitMix64(0)),
+            Duration::positive(27, 821_050_564)
+        );
+    }
+
+    #[test]
+    fn test_duration_distribution_sample_covers_all_values() {
+        let distribution = uniform_distribution(Duration::ZERO, Duration::positive(0, 3));
+        let mut rng = SplitMix64(1);
+        let mut counts = [0_u32; 4];
+        for _ in 0..4000_u32 {
+            let nanos = distribution.sample(&mut rng).as_nanos();
+            counts[usize::try_from(nanos).unwrap()] += 1;
+        }
+        assert!(
+            counts.iter().all(|count| (900..1100).contains(count)),
+            "{counts:?}"
+        );
+    }
+
+    #[test]
+    fn test_duration_distribution_sample_with_dyn_random_source() {
+        let distribution = uniform_distribution(secs(1), secs(2));
+        let mut rng = SplitMix64(3);
+        let rng: &mut dyn RandomSource = &mut rng;
+        let sample = distribution.sample(rng);
+        assert!(secs(1) <= sample && sample <= secs(2));
+    }
+
+    #[test]
+    fn test_uniform_when_span_is_max() {
+        let mut rng = Sequence(&[1, 2]);
+        assert_eq!(uniform(&mut rng, u128::MAX), (1 << 64u32) | 2);
+    }
+}
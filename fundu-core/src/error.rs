@@ -3,15 +3,287 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
-//! Provide the errors used in fundu like [`ParseError`] and [`TryFromDurationError`]
+//! Provide the errors used in fundu like [`ParseError`], [`BorrowedParseError`] and
+//! [`TryFromDurationError`]
 
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+use core::fmt::Display;
+use core::str::Utf8Error;
+#[cfg(feature = "std")]
 use std::error::Error;
-use std::fmt::Display;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// A part of the input or a value derived from it, which is part of the message of an
+/// [`ErrorReason`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Detail<'input> {
+    /// A slice of the input like an invalid time unit
+    Input(&'input str),
+    /// A single character like an unexpected byte of the input
+    Char(char),
+    /// The input was not valid utf-8
+    Utf8(Utf8Error),
+}
+
+impl Detail<'_> {
+    /// Return this `Detail` with the [`Detail::Input`] borrowed from the `source`
+    ///
+    /// The input of the detail must be a slice of the `source`.
+    fn reborrow(self, source: &str) -> Detail<'_> {
+        match self {
+            Self::Input(input) => {
+                let start = (input.as_ptr() as usize).wrapping_sub(source.as_ptr() as usize);
+                Detail::Input(
+                    source
+                        .get(start..start + input.len())
+                        .expect("The input of the detail should be a slice of the source"),
+                )
+            }
+            Self::Char(c) => Detail::Char(c),
+            Self::Utf8(error) => Detail::Utf8(error),
+        }
+    }
+}
+
+impl Display for Detail<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Input(input) => f.write_str(input),
+            Self::Char(c) => c.fmt(f),
+            Self::Utf8(error) => error.fmt(f),
+        }
+    }
+}
+
+/// The reason of a [`BorrowedParseError`]
+///
+/// An `ErrorReason` consists of a static reason and an optional [`Detail`] borrowed from the input.
+/// The message is rendered lazily in the `Display` implementation, which replaces the `{}` of the
+/// static format with the detail, so creating an `ErrorReason` never allocates.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_core::error::{Detail, ErrorReason};
+///
+/// let reason = ErrorReason::with_detail(
+///     "Invalid time unit",
+///     "Invalid time unit: '{}'",
+///     Detail::Input("x"),
+/// );
+/// assert_eq!(reason.reason(), "Invalid time unit");
+/// assert_eq!(reason.detail(), Some(Detail::Input("x")));
+/// assert_eq!(reason.to_string(), "Invalid time unit: 'x'");
+///
+/// let reason = ErrorReason::new("No fraction allowed");
+/// assert_eq!(reason.to_string(), "No fraction allowed");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ErrorReason<'input> {
+    reason: &'static str,
+    format: &'static str,
+    detail: Option<Detail<'input>>,
+}
+
+impl<'input> ErrorReason<'input> {
+    /// Create a new `ErrorReason` with a static `reason` and without [`Detail`]
+    pub const fn new(reason: &'static str) -> Self {
+        Self {
+            reason,
+            format: reason,
+            detail: None,
+        }
+    }
+
+    /// Create a new `ErrorReason` with a static `reason` and a [`Detail`]
+    ///
+    /// The message is the `format` with the first `{}` replaced by the `detail`.
+    pub const fn with_detail(
+        reason: &'static str,
+        format: &'static str,
+        detail: Detail<'input>,
+    ) -> Self {
+        Self {
+            reason,
+            format,
+            detail: Some(detail),
+        }
+    }
+
+    /// Return the static reason without the [`Detail`]
+    pub const fn reason(&self) -> &'static str {
+        self.reason
+    }
+
+    /// Return the [`Detail`] if present
+    pub const fn detail(&self) -> Option<Detail<'input>> {
+        self.detail
+    }
+
+    /// Return this `ErrorReason` with the [`Detail`] borrowed from the `source`
+    fn reborrow(self, source: &str) -> ErrorReason<'_> {
+        ErrorReason {
+            reason: self.reason,
+            format: self.format,
+            detail: self.detail.map(|detail| detail.reborrow(source)),
+        }
+    }
+}
+
+impl Display for ErrorReason<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match (self.detail, self.format.split_once("{}")) {
+            (Some(detail), Some((prefix, suffix))) => {
+                f.write_str(prefix)?;
+                detail.fmt(f)?;
+                f.write_str(suffix)
+            }
+            _ => f.write_str(self.format),
+        }
+    }
+}
+
+/// The zero-copy counterpart of [`ParseError`]
+///
+/// The variants are the same as the variants of the [`ParseError`], but the reasons are
+/// [`ErrorReason`]s which borrow from the input instead of owning a `String`. Creating a
+/// `BorrowedParseError` never allocates and the `Display` implementation writes the message
+/// directly to the formatter. Use [`BorrowedParseError::into_owned`] (or `From`) to convert it
+/// into a [`ParseError`] which outlives the input.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_core::error::{BorrowedParseError, Detail, ErrorReason, ParseError};
+///
+/// let error = BorrowedParseError::TimeUnit(
+///     1,
+///     ErrorReason::with_detail(
+///         "Invalid time unit",
+///         "Invalid time unit: '{}'",
+///         Detail::Input("x"),
+///     ),
+/// );
+/// assert_eq!(
+///     error.to_string(),
+///     "Time unit error: Invalid time unit: 'x' at column 1"
+/// );
+/// assert_eq!(
+///     error.into_owned(),
+///     ParseError::TimeUnit(1, "Invalid time unit: 'x'".to_string())
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum BorrowedParseError<'input> {
+    /// See [`ParseError::Empty`]
+    Empty,
+    /// See [`ParseError::Syntax`]
+    Syntax(usize, ErrorReason<'input>),
+    /// See [`ParseError::Overflow`]
+    Overflow,
+    /// See [`ParseError::TimeUnit`]
+    TimeUnit(usize, ErrorReason<'input>),
+    /// See [`ParseError::NegativeExponentOverflow`]
+    NegativeExponentOverflow,
+    /// See [`ParseError::PositiveExponentOverflow`]
+    PositiveExponentOverflow,
+    /// See [`ParseError::NegativeNumber`]
+    NegativeNumber,
+    /// See [`ParseError::InvalidInput`]
+    InvalidInput(ErrorReason<'input>),
+}
+
+impl BorrowedParseError<'_> {
+    /// Convert this error into a [`ParseError`] which doesn't borrow from the input
+    ///
+    /// With the `alloc` feature, the message of the [`ErrorReason`] is rendered into the `String`
+    /// of [`ParseError::Syntax`], [`ParseError::TimeUnit`] or [`ParseError::InvalidInput`].
+    /// Without the `alloc` feature, the static reason is kept in [`ParseError::StaticSyntax`],
+    /// [`ParseError::StaticTimeUnit`] or [`ParseError::StaticInvalidInput`].
+    pub fn into_owned(self) -> ParseError {
+        match self {
+            Self::Empty => ParseError::Empty,
+            #[cfg(feature = "alloc")]
+            Self::Syntax(column, reason) => ParseError::Syntax(column, reason.to_string()),
+            #[cfg(not(feature = "alloc"))]
+            Self::Syntax(column, reason) => ParseError::StaticSyntax(column, reason.reason()),
+            Self::Overflow => ParseError::Overflow,
+            #[cfg(feature = "alloc")]
+            Self::TimeUnit(column, reason) => ParseError::TimeUnit(column, reason.to_string()),
+            #[cfg(not(feature = "alloc"))]
+            Self::TimeUnit(column, reason) => ParseError::StaticTimeUnit(column, reason.reason()),
+            Self::NegativeExponentOverflow => ParseError::NegativeExponentOverflow,
+            Self::PositiveExponentOverflow => ParseError::PositiveExponentOverflow,
+            Self::NegativeNumber => ParseError::NegativeNumber,
+            #[cfg(feature = "alloc")]
+            Self::InvalidInput(reason) => ParseError::InvalidInput(reason.to_string()),
+            #[cfg(not(feature = "alloc"))]
+            Self::InvalidInput(reason) => ParseError::StaticInvalidInput(reason.reason()),
+        }
+    }
+
+    /// Return this error with the [`Detail`]s borrowed from the `source`
+    ///
+    /// The parsers borrow the input for a shorter lifetime than the lifetime of the `source`. The
+    /// [`Detail::Input`]s of an error are slices of the `source`, so they can be borrowed from the
+    /// `source` instead.
+    pub(crate) fn reborrow(self, source: &str) -> BorrowedParseError<'_> {
+        match self {
+            Self::Empty => BorrowedParseError::Empty,
+            Self::Syntax(column, reason) => {
+                BorrowedParseError::Syntax(column, reason.reborrow(source))
+            }
+            Self::Overflow => BorrowedParseError::Overflow,
+            Self::TimeUnit(column, reason) => {
+                BorrowedParseError::TimeUnit(column, reason.reborrow(source))
+            }
+            Self::NegativeExponentOverflow => BorrowedParseError::NegativeExponentOverflow,
+            Self::PositiveExponentOverflow => BorrowedParseError::PositiveExponentOverflow,
+            Self::NegativeNumber => BorrowedParseError::NegativeNumber,
+            Self::InvalidInput(reason) => BorrowedParseError::InvalidInput(reason.reborrow(source)),
+        }
+    }
+}
+
+impl From<BorrowedParseError<'_>> for ParseError {
+    fn from(error: BorrowedParseError<'_>) -> Self {
+        error.into_owned()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for BorrowedParseError<'_> {}
+
+impl Display for BorrowedParseError<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Syntax(column, reason) => {
+                write!(f, "Syntax error: {reason} at column {column}")
+            }
+            Self::TimeUnit(pos, reason) => {
+                write!(f, "Time unit error: {reason} at column {pos}")
+            }
+            Self::InvalidInput(reason) => write!(f, "Invalid input: {reason}"),
+            Self::Empty => ParseError::Empty.fmt(f),
+            Self::Overflow => ParseError::Overflow.fmt(f),
+            Self::NegativeExponentOverflow => ParseError::NegativeExponentOverflow.fmt(f),
+            Self::PositiveExponentOverflow => ParseError::PositiveExponentOverflow.fmt(f),
+            Self::NegativeNumber => ParseError::NegativeNumber.fmt(f),
+        }
+    }
+}
+
 /// Error type emitted during the parsing
+///
+/// The [`ParseError::Syntax`], [`ParseError::TimeUnit`] and [`ParseError::InvalidInput`] variants
+/// own a `String` and are only available with the `alloc` feature. Without the `alloc` feature,
+/// the parsers return the allocation-free [`ParseError::StaticSyntax`],
+/// [`ParseError::StaticTimeUnit`] and [`ParseError::StaticInvalidInput`] instead, which carry the
+/// static description of the error without the parts of the input.
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[non_exhaustive]
@@ -20,6 +292,7 @@ pub enum ParseError {
     Empty,
     /// A syntax error. Syntax errors report the position (column) where it was encountered and a
     /// reason.
+    #[cfg(feature = "alloc")]
     Syntax(usize, String),
     /// Currently only used internally for overflows of the maximum Duration.
     /// TODO: Rename to positive overflow
@@ -27,6 +300,7 @@ pub enum ParseError {
     Overflow,
     /// An error concerning time units. Like [`ParseError::Syntax`]  the position where the error
     /// occurred is included.
+    #[cfg(feature = "alloc")]
     TimeUnit(usize, String),
     /// The exponent exceeded the minimum negative exponent (`-32768`)
     NegativeExponentOverflow,
@@ -36,32 +310,71 @@ pub enum ParseError {
     /// but resolve to `0`
     NegativeNumber,
     /// A generic error if no other error type fits
+    #[cfg(feature = "alloc")]
     InvalidInput(String),
+    /// The allocation-free [`ParseError::Syntax`] with a static reason. Only returned without the
+    /// `alloc` feature.
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    StaticSyntax(usize, &'static str),
+    /// The allocation-free [`ParseError::TimeUnit`] with a static reason. Only returned without
+    /// the `alloc` feature.
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    StaticTimeUnit(usize, &'static str),
+    /// The allocation-free [`ParseError::InvalidInput`] with a static reason. Only returned
+    /// without the `alloc` feature.
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    StaticInvalidInput(&'static str),
+}
+
+impl ParseError {
+    /// Return this error with the column moved by `offset`
+    ///
+    /// Used if the error occurred in a part of the input starting at `offset`.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn with_offset(self, offset: usize) -> Self {
+        match self {
+            Self::Syntax(column, reason) => Self::Syntax(offset + column, reason),
+            Self::TimeUnit(column, reason) => Self::TimeUnit(offset + column, reason),
+            Self::StaticSyntax(column, reason) => Self::StaticSyntax(offset + column, reason),
+            Self::StaticTimeUnit(column, reason) => Self::StaticTimeUnit(offset + column, reason),
+            error => error,
+        }
+    }
 }
 
+#[cfg(feature = "std")]
 impl Error for ParseError {}
 
 impl Display for ParseError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let msg = match self {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            #[cfg(feature = "alloc")]
             Self::Syntax(column, reason) => {
-                format!("Syntax error: {reason} at column {column}")
+                write!(f, "Syntax error: {reason} at column {column}")
+            }
+            Self::StaticSyntax(column, reason) => {
+                write!(f, "Syntax error: {reason} at column {column}")
             }
-            Self::Overflow => "Number overflow".to_owned(),
+            Self::Overflow => f.write_str("Number overflow"),
+            #[cfg(feature = "alloc")]
             Self::TimeUnit(pos, reason) => {
-                format!("Time unit error: {reason} at column {pos}")
+                write!(f, "Time unit error: {reason} at column {pos}")
+            }
+            Self::StaticTimeUnit(pos, reason) => {
+                write!(f, "Time unit error: {reason} at column {pos}")
             }
             Self::NegativeExponentOverflow => {
-                "Negative exponent overflow: Minimum is -32768".to_owned()
+                f.write_str("Negative exponent overflow: Minimum is -32768")
             }
             Self::PositiveExponentOverflow => {
-                "Positive exponent overflow: Maximum is +32767".to_owned()
+                f.write_str("Positive exponent overflow: Maximum is +32767")
             }
-            Self::NegativeNumber => "Number was negative".to_owned(),
-            Self::InvalidInput(reason) => format!("Invalid input: {reason}"),
-            Self::Empty => "Empty input".to_owned(),
-        };
-        f.write_str(&msg)
+            Self::NegativeNumber => f.write_str("Number was negative"),
+            #[cfg(feature = "alloc")]
+            Self::InvalidInput(reason) => write!(f, "Invalid input: {reason}"),
+            Self::StaticInvalidInput(reason) => write!(f, "Invalid input: {reason}"),
+            Self::Empty => f.write_str("Empty input"),
+        }
     }
 }
 
@@ -89,10 +402,22 @@ impl From<TryFromDurationError> for ParseError {
     }
 }
 
+impl From<TryFromDurationError> for BorrowedParseError<'_> {
+    fn from(error: TryFromDurationError) -> Self {
+        match error {
+            TryFromDurationError::NegativeDuration => Self::NegativeNumber,
+            TryFromDurationError::PositiveOverflow | TryFromDurationError::NegativeOverflow => {
+                Self::Overflow
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
 impl Error for TryFromDurationError {}
 
 impl Display for TryFromDurationError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let description = match self {
             Self::NegativeDuration => "Error converting duration: value is negative",
             Self::PositiveOverflow => {
@@ -114,16 +439,29 @@ mod tests {
 
     use super::*;
 
+    #[cfg(feature = "alloc")]
     #[rstest]
     #[case::syntax_error(
         ParseError::Syntax(10, "Invalid character".to_owned()),
         "Syntax error: Invalid character at column 10"
     )]
-    #[case::overflow(ParseError::Overflow, "Number overflow")]
     #[case::time_unit_error(
         ParseError::TimeUnit(10, "Found invalid 'y'".to_owned()),
         "Time unit error: Found invalid 'y' at column 10"
     )]
+    #[case::invalid_input(
+        ParseError::InvalidInput("Unexpected".to_owned()),
+        "Invalid input: Unexpected"
+    )]
+    fn test_error_messages_parse_error_with_string(
+        #[case] error: ParseError,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(error.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case::overflow(ParseError::Overflow, "Number overflow")]
     #[case::negative_exponent_overflow_error(
         ParseError::NegativeExponentOverflow,
         "Negative exponent overflow: Minimum is -32768"
@@ -133,11 +471,19 @@ mod tests {
         "Positive exponent overflow: Maximum is +32767"
     )]
     #[case::negative_number_error(ParseError::NegativeNumber, "Number was negative")]
-    #[case::invalid_input(
-        ParseError::InvalidInput("Unexpected".to_owned()),
-        "Invalid input: Unexpected"
-    )]
     #[case::empty(ParseError::Empty, "Empty input")]
+    #[case::static_syntax_error(
+        ParseError::StaticSyntax(10, "Invalid character"),
+        "Syntax error: Invalid character at column 10"
+    )]
+    #[case::static_time_unit_error(
+        ParseError::StaticTimeUnit(10, "Invalid time unit"),
+        "Time unit error: Invalid time unit at column 10"
+    )]
+    #[case::static_invalid_input(
+        ParseError::StaticInvalidInput("Invalid input"),
+        "Invalid input: Invalid input"
+    )]
     fn test_error_messages_parse_error(#[case] error: ParseError, #[case] expected: &str) {
         assert_eq!(error.to_string(), expected);
     }
@@ -170,6 +516,143 @@ mod tests {
         assert_eq!(error.to_string(), expected);
     }
 
+    #[rstest]
+    #[case::without_detail(ErrorReason::new("No fraction allowed"), "No fraction allowed")]
+    #[case::input(
+        ErrorReason::with_detail(
+            "Invalid time unit",
+            "Invalid time unit: '{}'",
+            Detail::Input("x")
+        ),
+        "Invalid time unit: 'x'"
+    )]
+    #[case::char(
+        ErrorReason::with_detail(
+            "Invalid character",
+            "Invalid character: '{}'",
+            Detail::Char('%')
+        ),
+        "Invalid character: '%'"
+    )]
+    #[case::format_without_placeholder(
+        ErrorReason::with_detail("Invalid input", "Invalid input", Detail::Char('%')),
+        "Invalid input"
+    )]
+    fn test_error_reason_display(#[case] reason: ErrorReason, #[case] expected: &str) {
+        assert_eq!(reason.to_string(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case::syntax(
+        BorrowedParseError::Syntax(
+            1,
+            ErrorReason::with_detail(
+                "Invalid character",
+                "Invalid character: '{}'",
+                Detail::Char('%')
+            )
+        ),
+        ParseError::Syntax(1, "Invalid character: '%'".to_owned())
+    )]
+    #[case::time_unit(
+        BorrowedParseError::TimeUnit(
+            2,
+            ErrorReason::with_detail(
+                "Invalid time unit",
+                "Invalid time unit: '{}'",
+                Detail::Input("y")
+            )
+        ),
+        ParseError::TimeUnit(2, "Invalid time unit: 'y'".to_owned())
+    )]
+    #[case::invalid_input(
+        BorrowedParseError::InvalidInput(ErrorReason::new("Unexpected")),
+        ParseError::InvalidInput("Unexpected".to_owned())
+    )]
+    fn test_borrowed_parse_error_into_owned_with_string(
+        #[case] error: BorrowedParseError,
+        #[case] expected: ParseError,
+    ) {
+        assert_eq!(error.to_string(), expected.to_string());
+        assert_eq!(error.into_owned(), expected);
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    #[rstest]
+    #[case::syntax(
+        BorrowedParseError::Syntax(
+            1,
+            ErrorReason::with_detail(
+                "Invalid character",
+                "Invalid character: '{}'",
+                Detail::Char('%')
+            )
+        ),
+        ParseError::StaticSyntax(1, "Invalid character")
+    )]
+    #[case::time_unit(
+        BorrowedParseError::TimeUnit(
+            2,
+            ErrorReason::with_detail(
+                "Invalid time unit",
+                "Invalid time unit: '{}'",
+                Detail::Input("y")
+            )
+        ),
+        ParseError::StaticTimeUnit(2, "Invalid time unit")
+    )]
+    #[case::invalid_input(
+        BorrowedParseError::InvalidInput(ErrorReason::new("Unexpected")),
+        ParseError::StaticInvalidInput("Unexpected")
+    )]
+    fn test_borrowed_parse_error_into_owned_without_alloc(
+        #[case] error: BorrowedParseError,
+        #[case] expected: ParseError,
+    ) {
+        assert_eq!(error.into_owned(), expected);
+    }
+
+    #[rstest]
+    #[case::overflow(BorrowedParseError::Overflow, ParseError::Overflow)]
+    #[case::negative_exponent_overflow(
+        BorrowedParseError::NegativeExponentOverflow,
+        ParseError::NegativeExponentOverflow
+    )]
+    #[case::positive_exponent_overflow(
+        BorrowedParseError::PositiveExponentOverflow,
+        ParseError::PositiveExponentOverflow
+    )]
+    #[case::negative_number(BorrowedParseError::NegativeNumber, ParseError::NegativeNumber)]
+    #[case::empty(BorrowedParseError::Empty, ParseError::Empty)]
+    fn test_borrowed_parse_error_into_owned_and_display(
+        #[case] error: BorrowedParseError,
+        #[case] expected: ParseError,
+    ) {
+        assert_eq!(error.to_string(), expected.to_string());
+        assert_eq!(error.into_owned(), expected);
+    }
+
+    #[test]
+    fn test_borrowed_parse_error_reborrow() {
+        let source = "1s 2x";
+        let error = BorrowedParseError::TimeUnit(
+            4,
+            ErrorReason::with_detail(
+                "Invalid time unit",
+                "Invalid time unit: '{}'",
+                Detail::Input(&source[4..]),
+            ),
+        );
+        match error.reborrow(source) {
+            BorrowedParseError::TimeUnit(4, reason) => match reason.detail() {
+                Some(Detail::Input(input)) => assert_eq!(input.as_ptr(), source[4..].as_ptr()),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_serde_try_from_duration_error() {
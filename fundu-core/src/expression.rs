@@ -0,0 +1,1083 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Evaluate arithmetic expressions over durations like `2 * (1h + 15m) - 30s`
+//!
+//! The [`ExpressionParser`] parses the durations within an expression with any parser implementing
+//! [`ParseDurationPrefix`], so the grammar of the durations is exactly the grammar of the
+//! configured parser. See [`ExpressionParser`] for the operators and functions.
+
+use alloc::format;
+use core::time::Duration as StdDuration;
+
+use crate::error::ParseError;
+use crate::parse::NANOS_PER_SEC_U128;
+use crate::stream::{ParseDuration, ParseDurationPrefix};
+use crate::time::{Duration, Multiplier, TimeUnit, DEFAULT_TIME_UNIT};
+
+/// The maximum nesting depth of parentheses, functions and unary operators
+const MAX_DEPTH: usize = 64;
+
+/// The comparison operators ordered such that `<=` is found before `<`
+#[allow(clippy::type_complexity)]
+const COMPARISONS: [(&str, fn(&Duration, &Duration) -> bool); 4] = [
+    ("<=", PartialOrd::le),
+    ("<", PartialOrd::lt),
+    (">=", PartialOrd::ge),
+    (">", PartialOrd::gt),
+];
+
+/// The functions taking one or more arguments
+#[allow(clippy::type_complexity)]
+const FUNCTIONS: [(&str, fn(Duration, Duration) -> Duration); 2] =
+    [("min", Ord::min), ("max", Ord::max)];
+
+/// A parser for arithmetic expressions over durations
+///
+/// An expression consists of durations, numbers, operators and functions:
+///
+/// * `+` and `-` add and subtract durations like in `1d - 1s`
+/// * `*` multiplies a duration with a number like in `3 * 5min` and `/` divides a duration by a
+///   number like in `1h / 4`
+/// * `(` and `)` group expressions like in `2 * (1h + 15m)`
+/// * `min(...)` and `max(...)` evaluate to the minimum and maximum of one or more comma separated
+///   expressions like in `max(1s, 10 * 50ms)`
+/// * `<`, `<=`, `>` and `>=` compare the value with a limit like in `2 * 45m <= 2h`. The
+///   comparison evaluates to the value on the left side or results in a
+///   [`ParseError::InvalidInput`] if it doesn't hold. Multiple comparisons like in
+///   `5m * 3 >= 1m <= 1h` compare the value with each limit.
+/// * A leading `+` or `-` is the sign of the following operand like in `-(1h + 1m)`
+///
+/// Multiplication and division bind stronger than addition and subtraction and comparisons bind
+/// weakest. All operators are left associative. The operands are parsed with the parser, so
+/// everything the parser accepts is a duration. A number without time unit like `3`, `1.5` or
+/// `1e3` is a number. Wherever a duration is needed, like in `1h + 30`, as argument of a function
+/// or as the result of the expression, the number is a duration in the
+/// [`ExpressionParser::default_unit`]. Whitespace may occur between all operators and operands.
+///
+/// The expression is evaluated exactly with [`Duration`] and [`Multiplier`] without floating
+/// point arithmetic. Like the parsers, durations are truncated to nanoseconds and saturate at
+/// [`Duration::MIN`] and [`Duration::MAX`]. Negative results are an error unless
+/// [`ExpressionParser::allow_negative`] is set.
+///
+/// # Problems
+///
+/// The parser must stop in front of the operators. A parser which parses multiple durations
+/// consumes `1s + 1s` or `1s - 1s` as a single duration if signs can be separated from the number
+/// by a delimiter.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_core::error::ParseError;
+/// use fundu_core::expression::ExpressionParser;
+/// use fundu_core::stream::ParseDurationPrefix;
+/// use fundu_core::time::Duration;
+///
+/// // A parser for whole minutes like `10m`
+/// struct Minutes;
+///
+/// impl ParseDurationPrefix for Minutes {
+///     fn parse_duration_prefix<'s>(
+///         &self,
+///         source: &'s str,
+///     ) -> Result<(Duration, &'s str), ParseError> {
+///         let end = source
+///             .find(|c: char| !c.is_ascii_digit())
+///             .unwrap_or(source.len());
+///         let minutes: u64 = source[..end]
+///             .parse()
+///             .map_err(|_| ParseError::Syntax(0, "Expected a number".to_string()))?;
+///         match source[end..].strip_prefix('m') {
+///             Some(rest) => Ok((Duration::positive(minutes * 60, 0), rest)),
+///             None => Ok((Duration::positive(minutes, 0), &source[end..])),
+///         }
+///     }
+/// }
+///
+/// let parser = ExpressionParser::new(Minutes);
+/// assert_eq!(parser.parse("2 * (60m + 15m) - 30"), Ok(Duration::positive(8970, 0)));
+/// assert_eq!(parser.parse("3 * 5m"), Ok(Duration::positive(900, 0)));
+/// assert_eq!(parser.parse("1m / 8"), Ok(Duration::positive(7, 500_000_000)));
+/// assert_eq!(parser.parse("min(10m, 2 * 4m)"), Ok(Duration::positive(480, 0)));
+/// assert_eq!(parser.parse("2 * 45m <= 120m"), Ok(Duration::positive(5400, 0)));
+/// assert_eq!(
+///     parser.parse("2 * 45m <= 60m"),
+///     Err(ParseError::InvalidInput(
+///         "The duration 1h 30m is not <= 1h".to_string()
+///     ))
+/// );
+/// assert_eq!(parser.parse("1m - 2m"), Err(ParseError::NegativeNumber));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ExpressionParser<P> {
+    parser: P,
+    default_unit: TimeUnit,
+    allow_negative: bool,
+}
+
+impl<P: ParseDurationPrefix> ExpressionParser<P> {
+    /// Create a new `ExpressionParser` which parses the durations with the `parser`
+    pub const fn new(parser: P) -> Self {
+        Self {
+            parser,
+            default_unit: DEFAULT_TIME_UNIT,
+            allow_negative: false,
+        }
+    }
+
+    /// Set the [`TimeUnit`] of numbers which are used as duration (Default: `TimeUnit::Second`)
+    ///
+    /// This should be the same time unit the parser uses for numbers without time unit.
+    pub const fn default_unit(mut self, time_unit: TimeUnit) -> Self {
+        self.default_unit = time_unit;
+        self
+    }
+
+    /// Allow negative results of the expression (Default: `false`)
+    ///
+    /// Without this setting, a negative result is a [`ParseError::NegativeNumber`]. Intermediate
+    /// results may be negative regardless of this setting, so `1d - 1s` and `-1s + 1d` are both
+    /// valid.
+    pub const fn allow_negative(mut self) -> Self {
+        self.allow_negative = true;
+        self
+    }
+
+    /// Return the parser of this `ExpressionParser`
+    pub const fn parser(&self) -> &P {
+        &self.parser
+    }
+
+    /// Parse and evaluate the expression in the `source` string
+    pub fn parse(&self, source: &str) -> Result<Duration, ParseError> {
+        if source.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let mut evaluator = Evaluator {
+            expression: self,
+            source,
+            position: 0,
+            depth: 0,
+        };
+        let value = evaluator.parse_comparison()?;
+        evaluator.skip_whitespace();
+        if evaluator.position < source.len() {
+            return Err(evaluator.unexpected("an operator"));
+        }
+
+        let duration = self.to_duration(value);
+        if !self.allow_negative && duration.is_negative() {
+            Err(ParseError::NegativeNumber)
+        } else {
+            Ok(duration)
+        }
+    }
+
+    /// Convert the `value` into a duration using the default unit for numbers
+    fn to_duration(&self, value: Value) -> Duration {
+        match value {
+            Value::Number(multiplier) => multiply(
+                multiply(Duration::positive(1, 0), self.default_unit.multiplier()),
+                multiplier,
+            ),
+            Value::Duration(duration) => duration,
+        }
+    }
+}
+
+impl<P: ParseDurationPrefix> ParseDuration for ExpressionParser<P> {
+    fn parse_duration(&self, source: &str) -> Result<Duration, ParseError> {
+        self.parse(source)
+    }
+}
+
+/// An intermediate value of the expression
+#[derive(Debug, Clone, Copy)]
+enum Value {
+    /// A number without time unit
+    Number(Multiplier),
+    Duration(Duration),
+}
+
+/// The recursive descent evaluator of a single expression
+struct Evaluator<'e, 's, P> {
+    expression: &'e ExpressionParser<P>,
+    source: &'s str,
+    position: usize,
+    depth: usize,
+}
+
+impl<'s, P: ParseDurationPrefix> Evaluator<'_, 's, P> {
+    #[inline]
+    fn rest(&self) -> &'s str {
+        &self.source[self.position..]
+    }
+
+    #[inline]
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len()
+            - rest
+                .trim_start_matches(|c: char| c.is_ascii_whitespace())
+                .len();
+    }
+
+    /// Skip the whitespace and consume the `token` if present. Return true if it was found.
+    fn consume(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.position += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn unexpected(&self, expected: &str) -> ParseError {
+        match self.rest().chars().next() {
+            Some(c) => ParseError::Syntax(
+                self.position,
+                format!("Expected {expected} but found '{c}'"),
+            ),
+            None => ParseError::Syntax(
+                self.position,
+                format!("Expected {expected} but reached the end of input"),
+            ),
+        }
+    }
+
+    fn parse_comparison(&mut self) -> Result<Value, ParseError> {
+        let value = self.parse_sum()?;
+        'outer: loop {
+            for (operator, compare) in COMPARISONS {
+                if self.consume(operator) {
+                    let limit = self.expression.to_duration(self.parse_sum()?);
+                    let duration = self.expression.to_duration(value);
+                    if !compare(&duration, &limit) {
+                        return Err(ParseError::InvalidInput(format!(
+                            "The duration {duration} is not {operator} {limit}"
+                        )));
+                    }
+                    continue 'outer;
+                }
+            }
+            return Ok(value);
+        }
+    }
+
+    fn parse_sum(&mut self) -> Result<Value, ParseError> {
+        let mut value = self.parse_product()?;
+        loop {
+            let column = self.position;
+            value = if self.consume("+") {
+                let rhs = self.parse_product()?;
+                self.add(value, rhs)?
+            } else if self.consume("-") {
+                let rhs = self.parse_product()?;
+                self.add(value, negate(rhs, column)?)?
+            } else {
+                return Ok(value);
+            };
+        }
+    }
+
+    fn parse_product(&mut self) -> Result<Value, ParseError> {
+        let mut value = self.parse_unary()?;
+        loop {
+            self.skip_whitespace();
+            let column = self.position;
+            value = if self.consume("*") {
+                let rhs = self.parse_unary()?;
+                Self::multiply(value, rhs, column)?
+            } else if self.consume("/") {
+                let rhs = self.parse_unary()?;
+                self.divide(value, rhs, column)?
+            } else {
+                return Ok(value);
+            };
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<Value, ParseError> {
+        self.skip_whitespace();
+        if self.depth == MAX_DEPTH {
+            return Err(ParseError::Syntax(
+                self.position,
+                format!("The expression exceeds the maximum nesting depth of {MAX_DEPTH}"),
+            ));
+        }
+
+        self.depth += 1;
+        let column = self.position;
+        let result = if self.consume("-") {
+            self.parse_unary().and_then(|value| negate(value, column))
+        } else if self.consume("+") {
+            self.parse_unary()
+        } else {
+            self.parse_primary()
+        };
+        self.depth -= 1;
+        result
+    }
+
+    fn parse_primary(&mut self) -> Result<Value, ParseError> {
+        if self.consume("(") {
+            let value = self.parse_comparison()?;
+            return if self.consume(")") {
+                Ok(value)
+            } else {
+                Err(self.unexpected("')'"))
+            };
+        }
+
+        for (name, function) in FUNCTIONS {
+            if let Some(rest) = self.rest().strip_prefix(name) {
+                if rest
+                    .trim_start_matches(|c: char| c.is_ascii_whitespace())
+                    .starts_with('(')
+                {
+                    self.position += name.len();
+                    self.consume("(");
+                    return self.parse_arguments(function);
+                }
+            }
+        }
+
+        match self.rest().chars().next() {
+            Some(c) if c.is_ascii_digit() || c == '.' || is_identifier(c) => self.parse_operand(),
+            _ => Err(self.unexpected("a duration, a number or '('")),
+        }
+    }
+
+    /// Parse the arguments of a function after the opening parenthesis
+    fn parse_arguments(
+        &mut self,
+        function: fn(Duration, Duration) -> Duration,
+    ) -> Result<Value, ParseError> {
+        let mut duration = self.expression.to_duration(self.parse_comparison()?);
+        while self.consume(",") {
+            duration = function(
+                duration,
+                self.expression.to_duration(self.parse_comparison()?),
+            );
+        }
+        if self.consume(")") {
+            Ok(Value::Duration(duration))
+        } else {
+            Err(self.unexpected("',' or ')'"))
+        }
+    }
+
+    /// Parse a duration with the parser or a number without time unit
+    fn parse_operand(&mut self) -> Result<Value, ParseError> {
+        let column = self.position;
+        let rest = self.rest();
+        let number = &rest[..number_length(rest)];
+
+        let result =
+            self.expression
+                .parser
+                .parse_duration_prefix(rest)
+                .map(|(duration, remainder)| {
+                    let length = rest[..rest.len() - remainder.len()].trim_end().len();
+                    (duration, length)
+                });
+        match result {
+            Ok((duration, length)) if length > number.len() => {
+                self.position += length;
+                Ok(Value::Duration(duration))
+            }
+            Err(error) if number.is_empty() || rest[number.len()..].starts_with(is_identifier) => {
+                Err(error.with_offset(column))
+            }
+            _ if !number.is_empty() => {
+                let multiplier = parse_number(number, column)?;
+                self.position += number.len();
+                Ok(Value::Number(multiplier))
+            }
+            // The parser didn't consume anything
+            _ => Err(self.unexpected("a duration, a number or '('")),
+        }
+    }
+
+    fn add(&self, lhs: Value, rhs: Value) -> Result<Value, ParseError> {
+        match (lhs, rhs) {
+            (Value::Number(lhs), Value::Number(rhs)) => add_numbers(lhs, rhs)
+                .map(Value::Number)
+                .ok_or(ParseError::Overflow),
+            (lhs, rhs) => Ok(Value::Duration(
+                self.expression
+                    .to_duration(lhs)
+                    .saturating_add(self.expression.to_duration(rhs)),
+            )),
+        }
+    }
+
+    fn multiply(lhs: Value, rhs: Value, column: usize) -> Result<Value, ParseError> {
+        match (lhs, rhs) {
+            (Value::Number(lhs), Value::Number(rhs)) => lhs
+                .checked_mul(rhs)
+                .map(Value::Number)
+                .ok_or(ParseError::Overflow),
+            (Value::Duration(duration), Value::Number(multiplier))
+            | (Value::Number(multiplier), Value::Duration(duration)) => {
+                Ok(Value::Duration(multiply(duration, multiplier)))
+            }
+            (Value::Duration(_), Value::Duration(_)) => Err(ParseError::Syntax(
+                column,
+                "Cannot multiply a duration with a duration".into(),
+            )),
+        }
+    }
+
+    fn divide(&self, lhs: Value, rhs: Value, column: usize) -> Result<Value, ParseError> {
+        match rhs {
+            Value::Number(Multiplier(0, _)) => {
+                Err(ParseError::Syntax(column, "Division by zero".into()))
+            }
+            Value::Number(divisor) => Ok(Value::Duration(divide(
+                self.expression.to_duration(lhs),
+                divisor,
+            ))),
+            Value::Duration(_) => Err(ParseError::Syntax(
+                column,
+                "Cannot divide by a duration".into(),
+            )),
+        }
+    }
+}
+
+/// Return true if the character can be part of a time unit or keyword identifier
+#[inline]
+fn is_identifier(c: char) -> bool {
+    c.is_ascii_alphabetic() || !c.is_ascii()
+}
+
+fn negate(value: Value, column: usize) -> Result<Value, ParseError> {
+    match value {
+        Value::Number(Multiplier(coefficient, exponent)) => coefficient
+            .checked_neg()
+            .map(|coefficient| Value::Number(Multiplier(coefficient, exponent)))
+            .ok_or_else(|| ParseError::Syntax(column, "Cannot negate the number".into())),
+        Value::Duration(duration) => Ok(Value::Duration(-duration)),
+    }
+}
+
+/// Return the length of the number like `1`, `1.5`, `.5` or `1e-3` at the start of `source`
+pub(crate) fn number_length(source: &str) -> usize {
+    let bytes = source.as_bytes();
+    let digits = |start: usize| {
+        start
+            + bytes[start.min(bytes.len())..]
+                .iter()
+                .take_while(|byte| byte.is_ascii_digit())
+                .count()
+    };
+
+    let mut end = digits(0);
+    match bytes.get(end) {
+        Some(b'.') => {
+            let fract_end = digits(end + 1);
+            if end == 0 && fract_end == 1 {
+                return 0;
+            }
+            end = fract_end;
+        }
+        _ if end == 0 => return 0,
+        _ => {}
+    }
+
+    if matches!(bytes.get(end), Some(b'e' | b'E')) {
+        let exponent_start = match bytes.get(end + 1) {
+            Some(b'+' | b'-') => end + 2,
+            _ => end + 1,
+        };
+        let exponent_end = digits(exponent_start);
+        if exponent_end > exponent_start {
+            end = exponent_end;
+        }
+    }
+    end
+}
+
+/// Parse the `number` found with [`number_length`] into an exact [`Multiplier`]
+pub(crate) fn parse_number(number: &str, column: usize) -> Result<Multiplier, ParseError> {
+    let (mantissa, exponent) = match number.find(['e', 'E']) {
+        Some(index) => (&number[..index], Some(&number[index + 1..])),
+        None => (number, None),
+    };
+    let exponent = match exponent {
+        Some(exponent) => exponent.parse::<i64>().map_err(|_| {
+            if exponent.starts_with('-') {
+                ParseError::NegativeExponentOverflow
+            } else {
+                ParseError::PositiveExponentOverflow
+            }
+        })?,
+        None => 0,
+    };
+    let (whole, fract) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    // Trailing zeros are moved into the exponent, so only the significant digits need to fit into
+    // the coefficient
+    let mut coefficient: i64 = 0;
+    let mut zeros: u32 = 0;
+    for byte in whole.bytes().chain(fract.bytes()) {
+        let digit = i64::from(byte - b'0');
+        if digit == 0 {
+            zeros += 1;
+        } else if coefficient == 0 {
+            coefficient = digit;
+            zeros = 0;
+        } else {
+            coefficient = 10_i64
+                .checked_pow(zeros + 1)
+                .and_then(|pow| coefficient.checked_mul(pow))
+                .and_then(|coefficient| coefficient.checked_add(digit))
+                .ok_or_else(|| {
+                    ParseError::Syntax(
+                        column,
+                        format!("The number has too many significant digits: '{number}'"),
+                    )
+                })?;
+            zeros = 0;
+        }
+    }
+    if coefficient == 0 {
+        return Ok(Multiplier(0, 0));
+    }
+
+    // The length of the source string is far below `i64::MAX`
+    #[allow(clippy::cast_possible_wrap)]
+    let exponent = exponent
+        .checked_add(i64::from(zeros) - fract.len() as i64)
+        .ok_or(ParseError::PositiveExponentOverflow)?;
+    i16::try_from(exponent)
+        .map(|exponent| Multiplier(coefficient, exponent))
+        .map_err(|_| {
+            if exponent.is_negative() {
+                ParseError::NegativeExponentOverflow
+            } else {
+                ParseError::PositiveExponentOverflow
+            }
+        })
+}
+
+/// Add the two numbers exactly or return `None` if the coefficient overflows
+fn add_numbers(lhs: Multiplier, rhs: Multiplier) -> Option<Multiplier> {
+    let exponent = lhs.1.min(rhs.1);
+    let scale = |Multiplier(coefficient, e): Multiplier| {
+        if coefficient == 0 {
+            Some(0)
+        } else {
+            10_i64
+                .checked_pow(u32::from(e.abs_diff(exponent)))
+                .and_then(|pow| coefficient.checked_mul(pow))
+        }
+    };
+    scale(lhs)?
+        .checked_add(scale(rhs)?)
+        .map(|coefficient| Multiplier(coefficient, exponent))
+}
+
+#[inline]
+fn pow10(exponent: u32) -> Option<u128> {
+    10_u128.checked_pow(exponent)
+}
+
+/// Return `a * b / d` truncated or `None` if the result overflows
+///
+/// The divisor `d` must be less than `2^127`.
+fn mul_div(a: u128, b: u128, d: u128) -> Option<u128> {
+    if let Some(product) = a.checked_mul(b) {
+        return Some(product / d);
+    }
+
+    // (a * b) / d = (a / d) * b + ((a % d) * b) / d and the last term is computed bitwise
+    let remainder = a % d;
+    let (mut quotient, mut rest) = (0_u128, 0_u128);
+    for bit in (0..u128::BITS).rev() {
+        quotient = quotient.checked_mul(2)?;
+        rest *= 2;
+        if rest >= d {
+            rest -= d;
+            quotient += 1;
+        }
+        if (b >> bit) & 1 == 1 {
+            rest += remainder;
+            if rest >= d {
+                rest -= d;
+                quotient += 1;
+            }
+        }
+    }
+    (a / d).checked_mul(b)?.checked_add(quotient)
+}
+
+/// Create a saturating [`Duration`] from the absolute amount of `nanos`
+pub(crate) fn from_nanos(is_negative: bool, nanos: Option<u128>) -> Duration {
+    nanos
+        .and_then(|nanos| {
+            let secs = u64::try_from(nanos / NANOS_PER_SEC_U128).ok()?;
+            let subsec_nanos = u32::try_from(nanos % NANOS_PER_SEC_U128).ok()?;
+            Some(Duration::from_std(
+                is_negative && nanos > 0,
+                StdDuration::new(secs, subsec_nanos),
+            ))
+        })
+        .unwrap_or(if is_negative {
+            Duration::MIN
+        } else {
+            Duration::MAX
+        })
+}
+
+/// Multiply the `duration` with the `multiplier` truncating below nanoseconds
+pub(crate) fn multiply(
+    duration: Duration,
+    Multiplier(coefficient, exponent): Multiplier,
+) -> Duration {
+    const POW10_MAX: u32 = 38;
+
+    let is_negative = duration.is_negative() != coefficient.is_negative();
+    let nanos = duration.as_nanos().unsigned_abs();
+    let coefficient = u128::from(coefficient.unsigned_abs());
+
+    let nanos = if nanos == 0 || coefficient == 0 {
+        Some(0)
+    } else if exponent >= 0 {
+        pow10(u32::from(exponent.unsigned_abs()))
+            .and_then(|pow| nanos.checked_mul(coefficient)?.checked_mul(pow))
+    } else {
+        // The divisor 10^38 is less than 2^127 as required by `mul_div`. The product of the nanos
+        // and the coefficient is less than 10^48, so the remaining division is a truncation.
+        let shift = u32::from(exponent.unsigned_abs());
+        let divisor = pow10(shift.min(POW10_MAX)).unwrap();
+        mul_div(nanos, coefficient, divisor)
+            .map(|nanos| pow10(shift.saturating_sub(POW10_MAX)).map_or(0, |pow| nanos / pow))
+    };
+    from_nanos(is_negative, nanos)
+}
+
+/// Divide the `duration` by the `divisor` truncating below nanoseconds
+///
+/// The coefficient of the `divisor` must not be zero.
+fn divide(duration: Duration, Multiplier(coefficient, exponent): Multiplier) -> Duration {
+    let is_negative = duration.is_negative() != coefficient.is_negative();
+    let nanos = duration.as_nanos().unsigned_abs();
+    let divisor = u128::from(coefficient.unsigned_abs());
+
+    if exponent >= 0 {
+        // A divisor greater than 10^38 is greater than any amount of nanos
+        let nanos =
+            pow10(u32::from(exponent.unsigned_abs())).map_or(0, |pow| nanos / divisor / pow);
+        return from_nanos(is_negative, Some(nanos));
+    }
+
+    // The result is nanos * 10^-exponent / divisor computed as long division, since the product
+    // may overflow. The quotient only grows, so an overflow saturates.
+    let (mut quotient, mut remainder) = (Some(nanos / divisor), nanos % divisor);
+    if nanos > 0 {
+        for _ in 0..exponent.unsigned_abs() {
+            remainder *= 10;
+            quotient = quotient.and_then(|q| q.checked_mul(10)?.checked_add(remainder / divisor));
+            if quotient.is_none() {
+                break;
+            }
+            remainder %= divisor;
+        }
+    }
+    from_nanos(is_negative, quotient)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Parses whole numbers with an optional time unit `ms`, `s`, `m` or `h`
+    struct Simple;
+
+    impl ParseDurationPrefix for Simple {
+        fn parse_duration_prefix<'s>(
+            &self,
+            source: &'s str,
+        ) -> Result<(Duration, &'s str), ParseError> {
+            let end = source
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(source.len());
+            if end == 0 {
+                return Err(ParseError::Syntax(0, "Expected a number".to_owned()));
+            }
+            let rest = &source[end..];
+            let unit_end = rest
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(rest.len());
+            let millis = match &rest[..unit_end] {
+                "" | "s" => 1000,
+                "ms" => 1,
+                "m" => 60_000,
+                "h" => 3_600_000,
+                unit => {
+                    return Err(ParseError::TimeUnit(
+                        end,
+                        format!("Invalid time unit: '{unit}'"),
+                    ))
+                }
+            };
+            let millis = source[..end]
+                .parse::<u64>()
+                .ok()
+                .and_then(|number| number.checked_mul(millis))
+                .ok_or(ParseError::Overflow)?;
+            Ok((
+                Duration::positive(millis / 1000, (millis % 1000) as u32 * 1_000_000),
+                &rest[unit_end..],
+            ))
+        }
+    }
+
+    #[rstest]
+    #[case::duration("1h", Duration::positive(3600, 0))]
+    #[case::number("90", Duration::positive(90, 0))]
+    #[case::number_with_fraction("1.5", Duration::positive(1, 500_000_000))]
+    #[case::number_with_exponent("1e3", Duration::positive(1000, 0))]
+    #[case::addition("1h + 15m", Duration::positive(4500, 0))]
+    #[case::subtraction("1h - 1s", Duration::positive(3599, 0))]
+    #[case::subtraction_to_zero("1s - 1000ms", Duration::ZERO)]
+    #[case::multiplication("3 * 5m", Duration::positive(900, 0))]
+    #[case::multiplication_with_duration_first("5m * 3", Duration::positive(900, 0))]
+    #[case::multiplication_with_fraction("1s * 1.5", Duration::positive(1, 500_000_000))]
+    #[case::multiplication_to_nanos("1s * 1e-9", Duration::positive(0, 1))]
+    #[case::multiplication_truncates("1s * 1e-10", Duration::ZERO)]
+    #[case::division("1h / 4", Duration::positive(900, 0))]
+    #[case::division_by_fraction("1h / 0.5", Duration::positive(7200, 0))]
+    #[case::division_truncates("1s / 3", Duration::positive(0, 333_333_333))]
+    #[case::division_of_number("90 / 2", Duration::positive(45, 0))]
+    #[case::precedence("1s + 2 * 3s", Duration::positive(7, 0))]
+    #[case::left_associative("1m - 10s - 10s", Duration::positive(40, 0))]
+    #[case::left_associative_division("1m / 2 / 3", Duration::positive(10, 0))]
+    #[case::parentheses("2 * (1h + 15m) - 30s", Duration::positive(8970, 0))]
+    #[case::nested_parentheses("((1s))", Duration::positive(1, 0))]
+    #[case::number_arithmetic("(2 + 1) * 1m", Duration::positive(180, 0))]
+    #[case::number_multiplication("2 * 3 * 1s", Duration::positive(6, 0))]
+    #[case::number_subtraction("(1 - 0.5) * 1m", Duration::positive(30, 0))]
+    #[case::number_as_duration("1h + 30", Duration::positive(3630, 0))]
+    #[case::unary_minus("-1s + 1m", Duration::positive(59, 0))]
+    #[case::unary_minus_of_parentheses("1h + -(1m + 1s)", Duration::positive(3539, 0))]
+    #[case::double_minus("1m - -1s", Duration::positive(61, 0))]
+    #[case::unary_plus("+1s", Duration::positive(1, 0))]
+    #[case::negative_number("1m * -1 * -1", Duration::positive(60, 0))]
+    #[case::min("min(1s, 10 * 50ms)", Duration::positive(0, 500_000_000))]
+    #[case::max("max(1s, 10 * 50ms)", Duration::positive(1, 0))]
+    #[case::max_of_single_argument("max(1)", Duration::positive(1, 0))]
+    #[case::min_of_many_arguments("min( 1m , 2m , 30s )", Duration::positive(30, 0))]
+    #[case::function_in_product("2 * max(1s, 2s)", Duration::positive(4, 0))]
+    #[case::function_with_whitespace("min (1s)", Duration::positive(1, 0))]
+    #[case::comparison("1h <= 1h", Duration::positive(3600, 0))]
+    #[case::comparison_less("1m < 1h", Duration::positive(60, 0))]
+    #[case::comparison_greater("1h > 1m", Duration::positive(3600, 0))]
+    #[case::comparison_greater_or_equal("1h >= 60m", Duration::positive(3600, 0))]
+    #[case::comparisons("5m * 3 >= 1m <= 1h", Duration::positive(900, 0))]
+    #[case::comparison_with_expression("2 * 45m <= 1h + 30m", Duration::positive(5400, 0))]
+    #[case::comparison_in_parentheses("(1m < 2m) + 1s", Duration::positive(61, 0))]
+    #[case::whitespace("\t1s\n+  2s ", Duration::positive(3, 0))]
+    #[case::without_whitespace("2*(1h+15m)-30s", Duration::positive(8970, 0))]
+    #[case::saturates("1e30", Duration::MAX)]
+    #[case::saturates_with_addition("1e30 + 1s", Duration::MAX)]
+    #[case::saturates_with_multiplication("1e30 * 1e30 * 1s", Duration::MAX)]
+    fn test_expression_parser(#[case] source: &str, #[case] expected: Duration) {
+        assert_eq!(ExpressionParser::new(Simple).parse(source), Ok(expected));
+    }
+
+    #[rstest]
+    #[case::empty("", ParseError::Empty)]
+    #[case::only_whitespace(
+        " ",
+        ParseError::Syntax(
+            1,
+            "Expected a duration, a number or '(' but reached the end of input".to_owned()
+        )
+    )]
+    #[case::missing_operand(
+        "1s +",
+        ParseError::Syntax(
+            4,
+            "Expected a duration, a number or '(' but reached the end of input".to_owned()
+        )
+    )]
+    #[case::invalid_operand(
+        "1s + #",
+        ParseError::Syntax(5, "Expected a duration, a number or '(' but found '#'".to_owned())
+    )]
+    #[case::missing_operator(
+        "1s 2s",
+        ParseError::Syntax(3, "Expected an operator but found '2'".to_owned())
+    )]
+    #[case::unclosed_parenthesis(
+        "(1s",
+        ParseError::Syntax(3, "Expected ')' but reached the end of input".to_owned())
+    )]
+    #[case::unopened_parenthesis(
+        "1s)",
+        ParseError::Syntax(2, "Expected an operator but found ')'".to_owned())
+    )]
+    #[case::unclosed_function(
+        "min(1s",
+        ParseError::Syntax(6, "Expected ',' or ')' but reached the end of input".to_owned())
+    )]
+    #[case::function_without_arguments(
+        "max()",
+        ParseError::Syntax(4, "Expected a duration, a number or '(' but found ')'".to_owned())
+    )]
+    #[case::function_without_parentheses(
+        "min",
+        ParseError::Syntax(0, "Expected a number".to_owned())
+    )]
+    #[case::invalid_time_unit(
+        "1s + 2x",
+        ParseError::TimeUnit(6, "Invalid time unit: 'x'".to_owned())
+    )]
+    #[case::multiplication_of_durations(
+        "1s * 2s",
+        ParseError::Syntax(3, "Cannot multiply a duration with a duration".to_owned())
+    )]
+    #[case::division_by_duration(
+        "1s / 2s",
+        ParseError::Syntax(3, "Cannot divide by a duration".to_owned())
+    )]
+    #[case::division_by_zero("1s / 0", ParseError::Syntax(3, "Division by zero".to_owned()))]
+    #[case::division_by_zero_expression(
+        "1s / (1 - 1)",
+        ParseError::Syntax(3, "Division by zero".to_owned())
+    )]
+    #[case::too_many_digits(
+        "1s * 99999999999999999999",
+        ParseError::Syntax(
+            5,
+            "The number has too many significant digits: '99999999999999999999'".to_owned()
+        )
+    )]
+    #[case::positive_exponent_overflow("1e40000", ParseError::PositiveExponentOverflow)]
+    #[case::negative_exponent_overflow("1e-40000", ParseError::NegativeExponentOverflow)]
+    #[case::number_overflow("1e30000 * 1e30000 * 1s", ParseError::Overflow)]
+    #[case::comparison_fails(
+        "2h <= 1h",
+        ParseError::InvalidInput("The duration 2h is not <= 1h".to_owned())
+    )]
+    #[case::second_comparison_fails(
+        "5m >= 1m < 5m",
+        ParseError::InvalidInput("The duration 5m is not < 5m".to_owned())
+    )]
+    #[case::negative("1s - 2s", ParseError::NegativeNumber)]
+    #[case::negative_number("-1", ParseError::NegativeNumber)]
+    fn test_expression_parser_when_error(#[case] source: &str, #[case] expected: ParseError) {
+        assert_eq!(ExpressionParser::new(Simple).parse(source), Err(expected));
+    }
+
+    #[rstest]
+    #[case::parentheses("(", "1", ")")]
+    #[case::unary_minus("-", "1", "")]
+    #[case::functions("max(", "1", ")")]
+    fn test_expression_parser_when_maximum_depth_exceeded(
+        #[case] open: &str,
+        #[case] operand: &str,
+        #[case] close: &str,
+    ) {
+        let parser = ExpressionParser::new(Simple).allow_negative();
+        let source = format!(
+            "{}{operand}{}",
+            open.repeat(MAX_DEPTH - 1),
+            close.repeat(MAX_DEPTH - 1)
+        );
+        assert!(parser.parse(&source).is_ok());
+
+        let source = format!(
+            "{}{operand}{}",
+            open.repeat(MAX_DEPTH),
+            close.repeat(MAX_DEPTH)
+        );
+        assert_eq!(
+            parser.parse(&source),
+            Err(ParseError::Syntax(
+                MAX_DEPTH * open.len(),
+                "The expression exceeds the maximum nesting depth of 64".to_owned()
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case::negative("1s - 2s", Duration::negative(1, 0))]
+    #[case::negative_number("-1", Duration::negative(1, 0))]
+    #[case::saturates("-1e30", Duration::MIN)]
+    fn test_expression_parser_with_allow_negative(
+        #[case] source: &str,
+        #[case] expected: Duration,
+    ) {
+        let parser = ExpressionParser::new(Simple).allow_negative();
+        assert_eq!(parser.parse(source), Ok(expected));
+    }
+
+    #[rstest]
+    #[case::nano_second(TimeUnit::NanoSecond, Duration::positive(0, 90))]
+    #[case::milli_second(TimeUnit::MilliSecond, Duration::positive(0, 90_000_000))]
+    #[case::minute(TimeUnit::Minute, Duration::positive(5400, 0))]
+    fn test_expression_parser_with_default_unit(
+        #[case] time_unit: TimeUnit,
+        #[case] expected: Duration,
+    ) {
+        let parser = ExpressionParser::new(Simple).default_unit(time_unit);
+        assert_eq!(parser.parse("90"), Ok(expected));
+        assert_eq!(parser.parse("2 * 45"), Ok(expected));
+        assert_eq!(parser.parse("0s + 90"), Ok(expected));
+    }
+
+    #[test]
+    fn test_expression_parser_parse_duration() {
+        let parser = ExpressionParser::new(Simple);
+        assert_eq!(
+            parser.parse_duration("2 * 1s"),
+            Ok(Duration::positive(2, 0))
+        );
+    }
+
+    #[rstest]
+    #[case::empty("", 0)]
+    #[case::no_number("s", 0)]
+    #[case::point(".", 0)]
+    #[case::point_with_exponent(".e1", 0)]
+    #[case::whole("123s", 3)]
+    #[case::whole_with_point("1.s", 2)]
+    #[case::fraction(".5s", 2)]
+    #[case::whole_and_fraction("1.5s", 3)]
+    #[case::exponent("1e3s", 3)]
+    #[case::exponent_uppercase("1E3", 3)]
+    #[case::exponent_with_sign("1.5e-3", 6)]
+    #[case::exponent_without_digits("1es", 1)]
+    #[case::exponent_with_sign_without_digits("1e+", 1)]
+    fn test_number_length(#[case] source: &str, #[case] expected: usize) {
+        assert_eq!(number_length(source), expected);
+    }
+
+    #[rstest]
+    #[case::one("1", Multiplier(1, 0))]
+    #[case::zero("000", Multiplier(0, 0))]
+    #[case::zero_with_exponent("0e100", Multiplier(0, 0))]
+    #[case::fraction("1.5", Multiplier(15, -1))]
+    #[case::trailing_zeros("100", Multiplier(1, 2))]
+    #[case::trailing_zeros_in_fraction("0.50", Multiplier(5, -1))]
+    #[case::zeros_in_between("1.0001", Multiplier(10001, -4))]
+    #[case::exponent(".5e2", Multiplier(5, 1))]
+    #[case::negative_exponent("1e-3", Multiplier(1, -3))]
+    #[case::max_exponent("1e32767", Multiplier(1, i16::MAX))]
+    #[case::min_exponent("1e-32768", Multiplier(1, i16::MIN))]
+    #[case::max_coefficient("9223372036854775807", Multiplier(i64::MAX, 0))]
+    fn test_parse_number(#[case] number: &str, #[case] expected: Multiplier) {
+        assert_eq!(parse_number(number, 0), Ok(expected));
+    }
+
+    #[rstest]
+    #[case::positive_exponent_overflow("10e32767", ParseError::PositiveExponentOverflow)]
+    #[case::negative_exponent_overflow("0.1e-32768", ParseError::NegativeExponentOverflow)]
+    #[case::coefficient_overflow(
+        "9223372036854775808",
+        ParseError::Syntax(
+            0,
+            "The number has too many significant digits: '9223372036854775808'".to_owned()
+        )
+    )]
+    fn test_parse_number_when_error(#[case] number: &str, #[case] expected: ParseError) {
+        assert_eq!(parse_number(number, 0), Err(expected));
+    }
+
+    #[rstest]
+    #[case::same_exponent(Multiplier(1, 0), Multiplier(2, 0), Some(Multiplier(3, 0)))]
+    #[case::different_exponents(Multiplier(1, 0), Multiplier(5, -1), Some(Multiplier(15, -1)))]
+    #[case::zero(Multiplier(0, 0), Multiplier(5, -1), Some(Multiplier(5, -1)))]
+    #[case::negative(Multiplier(1, 0), Multiplier(-5, -1), Some(Multiplier(5, -1)))]
+    #[case::overflow(Multiplier(i64::MAX, 0), Multiplier(1, 0), None)]
+    #[case::scale_overflow(Multiplier(1, 100), Multiplier(1, 0), None)]
+    fn test_add_numbers(
+        #[case] lhs: Multiplier,
+        #[case] rhs: Multiplier,
+        #[case] expected: Option<Multiplier>,
+    ) {
+        assert_eq!(add_numbers(lhs, rhs), expected);
+    }
+
+    #[rstest]
+    #[case::small(6, 7, 4, Some(10))]
+    #[case::product_overflows(u128::MAX, 2, 4, Some(u128::MAX / 2))]
+    #[case::large_divisor(10_u128.pow(30), 10_u128.pow(20), 10_u128.pow(38), Some(10_u128.pow(12)))]
+    #[case::result_overflows(u128::MAX, u128::MAX, 1, None)]
+    fn test_mul_div(
+        #[case] a: u128,
+        #[case] b: u128,
+        #[case] d: u128,
+        #[case] expected: Option<u128>,
+    ) {
+        assert_eq!(mul_div(a, b, d), expected);
+    }
+
+    #[rstest]
+    #[case::identity(Duration::positive(1, 1), Multiplier(1, 0), Duration::positive(1, 1))]
+    #[case::zero(Duration::MAX, Multiplier(0, 0), Duration::ZERO)]
+    #[case::negative(Duration::positive(1, 0), Multiplier(-2, 0), Duration::negative(2, 0))]
+    #[case::negative_duration(Duration::negative(1, 0), Multiplier(-2, 0), Duration::positive(2, 0))]
+    #[case::exponent(Duration::positive(0, 1), Multiplier(1, 9), Duration::positive(1, 0))]
+    #[case::negative_exponent(Duration::positive(1, 0), Multiplier(15, -1), Duration::positive(1, 500_000_000))]
+    #[case::large_product(Duration::MAX, Multiplier(i64::MAX, -18), Duration::MAX)]
+    #[case::large_product_exact(
+        Duration::positive(1_000_000_000_000_000_000, 0),
+        Multiplier(1_000_000_000_000_000_001, -18),
+        Duration::positive(1_000_000_000_000_000_001, 0)
+    )]
+    #[case::very_small_exponent(Duration::MAX, Multiplier(i64::MAX, -40), Duration::positive(0, 17_014_118))]
+    #[case::smallest_exponent(Duration::MAX, Multiplier(1, i16::MIN), Duration::ZERO)]
+    #[case::saturates(Duration::positive(1, 0), Multiplier(1, 100), Duration::MAX)]
+    #[case::saturates_negative(Duration::negative(1, 0), Multiplier(1, 100), Duration::MIN)]
+    fn test_multiply(
+        #[case] duration: Duration,
+        #[case] multiplier: Multiplier,
+        #[case] expected: Duration,
+    ) {
+        assert_eq!(multiply(duration, multiplier), expected);
+    }
+
+    #[rstest]
+    #[case::identity(Duration::positive(1, 1), Multiplier(1, 0), Duration::positive(1, 1))]
+    #[case::truncates(
+        Duration::positive(1, 0),
+        Multiplier(3, 0),
+        Duration::positive(0, 333_333_333)
+    )]
+    #[case::negative(Duration::positive(1, 0), Multiplier(-2, 0), Duration::negative(0, 500_000_000))]
+    #[case::exponent(Duration::positive(1, 0), Multiplier(1, 9), Duration::positive(0, 1))]
+    #[case::large_exponent(Duration::MAX, Multiplier(1, 100), Duration::ZERO)]
+    #[case::negative_exponent(Duration::positive(1, 0), Multiplier(5, -1), Duration::positive(2, 0))]
+    #[case::negative_exponent_truncates(
+        Duration::positive(1, 0),
+        Multiplier(3, -1),
+        Duration::positive(3, 333_333_333)
+    )]
+    #[case::long_division(
+        Duration::MAX,
+        Multiplier(i64::MAX, -18),
+        Duration::positive(2_000_000_000_000_000_000, 216_840_434)
+    )]
+    #[case::zero(Duration::ZERO, Multiplier(1, i16::MIN), Duration::ZERO)]
+    #[case::saturates(Duration::positive(0, 1), Multiplier(1, -40), Duration::MAX)]
+    #[case::saturates_negative(Duration::positive(0, 1), Multiplier(-1, -40), Duration::MIN)]
+    fn test_divide(
+        #[case] duration: Duration,
+        #[case] divisor: Multiplier,
+        #[case] expected: Duration,
+    ) {
+        assert_eq!(divide(duration, divisor), expected);
+    }
+}
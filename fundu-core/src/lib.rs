@@ -4,6 +4,7 @@
 // https://opensource.org/licenses/MIT
 
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![doc(test(attr(warn(unused))))]
 #![doc(test(attr(allow(unused_extern_crates))))]
 #![warn(clippy::pedantic)]
@@ -30,9 +31,25 @@
 #![allow(clippy::must_use_candidate)]
 #![allow(clippy::return_self_not_must_use)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+pub mod batch;
 pub mod config;
+#[cfg(feature = "alloc")]
+pub mod distribution;
 pub mod error;
+#[cfg(feature = "alloc")]
+pub mod expression;
 pub mod parse;
+pub mod presets;
+#[cfg(feature = "alloc")]
+pub mod range;
+pub mod relative;
+#[cfg(feature = "alloc")]
+pub mod scan;
+pub mod stream;
 pub mod time;
 pub mod util;
 
@@ -43,6 +60,7 @@ pub use rstest_reuse;
 mod tests {
     use crate::config::Delimiter;
     use crate::error::{ParseError, TryFromDurationError};
+    use crate::relative::RelativeDuration;
     use crate::time::{Duration, Multiplier, TimeUnit};
 
     #[test]
@@ -53,6 +71,7 @@ mod tests {
         assert_send::<TimeUnit>();
         assert_send::<Duration>();
         assert_send::<Multiplier>();
+        assert_send::<RelativeDuration>();
 
         assert_send::<ParseError>();
         assert_send::<TryFromDurationError>();
@@ -80,6 +99,7 @@ mod tests {
         assert_sync::<TimeUnit>();
         assert_sync::<Duration>();
         assert_sync::<Multiplier>();
+        assert_sync::<RelativeDuration>();
 
         assert_sync::<ParseError>();
         assert_sync::<TryFromDurationError>();
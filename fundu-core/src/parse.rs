@@ -6,13 +6,18 @@
 //! This module is the working horse of the parser. Public interfaces to the parser are located in
 //! the main library `lib.rs`.
 
-use std::cmp::Ordering::{Equal, Greater, Less};
-use std::str::Utf8Error;
-use std::time::Duration as StdDuration;
-
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering::{Equal, Greater, Less};
+use core::str::Utf8Error;
+use core::time::Duration as StdDuration;
+
+#[cfg(feature = "alloc")]
+use crate::batch::FastPath;
 use crate::config::{Config, Delimiter, NumbersLike, DEFAULT_CONFIG};
-use crate::error::ParseError;
-use crate::time::{Duration, Multiplier, TimeUnit, TimeUnitsLike};
+use crate::error::{BorrowedParseError, Detail, ErrorReason, ParseError};
+use crate::relative::RelativeDuration;
+use crate::time::{Duration, Multiplier, TimeUnit, TimeUnitConstraints, TimeUnitsLike};
 use crate::util::POW10;
 
 pub const ATTOS_PER_SEC: u64 = 1_000_000_000_000_000_000;
@@ -114,15 +119,45 @@ impl<'a> Parser<'a> {
         keywords: Option<&dyn TimeUnitsLike>,
         numerals: Option<&dyn NumbersLike>,
     ) -> Result<Duration, ParseError> {
+        self.parse_multiple_borrowed(source, time_units, keywords, numerals)
+            .map_err(ParseError::from)
+    }
+
+    #[inline]
+    pub fn parse_single(
+        &self,
+        source: &str,
+        time_units: &dyn TimeUnitsLike,
+        keywords: Option<&dyn TimeUnitsLike>,
+        numerals: Option<&dyn NumbersLike>,
+    ) -> Result<Duration, ParseError> {
+        self.parse_single_borrowed(source, time_units, keywords, numerals)
+            .map_err(ParseError::from)
+    }
+
+    #[inline]
+    fn parse_multiple_borrowed<'s>(
+        &self,
+        source: &'s str,
+        time_units: &dyn TimeUnitsLike,
+        keywords: Option<&dyn TimeUnitsLike>,
+        numerals: Option<&dyn NumbersLike>,
+    ) -> Result<Duration, BorrowedParseError<'s>> {
         let mut duration = Duration::ZERO;
+        let validate = MultipleValidator::is_enabled(&self.config);
+        let mut validator = MultipleValidator::new();
 
         let mut parser = &mut ReprParserMultiple::new(source);
         loop {
-            let (mut duration_repr, maybe_parser) =
-                parser.parse(&self.config, time_units, keywords, numerals)?;
-            let parsed_duration = duration_repr.parse()?;
+            let start = parser.bytes.current_pos;
+            let (mut duration_repr, maybe_parser) = parser
+                .parse(&self.config, time_units, keywords, numerals)
+                .map_err(|error| error.reborrow(source))?;
+            let parsed_duration = duration_repr
+                .parse_borrowed()
+                .map_err(|error| error.reborrow(source))?;
             duration = if !self.config.allow_negative && parsed_duration.is_negative() {
-                return Err(ParseError::NegativeNumber);
+                return Err(BorrowedParseError::NegativeNumber);
             } else if parsed_duration.is_zero() {
                 duration
             } else if duration.is_zero() {
@@ -130,6 +165,18 @@ impl<'a> Parser<'a> {
             } else {
                 duration.saturating_add(parsed_duration)
             };
+            if validate {
+                let end = maybe_parser
+                    .as_ref()
+                    .map_or(source.len(), |p| p.get_duration_end(start));
+                validator.validate(
+                    &self.config,
+                    &duration_repr,
+                    (!parsed_duration.is_zero()).then(|| parsed_duration.is_negative()),
+                    start,
+                    &source[start..end],
+                )?;
+            }
             match maybe_parser {
                 Some(p) => parser = p,
                 None => break Ok(duration),
@@ -138,24 +185,25 @@ impl<'a> Parser<'a> {
     }
 
     #[inline]
-    pub fn parse_single(
+    fn parse_single_borrowed<'s>(
         &self,
-        source: &str,
+        source: &'s str,
         time_units: &dyn TimeUnitsLike,
         keywords: Option<&dyn TimeUnitsLike>,
         numerals: Option<&dyn NumbersLike>,
-    ) -> Result<Duration, ParseError> {
+    ) -> Result<Duration, BorrowedParseError<'s>> {
         ReprParserSingle::new(source)
             .parse(&self.config, time_units, keywords, numerals)
             .and_then(|mut duration_repr| {
-                duration_repr.parse().and_then(|duration| {
+                duration_repr.parse_borrowed().and_then(|duration| {
                     if !self.config.allow_negative && duration.is_negative() {
-                        Err(ParseError::NegativeNumber)
+                        Err(BorrowedParseError::NegativeNumber)
                     } else {
                         Ok(duration)
                     }
                 })
             })
+            .map_err(|error| error.reborrow(source))
     }
 
     /// Parse the `source` string into a saturating [`crate::time::Duration`]
@@ -226,11 +274,447 @@ impl<'a> Parser<'a> {
         keywords: Option<&dyn TimeUnitsLike>,
         numerals: Option<&dyn NumbersLike>,
     ) -> Result<Duration, ParseError> {
+        self.parse_borrowed(source, time_units, keywords, numerals)
+            .map_err(ParseError::from)
+    }
+
+    /// Parse the `source` string like [`Parser::parse`] without allocating on errors
+    ///
+    /// The returned [`BorrowedParseError`] borrows the parts of the `source` which are needed for
+    /// the error message instead of copying them into a `String`, so neither a successful nor a
+    /// failed parse allocates. The message is rendered only if the error is displayed and
+    /// [`BorrowedParseError::into_owned`] converts the error into the [`ParseError`] returned by
+    /// [`Parser::parse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BorrowedParseError`] if the given `source` string is invalid
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_core::error::{BorrowedParseError, Detail, ErrorReason, ParseError};
+    /// use fundu_core::parse::Parser;
+    /// use fundu_core::time::TimeUnit::*;
+    /// use fundu_core::time::{Duration, Multiplier, TimeUnit, TimeUnitsLike};
+    ///
+    /// struct TimeUnits {}
+    ///
+    /// impl TimeUnitsLike for TimeUnits {
+    ///     #[inline]
+    ///     fn is_empty(&self) -> bool {
+    ///         false
+    ///     }
+    ///
+    ///     #[inline]
+    ///     fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
+    ///         match identifier {
+    ///             "s" => Some((Second, Multiplier(1, 0))),
+    ///             _ => None,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let parser = Parser::new();
+    /// let time_units = TimeUnits {};
+    ///
+    /// assert_eq!(
+    ///     parser.parse_borrowed("1.5s", &time_units, None, None),
+    ///     Ok(Duration::positive(1, 500_000_000))
+    /// );
+    ///
+    /// let error = parser
+    ///     .parse_borrowed("1ms", &time_units, None, None)
+    ///     .unwrap_err();
+    /// match error {
+    ///     BorrowedParseError::TimeUnit(column, reason) => {
+    ///         assert_eq!(column, 1);
+    ///         assert_eq!(reason.reason(), "Invalid time unit");
+    ///         assert_eq!(reason.detail(), Some(Detail::Input("ms")));
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "Time unit error: Invalid time unit: 'ms' at column 1"
+    /// );
+    /// assert_eq!(
+    ///     ParseError::from(error),
+    ///     ParseError::TimeUnit(1, "Invalid time unit: 'ms'".to_string())
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_borrowed<'s>(
+        &self,
+        source: &'s str,
+        time_units: &dyn TimeUnitsLike,
+        keywords: Option<&dyn TimeUnitsLike>,
+        numerals: Option<&dyn NumbersLike>,
+    ) -> Result<Duration, BorrowedParseError<'s>> {
         if self.config.allow_multiple {
-            self.parse_multiple(source, time_units, keywords, numerals)
+            self.parse_multiple_borrowed(source, time_units, keywords, numerals)
         } else {
-            self.parse_single(source, time_units, keywords, numerals)
+            self.parse_single_borrowed(source, time_units, keywords, numerals)
+        }
+    }
+
+    /// Parse the longest valid duration at the start of `source` and return the unconsumed rest
+    ///
+    /// Unlike [`Parser::parse`], the `source` may continue after the duration, so this method can
+    /// be used to parse durations embedded in a larger grammar (like `5m then fail`). The duration
+    /// ends in front of the first byte which can't continue it and the remainder starts with this
+    /// byte (for example with the delimiter after the duration). Time units and keywords end at the
+    /// first ascii character which is not alphabetic, so identifiers which contain other ascii
+    /// characters like digits or whitespace are not recognized by this method. Time units and
+    /// keywords must match the whole word, so they have to be followed by the end of the input or
+    /// an ascii character like whitespace, punctuation or a digit. Otherwise, a number is parsed
+    /// without time unit, so `5 months later` is parsed as `5` with the remainder ` months later`
+    /// if `months` is no time unit. If [`Config::allow_multiple`] is set, the following durations
+    /// are added as long as they are valid, so a trailing delimiter or conjunction belongs to the
+    /// remainder.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`crate::error::ParseError`] if there's no valid duration at the start of the
+    /// `source`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_core::config::Config;
+    /// use fundu_core::error::ParseError;
+    /// use fundu_core::parse::Parser;
+    /// use fundu_core::time::TimeUnit::*;
+    /// use fundu_core::time::{Duration, Multiplier, TimeUnit, TimeUnitsLike};
+    ///
+    /// struct TimeUnits {}
+    ///
+    /// impl TimeUnitsLike for TimeUnits {
+    ///     #[inline]
+    ///     fn is_empty(&self) -> bool {
+    ///         false
+    ///     }
+    ///
+    ///     #[inline]
+    ///     fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
+    ///         match identifier {
+    ///             "s" => Some((Second, Multiplier(1, 0))),
+    ///             "m" => Some((Minute, Multiplier(1, 0))),
+    ///             _ => None,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let parser = Parser::new();
+    /// let time_units = TimeUnits {};
+    ///
+    /// assert_eq!(
+    ///     parser.parse_prefix("5m then fail", &time_units, None, None),
+    ///     Ok((Duration::positive(300, 0), " then fail"))
+    /// );
+    /// assert_eq!(
+    ///     parser.parse_prefix("5mthen", &time_units, None, None),
+    ///     Ok((Duration::positive(5, 0), "mthen"))
+    /// );
+    /// assert_eq!(
+    ///     parser.parse_prefix("3x every", &time_units, None, None),
+    ///     Ok((Duration::positive(3, 0), "x every"))
+    /// );
+    /// assert!(parser
+    ///     .parse_prefix("then 5m", &time_units, None, None)
+    ///     .is_err());
+    ///
+    /// let mut config = Config::new();
+    /// config.allow_multiple = true;
+    /// let parser = Parser::with_config(config);
+    ///
+    /// assert_eq!(
+    ///     parser.parse_prefix("1m 30s, then fail", &time_units, None, None),
+    ///     Ok((Duration::positive(90, 0), ", then fail"))
+    /// );
+    /// ```
+    pub fn parse_prefix<'s>(
+        &self,
+        source: &'s str,
+        time_units: &dyn TimeUnitsLike,
+        keywords: Option<&dyn TimeUnitsLike>,
+        numerals: Option<&dyn NumbersLike>,
+    ) -> Result<(Duration, &'s str), ParseError> {
+        let check_negative = |duration: Duration| {
+            if !self.config.allow_negative && duration.is_negative() {
+                Err(BorrowedParseError::NegativeNumber)
+            } else {
+                Ok(duration)
+            }
+        };
+
+        let mut parser = &mut ReprParserPrefix::new(source);
+        let (mut duration_repr, next) =
+            parser.parse(&self.config, time_units, keywords, numerals)?;
+        parser = next;
+        let mut duration = check_negative(duration_repr.parse_borrowed()?)?;
+        let mut end = parser.bytes.current_pos;
+
+        if self.config.allow_multiple {
+            let conjunctions = self.config.conjunctions.unwrap_or_default();
+            while parser.try_consume_connection(self.config.outer_delimiter, conjunctions) {
+                let (mut duration_repr, next) =
+                    match parser.parse(&self.config, time_units, keywords, numerals) {
+                        Ok(output) => output,
+                        Err(_) => break,
+                    };
+                match duration_repr.parse_borrowed().and_then(check_negative) {
+                    Ok(parsed) if parsed.is_zero() => {}
+                    Ok(parsed) if duration.is_zero() => duration = parsed,
+                    Ok(parsed) => duration = duration.saturating_add(parsed),
+                    Err(_) => break,
+                }
+                end = next.bytes.current_pos;
+                parser = next;
+            }
+        }
+
+        Ok((duration, &source[end..]))
+    }
+
+    /// Parse the `source` string into a [`RelativeDuration`] keeping calendar time units symbolic
+    ///
+    /// Unlike [`Parser::parse`], the [`TimeUnit::Year`], [`TimeUnit::Month`], [`TimeUnit::Week`]
+    /// and [`TimeUnit::Day`] time units are not converted with their fixed [`Multiplier`] but are
+    /// counted in the respective fields of the [`RelativeDuration`]. All other time units are
+    /// accumulated in [`RelativeDuration::exact`]. The whole part of a number with a calendar time
+    /// unit is kept symbolic and a remaining fraction (like in `1.5 days`) is converted with the
+    /// fixed [`Multiplier`] of the time unit into the `exact` duration. Custom time units and
+    /// keywords based on a calendar time unit (like a `fortnight` with `Week` and `Multiplier(2,
+    /// 0)`) are resolved with their [`Multiplier`]. The calendar fields saturate at `i64::MAX`
+    /// respectively `i64::MIN`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`crate::error::ParseError`] if the given `source` string is invalid
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_core::config::Config;
+    /// use fundu_core::parse::Parser;
+    /// use fundu_core::relative::RelativeDuration;
+    /// use fundu_core::time::TimeUnit::*;
+    /// use fundu_core::time::{Duration, Multiplier, TimeUnit, TimeUnitsLike};
+    ///
+    /// struct TimeUnits {}
+    ///
+    /// impl TimeUnitsLike for TimeUnits {
+    ///     #[inline]
+    ///     fn is_empty(&self) -> bool {
+    ///         false
+    ///     }
+    ///
+    ///     #[inline]
+    ///     fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
+    ///         match identifier {
+    ///             "s" => Some((Second, Multiplier(1, 0))),
+    ///             "d" => Some((Day, Multiplier(1, 0))),
+    ///             "M" => Some((Month, Multiplier(1, 0))),
+    ///             "y" => Some((Year, Multiplier(1, 0))),
+    ///             _ => None,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut config = Config::new();
+    /// config.allow_multiple = true;
+    /// let parser = Parser::with_config(config);
+    /// let time_units = TimeUnits {};
+    ///
+    /// assert_eq!(
+    ///     parser.parse_relative("1y 2M 3d 4s", &time_units, None, None),
+    ///     Ok(RelativeDuration::new(1, 2, 0, 3, Duration::positive(4, 0)))
+    /// );
+    /// assert_eq!(
+    ///     parser.parse_relative("1.5d", &time_units, None, None),
+    ///     Ok(RelativeDuration::new(
+    ///         0,
+    ///         0,
+    ///         0,
+    ///         1,
+    ///         Duration::positive(12 * 60 * 60, 0)
+    ///     ))
+    /// );
+    /// ```
+    pub fn parse_relative(
+        &self,
+        source: &str,
+        time_units: &dyn TimeUnitsLike,
+        keywords: Option<&dyn TimeUnitsLike>,
+        numerals: Option<&dyn NumbersLike>,
+    ) -> Result<RelativeDuration, ParseError> {
+        let check_negative = |relative: RelativeDuration| {
+            if !self.config.allow_negative && relative.is_negative() {
+                Err(BorrowedParseError::NegativeNumber)
+            } else {
+                Ok(relative)
+            }
+        };
+
+        if !self.config.allow_multiple {
+            return ReprParserSingle::new(source)
+                .parse(&self.config, time_units, keywords, numerals)
+                .and_then(|mut duration_repr| duration_repr.parse_relative_borrowed())
+                .and_then(check_negative)
+                .map_err(ParseError::from);
         }
+
+        let mut relative = RelativeDuration::ZERO;
+        let validate = MultipleValidator::is_enabled(&self.config);
+        let mut validator = MultipleValidator::new();
+
+        let mut parser = &mut ReprParserMultiple::new(source);
+        loop {
+            let start = parser.bytes.current_pos;
+            let (mut duration_repr, maybe_parser) =
+                parser.parse(&self.config, time_units, keywords, numerals)?;
+            let parsed_relative = check_negative(duration_repr.parse_relative_borrowed()?)?;
+            relative = relative.saturating_add(parsed_relative);
+            if validate {
+                let end = maybe_parser
+                    .as_ref()
+                    .map_or(source.len(), |p| p.get_duration_end(start));
+                validator.validate(
+                    &self.config,
+                    &duration_repr,
+                    (!parsed_relative.is_zero()).then(|| parsed_relative.is_negative()),
+                    start,
+                    &source[start..end],
+                )?;
+            }
+            match maybe_parser {
+                Some(p) => parser = p,
+                None => break Ok(relative),
+            }
+        }
+    }
+
+    /// Parse all `sources` into saturating [`crate::time::Duration`]s like [`Parser::parse`]
+    ///
+    /// This method is meant for columnar data with many source strings. The configuration is
+    /// evaluated only once for all `sources` and plain numbers (like `123`) and numbers directly
+    /// followed by a time unit (like `123ms`) take a fast path, which skips the full parser. See
+    /// [`crate::batch`] for the details. The results are in the same order as the `sources` and
+    /// are the same as the results of [`Parser::parse`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_core::error::ParseError;
+    /// use fundu_core::parse::Parser;
+    /// use fundu_core::time::TimeUnit::*;
+    /// use fundu_core::time::{Duration, Multiplier, TimeUnit, TimeUnitsLike};
+    ///
+    /// struct TimeUnits {}
+    ///
+    /// impl TimeUnitsLike for TimeUnits {
+    ///     #[inline]
+    ///     fn is_empty(&self) -> bool {
+    ///         false
+    ///     }
+    ///
+    ///     #[inline]
+    ///     fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
+    ///         match identifier {
+    ///             "s" => Some((Second, Multiplier(1, 0))),
+    ///             "ms" => Some((MilliSecond, Multiplier(1, 0))),
+    ///             _ => None,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let parser = Parser::new();
+    /// let time_units = TimeUnits {};
+    ///
+    /// assert_eq!(
+    ///     parser.parse_many(&["1", "20ms", "1.5s", "1x"], &time_units, None, None),
+    ///     vec![
+    ///         Ok(Duration::positive(1, 0)),
+    ///         Ok(Duration::positive(0, 20_000_000)),
+    ///         Ok(Duration::positive(1, 500_000_000)),
+    ///         Err(ParseError::TimeUnit(1, "Invalid time unit: 'x'".to_string())),
+    ///     ]
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn parse_many<S: AsRef<str>>(
+        &self,
+        sources: &[S],
+        time_units: &dyn TimeUnitsLike,
+        keywords: Option<&dyn TimeUnitsLike>,
+        numerals: Option<&dyn NumbersLike>,
+    ) -> Vec<Result<Duration, ParseError>> {
+        let mut results = Vec::with_capacity(sources.len());
+        self.parse_many_into(sources, time_units, keywords, numerals, &mut results);
+        results
+    }
+
+    /// Parse all `sources` like [`Parser::parse_many`] and append the results to `results`
+    ///
+    /// Reusing the `results` buffer avoids the allocation of a new vector for every batch of
+    /// `sources`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_core::parse::Parser;
+    /// use fundu_core::time::TimeUnit::*;
+    /// use fundu_core::time::{Duration, Multiplier, TimeUnit, TimeUnitsLike};
+    ///
+    /// struct TimeUnits {}
+    ///
+    /// impl TimeUnitsLike for TimeUnits {
+    ///     #[inline]
+    ///     fn is_empty(&self) -> bool {
+    ///         false
+    ///     }
+    ///
+    ///     #[inline]
+    ///     fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
+    ///         match identifier {
+    ///             "s" => Some((Second, Multiplier(1, 0))),
+    ///             _ => None,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let parser = Parser::new();
+    /// let time_units = TimeUnits {};
+    ///
+    /// let mut results = Vec::new();
+    /// for batch in [["1s", "2s"], ["3s", "4s"]] {
+    ///     results.clear();
+    ///     parser.parse_many_into(&batch, &time_units, None, None, &mut results);
+    ///     assert_eq!(results.len(), 2);
+    /// }
+    /// assert_eq!(
+    ///     results,
+    ///     vec![Ok(Duration::positive(3, 0)), Ok(Duration::positive(4, 0))]
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn parse_many_into<S: AsRef<str>>(
+        &self,
+        sources: &[S],
+        time_units: &dyn TimeUnitsLike,
+        keywords: Option<&dyn TimeUnitsLike>,
+        numerals: Option<&dyn NumbersLike>,
+        results: &mut Vec<Result<Duration, ParseError>>,
+    ) {
+        let fast_path = FastPath::new(&self.config, time_units);
+        results.reserve(sources.len());
+        results.extend(sources.iter().map(|source| {
+            let source = source.as_ref();
+            fast_path
+                .parse(source)
+                .map_or_else(|| self.parse(source, time_units, keywords, numerals), Ok)
+        }));
     }
 }
 
@@ -424,11 +908,23 @@ pub struct DurationRepr<'a> {
     pub exponent: i16,
     pub multiplier: Multiplier,
     pub numeral: Option<Multiplier>,
+    pub constraints: TimeUnitConstraints,
 }
 
 impl<'a> DurationRepr<'a> {
-    #[allow(clippy::too_many_lines)]
     pub fn parse(&mut self) -> Result<Duration, ParseError> {
+        self.parse_borrowed().map_err(ParseError::from)
+    }
+
+    /// Parse this representation into a [`RelativeDuration`]
+    ///
+    /// See also [`Parser::parse_relative`]
+    pub fn parse_relative(&mut self) -> Result<RelativeDuration, ParseError> {
+        self.parse_relative_borrowed().map_err(ParseError::from)
+    }
+
+    #[allow(clippy::too_many_lines)]
+    pub(crate) fn parse_borrowed(&mut self) -> Result<Duration, BorrowedParseError<'a>> {
         if self.is_infinite {
             return Ok(Duration::from_std(
                 self.is_negative.unwrap_or_default(),
@@ -553,12 +1049,154 @@ impl<'a> DurationRepr<'a> {
             }
         };
 
-        Ok(Self::calculate_duration(
-            duration_is_negative,
-            seconds,
-            attos,
-            coefficient,
-        ))
+        Ok(Self::calculate_duration(
+            duration_is_negative,
+            seconds,
+            attos,
+            coefficient,
+        ))
+    }
+
+    /// Check the number of this representation against the [`TimeUnitConstraints`]
+    ///
+    /// The number spans from `start` to `end` in the input (without the sign).
+    pub fn check_constraints(
+        &mut self,
+        start: usize,
+        end: usize,
+    ) -> Result<(), BorrowedParseError<'a>> {
+        let constraints = self.constraints;
+        // SAFETY: The number consists of ascii characters only
+        let number = unsafe { core::str::from_utf8_unchecked(&self.input[start..end]) };
+
+        if let (true, Some(Fract(fract_start, _))) = (constraints.disable_fraction, self.fract) {
+            // The decimal point is directly in front of the fraction
+            return Err(BorrowedParseError::Syntax(
+                fract_start - 1,
+                ErrorReason::new("No fraction allowed for this time unit"),
+            ));
+        }
+
+        // The exponent follows directly after the digits
+        let digits_end = match (self.whole, self.fract) {
+            (_, Some(Fract(_, fract_end))) => fract_end,
+            (Some(Whole(_, whole_end)), None) => whole_end,
+            (None, None) => end,
+        };
+        if constraints.disable_exponent && digits_end < end {
+            return Err(BorrowedParseError::Syntax(
+                digits_end,
+                ErrorReason::new("No exponent allowed for this time unit"),
+            ));
+        }
+
+        if constraints.integer_only {
+            let whole = self
+                .whole
+                .map_or(&[][..], |whole| &self.input[whole.0..whole.1]);
+            let fract = self
+                .fract
+                .map_or(&[][..], |fract| &self.input[fract.0..fract.1]);
+            // The position of the decimal point within the digits after applying the exponent
+            let point = i32::try_from(whole.len()).unwrap_or(i32::MAX) + i32::from(self.exponent);
+            let point = usize::try_from(point).unwrap_or(0);
+            if !whole
+                .iter()
+                .chain(fract)
+                .skip(point)
+                .all(|byte| *byte == b'0')
+            {
+                return Err(BorrowedParseError::TimeUnit(
+                    start,
+                    ErrorReason::with_detail(
+                        "The number must be an integer for this time unit",
+                        "The number must be an integer for this time unit but was: '{}'",
+                        Detail::Input(number),
+                    ),
+                ));
+            }
+        }
+
+        if let Some((min, max)) = constraints.range {
+            // Parsing the number with seconds yields the absolute value of the number in the
+            // seconds. The value is truncated to nano seconds, which doesn't change the result of
+            // the comparison with the integer bounds.
+            let unit = self.unit.replace(TimeUnit::Second);
+            let multiplier = core::mem::take(&mut self.multiplier);
+            let is_negative = self.is_negative.take();
+            let value = self.parse_borrowed();
+            self.unit = unit;
+            self.multiplier = multiplier;
+            self.is_negative = is_negative;
+
+            let seconds = value?.as_seconds();
+            if seconds < i128::from(min) || seconds >= i128::from(max) {
+                return Err(BorrowedParseError::TimeUnit(
+                    start,
+                    ErrorReason::with_detail(
+                        "The number is out of the range of this time unit",
+                        "The number is out of the range of this time unit: '{}'",
+                        Detail::Input(number),
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn parse_relative_borrowed(
+        &mut self,
+    ) -> Result<RelativeDuration, BorrowedParseError<'a>> {
+        let time_unit = self.unit.unwrap_or(self.default_unit);
+        if self.is_infinite
+            || !matches!(
+                time_unit,
+                TimeUnit::Day | TimeUnit::Week | TimeUnit::Month | TimeUnit::Year
+            )
+        {
+            return self.parse_borrowed().map(RelativeDuration::from_exact);
+        }
+
+        // Parsing with seconds as time unit yields the amount of the calendar time unit in the
+        // seconds and a possible fraction in the sub-seconds
+        let unit = self.unit.replace(TimeUnit::Second);
+        let amount = self.parse_borrowed();
+        self.unit = unit;
+        let amount = amount?;
+
+        let seconds = amount.as_seconds();
+        let value = i64::try_from(seconds).unwrap_or(if amount.is_negative() {
+            i64::MIN
+        } else {
+            i64::MAX
+        });
+        // A fraction below the nano second resolution doesn't show up in the `amount`
+        let exact = if amount.subsec_nanos() == 0_i32
+            && self.fract.map_or(true, |fract| fract.is_empty())
+            && self.exponent >= 0_i16
+        {
+            Duration::ZERO
+        } else {
+            let Multiplier(coefficient, _) = time_unit.multiplier();
+            let calendar = DurationRepr::calculate_duration(
+                amount.is_negative(),
+                u64::try_from(seconds.unsigned_abs()).unwrap(),
+                0,
+                coefficient,
+            );
+            self.parse_borrowed()?.saturating_sub(calendar)
+        };
+
+        let mut relative = RelativeDuration::from_exact(exact);
+        match time_unit {
+            TimeUnit::Day => relative.days = value,
+            TimeUnit::Week => relative.weeks = value,
+            TimeUnit::Month => relative.months = value,
+            TimeUnit::Year => relative.years = value,
+            _ => unreachable!(), // cov:excl-line
+        }
+        Ok(relative)
     }
 
     #[inline]
@@ -729,24 +1367,34 @@ impl<'a> Bytes<'a> {
     }
 
     #[inline]
-    pub fn get_remainder(&self) -> &[u8] {
+    pub fn get_remainder(&self) -> &'a [u8] {
         &self.input[self.current_pos..]
     }
 
     #[inline]
-    pub unsafe fn get_remainder_str_unchecked(&self) -> &str {
-        std::str::from_utf8_unchecked(self.get_remainder())
+    pub unsafe fn get_remainder_str_unchecked(&self) -> &'a str {
+        core::str::from_utf8_unchecked(self.get_remainder())
     }
 
     #[inline]
-    pub fn get_remainder_str(&self) -> Result<&str, ParseError> {
-        std::str::from_utf8(self.get_remainder())
-            .map_err(|err| ParseError::InvalidInput(err.to_string()))
+    pub fn get_remainder_str(&self) -> Result<&'a str, ParseError> {
+        self.get_remainder_str_borrowed().map_err(ParseError::from)
+    }
+
+    #[inline]
+    pub(crate) fn get_remainder_str_borrowed(&self) -> Result<&'a str, BorrowedParseError<'a>> {
+        core::str::from_utf8(self.get_remainder()).map_err(|err| {
+            BorrowedParseError::InvalidInput(ErrorReason::with_detail(
+                "Invalid utf-8",
+                "{}",
+                Detail::Utf8(err),
+            ))
+        })
     }
 
     #[inline]
-    pub fn get_current_str(&self, start: usize) -> Result<&str, Utf8Error> {
-        std::str::from_utf8(&self.input[start..self.current_pos])
+    pub fn get_current_str(&self, start: usize) -> Result<&'a str, Utf8Error> {
+        core::str::from_utf8(&self.input[start..self.current_pos])
     }
 
     #[inline]
@@ -857,22 +1505,39 @@ impl<'a> Bytes<'a> {
 
     #[inline]
     pub fn check_end_of_input(&self) -> Result<(), ParseError> {
+        self.check_end_of_input_borrowed().map_err(ParseError::from)
+    }
+
+    #[inline]
+    pub(crate) fn check_end_of_input_borrowed(&self) -> Result<(), BorrowedParseError<'a>> {
         self.current_byte.map_or(Ok(()), |_| {
-            self.get_remainder_str().and_then(|remainder| {
-                Err(ParseError::Syntax(
+            self.get_remainder_str_borrowed().and_then(|remainder| {
+                Err(BorrowedParseError::Syntax(
                     self.current_pos,
-                    format!("Expected end of input but found: '{remainder}'"),
+                    ErrorReason::with_detail(
+                        "Expected end of input",
+                        "Expected end of input but found: '{}'",
+                        Detail::Input(remainder),
+                    ),
                 ))
             })
         })
     }
 
     pub fn try_consume_delimiter(&mut self, delimiter: Delimiter) -> Result<(), ParseError> {
+        self.try_consume_delimiter_borrowed(delimiter)
+            .map_err(ParseError::from)
+    }
+
+    pub(crate) fn try_consume_delimiter_borrowed(
+        &mut self,
+        delimiter: Delimiter,
+    ) -> Result<(), BorrowedParseError<'a>> {
         debug_assert!(delimiter(*self.current_byte.unwrap())); // cov:excl-line
         if self.current_pos == 0 {
-            return Err(ParseError::Syntax(
+            return Err(BorrowedParseError::Syntax(
                 0,
-                "Input may not start with a delimiter".to_owned(),
+                ErrorReason::new("Input may not start with a delimiter"),
             ));
         }
 
@@ -888,14 +1553,31 @@ impl<'a> Bytes<'a> {
 
         match self.current_byte {
             Some(_) => Ok(()),
-            None => Err(ParseError::Syntax(
+            None => Err(BorrowedParseError::Syntax(
                 start,
-                "Input may not end with a delimiter".to_owned(),
+                ErrorReason::new("Input may not end with a delimiter"),
             )),
         }
     }
 }
 
+/// Look up the time unit `identifier` in the `time_units`
+///
+/// If `without_number` is true, time units which don't allow a missing number are not returned.
+#[inline]
+fn lookup_time_unit(
+    config: &Config,
+    time_units: &dyn TimeUnitsLike,
+    identifier: &str,
+    without_number: bool,
+) -> Option<(TimeUnit, Multiplier, TimeUnitConstraints)> {
+    time_units
+        .get_with_constraints(identifier)
+        .filter(|(_, _, constraints)| {
+            !without_number || constraints.allows_missing_number(config.number_is_optional)
+        })
+}
+
 pub trait ReprParserTemplate<'a> {
     type Output;
 
@@ -907,32 +1589,37 @@ pub trait ReprParserTemplate<'a> {
         &'a mut self,
         duration_repr: DurationRepr<'a>,
         config: &'a Config,
-    ) -> Result<Self::Output, ParseError>;
+    ) -> Result<Self::Output, BorrowedParseError<'a>>;
 
     fn parse_keyword(
         &mut self,
         keywords: Option<&dyn TimeUnitsLike>,
         config: &'a Config,
-    ) -> Result<Option<(TimeUnit, Multiplier)>, ParseError>;
+    ) -> Result<Option<(TimeUnit, Multiplier)>, BorrowedParseError<'a>>;
 
+    /// Parse the time unit
+    ///
+    /// If `without_number` is true and the time unit doesn't allow a missing number (see
+    /// [`TimeUnitConstraints::allows_missing_number`]), the time unit is not consumed.
     fn parse_time_unit(
         &mut self,
         config: &'a Config,
         time_units: &dyn TimeUnitsLike,
-    ) -> Result<Option<(TimeUnit, Multiplier)>, ParseError>;
+        without_number: bool,
+    ) -> Result<Option<(TimeUnit, Multiplier, TimeUnitConstraints)>, BorrowedParseError<'a>>;
 
     fn parse_number_time_unit(
         &mut self,
         duration_repr: &mut DurationRepr<'a>,
         config: &'a Config,
         time_units: &dyn TimeUnitsLike,
-    ) -> Result<bool, ParseError>;
+    ) -> Result<bool, BorrowedParseError<'a>>;
 
     fn finalize(
         &'a mut self,
         duration_repr: DurationRepr<'a>,
         config: &'a Config,
-    ) -> Result<Self::Output, ParseError>;
+    ) -> Result<Self::Output, BorrowedParseError<'a>>;
 
     #[inline]
     fn parse_whole(&mut self) -> Whole {
@@ -945,7 +1632,7 @@ pub trait ReprParserTemplate<'a> {
         &'_ mut self,
         numerals: Option<&'a dyn NumbersLike>,
         config: &'a Config,
-    ) -> Result<Option<(&'a str, Multiplier)>, ParseError> {
+    ) -> Result<Option<(&'a str, Multiplier)>, BorrowedParseError<'a>> {
         if let Some(numerals) = numerals {
             let bytes = self.bytes();
             let start = bytes.current_pos;
@@ -957,7 +1644,7 @@ pub trait ReprParserTemplate<'a> {
             }
             // SAFETY: we've only parsed valid utf-8 up to this point and the delimiter only matches
             // ascii
-            let string = unsafe { std::str::from_utf8_unchecked(buffer) };
+            let string = unsafe { core::str::from_utf8_unchecked(buffer) };
             return match numerals.get(string) {
                 None => {
                     bytes.reset(start);
@@ -965,7 +1652,7 @@ pub trait ReprParserTemplate<'a> {
                 }
                 some_option => match bytes.current_byte {
                     Some(byte) if (config.inner_delimiter)(*byte) => {
-                        bytes.try_consume_delimiter(config.inner_delimiter)?;
+                        bytes.try_consume_delimiter_borrowed(config.inner_delimiter)?;
                         Ok(some_option.map(|m| (string, m)))
                     }
                     None | Some(_) => {
@@ -978,15 +1665,16 @@ pub trait ReprParserTemplate<'a> {
         Ok(None)
     }
 
+    #[allow(clippy::too_many_lines)]
     fn parse(
         &'a mut self,
         config: &'a Config,
         time_units: &dyn TimeUnitsLike,
         keywords: Option<&dyn TimeUnitsLike>,
         numerals: Option<&'a dyn NumbersLike>,
-    ) -> Result<Self::Output, ParseError> {
+    ) -> Result<Self::Output, BorrowedParseError<'a>> {
         if self.bytes().current_byte.is_none() {
-            return Err(ParseError::Empty);
+            return Err(BorrowedParseError::Empty);
         }
 
         let mut duration_repr = DurationRepr {
@@ -996,6 +1684,7 @@ pub trait ReprParserTemplate<'a> {
         };
 
         self.parse_number_sign(&mut duration_repr, config)?;
+        let number_start = self.bytes().current_pos;
 
         // parse infinity, keywords, ... or the whole number part of the input
         match self.bytes().current_byte.copied() {
@@ -1016,10 +1705,11 @@ pub trait ReprParserTemplate<'a> {
                     duration_repr.multiplier = multi;
                     return self.finalize(duration_repr, config);
                 }
-                if config.number_is_optional {
+                // Time units may allow a missing number even if `number_is_optional` is not set
+                if !time_units.is_empty() {
                     let start = self.bytes().current_pos;
-                    match self.parse_time_unit(config, time_units)? {
-                        Some((time_unit, multiplier)) => {
+                    match self.parse_time_unit(config, time_units, true)? {
+                        Some((time_unit, multiplier, _)) => {
                             duration_repr.unit = Some(time_unit);
                             duration_repr.multiplier = multiplier;
                             return self.finalize(duration_repr, config);
@@ -1030,39 +1720,53 @@ pub trait ReprParserTemplate<'a> {
                     }
                 }
                 if let Some((id, numeral)) = self.parse_numeral(numerals, config)? {
-                    match self.parse_time_unit(config, time_units)? {
-                        Some((time_unit, multiplier)) => {
+                    match self.parse_time_unit(config, time_units, false)? {
+                        Some((time_unit, multiplier, _)) => {
                             duration_repr.numeral = Some(numeral);
                             duration_repr.unit = Some(time_unit);
                             duration_repr.multiplier = multiplier;
                             return self.finalize(duration_repr, config);
                         }
                         None if time_units.is_empty() => {
-                            return Err(ParseError::TimeUnit(
+                            return Err(BorrowedParseError::TimeUnit(
                                 self.bytes().current_pos,
-                                format!("Found numeral '{id}' without time units being defined"),
+                                ErrorReason::with_detail(
+                                    "Found numeral without time units being defined",
+                                    "Found numeral '{}' without time units being defined",
+                                    Detail::Input(id),
+                                ),
                             ));
                         }
                         None => {
-                            return Err(ParseError::TimeUnit(
+                            return Err(BorrowedParseError::TimeUnit(
                                 self.bytes().current_pos,
-                                format!("Found numeral '{id}' without a time unit"),
+                                ErrorReason::with_detail(
+                                    "Found numeral without a time unit",
+                                    "Found numeral '{}' without a time unit",
+                                    Detail::Input(id),
+                                ),
                             ));
                         }
                     }
                 }
                 return self
                     .bytes()
-                    .get_remainder_str()
-                    .and_then(|remainder| Err(ParseError::InvalidInput(remainder.to_owned())));
+                    .get_remainder_str_borrowed()
+                    .and_then(|remainder| {
+                        Err(BorrowedParseError::InvalidInput(ErrorReason::with_detail(
+                            "Unexpected input",
+                            "{}",
+                            Detail::Input(remainder),
+                        )))
+                    });
             }
             // This is currently unreachable code since empty input and a standalone sign are
             // already handled as errors before. However, keep this code as safety net.
             // cov:excl-start
             None => {
-                return Err(ParseError::Syntax(
+                return Err(BorrowedParseError::Syntax(
                     self.bytes().current_pos,
-                    "Unexpected end of input".to_owned(),
+                    ErrorReason::new("Unexpected end of input"),
                 ));
             } // cov:excl-stop
         }
@@ -1075,6 +1779,7 @@ pub trait ReprParserTemplate<'a> {
             return Ok(self.make_output(duration_repr));
         }
 
+        let number_end = self.bytes().current_pos;
         if !self.parse_number_delimiter(
             config
                 .allow_time_unit_delimiter
@@ -1089,11 +1794,15 @@ pub trait ReprParserTemplate<'a> {
             return Ok(self.make_output(duration_repr)); // cov:excl-line
         }
 
+        if duration_repr.constraints.restricts_number() {
+            duration_repr.check_constraints(number_start, number_end)?;
+        }
+
         self.finalize(duration_repr, config)
     }
 
     /// Parse and consume the sign if present. Return true if sign is negative.
-    fn parse_sign_is_negative(&mut self) -> Result<Option<bool>, ParseError> {
+    fn parse_sign_is_negative(&mut self) -> Result<Option<bool>, BorrowedParseError<'a>> {
         let bytes = self.bytes();
         match bytes.current_byte {
             Some(byte) if *byte == b'+' => {
@@ -1105,9 +1814,9 @@ pub trait ReprParserTemplate<'a> {
                 Ok(Some(true))
             }
             Some(_) => Ok(None),
-            None => Err(ParseError::Syntax(
+            None => Err(BorrowedParseError::Syntax(
                 bytes.current_pos,
-                "Unexpected end of input".to_owned(),
+                ErrorReason::new("Unexpected end of input"),
             )),
         }
     }
@@ -1116,20 +1825,20 @@ pub trait ReprParserTemplate<'a> {
         &mut self,
         duration_repr: &mut DurationRepr,
         config: &Config,
-    ) -> Result<(), ParseError> {
+    ) -> Result<(), BorrowedParseError<'a>> {
         if let Some(is_negative) = self.parse_sign_is_negative()? {
             duration_repr.is_negative = Some(is_negative);
 
             let bytes = self.bytes();
             match bytes.current_byte {
                 Some(byte) if config.allow_sign_delimiter && (config.inner_delimiter)(*byte) => {
-                    return bytes.try_consume_delimiter(config.inner_delimiter);
+                    return bytes.try_consume_delimiter_borrowed(config.inner_delimiter);
                 }
                 Some(_) => {}
                 None => {
-                    return Err(ParseError::Syntax(
+                    return Err(BorrowedParseError::Syntax(
                         bytes.current_pos,
-                        "Unexpected end of input. Sign without a number".to_owned(),
+                        ErrorReason::new("Unexpected end of input. Sign without a number"),
                     ));
                 }
             }
@@ -1147,7 +1856,7 @@ pub trait ReprParserTemplate<'a> {
         &mut self,
         duration_repr: &mut DurationRepr<'a>,
         disable_fraction: bool,
-    ) -> Result<bool, ParseError> {
+    ) -> Result<bool, BorrowedParseError<'a>> {
         let bytes = self.bytes();
         match bytes.current_byte {
             Some(byte) if *byte == b'.' && !disable_fraction => {
@@ -1157,10 +1866,11 @@ pub trait ReprParserTemplate<'a> {
                     Some(_) | None if duration_repr.whole.is_none() => {
                         // Use the decimal point as anchor for the error position. Subtraction by 1
                         // is safe since we were advancing by one before.
-                        return Err(ParseError::Syntax(
+                        return Err(BorrowedParseError::Syntax(
                             bytes.current_pos - 1,
-                            "Either the whole number part or the fraction must be present"
-                                .to_owned(),
+                            ErrorReason::new(
+                                "Either the whole number part or the fraction must be present",
+                            ),
                         ));
                     }
                     Some(_) => Some(Fract(self.bytes().current_pos, self.bytes().current_pos)),
@@ -1173,16 +1883,16 @@ pub trait ReprParserTemplate<'a> {
                 duration_repr.fract = fract;
                 Ok(true)
             }
-            Some(byte) if *byte == b'.' => Err(ParseError::Syntax(
+            Some(byte) if *byte == b'.' => Err(BorrowedParseError::Syntax(
                 bytes.current_pos,
-                "No fraction allowed".to_owned(),
+                ErrorReason::new("No fraction allowed"),
             )),
             Some(_) => Ok(true),
             None => Ok(false),
         }
     }
 
-    fn parse_exponent(&mut self) -> Result<i16, ParseError> {
+    fn parse_exponent(&mut self) -> Result<i16, BorrowedParseError<'a>> {
         let is_negative = self.parse_sign_is_negative()?.unwrap_or_default();
         let bytes = self.bytes();
 
@@ -1197,7 +1907,7 @@ pub trait ReprParserTemplate<'a> {
                         .and_then(|e| e.checked_sub(i16::from(digit)))
                     {
                         Some(exponent) => exponent,
-                        None => return Err(ParseError::NegativeExponentOverflow),
+                        None => return Err(BorrowedParseError::NegativeExponentOverflow),
                     }
                 } else {
                     match exponent
@@ -1205,7 +1915,7 @@ pub trait ReprParserTemplate<'a> {
                         .and_then(|e| e.checked_add(i16::from(digit)))
                     {
                         Some(exponent) => exponent,
-                        None => return Err(ParseError::PositiveExponentOverflow),
+                        None => return Err(BorrowedParseError::PositiveExponentOverflow),
                     }
                 };
                 bytes.advance();
@@ -1217,14 +1927,14 @@ pub trait ReprParserTemplate<'a> {
         if bytes.current_pos - start > 0 {
             Ok(exponent)
         } else if bytes.is_end_of_input() {
-            Err(ParseError::Syntax(
+            Err(BorrowedParseError::Syntax(
                 bytes.current_pos,
-                "Expected exponent but reached end of input".to_owned(),
+                ErrorReason::new("Expected exponent but reached end of input"),
             ))
         } else {
-            Err(ParseError::Syntax(
+            Err(BorrowedParseError::Syntax(
                 bytes.current_pos,
-                "The exponent must have at least one digit".to_owned(),
+                ErrorReason::new("The exponent must have at least one digit"),
             ))
         }
     }
@@ -1233,7 +1943,7 @@ pub trait ReprParserTemplate<'a> {
         &mut self,
         duration_repr: &mut DurationRepr<'a>,
         disable_exponent: bool,
-    ) -> Result<bool, ParseError> {
+    ) -> Result<bool, BorrowedParseError<'a>> {
         let bytes = self.bytes();
         match bytes.current_byte {
             Some(byte) if byte.eq_ignore_ascii_case(&b'e') && !disable_exponent => {
@@ -1241,23 +1951,26 @@ pub trait ReprParserTemplate<'a> {
                 duration_repr.exponent = self.parse_exponent()?;
                 Ok(true)
             }
-            Some(byte) if byte.eq_ignore_ascii_case(&b'e') => Err(ParseError::Syntax(
+            Some(byte) if byte.eq_ignore_ascii_case(&b'e') => Err(BorrowedParseError::Syntax(
                 bytes.current_pos,
-                "No exponent allowed".to_owned(),
+                ErrorReason::new("No exponent allowed"),
             )),
             Some(_) => Ok(true),
             None => Ok(false),
         }
     }
 
-    fn parse_number_delimiter(&mut self, delimiter: Option<Delimiter>) -> Result<bool, ParseError> {
+    fn parse_number_delimiter(
+        &mut self,
+        delimiter: Option<Delimiter>,
+    ) -> Result<bool, BorrowedParseError<'a>> {
         let bytes = self.bytes();
 
         // If allow_time_unit_delimiter is true and there are any delimiters between the number and
         // the time unit, the delimiters are consumed before trying to parse the time units
         match (bytes.current_byte, delimiter) {
             (Some(byte), Some(delimiter)) if delimiter(*byte) => {
-                bytes.try_consume_delimiter(delimiter)?;
+                bytes.try_consume_delimiter_borrowed(delimiter)?;
                 Ok(true)
             }
             (Some(_), _) => Ok(true),
@@ -1296,7 +2009,7 @@ impl<'a> ReprParserTemplate<'a> for ReprParserSingle<'a> {
         &'a mut self,
         mut duration_repr: DurationRepr<'a>,
         _: &Config,
-    ) -> Result<DurationRepr<'a>, ParseError> {
+    ) -> Result<DurationRepr<'a>, BorrowedParseError<'a>> {
         if self.bytes.is_end_of_input() {
             duration_repr.is_infinite = true;
             return Ok(duration_repr);
@@ -1308,25 +2021,28 @@ impl<'a> ReprParserTemplate<'a> for ReprParserSingle<'a> {
                 Some(current) if current.eq_ignore_ascii_case(byte) => self.bytes.advance(),
                 // wrong character
                 Some(current) => {
-                    return Err(ParseError::Syntax(
+                    return Err(BorrowedParseError::Syntax(
                         self.bytes.current_pos,
-                        format!(
+                        ErrorReason::with_detail(
+                            "Error parsing infinity: Invalid character",
                             "Error parsing infinity: Invalid character '{}'",
-                            *current as char
+                            Detail::Char(*current as char),
                         ),
                     ));
                 }
                 None => {
-                    return Err(ParseError::Syntax(
+                    return Err(BorrowedParseError::Syntax(
                         self.bytes.current_pos,
-                        "Error parsing infinity: Premature end of input".to_owned(),
+                        ErrorReason::new("Error parsing infinity: Premature end of input"),
                     ));
                 }
             }
         }
 
         duration_repr.is_infinite = true;
-        self.bytes.check_end_of_input().map(|_| duration_repr)
+        self.bytes
+            .check_end_of_input_borrowed()
+            .map(|_| duration_repr)
     }
 
     #[inline]
@@ -1334,7 +2050,7 @@ impl<'a> ReprParserTemplate<'a> for ReprParserSingle<'a> {
         &mut self,
         keywords: Option<&dyn TimeUnitsLike>,
         _: &Config,
-    ) -> Result<Option<(TimeUnit, Multiplier)>, ParseError> {
+    ) -> Result<Option<(TimeUnit, Multiplier)>, BorrowedParseError<'a>> {
         if let Some(keywords) = keywords {
             // SAFETY: we've only parsed valid utf-8 up to this point
             let keyword = unsafe { self.bytes.get_remainder_str_unchecked() };
@@ -1354,7 +2070,8 @@ impl<'a> ReprParserTemplate<'a> for ReprParserSingle<'a> {
         &mut self,
         config: &Config,
         time_units: &dyn TimeUnitsLike,
-    ) -> Result<Option<(TimeUnit, Multiplier)>, ParseError> {
+        without_number: bool,
+    ) -> Result<Option<(TimeUnit, Multiplier, TimeUnitConstraints)>, BorrowedParseError<'a>> {
         // cov:excl-start
         debug_assert!(
             self.bytes.current_byte.is_some(),
@@ -1366,14 +2083,14 @@ impl<'a> ReprParserTemplate<'a> for ReprParserSingle<'a> {
             // SAFETY: The delimiter may not match non-ascii bytes and we've parsed only valid utf-8
             // so far
             let string = unsafe {
-                std::str::from_utf8_unchecked(self.bytes.advance_to(config.inner_delimiter))
+                core::str::from_utf8_unchecked(self.bytes.advance_to(config.inner_delimiter))
             };
 
-            let (time_unit, mut multiplier) = if string.is_empty() {
+            let (time_unit, mut multiplier, constraints) = if string.is_empty() {
                 // Haven't found a way to trigger this line, so it's excluded from coverage for now
                 return Ok(None); // cov:excl-line
             } else {
-                match time_units.get(string) {
+                match lookup_time_unit(config, time_units, string, without_number) {
                     None => {
                         self.bytes.reset(start);
                         return Ok(None);
@@ -1385,7 +2102,8 @@ impl<'a> ReprParserTemplate<'a> for ReprParserSingle<'a> {
             // At this point, either there are one or more bytes of which the first is the
             // delimiter or we've reached the end of input
             if self.bytes.current_byte.is_some() {
-                self.bytes.try_consume_delimiter(config.inner_delimiter)?;
+                self.bytes
+                    .try_consume_delimiter_borrowed(config.inner_delimiter)?;
                 if self.bytes.next_is_ignore_ascii_case(b"ago") {
                     // SAFETY: We have checked that there are at least 3 bytes
                     unsafe { self.bytes.advance_by(3) };
@@ -1398,12 +2116,12 @@ impl<'a> ReprParserTemplate<'a> for ReprParserSingle<'a> {
                 }
             };
 
-            Ok(Some((time_unit, multiplier)))
+            Ok(Some((time_unit, multiplier, constraints)))
         } else {
             // SAFETY: The input of `parse` is &str and therefore valid utf-8 and we have read
             // only ascii characters up to this point.
             let string = unsafe { self.bytes.get_remainder_str_unchecked() };
-            let result = match time_units.get(string) {
+            let result = match lookup_time_unit(config, time_units, string, without_number) {
                 None => return Ok(None),
                 some_time_unit => Ok(some_time_unit),
             };
@@ -1418,29 +2136,40 @@ impl<'a> ReprParserTemplate<'a> for ReprParserSingle<'a> {
         duration_repr: &mut DurationRepr<'a>,
         config: &'a Config,
         time_units: &dyn TimeUnitsLike,
-    ) -> Result<bool, ParseError> {
+    ) -> Result<bool, BorrowedParseError<'a>> {
         match self.bytes.current_byte {
             Some(_) if !time_units.is_empty() => {
-                if let Some((unit, multi)) = self.parse_time_unit(config, time_units)? {
+                if let Some((unit, multi, constraints)) =
+                    self.parse_time_unit(config, time_units, false)?
+                {
                     duration_repr.unit = Some(unit);
                     duration_repr.multiplier = multi;
+                    duration_repr.constraints = constraints;
                     Ok(true)
                 } else {
-                    self.bytes.get_remainder_str().and_then(|remainder| {
-                        Err(ParseError::TimeUnit(
-                            self.bytes.current_pos,
-                            format!("Invalid time unit: '{remainder}'"),
-                        ))
-                    })
+                    self.bytes
+                        .get_remainder_str_borrowed()
+                        .and_then(|remainder| {
+                            Err(BorrowedParseError::TimeUnit(
+                                self.bytes.current_pos,
+                                ErrorReason::with_detail(
+                                    "Invalid time unit",
+                                    "Invalid time unit: '{}'",
+                                    Detail::Input(remainder),
+                                ),
+                            ))
+                        })
                 }
             }
             Some(_) => {
-                Err(ParseError::TimeUnit(
+                Err(BorrowedParseError::TimeUnit(
                     self.bytes.current_pos,
-                    // SAFETY: We've parsed only valid utf-8 so far
-                    format!("No time units allowed but found: '{}'", unsafe {
-                        self.bytes.get_remainder_str_unchecked()
-                    }),
+                    ErrorReason::with_detail(
+                        "No time units allowed",
+                        "No time units allowed but found: '{}'",
+                        // SAFETY: We've parsed only valid utf-8 so far
+                        Detail::Input(unsafe { self.bytes.get_remainder_str_unchecked() }),
+                    ),
                 ))
             }
             // This branch is excluded from coverage because parsing with parse_number_delimiter
@@ -1454,19 +2183,34 @@ impl<'a> ReprParserTemplate<'a> for ReprParserSingle<'a> {
         &'a mut self,
         duration_repr: DurationRepr<'a>,
         _: &Config,
-    ) -> Result<Self::Output, ParseError> {
-        self.bytes.check_end_of_input().map(|_| duration_repr)
+    ) -> Result<Self::Output, BorrowedParseError<'a>> {
+        self.bytes
+            .check_end_of_input_borrowed()
+            .map(|_| duration_repr)
     }
 }
 
 pub struct ReprParserMultiple<'a> {
     pub bytes: Bytes<'a>,
+    /// The position after the last duration if it was followed by the outer delimiter
+    pub duration_end: usize,
 }
 
 impl<'a> ReprParserMultiple<'a> {
     pub fn new(input: &'a str) -> Self {
         Self {
             bytes: Bytes::new(input.as_bytes()),
+            duration_end: 0,
+        }
+    }
+
+    /// Return the end of the duration which started at `start` and was just parsed
+    #[inline]
+    pub fn get_duration_end(&self, start: usize) -> usize {
+        if self.duration_end > start {
+            self.duration_end
+        } else {
+            self.bytes.current_pos
         }
     }
 
@@ -1479,10 +2223,11 @@ impl<'a> ReprParserMultiple<'a> {
         &mut self,
         delimiter: Delimiter,
         conjunctions: &'a [&'a str],
-    ) -> Result<(), ParseError> {
+    ) -> Result<(), BorrowedParseError<'a>> {
         debug_assert!(delimiter(*self.bytes.current_byte.unwrap()));
 
-        self.bytes.try_consume_delimiter(delimiter)?;
+        self.duration_end = self.bytes.current_pos;
+        self.bytes.try_consume_delimiter_borrowed(delimiter)?;
         let start = self.bytes.current_pos;
         // try_consume_delimiter ensures there's at least one byte here
         for word in conjunctions {
@@ -1491,23 +2236,30 @@ impl<'a> ReprParserMultiple<'a> {
                 unsafe { self.bytes.advance_by(word.len()) };
                 match self.bytes.current_byte {
                     Some(byte) if delimiter(*byte) => {
-                        self.bytes.try_consume_delimiter(delimiter)?;
+                        self.bytes.try_consume_delimiter_borrowed(delimiter)?;
                     }
                     Some(byte) if Self::is_next_duration(*byte) => {}
                     Some(byte) => {
-                        return Err(ParseError::Syntax(
+                        return Err(BorrowedParseError::Syntax(
                             self.bytes.current_pos,
-                            format!(
+                            ErrorReason::with_detail(
+                                "A conjunction must be separated by a delimiter, sign or digit",
                                 "A conjunction must be separated by a delimiter, sign or digit \
                                  but found: '{}'",
-                                *byte as char
+                                Detail::Char(*byte as char),
                             ),
                         ));
                     }
                     None => {
-                        return Err(ParseError::Syntax(
+                        return Err(BorrowedParseError::Syntax(
                             start,
-                            format!("Input may not end with a conjunction but found: '{word}'"),
+                            ErrorReason::with_detail(
+                                "Input may not end with a conjunction",
+                                "Input may not end with a conjunction but found: '{}'",
+                                // unwrap is safe, because the bytes are equal to the bytes of the
+                                // conjunction
+                                Detail::Input(self.bytes.get_current_str(start).unwrap()),
+                            ),
                         ));
                     }
                 }
@@ -1536,7 +2288,8 @@ impl<'a> ReprParserTemplate<'a> for ReprParserMultiple<'a> {
         &'a mut self,
         mut duration_repr: DurationRepr<'a>,
         config: &'a Config,
-    ) -> Result<(DurationRepr<'a>, Option<&'a mut ReprParserMultiple<'a>>), ParseError> {
+    ) -> Result<(DurationRepr<'a>, Option<&'a mut ReprParserMultiple<'a>>), BorrowedParseError<'a>>
+    {
         match self.bytes.current_byte {
             Some(byte) if (config.outer_delimiter)(*byte) => {
                 duration_repr.is_infinite = true;
@@ -1561,22 +2314,25 @@ impl<'a> ReprParserTemplate<'a> for ReprParserMultiple<'a> {
                 Some(current) if current.eq_ignore_ascii_case(byte) => self.bytes.advance(),
                 // wrong character
                 Some(current) => {
-                    return Err(ParseError::Syntax(
+                    return Err(BorrowedParseError::Syntax(
                         self.bytes.current_pos,
-                        format!(
+                        ErrorReason::with_detail(
+                            "Error parsing infinity: Invalid character",
                             "Error parsing infinity: Invalid character '{}'",
-                            *current as char
+                            Detail::Char(*current as char),
                         ),
                     ));
                 }
                 None => {
-                    return Err(ParseError::Syntax(
+                    return Err(BorrowedParseError::Syntax(
                         // This subtraction is safe since we're here only if there's at least `inf`
                         // present
                         start - 3,
-                        format!(
+                        ErrorReason::with_detail(
+                            "Error parsing infinity: Invalid identifier for infinity",
                             "Error parsing infinity: 'inf{}' is an invalid identifier for infinity",
-                            self.bytes.get_current_str(start).unwrap() // unwrap is safe
+                            // unwrap is safe
+                            Detail::Input(self.bytes.get_current_str(start).unwrap()),
                         ),
                     ));
                 }
@@ -1592,11 +2348,12 @@ impl<'a> ReprParserTemplate<'a> for ReprParserMultiple<'a> {
                 )?;
                 Ok((duration_repr, Some(self)))
             }
-            Some(byte) => Err(ParseError::Syntax(
+            Some(byte) => Err(BorrowedParseError::Syntax(
                 self.bytes.current_pos,
-                format!(
+                ErrorReason::with_detail(
+                    "Error parsing infinity: Expected a delimiter",
                     "Error parsing infinity: Expected a delimiter but found '{}'",
-                    *byte as char
+                    Detail::Char(*byte as char),
                 ),
             )),
             None => Ok((duration_repr, None)),
@@ -1608,7 +2365,7 @@ impl<'a> ReprParserTemplate<'a> for ReprParserMultiple<'a> {
         &mut self,
         keywords: Option<&dyn TimeUnitsLike>,
         config: &'a Config,
-    ) -> Result<Option<(TimeUnit, Multiplier)>, ParseError> {
+    ) -> Result<Option<(TimeUnit, Multiplier)>, BorrowedParseError<'a>> {
         if let Some(keywords) = keywords {
             let start = self.bytes.current_pos;
             let buffer = self.bytes.buffered_advance_to(|byte: u8| {
@@ -1622,7 +2379,7 @@ impl<'a> ReprParserTemplate<'a> for ReprParserMultiple<'a> {
 
             // SAFETY: The delimiter may not match non-ascii bytes and we've parsed only valid utf-8
             // so far
-            let string = unsafe { std::str::from_utf8_unchecked(buffer) };
+            let string = unsafe { core::str::from_utf8_unchecked(buffer) };
 
             match keywords.get(string) {
                 None => {
@@ -1651,7 +2408,8 @@ impl<'a> ReprParserTemplate<'a> for ReprParserMultiple<'a> {
         &mut self,
         config: &'a Config,
         time_units: &dyn TimeUnitsLike,
-    ) -> Result<Option<(TimeUnit, Multiplier)>, ParseError> {
+        without_number: bool,
+    ) -> Result<Option<(TimeUnit, Multiplier, TimeUnitConstraints)>, BorrowedParseError<'a>> {
         // cov:excl-start
         debug_assert!(
             self.bytes.current_byte.is_some(),
@@ -1676,20 +2434,22 @@ impl<'a> ReprParserTemplate<'a> for ReprParserMultiple<'a> {
 
         // SAFETY: The delimiter may not match non-ascii bytes and we've parsed only valid utf-8 so
         // far
-        let string = unsafe { std::str::from_utf8_unchecked(buffer) };
+        let string = unsafe { core::str::from_utf8_unchecked(buffer) };
 
-        let (time_unit, mut multiplier) = match time_units.get(string) {
-            None => {
-                self.bytes.reset(start);
-                return Ok(None);
-            }
-            Some(some_time_unit) => some_time_unit,
-        };
+        let (time_unit, mut multiplier, constraints) =
+            match lookup_time_unit(config, time_units, string, without_number) {
+                None => {
+                    self.bytes.reset(start);
+                    return Ok(None);
+                }
+                Some(some_time_unit) => some_time_unit,
+            };
 
         match self.bytes.current_byte {
             Some(byte) if config.allow_ago && (config.inner_delimiter)(*byte) => {
                 let start = self.bytes.current_pos;
-                self.bytes.try_consume_delimiter(config.inner_delimiter)?;
+                self.bytes
+                    .try_consume_delimiter_borrowed(config.inner_delimiter)?;
                 if self.bytes.next_is_ignore_ascii_case(b"ago") {
                     // SAFETY: We know that next is `ago` which has 3 bytes
                     unsafe { self.bytes.advance_by(3) };
@@ -1723,7 +2483,7 @@ impl<'a> ReprParserTemplate<'a> for ReprParserMultiple<'a> {
             Some(_) | None => {}
         }
 
-        Ok(Some((time_unit, multiplier)))
+        Ok(Some((time_unit, multiplier, constraints)))
     }
 
     #[inline]
@@ -1732,12 +2492,15 @@ impl<'a> ReprParserTemplate<'a> for ReprParserMultiple<'a> {
         duration_repr: &mut DurationRepr<'a>,
         config: &'a Config,
         time_units: &dyn TimeUnitsLike,
-    ) -> Result<bool, ParseError> {
+    ) -> Result<bool, BorrowedParseError<'a>> {
         match self.bytes().current_byte {
             Some(_) if !time_units.is_empty() => {
-                if let Some((unit, multi)) = self.parse_time_unit(config, time_units)? {
+                if let Some((unit, multi, constraints)) =
+                    self.parse_time_unit(config, time_units, false)?
+                {
                     duration_repr.unit = Some(unit);
                     duration_repr.multiplier = multi;
+                    duration_repr.constraints = constraints;
                 }
             }
             Some(_) => {}
@@ -1753,7 +2516,7 @@ impl<'a> ReprParserTemplate<'a> for ReprParserMultiple<'a> {
         &'a mut self,
         duration_repr: DurationRepr<'a>,
         config: &'a Config,
-    ) -> Result<Self::Output, ParseError> {
+    ) -> Result<Self::Output, BorrowedParseError<'a>> {
         match self.bytes().current_byte {
             Some(byte) if (config.outer_delimiter)(*byte) => self
                 .try_consume_connection(
@@ -1767,6 +2530,374 @@ impl<'a> ReprParserTemplate<'a> for ReprParserMultiple<'a> {
     }
 }
 
+/// Validate the time units and signs of multiple durations
+///
+/// See [`Config::require_descending_order`], [`Config::disable_duplicate_units`] and
+/// [`Config::disable_mixed_signs`].
+#[derive(Debug, Default)]
+pub struct MultipleValidator {
+    /// The time unit of the previous duration
+    previous_unit: Option<TimeUnit>,
+    /// The bit set of all time units seen so far
+    seen_units: u16,
+    /// The sign of the first duration which wasn't zero
+    is_negative: Option<bool>,
+}
+
+impl MultipleValidator {
+    pub const fn new() -> Self {
+        Self {
+            previous_unit: None,
+            seen_units: 0,
+            is_negative: None,
+        }
+    }
+
+    /// Return true if the `config` requires any validation of multiple durations
+    #[inline]
+    pub const fn is_enabled(config: &Config) -> bool {
+        config.require_descending_order
+            || config.disable_duplicate_units
+            || config.disable_mixed_signs
+    }
+
+    /// Validate the `duration_repr` against all durations validated before
+    ///
+    /// `is_negative` is the sign of the parsed duration or `None` if the duration is zero. The
+    /// `duration` is the slice of the source string of this duration starting at `start`.
+    pub fn validate<'a>(
+        &mut self,
+        config: &Config,
+        duration_repr: &DurationRepr,
+        is_negative: Option<bool>,
+        start: usize,
+        duration: &'a str,
+    ) -> Result<(), BorrowedParseError<'a>> {
+        if !duration_repr.is_infinite {
+            let unit = duration_repr.unit.unwrap_or(duration_repr.default_unit);
+            let bit = 1 << unit as u16;
+            if config.disable_duplicate_units && self.seen_units & bit != 0 {
+                return Err(BorrowedParseError::TimeUnit(
+                    start,
+                    ErrorReason::with_detail(
+                        "Duplicate time unit",
+                        "Duplicate time unit: The time unit of '{}' occurred already",
+                        Detail::Input(duration),
+                    ),
+                ));
+            }
+            if config.require_descending_order
+                && self.previous_unit.map_or(false, |previous| unit > previous)
+            {
+                return Err(BorrowedParseError::TimeUnit(
+                    start,
+                    ErrorReason::with_detail(
+                        "Time units must be in descending order",
+                        "Time units must be in descending order but the time unit of '{}' is \
+                         greater than the previous time unit",
+                        Detail::Input(duration),
+                    ),
+                ));
+            }
+            self.seen_units |= bit;
+            self.previous_unit = Some(unit);
+        }
+
+        if let (true, Some(is_negative)) = (config.disable_mixed_signs, is_negative) {
+            match self.is_negative {
+                Some(previous) if previous != is_negative => {
+                    return Err(BorrowedParseError::Syntax(
+                        start,
+                        ErrorReason::with_detail(
+                            "Mixed signs are not allowed",
+                            "Mixed signs are not allowed but the sign of '{}' differs from the \
+                             previous durations",
+                            Detail::Input(duration),
+                        ),
+                    ));
+                }
+                Some(_) => {}
+                None => self.is_negative = Some(is_negative),
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct ReprParserPrefix<'a> {
+    pub bytes: Bytes<'a>,
+    /// The position after the number if the inner delimiter after it was consumed
+    pub number_end: Option<usize>,
+}
+
+impl<'a> ReprParserPrefix<'a> {
+    pub const fn new(input: &'a str) -> Self {
+        Self {
+            bytes: Bytes::new(input.as_bytes()),
+            number_end: None,
+        }
+    }
+
+    /// Return true if the `byte` can be part of a time unit or keyword identifier
+    #[inline]
+    pub const fn is_identifier(byte: u8) -> bool {
+        byte.is_ascii_alphabetic() || !byte.is_ascii()
+    }
+
+    #[inline]
+    fn is_end_of_identifier(&self) -> bool {
+        self.bytes
+            .current_byte
+            .map_or(true, |byte| !Self::is_identifier(*byte))
+    }
+
+    /// Consume the `delimiter` if present and the next byte matches `predicate`. Return true if
+    /// the delimiter and the byte were found.
+    fn try_consume_delimiter_before(
+        &mut self,
+        delimiter: Delimiter,
+        predicate: impl Fn(&Bytes) -> bool,
+    ) -> bool {
+        let start = self.bytes.current_pos;
+        while let Some(byte) = self.bytes.current_byte {
+            if delimiter(*byte) {
+                self.bytes.advance();
+            } else {
+                break;
+            }
+        }
+        if self.bytes.current_pos > start && predicate(&self.bytes) {
+            true
+        } else {
+            self.bytes.reset(start);
+            false
+        }
+    }
+
+    /// Consume the delimiter and conjunction between two durations if a new duration may follow
+    ///
+    /// Return false if there's no more input after the connection. The position is reset if
+    /// nothing was consumed.
+    pub fn try_consume_connection(
+        &mut self,
+        delimiter: Delimiter,
+        conjunctions: &'a [&'a str],
+    ) -> bool {
+        let start = self.bytes.current_pos;
+        if self.try_consume_delimiter_before(delimiter, |bytes| !bytes.is_end_of_input()) {
+            for word in conjunctions {
+                if self.bytes.next_is_ignore_ascii_case(word.as_bytes()) {
+                    let position = self.bytes.current_pos;
+                    // SAFETY: We're advancing by the amount of bytes of the word we just found
+                    unsafe { self.bytes.advance_by(word.len()) };
+                    let is_connection = match self.bytes.current_byte {
+                        Some(byte) if ReprParserMultiple::is_next_duration(*byte) => true,
+                        Some(_) => self.try_consume_delimiter_before(delimiter, |bytes| {
+                            !bytes.is_end_of_input()
+                        }),
+                        None => false,
+                    };
+                    if !is_connection {
+                        self.bytes.reset(position);
+                    }
+                    break;
+                }
+            }
+        }
+        if self.bytes.is_end_of_input() {
+            self.bytes.reset(start);
+            false
+        } else {
+            true
+        }
+    }
+}
+
+impl<'a> ReprParserTemplate<'a> for ReprParserPrefix<'a> {
+    type Output = (DurationRepr<'a>, &'a mut ReprParserPrefix<'a>);
+
+    #[inline]
+    fn bytes(&mut self) -> &mut Bytes<'a> {
+        &mut self.bytes
+    }
+
+    #[inline]
+    fn make_output(&'a mut self, duration_repr: DurationRepr<'a>) -> Self::Output {
+        (duration_repr, self)
+    }
+
+    fn parse_infinity_remainder(
+        &'a mut self,
+        mut duration_repr: DurationRepr<'a>,
+        _: &'a Config,
+    ) -> Result<Self::Output, BorrowedParseError<'a>> {
+        let start = self.bytes.current_pos;
+        if self.bytes.next_is_ignore_ascii_case(b"inity") {
+            // SAFETY: We just checked that there are at least 5 bytes
+            unsafe { self.bytes.advance_by(5) };
+            if !self.is_end_of_identifier() {
+                self.bytes.reset(start);
+            }
+        }
+        if self.is_end_of_identifier() {
+            duration_repr.is_infinite = true;
+            Ok((duration_repr, self))
+        } else {
+            Err(BorrowedParseError::Syntax(
+                // This subtraction is safe since we're here only if there's at least `inf` present
+                start - 3,
+                ErrorReason::new("Error parsing infinity: Expected 'inf' or 'infinity'"),
+            ))
+        }
+    }
+
+    #[inline]
+    fn parse_keyword(
+        &mut self,
+        keywords: Option<&dyn TimeUnitsLike>,
+        _: &'a Config,
+    ) -> Result<Option<(TimeUnit, Multiplier)>, BorrowedParseError<'a>> {
+        if let Some(keywords) = keywords {
+            let start = self.bytes.current_pos;
+            let buffer = self
+                .bytes
+                .buffered_advance_to(|byte| !Self::is_identifier(byte));
+            // SAFETY: The identifier ends at an ascii byte and we've parsed only valid utf-8 so far
+            let string = unsafe { core::str::from_utf8_unchecked(buffer) };
+            match keywords.get(string) {
+                None => {
+                    self.bytes.reset(start);
+                    Ok(None)
+                }
+                some_time_unit => Ok(some_time_unit),
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn parse_time_unit(
+        &mut self,
+        config: &'a Config,
+        time_units: &dyn TimeUnitsLike,
+        without_number: bool,
+    ) -> Result<Option<(TimeUnit, Multiplier, TimeUnitConstraints)>, BorrowedParseError<'a>> {
+        let start = self.bytes.current_pos;
+        let buffer = self
+            .bytes
+            .buffered_advance_to(|byte| !Self::is_identifier(byte));
+        if buffer.is_empty() {
+            return Ok(None);
+        }
+
+        // SAFETY: The identifier ends at an ascii byte and we've parsed only valid utf-8 so far
+        let string = unsafe { core::str::from_utf8_unchecked(buffer) };
+        // The time unit has to be the whole identifier, so the `m` in `5 months` is no time unit
+        let (time_unit, mut multiplier, constraints) =
+            match lookup_time_unit(config, time_units, string, without_number) {
+                None => {
+                    self.bytes.reset(start);
+                    return Ok(None);
+                }
+                Some(found) => found,
+            };
+
+        if config.allow_ago {
+            let end = self.bytes.current_pos;
+            if self.try_consume_delimiter_before(config.inner_delimiter, |bytes| {
+                bytes.next_is_ignore_ascii_case(b"ago")
+            }) {
+                // SAFETY: We know that next is `ago` which has 3 bytes
+                unsafe { self.bytes.advance_by(3) };
+                if self.is_end_of_identifier() {
+                    multiplier = multiplier.saturating_neg();
+                } else {
+                    self.bytes.reset(end);
+                }
+            }
+        }
+
+        Ok(Some((time_unit, multiplier, constraints)))
+    }
+
+    fn parse_number_time_unit(
+        &mut self,
+        duration_repr: &mut DurationRepr<'a>,
+        config: &'a Config,
+        time_units: &dyn TimeUnitsLike,
+    ) -> Result<bool, BorrowedParseError<'a>> {
+        if !time_units.is_empty() && self.bytes.current_byte.is_some() {
+            if let Some((unit, multi, constraints)) =
+                self.parse_time_unit(config, time_units, false)?
+            {
+                duration_repr.unit = Some(unit);
+                duration_repr.multiplier = multi;
+                duration_repr.constraints = constraints;
+                return Ok(true);
+            }
+        }
+        // The delimiter after the number belongs to the remainder if there's no time unit
+        if let Some(number_end) = self.number_end {
+            self.bytes.reset(number_end);
+        }
+        Ok(true)
+    }
+
+    #[inline]
+    fn finalize(
+        &'a mut self,
+        duration_repr: DurationRepr<'a>,
+        _: &'a Config,
+    ) -> Result<Self::Output, BorrowedParseError<'a>> {
+        Ok((duration_repr, self))
+    }
+
+    fn parse_number_exponent(
+        &mut self,
+        duration_repr: &mut DurationRepr<'a>,
+        disable_exponent: bool,
+    ) -> Result<bool, BorrowedParseError<'a>> {
+        let bytes = self.bytes();
+        match bytes.current_byte {
+            Some(byte) if byte.eq_ignore_ascii_case(&b'e') && !disable_exponent => {
+                // Only an `e` followed by an optional sign and a digit is an exponent, otherwise
+                // the `e` may be the start of a time unit or of the remainder
+                let is_exponent = match bytes.peek(3) {
+                    Some([_, b'+' | b'-', digit] | [_, digit, _]) => digit.is_ascii_digit(),
+                    _ => bytes.peek(2).map_or(false, |e| e[1].is_ascii_digit()),
+                };
+                if is_exponent {
+                    bytes.advance();
+                    duration_repr.exponent = self.parse_exponent()?;
+                }
+                Ok(true)
+            }
+            Some(_) => Ok(true),
+            None => Ok(false),
+        }
+    }
+
+    fn parse_number_delimiter(
+        &mut self,
+        delimiter: Option<Delimiter>,
+    ) -> Result<bool, BorrowedParseError<'a>> {
+        self.number_end = None;
+        match (self.bytes.current_byte, delimiter) {
+            (Some(byte), Some(delimiter)) if delimiter(*byte) => {
+                let start = self.bytes.current_pos;
+                if self.try_consume_delimiter_before(delimiter, |bytes| !bytes.is_end_of_input()) {
+                    self.number_end = Some(start);
+                    Ok(true)
+                } else {
+                    Ok(false)
+                }
+            }
+            (Some(_), _) => Ok(true),
+            (None, _) => Ok(false),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -1954,10 +3085,10 @@ mod tests {
     fn test_try_consume_delimiter_when_input_starts_with_delimiter_then_error() {
         let mut bytes = Bytes::new(b" some");
         assert_eq!(
-            bytes.try_consume_delimiter(|byte| byte == b' '),
-            Err(ParseError::Syntax(
+            bytes.try_consume_delimiter_borrowed(|byte| byte == b' '),
+            Err(BorrowedParseError::Syntax(
                 0,
-                "Input may not start with a delimiter".to_owned()
+                ErrorReason::new("Input may not start with a delimiter")
             ))
         );
     }
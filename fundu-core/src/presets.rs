@@ -0,0 +1,83 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! The default time units and configurations of the parsers of `fundu` and `fundu-systemd`
+//!
+//! The parsers and the compile time macros of `fundu-macros` are built from these definitions, so
+//! the macros parse exactly like `fundu::DurationParser::new()` and
+//! `fundu_systemd::TimeSpanParser::new()`.
+
+use crate::config::{Config, ConfigBuilder, Delimiter};
+use crate::time::Duration;
+use crate::time::TimeUnit::{self, *};
+
+/// The time units of `fundu::DurationParser::new()` with their [`TimeUnit::default_identifier`]
+pub const STANDARD_TIME_UNITS: [TimeUnit; 8] = [
+    NanoSecond,
+    MicroSecond,
+    MilliSecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+];
+
+/// The whitespace of systemd: `b' '`, `b'\x09'`, `b'\x0A'`, `b'\x0B'`, `b'\x0C'` and `b'\x0D'`
+pub const SYSTEMD_DELIMITER: Delimiter = |byte| byte == b' ' || byte.wrapping_sub(9) < 5;
+
+/// The [`Config`] of `fundu_systemd::TimeSpanParser::new()`
+pub const SYSTEMD_CONFIG: Config<'static> = ConfigBuilder::new()
+    .allow_time_unit_delimiter()
+    .disable_exponent()
+    .disable_infinity()
+    .number_is_optional()
+    .parse_multiple(None)
+    .inner_delimiter(SYSTEMD_DELIMITER)
+    .outer_delimiter(SYSTEMD_DELIMITER)
+    .build();
+
+/// The maximum duration used when parsing with micro seconds precision
+pub const SYSTEMD_MAX_MICRO_DURATION: Duration =
+    Duration::positive(u64::MAX / 1_000_000, (u64::MAX % 1_000_000) as u32 * 1000);
+
+/// The maximum duration used when parsing with nano seconds precision
+pub const SYSTEMD_MAX_NANOS_DURATION: Duration =
+    Duration::positive(u64::MAX / 1_000_000_000, (u64::MAX % 1_000_000_000) as u32);
+
+/// Return the [`TimeUnit`] of the systemd time unit `identifier`
+///
+/// The nano second time units `ns` and `nsec` are included although only the parsers with nano
+/// seconds precision of `fundu-systemd` accept them.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_core::presets::systemd_time_unit;
+/// use fundu_core::time::TimeUnit;
+///
+/// assert_eq!(systemd_time_unit("min"), Some(TimeUnit::Minute));
+/// assert_eq!(systemd_time_unit("M"), Some(TimeUnit::Month));
+/// assert_eq!(systemd_time_unit("mins"), None);
+/// ```
+#[inline]
+pub fn systemd_time_unit(identifier: &str) -> Option<TimeUnit> {
+    let time_unit = match identifier {
+        "ns" | "nsec" => NanoSecond,
+        // These are two different letters: the greek small letter mu U+03BC and the micro sign
+        // U+00B5
+        "us" | "\u{03bc}s" | "\u{00b5}s" | "usec" => MicroSecond,
+        "ms" | "msec" => MilliSecond,
+        "s" | "sec" | "second" | "seconds" => Second,
+        "m" | "min" | "minute" | "minutes" => Minute,
+        "h" | "hr" | "hour" | "hours" => Hour,
+        "d" | "day" | "days" => Day,
+        "w" | "week" | "weeks" => Week,
+        "M" | "month" | "months" => Month,
+        "y" | "year" | "years" => Year,
+        _ => return None,
+    };
+    Some(time_unit)
+}
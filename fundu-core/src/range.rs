@@ -0,0 +1,555 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Parse ranges and bounds of durations like `5m..10m`, `1s to 3s` or `<=500ms`
+//!
+//! The [`DurationRangeParser`] parses the endpoints of a range with any parser implementing
+//! [`ParseDurationPrefix`] into a [`DurationRange`]. See [`DurationRangeParser`] for the syntax.
+
+use alloc::format;
+use core::ops::{Bound, RangeBounds};
+
+use crate::error::ParseError;
+use crate::stream::ParseDurationPrefix;
+use crate::time::Duration;
+use crate::util::{skip_whitespace, unexpected};
+
+/// The comparison operators ordered such that `<=` is found before `<` with their bound and
+/// whether the bound is the start of the range
+#[allow(clippy::type_complexity)]
+const COMPARISONS: [(&str, fn(Duration) -> Bound<Duration>, bool); 4] = [
+    ("<=", Bound::Included, false),
+    ("<", Bound::Excluded, false),
+    (">=", Bound::Included, true),
+    (">", Bound::Excluded, true),
+];
+
+/// A range of [`Duration`]s with inclusive, exclusive or without bounds
+///
+/// A `DurationRange` is usually the result of the [`DurationRangeParser`] but can also be created
+/// directly with [`DurationRange::new`].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::ops::Bound;
+///
+/// use fundu_core::range::DurationRange;
+/// use fundu_core::time::Duration;
+///
+/// let range = DurationRange::new(
+///     Bound::Included(Duration::positive(300, 0)),
+///     Bound::Excluded(Duration::positive(600, 0)),
+/// );
+/// assert!(range.contains(&Duration::positive(300, 0)));
+/// assert!(range.contains(&Duration::positive(599, 999_999_999)));
+/// assert!(!range.contains(&Duration::positive(600, 0)));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct DurationRange {
+    /// The lower bound of the range
+    pub start: Bound<Duration>,
+    /// The upper bound of the range
+    pub end: Bound<Duration>,
+}
+
+impl DurationRange {
+    /// Create a new `DurationRange` with the `start` and `end` bound
+    ///
+    /// Unlike the [`DurationRangeParser`], this method doesn't validate that `start` is not
+    /// greater than `end`. Such a range doesn't contain any duration.
+    pub const fn new(start: Bound<Duration>, end: Bound<Duration>) -> Self {
+        Self { start, end }
+    }
+
+    /// Return true if the `duration` is contained in this range
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::ops::Bound;
+    ///
+    /// use fundu_core::range::DurationRange;
+    /// use fundu_core::time::Duration;
+    ///
+    /// let range = DurationRange::new(Bound::Unbounded, Bound::Included(Duration::positive(1, 0)));
+    /// assert!(range.contains(&Duration::ZERO));
+    /// assert!(range.contains(&Duration::positive(1, 0)));
+    /// assert!(!range.contains(&Duration::positive(1, 1)));
+    /// ```
+    pub fn contains(&self, duration: &Duration) -> bool {
+        RangeBounds::contains(self, duration)
+    }
+}
+
+impl RangeBounds<Duration> for DurationRange {
+    fn start_bound(&self) -> Bound<&Duration> {
+        match &self.start {
+            Bound::Included(start) => Bound::Included(start),
+            Bound::Excluded(start) => Bound::Excluded(start),
+            Bound::Unbounded => Bound::Unbounded,
+        }
+    }
+
+    fn end_bound(&self) -> Bound<&Duration> {
+        match &self.end {
+            Bound::Included(end) => Bound::Included(end),
+            Bound::Excluded(end) => Bound::Excluded(end),
+            Bound::Unbounded => Bound::Unbounded,
+        }
+    }
+}
+
+/// A parser for ranges and bounds of durations
+///
+/// The endpoints of the range are parsed with the parser, so the grammar of the durations is
+/// exactly the grammar of the configured parser. The following forms are recognized:
+///
+/// * `start..end` is a range including `start` and excluding `end` like in `5m..10m`
+/// * `start..=end` is a range including `start` and `end` like in `5m..=10m`
+/// * `start to end` and `start-end` are ranges including `start` and `end` like in `1s to 3s` and
+///   `1s-3s`
+/// * `start..` and `..end` or `..=end` are open ranges like in `1h..` and `..1h`. A range without
+///   any bounds is written as `..`
+/// * `<end`, `<=end`, `>start` and `>=start` are bounds like in `<=500ms` and `>2h`
+///
+/// Whitespace may occur between the endpoints and the separators. If both bounds are present, the
+/// start must not be greater than the end or the result is a [`ParseError::InvalidInput`]. The
+/// ranges `5m..5m` and `>inf` are valid but empty.
+///
+/// # Problems
+///
+/// The parser must stop in front of the separators `to` and `-`. A parser which parses multiple
+/// durations consumes `1s-3s` as a single duration if signs can follow a duration without a
+/// delimiter. The separator `..` doesn't have this problem.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::ops::Bound;
+///
+/// use fundu_core::error::ParseError;
+/// use fundu_core::range::{DurationRange, DurationRangeParser};
+/// use fundu_core::stream::ParseDurationPrefix;
+/// use fundu_core::time::Duration;
+///
+/// // A parser for whole minutes like `10m`
+/// struct Minutes;
+///
+/// impl ParseDurationPrefix for Minutes {
+///     fn parse_duration_prefix<'s>(
+///         &self,
+///         source: &'s str,
+///     ) -> Result<(Duration, &'s str), ParseError> {
+///         let end = source
+///             .find(|c: char| !c.is_ascii_digit())
+///             .unwrap_or(source.len());
+///         let minutes: u64 = source[..end]
+///             .parse()
+///             .map_err(|_| ParseError::Syntax(0, "Expected a number".to_string()))?;
+///         match source[end..].strip_prefix('m') {
+///             Some(rest) => Ok((Duration::positive(minutes * 60, 0), rest)),
+///             None => Err(ParseError::TimeUnit(end, "Expected a time unit".to_string())),
+///         }
+///     }
+/// }
+///
+/// let parser = DurationRangeParser::new(Minutes);
+/// let range = parser.parse("5m..10m").unwrap();
+/// assert_eq!(
+///     range,
+///     DurationRange::new(
+///         Bound::Included(Duration::positive(300, 0)),
+///         Bound::Excluded(Duration::positive(600, 0))
+///     )
+/// );
+/// assert!(range.contains(&Duration::positive(300, 0)));
+/// assert!(!range.contains(&Duration::positive(600, 0)));
+///
+/// let range = parser.parse("1m to 3m").unwrap();
+/// assert!(range.contains(&Duration::positive(180, 0)));
+///
+/// let range = parser.parse(">= 2m").unwrap();
+/// assert_eq!(
+///     range,
+///     DurationRange::new(Bound::Included(Duration::positive(120, 0)), Bound::Unbounded)
+/// );
+///
+/// assert_eq!(
+///     parser.parse("10m..5m"),
+///     Err(ParseError::InvalidInput(
+///         "The start 10m of the range is greater than the end 5m".to_string()
+///     ))
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DurationRangeParser<P> {
+    parser: P,
+}
+
+impl<P: ParseDurationPrefix> DurationRangeParser<P> {
+    /// Create a new `DurationRangeParser` which parses the endpoints with the `parser`
+    pub const fn new(parser: P) -> Self {
+        Self { parser }
+    }
+
+    /// Return the parser of this `DurationRangeParser`
+    pub const fn parser(&self) -> &P {
+        &self.parser
+    }
+
+    /// Parse the `source` string into a [`DurationRange`]
+    pub fn parse(&self, source: &str) -> Result<DurationRange, ParseError> {
+        if source.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let mut position = skip_whitespace(source, 0);
+        for (operator, to_bound, is_start) in COMPARISONS {
+            if source[position..].starts_with(operator) {
+                position = skip_whitespace(source, position + operator.len());
+                let (duration, position) = self.parse_endpoint(source, position, source.len())?;
+                expect_end(source, position)?;
+                return Ok(if is_start {
+                    DurationRange::new(to_bound(duration), Bound::Unbounded)
+                } else {
+                    DurationRange::new(Bound::Unbounded, to_bound(duration))
+                });
+            }
+        }
+
+        // The durations don't contain `..`, so the start ends at the latest in front of it. This
+        // prevents the parser from consuming the first `.` of `..` like in `5..10` as fraction.
+        let limit = source.find("..").unwrap_or(source.len());
+        let start = if position < limit {
+            let (duration, end) = self.parse_endpoint(source, position, limit)?;
+            position = skip_whitespace(source, end);
+            Some(duration)
+        } else {
+            None
+        };
+
+        let rest = &source[position..];
+        let range = if let Some(rest) = rest.strip_prefix("..=") {
+            let column = skip_whitespace(source, source.len() - rest.len());
+            let (end, position) = self.parse_endpoint(source, column, source.len())?;
+            expect_end(source, position)?;
+            DurationRange::new(
+                start.map_or(Bound::Unbounded, Bound::Included),
+                Bound::Included(end),
+            )
+        } else if let Some(rest) = rest.strip_prefix("..") {
+            let column = skip_whitespace(source, source.len() - rest.len());
+            let end = if column < source.len() {
+                let (end, position) = self.parse_endpoint(source, column, source.len())?;
+                expect_end(source, position)?;
+                Bound::Excluded(end)
+            } else {
+                Bound::Unbounded
+            };
+            DurationRange::new(start.map_or(Bound::Unbounded, Bound::Included), end)
+        } else if let (Some(start), Some(rest)) = (
+            start,
+            rest.strip_prefix("to").or_else(|| rest.strip_prefix('-')),
+        ) {
+            let column = skip_whitespace(source, source.len() - rest.len());
+            let (end, position) = self.parse_endpoint(source, column, source.len())?;
+            expect_end(source, position)?;
+            DurationRange::new(Bound::Included(start), Bound::Included(end))
+        } else {
+            return Err(unexpected(
+                source,
+                position,
+                if start.is_some() {
+                    "'..', '..=', 'to' or '-'"
+                } else {
+                    "a duration, '..', '<' or '>'"
+                },
+            ));
+        };
+
+        match range {
+            DurationRange {
+                start: Bound::Included(start) | Bound::Excluded(start),
+                end: Bound::Included(end) | Bound::Excluded(end),
+            } if start > end => Err(ParseError::InvalidInput(format!(
+                "The start {start} of the range is greater than the end {end}"
+            ))),
+            range => Ok(range),
+        }
+    }
+
+    /// Parse the duration at `position` which ends at the latest at `limit`
+    ///
+    /// Return the duration and the position after the duration.
+    fn parse_endpoint(
+        &self,
+        source: &str,
+        position: usize,
+        limit: usize,
+    ) -> Result<(Duration, usize), ParseError> {
+        let input = &source[position..limit];
+        if input.is_empty() {
+            return Err(unexpected(source, position, "a duration"));
+        }
+        self.parser
+            .parse_duration_prefix(input)
+            .map(|(duration, rest)| (duration, limit - rest.len()))
+            .map_err(|error| error.with_offset(position))
+    }
+}
+
+/// Return an error if there is more than whitespace after `position`
+fn expect_end(source: &str, position: usize) -> Result<(), ParseError> {
+    let position = skip_whitespace(source, position);
+    if position < source.len() {
+        Err(unexpected(source, position, "the end of input"))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration as StdDuration;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Parses numbers with an optional fraction and time unit `ms`, `s`, `m` or `h` and `inf`
+    struct Simple;
+
+    impl ParseDurationPrefix for Simple {
+        fn parse_duration_prefix<'s>(
+            &self,
+            source: &'s str,
+        ) -> Result<(Duration, &'s str), ParseError> {
+            if let Some(rest) = source.strip_prefix("inf") {
+                return Ok((Duration::MAX, rest));
+            }
+            let (sign, digits) = match source.strip_prefix('-') {
+                Some(digits) => (true, digits),
+                None => (false, source),
+            };
+            let end = digits
+                .find(|c: char| !c.is_ascii_digit() && c != '.')
+                .unwrap_or(digits.len());
+            let number: f64 = digits[..end].parse().map_err(|_| {
+                ParseError::Syntax(usize::from(sign), "Expected a number".to_owned())
+            })?;
+            let rest = &digits[end..];
+            let unit_end = rest
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(rest.len());
+            let seconds: f64 = match &rest[..unit_end] {
+                "" | "s" => 1.0,
+                "ms" => 0.001,
+                "m" => 60.0,
+                "h" => 3600.0,
+                unit => {
+                    return Err(ParseError::TimeUnit(
+                        usize::from(sign) + end,
+                        format!("Invalid time unit: '{unit}'"),
+                    ))
+                }
+            };
+            let duration = StdDuration::from_secs_f64(number * seconds);
+            Ok((Duration::from_std(sign, duration), &rest[unit_end..]))
+        }
+    }
+
+    const fn secs(seconds: u64) -> Duration {
+        Duration::positive(seconds, 0)
+    }
+
+    #[rstest]
+    #[case::exclusive("5m..10m", Bound::Included(secs(300)), Bound::Excluded(secs(600)))]
+    #[case::inclusive("5m..=10m", Bound::Included(secs(300)), Bound::Included(secs(600)))]
+    #[case::to("1s to 3s", Bound::Included(secs(1)), Bound::Included(secs(3)))]
+    #[case::hyphen("1s-3s", Bound::Included(secs(1)), Bound::Included(secs(3)))]
+    #[case::hyphen_with_whitespace("1s - 3s", Bound::Included(secs(1)), Bound::Included(secs(3)))]
+    #[case::open_end("1h..", Bound::Included(secs(3600)), Bound::Unbounded)]
+    #[case::open_start("..1h", Bound::Unbounded, Bound::Excluded(secs(3600)))]
+    #[case::open_start_inclusive("..=1h", Bound::Unbounded, Bound::Included(secs(3600)))]
+    #[case::full("..", Bound::Unbounded, Bound::Unbounded)]
+    #[case::less("<1h", Bound::Unbounded, Bound::Excluded(secs(3600)))]
+    #[case::less_or_equal(
+        "<=500ms",
+        Bound::Unbounded,
+        Bound::Included(Duration::positive(0, 500_000_000))
+    )]
+    #[case::greater(">2h", Bound::Excluded(secs(7200)), Bound::Unbounded)]
+    #[case::greater_or_equal(">=2h", Bound::Included(secs(7200)), Bound::Unbounded)]
+    #[case::whitespace(
+        " 5m  ..\t10m ",
+        Bound::Included(secs(300)),
+        Bound::Excluded(secs(600))
+    )]
+    #[case::comparison_with_whitespace(" >=  2h ", Bound::Included(secs(7200)), Bound::Unbounded)]
+    #[case::numbers("5..10", Bound::Included(secs(5)), Bound::Excluded(secs(10)))]
+    #[case::fractions(
+        "0.5..1.5",
+        Bound::Included(Duration::positive(0, 500_000_000)),
+        Bound::Excluded(Duration::positive(1, 500_000_000))
+    )]
+    #[case::fraction_without_digits(
+        "0...5",
+        Bound::Included(secs(0)),
+        Bound::Excluded(Duration::positive(0, 500_000_000))
+    )]
+    #[case::equal("5m..5m", Bound::Included(secs(300)), Bound::Excluded(secs(300)))]
+    #[case::infinity("1h..inf", Bound::Included(secs(3600)), Bound::Excluded(Duration::MAX))]
+    #[case::negative(
+        "-1s..1s",
+        Bound::Included(Duration::negative(1, 0)),
+        Bound::Excluded(secs(1))
+    )]
+    #[case::negative_end(
+        "-2s..-1s",
+        Bound::Included(Duration::negative(2, 0)),
+        Bound::Excluded(Duration::negative(1, 0))
+    )]
+    #[case::negative_with_hyphen(
+        "-2s--1s",
+        Bound::Included(Duration::negative(2, 0)),
+        Bound::Included(Duration::negative(1, 0))
+    )]
+    fn test_duration_range_parser(
+        #[case] source: &str,
+        #[case] start: Bound<Duration>,
+        #[case] end: Bound<Duration>,
+    ) {
+        assert_eq!(
+            DurationRangeParser::new(Simple).parse(source),
+            Ok(DurationRange::new(start, end))
+        );
+    }
+
+    #[rstest]
+    #[case::empty("", ParseError::Empty)]
+    #[case::only_whitespace(
+        "  ",
+        ParseError::Syntax(
+            2,
+            "Expected a duration, '..', '<' or '>' but reached the end of input".to_owned()
+        )
+    )]
+    #[case::single_duration(
+        "5m",
+        ParseError::Syntax(
+            2,
+            "Expected '..', '..=', 'to' or '-' but reached the end of input".to_owned()
+        )
+    )]
+    #[case::invalid_separator(
+        "5m ~ 10m",
+        ParseError::Syntax(3, "Expected '..', '..=', 'to' or '-' but found '~'".to_owned())
+    )]
+    #[case::invalid_start(
+        "x..1s",
+        ParseError::Syntax(0, "Expected a number".to_owned())
+    )]
+    #[case::invalid_time_unit(
+        "1s..1x",
+        ParseError::TimeUnit(5, "Invalid time unit: 'x'".to_owned())
+    )]
+    #[case::invalid_time_unit_after_whitespace(
+        "1s to  1x",
+        ParseError::TimeUnit(8, "Invalid time unit: 'x'".to_owned())
+    )]
+    #[case::missing_end_of_inclusive(
+        "1s..=",
+        ParseError::Syntax(5, "Expected a duration but reached the end of input".to_owned())
+    )]
+    #[case::missing_end_of_to(
+        "1s to ",
+        ParseError::Syntax(6, "Expected a duration but reached the end of input".to_owned())
+    )]
+    #[case::missing_start_of_to(
+        "to 1s",
+        ParseError::Syntax(0, "Expected a number".to_owned())
+    )]
+    #[case::missing_start_of_hyphen(
+        "- 1s",
+        ParseError::Syntax(1, "Expected a number".to_owned())
+    )]
+    #[case::missing_duration_of_comparison(
+        "<=",
+        ParseError::Syntax(2, "Expected a duration but reached the end of input".to_owned())
+    )]
+    #[case::trailing_input(
+        "1s..2s 3s",
+        ParseError::Syntax(7, "Expected the end of input but found '3'".to_owned())
+    )]
+    #[case::trailing_input_of_comparison(
+        ">1s..",
+        ParseError::Syntax(3, "Expected the end of input but found '.'".to_owned())
+    )]
+    #[case::double_range(
+        "1s..2s..3s",
+        ParseError::Syntax(6, "Expected the end of input but found '.'".to_owned())
+    )]
+    #[case::start_greater_than_end(
+        "10m..5m",
+        ParseError::InvalidInput("The start 10m of the range is greater than the end 5m".to_owned())
+    )]
+    #[case::start_greater_than_end_inclusive(
+        "2s to 1s",
+        ParseError::InvalidInput("The start 2s of the range is greater than the end 1s".to_owned())
+    )]
+    fn test_duration_range_parser_when_error(#[case] source: &str, #[case] expected: ParseError) {
+        assert_eq!(
+            DurationRangeParser::new(Simple).parse(source),
+            Err(expected)
+        );
+    }
+
+    #[rstest]
+    #[case::inclusive_exclusive("5s..10s", &[5, 9], &[4, 10])]
+    #[case::inclusive("5s..=10s", &[5, 10], &[4, 11])]
+    #[case::less("<10s", &[0, 9], &[10, 11])]
+    #[case::less_or_equal("<=10s", &[0, 10], &[11])]
+    #[case::greater(">10s", &[11, u64::MAX], &[0, 10])]
+    #[case::greater_or_equal(">=10s", &[10, 11], &[9])]
+    #[case::full("..", &[0, u64::MAX], &[])]
+    #[case::empty("5s..5s", &[], &[4, 5, 6])]
+    fn test_duration_range_contains(
+        #[case] source: &str,
+        #[case] contained: &[u64],
+        #[case] not_contained: &[u64],
+    ) {
+        let range = DurationRangeParser::new(Simple).parse(source).unwrap();
+        for seconds in contained {
+            assert!(range.contains(&secs(*seconds)), "{seconds}");
+        }
+        for seconds in not_contained {
+            assert!(!range.contains(&secs(*seconds)), "{seconds}");
+        }
+    }
+
+    #[test]
+    fn test_duration_range_contains_with_negative_durations() {
+        let range = DurationRangeParser::new(Simple).parse("-1s..=1s").unwrap();
+        assert!(range.contains(&Duration::negative(1, 0)));
+        assert!(range.contains(&Duration::ZERO));
+        assert!(range.contains(&Duration::positive(1, 0)));
+        assert!(!range.contains(&Duration::negative(1, 1)));
+    }
+
+    #[test]
+    fn test_duration_range_when_start_greater_than_end() {
+        let range = DurationRange::new(Bound::Included(secs(2)), Bound::Included(secs(1)));
+        assert!(!range.contains(&secs(1)));
+        assert!(!range.contains(&secs(2)));
+    }
+
+    #[test]
+    fn test_duration_range_range_bounds() {
+        let range = DurationRange::new(Bound::Excluded(secs(1)), Bound::Unbounded);
+        assert_eq!(range.start_bound(), Bound::Excluded(&secs(1)));
+        assert_eq!(range.end_bound(), Bound::Unbounded);
+    }
+}
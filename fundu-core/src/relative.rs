@@ -0,0 +1,619 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Calendar-aware durations which keep calendar time units symbolic
+//!
+//! The [`crate::time::TimeUnit::Month`] and [`crate::time::TimeUnit::Year`] time units have a
+//! fixed [`crate::time::Multiplier`] (`30.44` days and `365.25` days) when parsed into a
+//! [`Duration`]. A [`RelativeDuration`] instead keeps the amount of years, months, weeks and days
+//! and resolves them only when applied to a date with [`RelativeDuration::checked_add_to`].
+
+use core::ops::Neg;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::time::Duration;
+
+const DAYS_PER_WEEK: i64 = 7;
+const MONTHS_PER_YEAR: i64 = 12;
+
+/// A duration with symbolic calendar time units
+///
+/// The `years`, `months`, `weeks` and `days` are not converted into seconds but kept as they
+/// were parsed. All other time units accumulate in the `exact` [`Duration`]. Since the length of a
+/// year or month (and in the presence of time zones, of a day) depends on the date, the
+/// [`RelativeDuration`] can only be resolved with a date at hand. Applying a [`RelativeDuration`]
+/// to a date with [`RelativeDuration::checked_add_to`] happens in the following order:
+///
+/// 1. The `years` and `months` are added to the calendar date. If the resulting month has fewer
+///    days than the day of the original date, the day is clamped to the last day of the resulting
+///    month. For example, `2023-01-31` plus `1 month` is `2023-02-28` and `2024-02-29` plus `1
+///    year` is `2025-02-28`.
+/// 2. The `weeks` and `days` are added as calendar days keeping the time of day.
+/// 3. The `exact` duration is added.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_core::relative::RelativeDuration;
+/// use fundu_core::time::Duration;
+///
+/// let relative = RelativeDuration::new(1, 2, 0, 3, Duration::positive(60, 0));
+/// assert_eq!(relative.total_months(), Some(14));
+/// assert_eq!(relative.total_days(), Some(3));
+/// assert!(!relative.is_zero());
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RelativeDuration {
+    /// The amount of calendar years
+    pub years: i64,
+    /// The amount of calendar months
+    pub months: i64,
+    /// The amount of calendar weeks
+    pub weeks: i64,
+    /// The amount of calendar days
+    pub days: i64,
+    /// The accumulated duration of all other time units
+    pub exact: Duration,
+}
+
+impl RelativeDuration {
+    /// A [`RelativeDuration`] of zero time
+    pub const ZERO: Self = Self::new(0, 0, 0, 0, Duration::ZERO);
+
+    /// Create a new [`RelativeDuration`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_core::relative::RelativeDuration;
+    /// use fundu_core::time::Duration;
+    ///
+    /// let relative = RelativeDuration::new(1, 0, 0, 0, Duration::ZERO);
+    /// assert_eq!(relative.years, 1);
+    /// ```
+    pub const fn new(years: i64, months: i64, weeks: i64, days: i64, exact: Duration) -> Self {
+        Self {
+            years,
+            months,
+            weeks,
+            days,
+            exact,
+        }
+    }
+
+    /// Create a new [`RelativeDuration`] without calendar time units
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_core::relative::RelativeDuration;
+    /// use fundu_core::time::Duration;
+    ///
+    /// let relative = RelativeDuration::from_exact(Duration::positive(1, 0));
+    /// assert_eq!(relative.total_months(), Some(0));
+    /// assert_eq!(relative.exact, Duration::positive(1, 0));
+    /// ```
+    pub const fn from_exact(exact: Duration) -> Self {
+        Self::new(0, 0, 0, 0, exact)
+    }
+
+    /// Return true if all components of this [`RelativeDuration`] are zero
+    pub fn is_zero(&self) -> bool {
+        self.years == 0
+            && self.months == 0
+            && self.weeks == 0
+            && self.days == 0
+            && self.exact.is_zero()
+    }
+
+    /// Return true if any of the components of this [`RelativeDuration`] is negative
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_core::relative::RelativeDuration;
+    /// use fundu_core::time::Duration;
+    ///
+    /// assert!(!RelativeDuration::ZERO.is_negative());
+    /// assert!(RelativeDuration::new(1, -1, 0, 0, Duration::ZERO).is_negative());
+    /// assert!(RelativeDuration::from_exact(Duration::negative(1, 0)).is_negative());
+    /// ```
+    pub fn is_negative(&self) -> bool {
+        self.years < 0
+            || self.months < 0
+            || self.weeks < 0
+            || self.days < 0
+            || self.exact.is_negative()
+    }
+
+    /// Return the `years` and `months` combined into months or `None` on overflow
+    pub const fn total_months(&self) -> Option<i64> {
+        match self.years.checked_mul(MONTHS_PER_YEAR) {
+            Some(months) => months.checked_add(self.months),
+            None => None,
+        }
+    }
+
+    /// Return the `weeks` and `days` combined into days or `None` on overflow
+    pub const fn total_days(&self) -> Option<i64> {
+        match self.weeks.checked_mul(DAYS_PER_WEEK) {
+            Some(days) => days.checked_add(self.days),
+            None => None,
+        }
+    }
+
+    /// Add two [`RelativeDuration`]s component-wise saturating at the respective maximum and
+    /// minimum
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_core::relative::RelativeDuration;
+    /// use fundu_core::time::Duration;
+    ///
+    /// let relative = RelativeDuration::new(1, 2, 3, 4, Duration::positive(5, 0));
+    /// assert_eq!(
+    ///     relative.saturating_add(relative),
+    ///     RelativeDuration::new(2, 4, 6, 8, Duration::positive(10, 0))
+    /// );
+    /// assert_eq!(
+    ///     RelativeDuration::new(i64::MAX, 0, 0, 0, Duration::ZERO)
+    ///         .saturating_add(RelativeDuration::new(1, 0, 0, 0, Duration::ZERO)),
+    ///     RelativeDuration::new(i64::MAX, 0, 0, 0, Duration::ZERO)
+    /// );
+    /// ```
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self {
+            years: self.years.saturating_add(other.years),
+            months: self.months.saturating_add(other.months),
+            weeks: self.weeks.saturating_add(other.weeks),
+            days: self.days.saturating_add(other.days),
+            exact: self.exact.saturating_add(other.exact),
+        }
+    }
+
+    /// Add this [`RelativeDuration`] to a date-like `target` returning `None` on overflow
+    ///
+    /// See the documentation of [`RelativeDuration`] for the rules how the components are applied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "chrono")]
+    /// # {
+    /// use chrono::NaiveDate;
+    /// use fundu_core::relative::RelativeDuration;
+    /// use fundu_core::time::Duration;
+    ///
+    /// let date_time = NaiveDate::from_ymd_opt(2023, 1, 31)
+    ///     .unwrap()
+    ///     .and_hms_opt(0, 0, 0)
+    ///     .unwrap();
+    /// let relative = RelativeDuration::new(0, 1, 0, 1, Duration::positive(3600, 0));
+    /// assert_eq!(
+    ///     relative.checked_add_to(&date_time),
+    ///     NaiveDate::from_ymd_opt(2023, 3, 1)
+    ///         .unwrap()
+    ///         .and_hms_opt(1, 0, 0)
+    /// );
+    /// # }
+    /// ```
+    pub fn checked_add_to<T: CheckedAddRelative>(&self, target: &T) -> Option<T> {
+        target.checked_add_relative(self)
+    }
+}
+
+impl From<Duration> for RelativeDuration {
+    fn from(duration: Duration) -> Self {
+        Self::from_exact(duration)
+    }
+}
+
+impl Neg for RelativeDuration {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            years: self.years.saturating_neg(),
+            months: self.months.saturating_neg(),
+            weeks: self.weeks.saturating_neg(),
+            days: self.days.saturating_neg(),
+            exact: self.exact.neg(),
+        }
+    }
+}
+
+/// Date-like types which support adding a [`RelativeDuration`]
+///
+/// Implementations are expected to follow the rules described in the documentation of
+/// [`RelativeDuration`]. With the `chrono` feature this trait is implemented for
+/// [`chrono::NaiveDateTime`] and [`chrono::DateTime`] and with the `time` feature for
+/// [`time::PrimitiveDateTime`] and [`time::OffsetDateTime`].
+///
+/// [`chrono::NaiveDateTime`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveDateTime.html
+/// [`chrono::DateTime`]: https://docs.rs/chrono/latest/chrono/struct.DateTime.html
+/// [`time::PrimitiveDateTime`]: https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
+/// [`time::OffsetDateTime`]: https://docs.rs/time/latest/time/struct.OffsetDateTime.html
+pub trait CheckedAddRelative: Sized {
+    /// Add the `relative` duration to `self` returning `None` if the result is out of range
+    fn checked_add_relative(&self, relative: &RelativeDuration) -> Option<Self>;
+}
+
+/// Return true if the gregorian `year` is a leap year
+pub const fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Return the amount of days of the gregorian `month` (`1 <= month <= 12`) in the given `year`
+pub const fn days_in_month(year: i64, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => panic!("Invalid month: Valid range is 1 <= month <= 12"),
+    }
+}
+
+/// Add `months` to the gregorian date clamping the `day` to the last day of the resulting month
+///
+/// Returns `None` if the calculation of the resulting `year` overflows.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_core::relative::checked_add_months;
+///
+/// assert_eq!(checked_add_months(2023, 1, 31, 1), Some((2023, 2, 28)));
+/// assert_eq!(checked_add_months(2024, 2, 29, 12), Some((2025, 2, 28)));
+/// assert_eq!(checked_add_months(2023, 3, 31, -1), Some((2023, 2, 28)));
+/// assert_eq!(checked_add_months(2023, 12, 15, 1), Some((2024, 1, 15)));
+/// ```
+pub fn checked_add_months(year: i64, month: u8, day: u8, months: i64) -> Option<(i64, u8, u8)> {
+    if months == 0 {
+        return Some((year, month, day));
+    }
+
+    let total = year
+        .checked_mul(MONTHS_PER_YEAR)?
+        .checked_add(i64::from(month) - 1)?
+        .checked_add(months)?;
+    let year = total.div_euclid(MONTHS_PER_YEAR);
+    // The remainder is in the range 0..12, so the cast is safe
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let month = total.rem_euclid(MONTHS_PER_YEAR) as u8 + 1;
+    Some((year, month, day.min(days_in_month(year, month))))
+}
+
+#[cfg(feature = "chrono")]
+fn checked_add_calendar_chrono(
+    date_time: chrono::NaiveDateTime,
+    months: i64,
+    days: i64,
+) -> Option<chrono::NaiveDateTime> {
+    use chrono::{Datelike, Days, NaiveDate};
+
+    let date = date_time.date();
+    let date = if months == 0 {
+        date
+    } else {
+        let (year, month, day) = checked_add_months(
+            i64::from(date.year()),
+            date.month().try_into().ok()?,
+            date.day().try_into().ok()?,
+            months,
+        )?;
+        NaiveDate::from_ymd_opt(year.try_into().ok()?, month.into(), day.into())?
+    };
+    let date = if days.is_negative() {
+        date.checked_sub_days(Days::new(days.unsigned_abs()))?
+    } else {
+        date.checked_add_days(Days::new(days.unsigned_abs()))?
+    };
+    Some(date.and_time(date_time.time()))
+}
+
+#[cfg(feature = "chrono")]
+impl CheckedAddRelative for chrono::NaiveDateTime {
+    fn checked_add_relative(&self, relative: &RelativeDuration) -> Option<Self> {
+        let date_time =
+            checked_add_calendar_chrono(*self, relative.total_months()?, relative.total_days()?)?;
+        date_time.checked_add_signed(chrono::Duration::try_from(&relative.exact).ok()?)
+    }
+}
+
+#[cfg(feature = "chrono")]
+/// The calendar time units are applied to the local date and time. If the resulting local date and
+/// time is ambiguous, the earliest one is chosen. If it does not exist in the time zone, `None` is
+/// returned.
+impl<Tz: chrono::TimeZone> CheckedAddRelative for chrono::DateTime<Tz> {
+    fn checked_add_relative(&self, relative: &RelativeDuration) -> Option<Self> {
+        let months = relative.total_months()?;
+        let days = relative.total_days()?;
+        let date_time = if months == 0 && days == 0 {
+            self.clone()
+        } else {
+            let local = checked_add_calendar_chrono(self.naive_local(), months, days)?;
+            self.timezone().from_local_datetime(&local).earliest()?
+        };
+        date_time.checked_add_signed(chrono::Duration::try_from(&relative.exact).ok()?)
+    }
+}
+
+#[cfg(feature = "time")]
+fn checked_add_calendar_time(
+    date_time: time::PrimitiveDateTime,
+    months: i64,
+    days: i64,
+) -> Option<time::PrimitiveDateTime> {
+    let date = date_time.date();
+    let date = if months == 0 {
+        date
+    } else {
+        let (year, month, day) = checked_add_months(
+            i64::from(date.year()),
+            date.month().into(),
+            date.day(),
+            months,
+        )?;
+        time::Date::from_calendar_date(year.try_into().ok()?, month.try_into().ok()?, day).ok()?
+    };
+    let date = date.checked_add(time::Duration::seconds(days.checked_mul(86400)?))?;
+    Some(time::PrimitiveDateTime::new(date, date_time.time()))
+}
+
+#[cfg(feature = "time")]
+impl CheckedAddRelative for time::PrimitiveDateTime {
+    fn checked_add_relative(&self, relative: &RelativeDuration) -> Option<Self> {
+        let date_time =
+            checked_add_calendar_time(*self, relative.total_months()?, relative.total_days()?)?;
+        date_time.checked_add(time::Duration::try_from(&relative.exact).ok()?)
+    }
+}
+
+#[cfg(feature = "time")]
+/// The calendar time units are applied to the date and time in the offset of this
+/// [`time::OffsetDateTime`].
+impl CheckedAddRelative for time::OffsetDateTime {
+    fn checked_add_relative(&self, relative: &RelativeDuration) -> Option<Self> {
+        let date_time = checked_add_calendar_time(
+            time::PrimitiveDateTime::new(self.date(), self.time()),
+            relative.total_months()?,
+            relative.total_days()?,
+        )?;
+        date_time
+            .assume_offset(self.offset())
+            .checked_add(time::Duration::try_from(&relative.exact).ok()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::zero(0, true)]
+    #[case::one(1, false)]
+    #[case::four(4, true)]
+    #[case::hundred(100, false)]
+    #[case::four_hundred(400, true)]
+    #[case::two_thousand(2000, true)]
+    #[case::nineteen_hundred(1900, false)]
+    #[case::negative_four(-4, true)]
+    #[case::negative_hundred(-100, false)]
+    fn test_is_leap_year(#[case] year: i64, #[case] expected: bool) {
+        assert_eq!(is_leap_year(year), expected);
+    }
+
+    #[rstest]
+    #[case::january(2023, 1, 31)]
+    #[case::february(2023, 2, 28)]
+    #[case::february_leap_year(2024, 2, 29)]
+    #[case::april(2023, 4, 30)]
+    #[case::december(2023, 12, 31)]
+    fn test_days_in_month(#[case] year: i64, #[case] month: u8, #[case] expected: u8) {
+        assert_eq!(days_in_month(year, month), expected);
+    }
+
+    #[test]
+    #[should_panic = "Invalid month: Valid range is 1 <= month <= 12"]
+    fn test_days_in_month_when_invalid_month_then_panic() {
+        days_in_month(2023, 13);
+    }
+
+    #[rstest]
+    #[case::zero((2023, 1, 31), 0, Some((2023, 1, 31)))]
+    #[case::one_month((2023, 1, 15), 1, Some((2023, 2, 15)))]
+    #[case::end_of_month_clamped((2023, 1, 31), 1, Some((2023, 2, 28)))]
+    #[case::end_of_month_leap_year((2024, 1, 31), 1, Some((2024, 2, 29)))]
+    #[case::end_of_month_thirty_days((2023, 3, 31), 1, Some((2023, 4, 30)))]
+    #[case::leap_day_plus_year((2024, 2, 29), 12, Some((2025, 2, 28)))]
+    #[case::leap_day_plus_four_years((2024, 2, 29), 48, Some((2028, 2, 29)))]
+    #[case::year_wrap((2023, 12, 31), 1, Some((2024, 1, 31)))]
+    #[case::negative_year_wrap((2023, 1, 31), -1, Some((2022, 12, 31)))]
+    #[case::negative_clamped((2023, 3, 30), -1, Some((2023, 2, 28)))]
+    #[case::negative_years((1, 1, 1), -24, Some((-1, 1, 1)))]
+    #[case::overflow((i64::MAX / 12, 12, 1), 1, None)]
+    #[case::negative_overflow((i64::MIN / 12 - 1, 1, 1), -1, None)]
+    fn test_checked_add_months(
+        #[case] date: (i64, u8, u8),
+        #[case] months: i64,
+        #[case] expected: Option<(i64, u8, u8)>,
+    ) {
+        assert_eq!(checked_add_months(date.0, date.1, date.2, months), expected);
+    }
+
+    #[rstest]
+    #[case::zero(RelativeDuration::ZERO, Some(0), Some(0))]
+    #[case::mixed(RelativeDuration::new(1, -1, 2, -2, Duration::ZERO), Some(11), Some(12))]
+    #[case::overflow(
+        RelativeDuration::new(i64::MAX, 0, i64::MAX, 0, Duration::ZERO),
+        None,
+        None
+    )]
+    #[case::overflow_add(RelativeDuration::new(0, i64::MAX, 0, i64::MAX, Duration::ZERO)
+        .saturating_add(RelativeDuration::new(1, 0, 1, 0, Duration::ZERO)), None, None)]
+    fn test_relative_duration_totals(
+        #[case] relative: RelativeDuration,
+        #[case] expected_months: Option<i64>,
+        #[case] expected_days: Option<i64>,
+    ) {
+        assert_eq!(relative.total_months(), expected_months);
+        assert_eq!(relative.total_days(), expected_days);
+    }
+
+    #[test]
+    fn test_relative_duration_neg() {
+        assert_eq!(
+            -RelativeDuration::new(1, -2, 3, i64::MIN, Duration::positive(1, 0)),
+            RelativeDuration::new(-1, 2, -3, i64::MAX, Duration::negative(1, 0))
+        );
+    }
+
+    #[test]
+    fn test_relative_duration_is_zero() {
+        assert!(RelativeDuration::ZERO.is_zero());
+        assert!(RelativeDuration::default().is_zero());
+        assert!(!RelativeDuration::new(0, 0, 0, 1, Duration::ZERO).is_zero());
+        assert!(!RelativeDuration::from(Duration::positive(0, 1)).is_zero());
+    }
+
+    #[cfg(feature = "chrono")]
+    fn chrono_date_time(year: i32, month: u32, day: u32, hour: u32) -> chrono::NaiveDateTime {
+        chrono::NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    #[cfg(feature = "chrono")]
+    #[rstest]
+    #[case::zero(
+        chrono_date_time(2023, 1, 31, 12),
+        RelativeDuration::ZERO,
+        Some(chrono_date_time(2023, 1, 31, 12))
+    )]
+    #[case::month_clamped(
+        chrono_date_time(2023, 1, 31, 12),
+        RelativeDuration::new(0, 1, 0, 0, Duration::ZERO),
+        Some(chrono_date_time(2023, 2, 28, 12))
+    )]
+    #[case::year_leap_day(
+        chrono_date_time(2024, 2, 29, 0),
+        RelativeDuration::new(1, 0, 0, 0, Duration::ZERO),
+        Some(chrono_date_time(2025, 2, 28, 0))
+    )]
+    #[case::months_then_days(
+        chrono_date_time(2023, 1, 31, 0),
+        RelativeDuration::new(0, 1, 1, 1, Duration::ZERO),
+        Some(chrono_date_time(2023, 3, 8, 0))
+    )]
+    #[case::negative_days(
+        chrono_date_time(2023, 3, 1, 0),
+        RelativeDuration::new(0, 0, 0, -1, Duration::ZERO),
+        Some(chrono_date_time(2023, 2, 28, 0))
+    )]
+    #[case::exact(
+        chrono_date_time(2023, 1, 31, 23),
+        RelativeDuration::new(0, 1, 0, 0, Duration::positive(3600, 0)),
+        Some(chrono_date_time(2023, 3, 1, 0))
+    )]
+    #[case::overflow(
+        chrono_date_time(2023, 1, 1, 0),
+        RelativeDuration::new(i64::MAX / 12, 0, 0, 0, Duration::ZERO),
+        None
+    )]
+    #[case::exact_overflow(
+        chrono_date_time(2023, 1, 1, 0),
+        RelativeDuration::from_exact(Duration::MAX),
+        None
+    )]
+    fn test_checked_add_relative_chrono(
+        #[case] naive: chrono::NaiveDateTime,
+        #[case] relative: RelativeDuration,
+        #[case] expected: Option<chrono::NaiveDateTime>,
+    ) {
+        use chrono::{TimeZone, Utc};
+
+        assert_eq!(relative.checked_add_to(&naive), expected);
+        assert_eq!(
+            relative.checked_add_to(&Utc.from_utc_datetime(&naive)),
+            expected.map(|e| Utc.from_utc_datetime(&e))
+        );
+    }
+
+    #[cfg(feature = "time")]
+    fn time_date_time(year: i32, month: u8, day: u8, hour: u8) -> time::PrimitiveDateTime {
+        use time::{Date, Month, PrimitiveDateTime, Time};
+
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(year, Month::try_from(month).unwrap(), day).unwrap(),
+            Time::from_hms(hour, 0, 0).unwrap(),
+        )
+    }
+
+    #[cfg(feature = "time")]
+    #[rstest]
+    #[case::zero(
+        time_date_time(2023, 1, 31, 12),
+        RelativeDuration::ZERO,
+        Some(time_date_time(2023, 1, 31, 12))
+    )]
+    #[case::month_clamped(
+        time_date_time(2023, 1, 31, 12),
+        RelativeDuration::new(0, 1, 0, 0, Duration::ZERO),
+        Some(time_date_time(2023, 2, 28, 12))
+    )]
+    #[case::year_leap_day(
+        time_date_time(2024, 2, 29, 0),
+        RelativeDuration::new(1, 0, 0, 0, Duration::ZERO),
+        Some(time_date_time(2025, 2, 28, 0))
+    )]
+    #[case::months_then_days(
+        time_date_time(2023, 1, 31, 0),
+        RelativeDuration::new(0, 1, 1, 1, Duration::ZERO),
+        Some(time_date_time(2023, 3, 8, 0))
+    )]
+    #[case::negative_days(
+        time_date_time(2023, 3, 1, 0),
+        RelativeDuration::new(0, 0, 0, -1, Duration::ZERO),
+        Some(time_date_time(2023, 2, 28, 0))
+    )]
+    #[case::exact(
+        time_date_time(2023, 1, 31, 23),
+        RelativeDuration::new(0, 1, 0, 0, Duration::positive(3600, 0)),
+        Some(time_date_time(2023, 3, 1, 0))
+    )]
+    #[case::overflow(
+        time_date_time(2023, 1, 1, 0),
+        RelativeDuration::new(i64::MAX / 12, 0, 0, 0, Duration::ZERO),
+        None
+    )]
+    #[case::exact_overflow(
+        time_date_time(2023, 1, 1, 0),
+        RelativeDuration::from_exact(Duration::MAX),
+        None
+    )]
+    fn test_checked_add_relative_time(
+        #[case] primitive: time::PrimitiveDateTime,
+        #[case] relative: RelativeDuration,
+        #[case] expected: Option<time::PrimitiveDateTime>,
+    ) {
+        use time::UtcOffset;
+
+        let offset = UtcOffset::from_hms(2, 0, 0).unwrap();
+
+        assert_eq!(relative.checked_add_to(&primitive), expected);
+        assert_eq!(
+            relative.checked_add_to(&primitive.assume_offset(offset)),
+            expected.map(|e| e.assume_offset(offset))
+        );
+    }
+}
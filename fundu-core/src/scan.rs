@@ -0,0 +1,334 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Find and extract all durations embedded in free text
+//!
+//! The parsers of fundu expect the whole input to be a duration. The [`Scanner`] finds the
+//! durations in arbitrary text like log lines or prose with any parser implementing
+//! [`ParseDuration`], so the grammar of the durations is exactly the grammar of the configured
+//! parser.
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::stream::ParseDuration;
+use crate::time::Duration;
+
+/// The default maximum number of whitespace separated words of a single match
+const DEFAULT_MAX_WORDS: usize = 8;
+
+/// A duration found in a text by the [`Scanner`]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Match<'t> {
+    /// The byte range of the match in the text
+    pub span: Range<usize>,
+    /// The matched part of the text
+    pub source: &'t str,
+    /// The parsed [`Duration`]
+    pub duration: Duration,
+}
+
+/// A scanner which finds all durations in a text
+///
+/// A match starts at the beginning of a word or after a non-alphanumeric character like `(` and
+/// ends in front of a non-alphanumeric character or at the end of the text. At each possible start,
+/// the scanner uses the longest part of the text which is accepted by the parser and continues
+/// after it, so the matches are maximal and don't overlap. A match spans at most
+/// [`Scanner::max_words`] whitespace separated words.
+///
+/// Numbers without a time unit like the `2` in `after 2 retries` are valid durations for most
+/// parsers, but are usually not meant as durations in free text. Matches ending with a digit or
+/// a `.` are therefore skipped unless [`Scanner::allow_numbers_without_unit`] is set.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_core::error::ParseError;
+/// use fundu_core::scan::Scanner;
+/// use fundu_core::stream::ParseDuration;
+/// use fundu_core::time::Duration;
+///
+/// // A parser for whole seconds like `10` or `10s`
+/// struct Seconds;
+///
+/// impl ParseDuration for Seconds {
+///     fn parse_duration(&self, source: &str) -> Result<Duration, ParseError> {
+///         source
+///             .strip_suffix('s')
+///             .unwrap_or(source)
+///             .parse()
+///             .map(|seconds| Duration::positive(seconds, 0))
+///             .map_err(|error| ParseError::InvalidInput(format!("{error}")))
+///     }
+/// }
+///
+/// let text = "request took 3s after 2 retries (backoff 10s)";
+/// let matches: Vec<_> = Scanner::new(Seconds).find_iter(text).collect();
+/// assert_eq!(matches.len(), 2);
+/// assert_eq!(matches[0].span, 13..15);
+/// assert_eq!(matches[0].source, "3s");
+/// assert_eq!(matches[0].duration, Duration::positive(3, 0));
+/// assert_eq!(matches[1].source, "10s");
+///
+/// let scanner = Scanner::new(Seconds).allow_numbers_without_unit();
+/// let sources: Vec<_> = scanner.find_iter(text).map(|m| m.source).collect();
+/// assert_eq!(sources, vec!["3s", "2", "10s"]);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Scanner<P> {
+    parser: P,
+    allow_numbers_without_unit: bool,
+    max_words: usize,
+}
+
+impl<P: ParseDuration> Scanner<P> {
+    /// Create a new `Scanner` which parses the durations with the `parser`
+    pub const fn new(parser: P) -> Self {
+        Self {
+            parser,
+            allow_numbers_without_unit: false,
+            max_words: DEFAULT_MAX_WORDS,
+        }
+    }
+
+    /// Also match numbers without a time unit like `2` or `1.5`
+    ///
+    /// Without this setting, matches ending with an ascii digit or a `.` are skipped.
+    pub const fn allow_numbers_without_unit(mut self) -> Self {
+        self.allow_numbers_without_unit = true;
+        self
+    }
+
+    /// Set the maximum number of whitespace separated words of a single match (Default: `8`)
+    ///
+    /// The time to scan a text grows with the number of words a match may span, so this setting
+    /// should be kept as small as the expected durations allow. For example, `1 hour 30 minutes`
+    /// consists of `4` words.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `max_words` is `0`.
+    pub const fn max_words(mut self, max_words: usize) -> Self {
+        assert!(max_words > 0);
+        self.max_words = max_words;
+        self
+    }
+
+    /// Return the parser of this `Scanner`
+    pub const fn parser(&self) -> &P {
+        &self.parser
+    }
+
+    /// Return an iterator over all durations in the `text`
+    pub fn find_iter<'t>(&self, text: &'t str) -> Matches<'t, &P> {
+        Scanner {
+            parser: &self.parser,
+            allow_numbers_without_unit: self.allow_numbers_without_unit,
+            max_words: self.max_words,
+        }
+        .into_find_iter(text)
+    }
+
+    /// Like [`Scanner::find_iter`] but the returned iterator takes ownership of this `Scanner`
+    pub fn into_find_iter(self, text: &str) -> Matches<'_, P> {
+        Matches {
+            scanner: self,
+            text,
+            position: 0,
+        }
+    }
+
+    /// Return the longest match starting at `start` or `None` if there is no such match
+    fn longest_match(&self, text: &str, start: usize) -> Option<(usize, Duration)> {
+        let mut ends = Vec::new();
+        let mut words = 1;
+        let mut chars = text[start..].char_indices().peekable();
+        while let Some((index, c)) = chars.next() {
+            let next = chars.peek().map(|(_, c)| *c);
+            if c.is_whitespace() {
+                if next.map_or(false, |next| !next.is_whitespace()) {
+                    words += 1;
+                    if words > self.max_words {
+                        break;
+                    }
+                }
+                continue;
+            }
+            if next.map_or(true, |next| !next.is_alphanumeric()) {
+                ends.push(start + index + c.len_utf8());
+            }
+        }
+
+        ends.into_iter().rev().find_map(|end| {
+            let source = &text[start..end];
+            if !self.allow_numbers_without_unit && is_number_end(source) {
+                return None;
+            }
+            self.parser
+                .parse_duration_ok(source)
+                .map(|duration| (end, duration))
+        })
+    }
+
+    /// Return the first match in the `text` at or after the byte `position`
+    ///
+    /// The `position` has to be at a char boundary. The characters before the `position` are
+    /// taken into account to decide whether a match may start at the `position`.
+    pub(crate) fn find_at<'t>(&self, text: &'t str, position: usize) -> Option<Match<'t>> {
+        let mut previous = text[..position].chars().next_back();
+        for (index, c) in text[position..].char_indices() {
+            let start = position + index;
+            let is_start = !c.is_whitespace()
+                && previous.map_or(true, |previous| {
+                    !(previous.is_alphanumeric() || previous == '.')
+                });
+            previous = Some(c);
+            if !is_start {
+                continue;
+            }
+
+            if let Some((end, duration)) = self.longest_match(text, start) {
+                return Some(Match {
+                    span: start..end,
+                    source: &text[start..end],
+                    duration,
+                });
+            }
+        }
+        None
+    }
+}
+
+/// Return true if the `source` ends like a number without a time unit
+fn is_number_end(source: &str) -> bool {
+    source
+        .as_bytes()
+        .last()
+        .map_or(false, |byte| byte.is_ascii_digit() || *byte == b'.')
+}
+
+/// An iterator over the durations in a text returned by [`Scanner::find_iter`]
+#[derive(Debug)]
+pub struct Matches<'t, P> {
+    scanner: Scanner<P>,
+    text: &'t str,
+    position: usize,
+}
+
+impl<'t, P: ParseDuration> Iterator for Matches<'t, P> {
+    type Item = Match<'t>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let found = self.scanner.find_at(self.text, self.position);
+        self.position = found.as_ref().map_or(self.text.len(), |m| m.span.end);
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::error::ParseError;
+
+    /// Parses whitespace separated numbers with an optional time unit `s` or `ms`
+    struct Simple;
+
+    impl ParseDuration for Simple {
+        fn parse_duration(&self, source: &str) -> Result<Duration, ParseError> {
+            let mut millis = 0;
+            for word in source.split(' ') {
+                let (number, multiplier) = if let Some(number) = word.strip_suffix("ms") {
+                    (number, 1)
+                } else if let Some(number) = word.strip_suffix('s') {
+                    (number, 1000)
+                } else {
+                    (word, 1000)
+                };
+                if number.is_empty() || !number.bytes().all(|byte| byte.is_ascii_digit()) {
+                    return Err(ParseError::InvalidInput(word.to_owned()));
+                }
+                millis += number.parse::<u64>().unwrap() * multiplier;
+            }
+            Ok(Duration::positive(
+                millis / 1000,
+                (millis % 1000) as u32 * 1_000_000,
+            ))
+        }
+    }
+
+    fn find(scanner: &Scanner<Simple>, text: &str) -> Vec<(Range<usize>, u64)> {
+        scanner
+            .find_iter(text)
+            .map(|m| {
+                assert_eq!(m.source, &text[m.span.clone()]);
+                let duration: std::time::Duration = m.duration.try_into().unwrap();
+                (m.span, u64::try_from(duration.as_millis()).unwrap())
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case::empty("", vec![])]
+    #[case::only_whitespace("  \t ", vec![])]
+    #[case::no_durations("no durations here", vec![])]
+    #[case::whole_text("1s", vec![(0..2, 1000)])]
+    #[case::embedded("took 350ms after", vec![(5..10, 350)])]
+    #[case::multiple("1s and 2ms", vec![(0..2, 1000), (7..10, 2)])]
+    #[case::maximal("took 1s 2s 3ms", vec![(5..14, 3003)])]
+    #[case::maximal_without_trailing_number("1s 2s 3", vec![(0..5, 3000)])]
+    #[case::number_without_unit("after 2 retries", vec![])]
+    #[case::punctuation("(1s), 2s.", vec![(1..3, 1000), (6..8, 2000)])]
+    #[case::not_inside_word("abc1s 1sabc", vec![])]
+    #[case::not_after_point("v1.5s", vec![])]
+    #[case::multibyte("Zeit:1s über 2ms", vec![(5..7, 1000), (14..17, 2)])]
+    fn test_scanner_find_iter(#[case] text: &str, #[case] expected: Vec<(Range<usize>, u64)>) {
+        assert_eq!(find(&Scanner::new(Simple), text), expected);
+    }
+
+    #[rstest]
+    #[case::number_without_unit("after 2 retries", vec![(6..7, 2000)])]
+    #[case::trailing_number("1s 2s 3", vec![(0..7, 6000)])]
+    #[case::only_numbers("1 2", vec![(0..3, 3000)])]
+    fn test_scanner_find_iter_with_numbers_without_unit(
+        #[case] text: &str,
+        #[case] expected: Vec<(Range<usize>, u64)>,
+    ) {
+        let scanner = Scanner::new(Simple).allow_numbers_without_unit();
+        assert_eq!(find(&scanner, text), expected);
+    }
+
+    #[rstest]
+    #[case::one(1, vec![(0..2, 1000), (3..5, 2000), (6..9, 3)])]
+    #[case::two(2, vec![(0..5, 3000), (6..9, 3)])]
+    #[case::three(3, vec![(0..9, 3003)])]
+    fn test_scanner_find_iter_with_max_words(
+        #[case] max_words: usize,
+        #[case] expected: Vec<(Range<usize>, u64)>,
+    ) {
+        let scanner = Scanner::new(Simple).max_words(max_words);
+        assert_eq!(find(&scanner, "1s 2s 3ms"), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: max_words > 0")]
+    fn test_scanner_max_words_when_zero_then_panic() {
+        let _ = Scanner::new(Simple).max_words(0);
+    }
+
+    #[test]
+    fn test_scanner_into_find_iter() {
+        let matches: Vec<_> = Scanner::new(Simple).into_find_iter("a 1s b").collect();
+        assert_eq!(
+            matches,
+            vec![Match {
+                span: 2..4,
+                source: "1s",
+                duration: Duration::positive(1, 0)
+            }]
+        );
+    }
+}
@@ -0,0 +1,676 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Parse durations incrementally from chunks of bytes or from a [`std::io::BufRead`]
+//!
+//! The parsers of fundu take a complete `&str`. The [`StreamParser`] splits a stream of bytes into
+//! tokens at a separator (by default the ascii whitespace), keeps an incomplete token at the end
+//! of a chunk until the next chunk arrives and finds the durations in every completed token with
+//! a [`Scanner`] and any parser implementing [`ParseDuration`], so the `1.5ms` in a token like
+//! `elapsed=1.5ms` is found, too. The [`BufReadParser`] drives a [`StreamParser`] with the data of
+//! a [`std::io::BufRead`].
+//!
+//! The [`ParseDuration`] and [`ParseDurationPrefix`] traits are always available. The
+//! [`StreamParser`] needs the `alloc` and the [`BufReadParser`] the `std` feature.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::Range;
+#[cfg(feature = "std")]
+use std::io::{self, BufRead};
+
+#[cfg(feature = "alloc")]
+use crate::config::Delimiter;
+use crate::error::ParseError;
+#[cfg(feature = "alloc")]
+use crate::scan::Scanner;
+use crate::time::Duration;
+
+/// The default separator of tokens in a stream: the ascii whitespace
+#[cfg(feature = "alloc")]
+const DEFAULT_SEPARATOR: Delimiter = |byte| byte.is_ascii_whitespace();
+
+/// A parser which parses a complete source string into a [`Duration`]
+///
+/// This trait is implemented by the parsers of `fundu`, `fundu-gnu` and `fundu-systemd` and makes
+/// them usable in the [`Scanner`], the [`StreamParser`] and the [`BufReadParser`].
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_core::error::ParseError;
+/// use fundu_core::stream::ParseDuration;
+/// use fundu_core::time::Duration;
+///
+/// struct Seconds;
+///
+/// impl ParseDuration for Seconds {
+///     fn parse_duration(&self, source: &str) -> Result<Duration, ParseError> {
+///         source
+///             .parse()
+///             .map(|seconds| Duration::positive(seconds, 0))
+///             .map_err(|error| ParseError::InvalidInput(format!("{error}")))
+///     }
+/// }
+///
+/// assert_eq!(Seconds.parse_duration("10"), Ok(Duration::positive(10, 0)));
+/// assert_eq!((&Seconds).parse_duration("10"), Ok(Duration::positive(10, 0)));
+/// assert_eq!(Seconds.parse_duration_ok("ten"), None);
+/// ```
+pub trait ParseDuration {
+    /// Parse the `source` string into a [`Duration`]
+    fn parse_duration(&self, source: &str) -> Result<Duration, ParseError>;
+
+    /// Parse the `source` string into a [`Duration`] and discard the error
+    ///
+    /// The [`Scanner`] and the [`StreamParser`] try to parse many parts of a text which are no
+    /// durations and need only to know if parsing succeeded. Parsers which can fail without
+    /// allocating the error message should override this method.
+    #[inline]
+    fn parse_duration_ok(&self, source: &str) -> Option<Duration> {
+        self.parse_duration(source).ok()
+    }
+}
+
+impl<P: ParseDuration + ?Sized> ParseDuration for &P {
+    #[inline]
+    fn parse_duration(&self, source: &str) -> Result<Duration, ParseError> {
+        (**self).parse_duration(source)
+    }
+
+    #[inline]
+    fn parse_duration_ok(&self, source: &str) -> Option<Duration> {
+        (**self).parse_duration_ok(source)
+    }
+}
+
+/// A parser which parses the longest valid duration at the start of a source string
+///
+/// Other than [`ParseDuration`], the source may continue after the duration and the unconsumed
+/// rest is returned together with the [`Duration`]. This trait is implemented by the parsers of
+/// `fundu` and `fundu-systemd` and is the basis of the `nom` and `winnow` combinators of `fundu`.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_core::error::ParseError;
+/// use fundu_core::stream::ParseDurationPrefix;
+/// use fundu_core::time::Duration;
+///
+/// struct Seconds;
+///
+/// impl ParseDurationPrefix for Seconds {
+///     fn parse_duration_prefix<'s>(
+///         &self,
+///         source: &'s str,
+///     ) -> Result<(Duration, &'s str), ParseError> {
+///         let end = source
+///             .find(|c: char| !c.is_ascii_digit())
+///             .unwrap_or(source.len());
+///         source[..end]
+///             .parse()
+///             .map(|seconds| (Duration::positive(seconds, 0), &source[end..]))
+///             .map_err(|error| ParseError::InvalidInput(format!("{error}")))
+///     }
+/// }
+///
+/// assert_eq!(
+///     Seconds.parse_duration_prefix("10 rest"),
+///     Ok((Duration::positive(10, 0), " rest"))
+/// );
+/// ```
+pub trait ParseDurationPrefix {
+    /// Parse the duration at the start of the `source` and return the unconsumed rest
+    fn parse_duration_prefix<'s>(&self, source: &'s str)
+        -> Result<(Duration, &'s str), ParseError>;
+}
+
+impl<P: ParseDurationPrefix + ?Sized> ParseDurationPrefix for &P {
+    #[inline]
+    fn parse_duration_prefix<'s>(
+        &self,
+        source: &'s str,
+    ) -> Result<(Duration, &'s str), ParseError> {
+        (**self).parse_duration_prefix(source)
+    }
+}
+
+/// A duration found in a stream
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct StreamItem {
+    /// The byte range of the duration counted from the start of the stream
+    pub span: Range<usize>,
+    /// The parsed [`Duration`]
+    pub duration: Duration,
+}
+
+/// An incremental parser for durations in a stream of bytes
+///
+/// The stream is fed in chunks of arbitrary size with [`StreamParser::feed`]. Tokens are delimited
+/// by the separator (by default the ascii whitespace, see [`StreamParser::with_separator`]) and a
+/// token is scanned for durations as soon as the separator after it was seen, so a token may be
+/// split over multiple chunks like `"1.2"` and `"5ms "`. The remaining tokens including the last
+/// token of the stream are scanned with [`StreamParser::finish`]. Only the bytes of the current
+/// token are buffered.
+///
+/// The durations inside a token are found with a [`Scanner`] like in free text, so the token
+/// `elapsed=1.5ms` yields the duration `1.5ms` and tokens without a duration yield nothing. Like
+/// with the [`Scanner`], numbers without a time unit are skipped unless the `StreamParser` is
+/// created with a [`Scanner::allow_numbers_without_unit`] in [`StreamParser::with_scanner`]. Tokens
+/// which are not valid UTF-8 are skipped. If the parser accepts multiple durations separated by
+/// whitespace (like `"1min 30s"`), use a different separator like the newline to find such
+/// durations in a stream.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_core::error::ParseError;
+/// use fundu_core::stream::{ParseDuration, StreamParser};
+/// use fundu_core::time::Duration;
+///
+/// # struct Seconds;
+/// # impl ParseDuration for Seconds {
+/// #     fn parse_duration(&self, source: &str) -> Result<Duration, ParseError> {
+/// #         source
+/// #             .strip_suffix('s')
+/// #             .unwrap_or(source)
+/// #             .parse()
+/// #             .map(|seconds| Duration::positive(seconds, 0))
+/// #             .map_err(|error| ParseError::InvalidInput(format!("{error}")))
+/// #     }
+/// # }
+/// // `Seconds` implements `ParseDuration` for whole seconds like `10` or `10s`
+/// let mut stream = StreamParser::new(Seconds);
+///
+/// let items: Vec<_> = stream.feed(b"took=10s 2").collect();
+/// assert_eq!(items.len(), 1);
+/// assert_eq!(items[0].span, 5..8);
+/// assert_eq!(items[0].duration, Duration::positive(10, 0));
+///
+/// let items: Vec<_> = stream.feed(b"0s (3s)").map(|item| item.duration).collect();
+/// assert_eq!(items, vec![Duration::positive(20, 0)]);
+///
+/// let item = stream.finish().next().unwrap();
+/// assert_eq!(item.span, 14..16);
+/// assert_eq!(item.duration, Duration::positive(3, 0));
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct StreamParser<P> {
+    scanner: Scanner<P>,
+    separator: Delimiter,
+    buffer: Vec<u8>,
+    /// The position of the first byte of the `buffer` in the stream
+    offset: usize,
+    /// The position in the `buffer` up to which the durations were already yielded
+    position: usize,
+    /// The range of the completed token in the `buffer` which is currently scanned
+    token: Option<Range<usize>>,
+    /// True if the stream was finished and the next chunk starts a new stream
+    is_finished: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl<P: ParseDuration> StreamParser<P> {
+    /// Create a new `StreamParser` with the ascii whitespace as separator
+    pub fn new(parser: P) -> Self {
+        Self::with_separator(parser, DEFAULT_SEPARATOR)
+    }
+
+    /// Create a new `StreamParser` with the given `separator` of tokens
+    ///
+    /// Like a [`Delimiter`] of the parsers, the `separator` should match ascii characters only.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_core::error::ParseError;
+    /// use fundu_core::stream::{ParseDuration, StreamParser};
+    /// use fundu_core::time::Duration;
+    ///
+    /// # struct Seconds;
+    /// # impl ParseDuration for Seconds {
+    /// #     fn parse_duration(&self, source: &str) -> Result<Duration, ParseError> {
+    /// #         source
+    /// #             .strip_suffix('s')
+    /// #             .unwrap_or(source)
+    /// #             .parse()
+    /// #             .map(|seconds| Duration::positive(seconds, 0))
+    /// #             .map_err(|error| ParseError::InvalidInput(format!("{error}")))
+    /// #     }
+    /// # }
+    /// let mut stream = StreamParser::with_separator(Seconds, |byte| byte == b',');
+    /// let items: Vec<_> = stream.feed(b"1s,2s,").map(|item| item.duration).collect();
+    /// assert_eq!(
+    ///     items,
+    ///     vec![Duration::positive(1, 0), Duration::positive(2, 0)]
+    /// );
+    /// ```
+    pub fn with_separator(parser: P, separator: Delimiter) -> Self {
+        Self::with_scanner(Scanner::new(parser), separator)
+    }
+
+    /// Create a new `StreamParser` which finds the durations in the tokens with the `scanner`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_core::error::ParseError;
+    /// use fundu_core::scan::Scanner;
+    /// use fundu_core::stream::{ParseDuration, StreamParser};
+    /// use fundu_core::time::Duration;
+    ///
+    /// # struct Seconds;
+    /// # impl ParseDuration for Seconds {
+    /// #     fn parse_duration(&self, source: &str) -> Result<Duration, ParseError> {
+    /// #         source
+    /// #             .strip_suffix('s')
+    /// #             .unwrap_or(source)
+    /// #             .parse()
+    /// #             .map(|seconds| Duration::positive(seconds, 0))
+    /// #             .map_err(|error| ParseError::InvalidInput(format!("{error}")))
+    /// #     }
+    /// # }
+    /// let scanner = Scanner::new(Seconds).allow_numbers_without_unit();
+    /// let mut stream = StreamParser::with_scanner(scanner, |byte| byte == b'\n');
+    /// let items: Vec<_> = stream.feed(b"retry=1\n").map(|item| item.duration).collect();
+    /// assert_eq!(items, vec![Duration::positive(1, 0)]);
+    /// ```
+    pub fn with_scanner(scanner: Scanner<P>, separator: Delimiter) -> Self {
+        Self {
+            scanner,
+            separator,
+            buffer: Vec::new(),
+            offset: 0,
+            position: 0,
+            token: None,
+            is_finished: false,
+        }
+    }
+
+    /// Feed the next `chunk` of the stream and return an iterator over the durations of the
+    /// completed tokens
+    ///
+    /// Durations which are not yielded by the returned iterator (for example if it is dropped
+    /// early) are yielded by the iterator of the next call to `feed` or by
+    /// [`StreamParser::finish`].
+    pub fn feed(&mut self, chunk: &[u8]) -> StreamIter<'_, P> {
+        self.append(chunk);
+        StreamIter { stream: self }
+    }
+
+    /// Finish the stream and return an iterator over the remaining durations including the
+    /// durations of the last token
+    ///
+    /// After the stream is finished, the next call to [`StreamParser::feed`] starts a new stream at
+    /// position `0`. Durations which were not yielded by the returned iterator until then are
+    /// dropped.
+    pub fn finish(&mut self) -> StreamIter<'_, P> {
+        self.is_finished = true;
+        StreamIter { stream: self }
+    }
+
+    /// Return the number of bytes buffered for the current token
+    pub fn buffered(&self) -> usize {
+        self.buffer.len() - self.buffer_start()
+    }
+
+    /// Return a reference to the [`ParseDuration`] parser
+    pub fn parser(&self) -> &P {
+        self.scanner.parser()
+    }
+
+    /// Append the `chunk` to the buffer after removing the bytes of the already scanned tokens
+    fn append(&mut self, chunk: &[u8]) {
+        if self.is_finished {
+            self.buffer.clear();
+            self.offset = 0;
+            self.position = 0;
+            self.token = None;
+            self.is_finished = false;
+        } else {
+            let start = self.buffer_start();
+            self.buffer.drain(..start);
+            self.offset += start;
+            self.position -= start;
+            if let Some(token) = self.token.as_mut() {
+                *token = token.start - start..token.end - start;
+            }
+        }
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    /// Return the position in the `buffer` from which on the bytes are still needed
+    fn buffer_start(&self) -> usize {
+        self.token
+            .as_ref()
+            .map_or(self.position, |token| token.start)
+    }
+
+    /// Return the next duration of the completed tokens in the buffer
+    ///
+    /// A token at the end of the buffer is only scanned if the stream is finished, otherwise it
+    /// might continue in the next chunk.
+    fn next_token(&mut self) -> Option<StreamItem> {
+        loop {
+            if let Some(token) = self.token.clone() {
+                if let Ok(text) = core::str::from_utf8(&self.buffer[token.clone()]) {
+                    if let Some(found) = self.scanner.find_at(text, self.position - token.start) {
+                        let span = token.start + found.span.start..token.start + found.span.end;
+                        self.position = span.end;
+                        return Some(StreamItem {
+                            span: self.offset + span.start..self.offset + span.end,
+                            duration: found.duration,
+                        });
+                    }
+                }
+                self.position = token.end;
+                self.token = None;
+            }
+
+            let separator = self.separator;
+            let remainder = &self.buffer[self.position..];
+            let start = if let Some(start) = remainder.iter().position(|byte| !separator(*byte)) {
+                self.position + start
+            } else {
+                self.position = self.buffer.len();
+                return None;
+            };
+            let end = match self.buffer[start..]
+                .iter()
+                .position(|byte| separator(*byte))
+            {
+                Some(length) => start + length,
+                None if self.is_finished => self.buffer.len(),
+                None => {
+                    self.position = start;
+                    return None;
+                }
+            };
+            self.position = start;
+            self.token = Some(start..end);
+        }
+    }
+}
+
+/// An iterator over the durations of a [`StreamParser`] returned by [`StreamParser::feed`] and
+/// [`StreamParser::finish`]
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct StreamIter<'s, P> {
+    stream: &'s mut StreamParser<P>,
+}
+
+#[cfg(feature = "alloc")]
+impl<P: ParseDuration> Iterator for StreamIter<'_, P> {
+    type Item = StreamItem;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.stream.next_token()
+    }
+}
+
+/// An iterator over the durations of a [`std::io::BufRead`]
+///
+/// The data of the reader is fed into a [`StreamParser`] until the end of the reader is reached.
+/// Errors of the reader are returned as `Err` and end the iteration.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::io::BufReader;
+///
+/// use fundu_core::error::ParseError;
+/// use fundu_core::stream::{BufReadParser, ParseDuration};
+/// use fundu_core::time::Duration;
+///
+/// # struct Seconds;
+/// # impl ParseDuration for Seconds {
+/// #     fn parse_duration(&self, source: &str) -> Result<Duration, ParseError> {
+/// #         source
+/// #             .strip_suffix('s')
+/// #             .unwrap_or(source)
+/// #             .parse()
+/// #             .map(|seconds| Duration::positive(seconds, 0))
+/// #             .map_err(|error| ParseError::InvalidInput(format!("{error}")))
+/// #     }
+/// # }
+/// // A small buffer capacity splits the tokens between multiple reads
+/// let reader = BufReader::with_capacity(2, "10s 20s\n30s".as_bytes());
+/// let durations: Vec<_> = BufReadParser::new(reader, Seconds)
+///     .map(|item| item.unwrap().duration)
+///     .collect();
+/// assert_eq!(
+///     durations,
+///     vec![
+///         Duration::positive(10, 0),
+///         Duration::positive(20, 0),
+///         Duration::positive(30, 0)
+///     ]
+/// );
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct BufReadParser<R, P> {
+    reader: R,
+    stream: StreamParser<P>,
+    is_finished: bool,
+}
+
+#[cfg(feature = "std")]
+impl<R: BufRead, P: ParseDuration> BufReadParser<R, P> {
+    /// Create a new `BufReadParser` with the ascii whitespace as separator
+    pub fn new(reader: R, parser: P) -> Self {
+        Self::with_stream(reader, StreamParser::new(parser))
+    }
+
+    /// Create a new `BufReadParser` reading into the given [`StreamParser`]
+    pub fn with_stream(reader: R, stream: StreamParser<P>) -> Self {
+        Self {
+            reader,
+            stream,
+            is_finished: false,
+        }
+    }
+
+    /// Return the reader and the [`StreamParser`]
+    pub fn into_inner(self) -> (R, StreamParser<P>) {
+        (self.reader, self.stream)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: BufRead, P: ParseDuration> Iterator for BufReadParser<R, P> {
+    type Item = io::Result<StreamItem>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.stream.next_token() {
+                return Some(Ok(item));
+            }
+            if self.is_finished {
+                return None;
+            }
+
+            let length = match self.reader.fill_buf() {
+                Ok([]) => {
+                    self.is_finished = true;
+                    self.stream.is_finished = true;
+                    continue;
+                }
+                Ok(buffer) => {
+                    self.stream.append(buffer);
+                    buffer.len()
+                }
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => {
+                    self.is_finished = true;
+                    return Some(Err(error));
+                }
+            };
+            self.reader.consume(length);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Parses whole seconds with an optional time unit `s`
+    struct Seconds;
+
+    impl ParseDuration for Seconds {
+        fn parse_duration(&self, source: &str) -> Result<Duration, ParseError> {
+            source
+                .strip_suffix('s')
+                .unwrap_or(source)
+                .parse()
+                .map(|seconds| Duration::positive(seconds, 0))
+                .map_err(|_| ParseError::InvalidInput(source.to_owned()))
+        }
+    }
+
+    fn ok(span: Range<usize>, seconds: u64) -> StreamItem {
+        StreamItem {
+            span,
+            duration: Duration::positive(seconds, 0),
+        }
+    }
+
+    /// Return a `StreamParser` which also yields numbers without time unit
+    fn new_stream() -> StreamParser<Seconds> {
+        let scanner = Scanner::new(Seconds).allow_numbers_without_unit();
+        StreamParser::with_scanner(scanner, DEFAULT_SEPARATOR)
+    }
+
+    fn feed_all(chunks: &[&[u8]]) -> Vec<StreamItem> {
+        let mut stream = new_stream();
+        let mut items = vec![];
+        for chunk in chunks {
+            items.extend(stream.feed(chunk));
+        }
+        items.extend(stream.finish());
+        items
+    }
+
+    #[rstest]
+    #[case::empty(&[], vec![])]
+    #[case::only_separators(&[" \n ", "\t"], vec![])]
+    #[case::single_chunk(&["1 22"], vec![ok(0..1, 1), ok(2..4, 22)])]
+    #[case::split_token(&["1", "2", "3 4"], vec![ok(0..3, 123), ok(4..5, 4)])]
+    #[case::split_at_separator(&["12", " 3"], vec![ok(0..2, 12), ok(3..4, 3)])]
+    #[case::leading_and_trailing_separators(&["  1 ", " 2  "], vec![ok(2..3, 1), ok(5..6, 2)])]
+    #[case::empty_chunks(&["", "1", "", " 2"], vec![ok(0..1, 1), ok(2..3, 2)])]
+    #[case::embedded(&["elapsed=1", "5s, (2s)"], vec![ok(8..11, 15), ok(14..16, 2)])]
+    #[case::multiple_in_token(&["1s,2s;3"], vec![ok(0..2, 1), ok(3..5, 2), ok(6..7, 3)])]
+    #[case::non_duration_tokens(&["a 1 b", "c 2s"], vec![ok(2..3, 1), ok(7..9, 2)])]
+    #[case::not_inside_word(&["a1s 1sa"], vec![])]
+    fn test_stream_parser(#[case] chunks: &[&str], #[case] expected: Vec<StreamItem>) {
+        let chunks: Vec<&[u8]> = chunks.iter().map(|chunk| chunk.as_bytes()).collect();
+        assert_eq!(feed_all(&chunks), expected);
+    }
+
+    #[test]
+    fn test_stream_parser_when_invalid_utf8_then_skip_token() {
+        let items = feed_all(&[b"1 a ", b"\xff\xfe2 2"]);
+        assert_eq!(items, vec![ok(0..1, 1), ok(8..9, 2)]);
+    }
+
+    #[test]
+    fn test_stream_parser_when_default_scanner_then_skip_numbers_without_unit() {
+        let mut stream = StreamParser::new(Seconds);
+        assert_eq!(
+            stream.feed(b"1 2s after=3").collect::<Vec<_>>(),
+            vec![ok(2..4, 2)]
+        );
+        assert_eq!(stream.finish().next(), None);
+    }
+
+    #[test]
+    fn test_stream_parser_buffers_only_current_token() {
+        let mut stream = new_stream();
+        assert_eq!(stream.feed(b"1 2 3").count(), 2);
+        assert_eq!(stream.buffered(), 1);
+        assert_eq!(stream.feed(b"45").count(), 0);
+        assert_eq!(stream.buffered(), 3);
+        assert_eq!(stream.finish().collect::<Vec<_>>(), vec![ok(4..7, 345)]);
+        assert_eq!(stream.buffered(), 0);
+        assert_eq!(stream.finish().next(), None);
+        assert_eq!(stream.feed(b"6 ").collect::<Vec<_>>(), vec![ok(0..1, 6)]);
+    }
+
+    #[test]
+    fn test_stream_parser_when_iterator_dropped_early() {
+        let mut stream = new_stream();
+        assert_eq!(stream.feed(b"1 2 3 ").next(), Some(ok(0..1, 1)));
+        let items: Vec<_> = stream.feed(b"4 ").collect();
+        assert_eq!(items, vec![ok(2..3, 2), ok(4..5, 3), ok(6..7, 4)]);
+
+        assert_eq!(stream.feed(b"5 6").next(), Some(ok(8..9, 5)));
+        assert_eq!(stream.feed(b" 7 8").next(), Some(ok(10..11, 6)));
+        let items: Vec<_> = stream.finish().collect();
+        assert_eq!(items, vec![ok(12..13, 7), ok(14..15, 8)]);
+    }
+
+    #[test]
+    fn test_stream_parser_when_iterator_dropped_inside_token() {
+        let mut stream = new_stream();
+        assert_eq!(stream.feed(b"x 1s,2s,3s ").next(), Some(ok(2..4, 1)));
+        assert_eq!(stream.buffered(), 9);
+        let items: Vec<_> = stream.feed(b"4").collect();
+        assert_eq!(items, vec![ok(5..7, 2), ok(8..10, 3)]);
+        assert_eq!(stream.buffered(), 1);
+        assert_eq!(stream.finish().collect::<Vec<_>>(), vec![ok(11..12, 4)]);
+    }
+
+    #[test]
+    fn test_stream_parser_with_separator() {
+        let scanner = Scanner::new(Seconds).allow_numbers_without_unit();
+        let mut stream = StreamParser::with_scanner(scanner, |byte| byte == b'\n');
+        let items: Vec<_> = stream.feed(b"1\n 2\n").collect();
+        assert_eq!(items, vec![ok(0..1, 1), ok(3..4, 2)]);
+    }
+
+    #[rstest]
+    #[case::capacity_one(1)]
+    #[case::capacity_two(2)]
+    #[case::capacity_large(1024)]
+    fn test_buf_read_parser(#[case] capacity: usize) {
+        let reader = io::BufReader::with_capacity(capacity, "10s 200s\n\n3 ".as_bytes());
+        let items: Vec<_> = BufReadParser::new(reader, Seconds)
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(items, vec![ok(0..3, 10), ok(4..8, 200)]);
+    }
+
+    #[rstest]
+    #[case::capacity_one(1)]
+    #[case::capacity_large(1024)]
+    fn test_buf_read_parser_with_stream(#[case] capacity: usize) {
+        let reader = io::BufReader::with_capacity(capacity, "10 t=2s\n\n3 ".as_bytes());
+        let items: Vec<_> = BufReadParser::with_stream(reader, new_stream())
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(items, vec![ok(0..2, 10), ok(5..7, 2), ok(9..10, 3)]);
+    }
+
+    #[test]
+    fn test_buf_read_parser_when_read_error() {
+        struct FailingReader;
+
+        impl io::Read for FailingReader {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::new(io::ErrorKind::Other, "failed"))
+            }
+        }
+
+        let mut parser = BufReadParser::new(io::BufReader::new(FailingReader), Seconds);
+        assert!(parser.next().unwrap().is_err());
+        assert!(parser.next().is_none());
+    }
+}
@@ -5,10 +5,10 @@
 
 //! Contains all time related structures used in fundu like [`TimeUnit`] and [`Duration`]
 
-use std::cmp::Ordering;
-use std::fmt::Display;
-use std::hash::{Hash, Hasher};
-use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use core::cmp::Ordering;
+use core::fmt::Display;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -355,6 +355,188 @@ pub trait TimeUnitsLike {
     /// assert_eq!(time_units.get("does_not_match"), None);
     /// ```
     fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)>;
+
+    /// Return the result of [`TimeUnitsLike::get`] together with the [`TimeUnitConstraints`] of
+    /// the time unit
+    ///
+    /// The parser uses this method instead of [`TimeUnitsLike::get`] and enforces the
+    /// [`TimeUnitConstraints`] on the number in front of the time unit. The default implementation
+    /// returns the unconstrained [`TimeUnitConstraints::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_core::time::{Multiplier, TimeUnit, TimeUnitConstraints, TimeUnitsLike};
+    ///
+    /// struct TimeUnits {}
+    /// impl TimeUnitsLike for TimeUnits {
+    ///     fn is_empty(&self) -> bool {
+    ///         false
+    ///     }
+    ///
+    ///     fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
+    ///         self.get_with_constraints(identifier)
+    ///             .map(|(time_unit, multiplier, _)| (time_unit, multiplier))
+    ///     }
+    ///
+    ///     fn get_with_constraints(
+    ///         &self,
+    ///         identifier: &str,
+    ///     ) -> Option<(TimeUnit, Multiplier, TimeUnitConstraints)> {
+    ///         match identifier {
+    ///             "s" => Some((TimeUnit::Second, Multiplier(1, 0), TimeUnitConstraints::new())),
+    ///             "min" => Some((
+    ///                 TimeUnit::Minute,
+    ///                 Multiplier(1, 0),
+    ///                 TimeUnitConstraints::new().integer_only().range(0, 60),
+    ///             )),
+    ///             _ => None,
+    ///         }
+    ///     }
+    /// }
+    /// let time_units = TimeUnits {};
+    ///
+    /// assert_eq!(
+    ///     time_units.get_with_constraints("min"),
+    ///     Some((
+    ///         TimeUnit::Minute,
+    ///         Multiplier(1, 0),
+    ///         TimeUnitConstraints::new().integer_only().range(0, 60)
+    ///     ))
+    /// );
+    /// ```
+    #[inline]
+    fn get_with_constraints(
+        &self,
+        identifier: &str,
+    ) -> Option<(TimeUnit, Multiplier, TimeUnitConstraints)> {
+        self.get(identifier)
+            .map(|(time_unit, multiplier)| (time_unit, multiplier, TimeUnitConstraints::new()))
+    }
+}
+
+/// Restrictions for the number in front of a time unit
+///
+/// The [`crate::config::Config`] applies to all time units. The `TimeUnitConstraints` are returned
+/// by [`TimeUnitsLike::get_with_constraints`] and restrict the number of a single time unit, like
+/// disallowing fractions for minutes or restricting minutes to the range `0..60`. The parser
+/// enforces the constraints if a number is followed by the time unit and returns a
+/// [`crate::error::ParseError`] pointing at the offending part of the number otherwise. Numerals
+/// (like `one`) and infinity are not restricted.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_core::time::TimeUnitConstraints;
+///
+/// let constraints = TimeUnitConstraints::new()
+///     .disable_exponent()
+///     .integer_only()
+///     .range(0, 60);
+///
+/// assert!(!constraints.disable_fraction);
+/// assert!(constraints.disable_exponent);
+/// assert!(constraints.integer_only);
+/// assert_eq!(constraints.range, Some((0, 60)));
+/// assert_eq!(constraints.number_is_optional, None);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub struct TimeUnitConstraints {
+    /// If true, the number may not have a fraction like in `1.5`
+    pub disable_fraction: bool,
+    /// If true, the number may not have an exponent like in `1e2`
+    pub disable_exponent: bool,
+    /// If true, the value of the number must be an integer
+    ///
+    /// Unlike [`TimeUnitConstraints::disable_fraction`], this doesn't reject fractions and
+    /// exponents as long as the value is an integer, so `1.0` or `15e-1` (which is not an integer)
+    /// are checked by their value.
+    pub integer_only: bool,
+    /// The range `min..max` of the absolute value of the number (`min` inclusive, `max`
+    /// exclusive)
+    pub range: Option<(u64, u64)>,
+    /// If the time unit may appear without a number
+    ///
+    /// `None` uses the [`crate::config::Config::number_is_optional`] setting of the parser.
+    pub number_is_optional: Option<bool>,
+}
+
+impl TimeUnitConstraints {
+    /// Create new `TimeUnitConstraints` without any restrictions
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_core::time::TimeUnitConstraints;
+    ///
+    /// assert_eq!(TimeUnitConstraints::new(), TimeUnitConstraints::default());
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            disable_fraction: false,
+            disable_exponent: false,
+            integer_only: false,
+            range: None,
+            number_is_optional: None,
+        }
+    }
+
+    /// Disallow a fraction in the number of the time unit
+    pub const fn disable_fraction(mut self) -> Self {
+        self.disable_fraction = true;
+        self
+    }
+
+    /// Disallow an exponent in the number of the time unit
+    pub const fn disable_exponent(mut self) -> Self {
+        self.disable_exponent = true;
+        self
+    }
+
+    /// Allow only numbers with an integer value
+    pub const fn integer_only(mut self) -> Self {
+        self.integer_only = true;
+        self
+    }
+
+    /// Restrict the absolute value of the number to the range `min..max`
+    ///
+    /// # Panics
+    ///
+    /// If `min` is greater than or equal to `max`
+    pub const fn range(mut self, min: u64, max: u64) -> Self {
+        assert!(
+            min < max,
+            "The minimum of the range must be lower than the maximum"
+        );
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Set if the time unit may appear without a number
+    ///
+    /// This setting overrides [`crate::config::Config::number_is_optional`] for this time unit.
+    pub const fn number_is_optional(mut self, value: bool) -> Self {
+        self.number_is_optional = Some(value);
+        self
+    }
+
+    /// Return true if the time unit may appear without a number
+    ///
+    /// The `default` is used if [`TimeUnitConstraints::number_is_optional`] is `None`.
+    #[inline]
+    pub const fn allows_missing_number(&self, default: bool) -> bool {
+        match self.number_is_optional {
+            Some(value) => value,
+            None => default,
+        }
+    }
+
+    /// Return true if the constraints restrict the number in front of the time unit
+    #[inline]
+    pub const fn restricts_number(&self) -> bool {
+        self.disable_fraction || self.disable_exponent || self.integer_only || self.range.is_some()
+    }
 }
 
 /// The multiplier of a [`TimeUnit`].
@@ -548,7 +730,7 @@ pub trait SaturatingInto<T>: Sized {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Duration {
     is_negative: bool,
-    inner: std::time::Duration,
+    inner: core::time::Duration,
 }
 
 impl Duration {
@@ -564,7 +746,7 @@ impl Duration {
     /// ```
     pub const ZERO: Self = Self {
         is_negative: false,
-        inner: std::time::Duration::ZERO,
+        inner: core::time::Duration::ZERO,
     };
 
     /// The minimum duration
@@ -579,7 +761,7 @@ impl Duration {
     /// ```
     pub const MIN: Self = Self {
         is_negative: true,
-        inner: std::time::Duration::MAX,
+        inner: core::time::Duration::MAX,
     };
 
     /// The maximum duration
@@ -594,7 +776,7 @@ impl Duration {
     /// ```
     pub const MAX: Self = Self {
         is_negative: false,
-        inner: std::time::Duration::MAX,
+        inner: core::time::Duration::MAX,
     };
 
     /// Creates a new `Duration` from a [`std::time::Duration`] which can be negative or positive
@@ -610,7 +792,7 @@ impl Duration {
     /// let duration = Duration::from_std(true, std::time::Duration::new(1, 0));
     /// assert_eq!(Duration::negative(1, 0), duration);
     /// ```
-    pub const fn from_std(is_negative: bool, inner: std::time::Duration) -> Self {
+    pub const fn from_std(is_negative: bool, inner: core::time::Duration) -> Self {
         Self { is_negative, inner }
     }
 
@@ -635,7 +817,7 @@ impl Duration {
     pub const fn positive(secs: u64, nanos: u32) -> Self {
         Self {
             is_negative: false,
-            inner: std::time::Duration::new(secs, nanos),
+            inner: core::time::Duration::new(secs, nanos),
         }
     }
 
@@ -657,7 +839,7 @@ impl Duration {
     pub const fn negative(secs: u64, nanos: u32) -> Self {
         Self {
             is_negative: true,
-            inner: std::time::Duration::new(secs, nanos),
+            inner: core::time::Duration::new(secs, nanos),
         }
     }
 
@@ -864,7 +1046,7 @@ impl Duration {
         let secs = self.inner.as_secs();
         let extracted = i64::try_from(secs / factor).unwrap();
         if extracted > 0 {
-            self.inner = std::time::Duration::new(secs % factor, self.inner.subsec_nanos());
+            self.inner = core::time::Duration::new(secs % factor, self.inner.subsec_nanos());
             if self.is_negative {
                 extracted.neg()
             } else {
@@ -976,7 +1158,7 @@ impl Duration {
         if extracted == 0 {
             0
         } else {
-            self.inner = std::time::Duration::new(0, self.inner.subsec_nanos());
+            self.inner = core::time::Duration::new(0, self.inner.subsec_nanos());
             extracted
         }
     }
@@ -1183,7 +1365,7 @@ impl Duration {
 }
 
 impl Display for Duration {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         const YEAR: u64 = Year.multiplier().0.unsigned_abs();
         const MONTH: u64 = Month.multiplier().0.unsigned_abs();
         const WEEK: u64 = Week.multiplier().0.unsigned_abs();
@@ -1197,58 +1379,66 @@ impl Display for Duration {
             return f.write_str("0ns");
         }
 
-        let mut result = Vec::with_capacity(10);
+        // The components are written directly to the formatter. Every component of a negative
+        // duration is prefixed with a `-` and the components are separated by a whitespace.
+        let (mut separator, next_separator) = if self.is_negative() {
+            ("-", " -")
+        } else {
+            ("", " ")
+        };
+        let mut write = |value: u64, unit: &str| {
+            let result = write!(f, "{separator}{value}{unit}");
+            separator = next_separator;
+            result
+        };
+
         let mut secs = self.inner.as_secs();
         if secs > 0 {
             if secs >= YEAR {
-                result.push(format!("{}y", secs / YEAR));
+                write(secs / YEAR, "y")?;
                 secs %= YEAR;
             }
             if secs >= MONTH {
-                result.push(format!("{}M", secs / MONTH));
+                write(secs / MONTH, "M")?;
                 secs %= MONTH;
             }
             if secs >= WEEK {
-                result.push(format!("{}w", secs / WEEK));
+                write(secs / WEEK, "w")?;
                 secs %= WEEK;
             }
             if secs >= DAY {
-                result.push(format!("{}d", secs / DAY));
+                write(secs / DAY, "d")?;
                 secs %= DAY;
             }
             if secs >= HOUR {
-                result.push(format!("{}h", secs / HOUR));
+                write(secs / HOUR, "h")?;
                 secs %= HOUR;
             }
             if secs >= MINUTE {
-                result.push(format!("{}m", secs / MINUTE));
+                write(secs / MINUTE, "m")?;
                 secs %= MINUTE;
             }
             if secs >= 1 {
-                result.push(format!("{secs}s"));
+                write(secs, "s")?;
             }
         }
 
         let mut nanos = self.inner.subsec_nanos();
         if nanos > 0 {
             if nanos >= MILLIS_PER_NANO {
-                result.push(format!("{}ms", nanos / MILLIS_PER_NANO));
+                write(u64::from(nanos / MILLIS_PER_NANO), "ms")?;
                 nanos %= MILLIS_PER_NANO;
             }
             if nanos >= MICROS_PER_NANO {
-                result.push(format!("{}Ms", nanos / MICROS_PER_NANO));
+                write(u64::from(nanos / MICROS_PER_NANO), "Ms")?;
                 nanos %= MICROS_PER_NANO;
             }
             if nanos >= 1 {
-                result.push(format!("{nanos}ns"));
+                write(u64::from(nanos), "ns")?;
             }
         }
 
-        if self.is_negative() {
-            f.write_str(&format!("-{}", &result.join(" -")))
-        } else {
-            f.write_str(&result.join(" "))
-        }
+        Ok(())
     }
 }
 
@@ -1330,8 +1520,8 @@ impl Ord for Duration {
 }
 
 /// Convert a [`std::time::Duration`] into a [`Duration`]
-impl From<std::time::Duration> for Duration {
-    fn from(duration: std::time::Duration) -> Self {
+impl From<core::time::Duration> for Duration {
+    fn from(duration: core::time::Duration) -> Self {
         Self {
             is_negative: false,
             inner: duration,
@@ -1339,17 +1529,17 @@ impl From<std::time::Duration> for Duration {
     }
 }
 
-impl SaturatingInto<std::time::Duration> for Duration {
-    fn saturating_into(self) -> std::time::Duration {
+impl SaturatingInto<core::time::Duration> for Duration {
+    fn saturating_into(self) -> core::time::Duration {
         self.try_into().unwrap_or_else(|error| match error {
-            TryFromDurationError::NegativeDuration => std::time::Duration::ZERO,
+            TryFromDurationError::NegativeDuration => core::time::Duration::ZERO,
             _ => unreachable!(), // cov:excl-line
         })
     }
 }
 
 /// Convert a [`Duration`] into a [`std::time::Duration`]
-impl TryFrom<Duration> for std::time::Duration {
+impl TryFrom<Duration> for core::time::Duration {
     type Error = TryFromDurationError;
 
     fn try_from(duration: Duration) -> Result<Self, Self::Error> {
@@ -1369,7 +1559,7 @@ impl From<time::Duration> for Duration {
     fn from(duration: time::Duration) -> Self {
         Self {
             is_negative: duration.is_negative(),
-            inner: std::time::Duration::new(
+            inner: core::time::Duration::new(
                 duration.whole_seconds().unsigned_abs(),
                 duration.subsec_nanoseconds().unsigned_abs(),
             ),
@@ -5,6 +5,12 @@
 
 //! A collection of useful functions, constants which are in use across the whole crate
 
+#[cfg(feature = "alloc")]
+use alloc::format;
+
+#[cfg(feature = "alloc")]
+use crate::error::ParseError;
+
 pub const POW10: [u64; 20] = [
     1,
     10,
@@ -64,6 +70,28 @@ impl FloorLog2 for u64 {
     }
 }
 
+/// Return the position of the first non-whitespace character at or after `position`
+#[cfg(feature = "alloc")]
+pub(crate) fn skip_whitespace(source: &str, position: usize) -> usize {
+    let rest = &source[position..];
+    position + rest.len()
+        - rest
+            .trim_start_matches(|c: char| c.is_ascii_whitespace())
+            .len()
+}
+
+/// Return a syntax error at `position` describing the `expected` and the found character
+#[cfg(feature = "alloc")]
+pub(crate) fn unexpected(source: &str, position: usize, expected: &str) -> ParseError {
+    match source[position..].chars().next() {
+        Some(c) => ParseError::Syntax(position, format!("Expected {expected} but found '{c}'")),
+        None => ParseError::Syntax(
+            position,
+            format!("Expected {expected} but reached the end of input"),
+        ),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,139 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::Write;
+
+use fundu_core::config::ConfigBuilder;
+use fundu_core::parse::Parser;
+use fundu_core::time::{Duration, Multiplier, TimeUnit, TimeUnitsLike};
+use rstest::rstest;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn count_allocations<F: FnOnce()>(func: F) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    func();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+struct TimeUnits {}
+impl TimeUnitsLike for TimeUnits {
+    fn is_empty(&self) -> bool {
+        false
+    }
+
+    fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
+        match identifier {
+            "s" => Some((TimeUnit::Second, Multiplier::default())),
+            "ms" => Some((TimeUnit::MilliSecond, Multiplier::default())),
+            "m" => Some((TimeUnit::Minute, Multiplier::default())),
+            _ => None,
+        }
+    }
+}
+
+/// A fixed size buffer on the stack implementing [`std::fmt::Write`]
+struct StackBuffer {
+    buffer: [u8; 128],
+    len: usize,
+}
+
+impl StackBuffer {
+    fn new() -> Self {
+        Self {
+            buffer: [0; 128],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.buffer[..self.len]).unwrap()
+    }
+}
+
+impl Write for StackBuffer {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let end = self.len + s.len();
+        self.buffer
+            .get_mut(self.len..end)
+            .ok_or(std::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[rstest]
+#[case::valid("1.5s", true)]
+#[case::valid_multiple("1m 2s 3ms", true)]
+#[case::empty("", false)]
+#[case::invalid_time_unit("1x", false)]
+#[case::missing_exponent("1e", false)]
+#[case::invalid_time_unit_multiple("1s 2x", false)]
+#[case::no_number("s", false)]
+#[case::exponent_overflow("1e100000s", false)]
+fn test_parse_borrowed_does_not_allocate(#[case] source: &str, #[case] is_ok: bool) {
+    let parser = Parser::with_config(ConfigBuilder::new().parse_multiple(None).build());
+    let time_units = TimeUnits {};
+
+    let mut result = None;
+    let allocations = count_allocations(|| {
+        result = Some(parser.parse_borrowed(source, &time_units, None, None));
+    });
+    assert_eq!(allocations, 0);
+    assert_eq!(result.unwrap().is_ok(), is_ok);
+}
+
+#[rstest]
+#[case::invalid_time_unit("1x", "Time unit error: Invalid time unit: 'x' at column 1")]
+#[case::syntax("1e", "Syntax error: Unexpected end of input at column 2")]
+fn test_display_borrowed_parse_error_does_not_allocate(
+    #[case] source: &str,
+    #[case] expected: &str,
+) {
+    let parser = Parser::new();
+    let time_units = TimeUnits {};
+
+    let mut buffer = StackBuffer::new();
+    let allocations = count_allocations(|| {
+        let error = parser
+            .parse_borrowed(source, &time_units, None, None)
+            .unwrap_err();
+        write!(buffer, "{error}").unwrap();
+    });
+    assert_eq!(allocations, 0);
+    assert_eq!(buffer.as_str(), expected);
+}
+
+#[rstest]
+#[case::zero(Duration::ZERO, "0ns")]
+#[case::positive(Duration::positive(1, 500_000_000), "1s 500ms")]
+#[case::negative(Duration::negative(61, 1), "-1m -1s -1ns")]
+fn test_display_duration_does_not_allocate(#[case] duration: Duration, #[case] expected: &str) {
+    let mut buffer = StackBuffer::new();
+    let allocations = count_allocations(|| write!(buffer, "{duration}").unwrap());
+    assert_eq!(allocations, 0);
+    assert_eq!(buffer.as_str(), expected);
+}
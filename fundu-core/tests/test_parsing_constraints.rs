@@ -0,0 +1,319 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use fundu_core::config::{Config, ConfigBuilder};
+use fundu_core::error::ParseError;
+use fundu_core::parse::Parser;
+use fundu_core::time::{Duration, Multiplier, TimeUnit, TimeUnitConstraints, TimeUnitsLike};
+use rstest::rstest;
+
+const MINUTE: u64 = 60;
+const HOUR: u64 = 60 * MINUTE;
+
+/// `s` is unconstrained, `S` disallows fractions, `x` disallows exponents, `min` allows only
+/// integers in the range `0..60`, `h` may appear without a number and `d` requires a number
+struct TimeUnits {}
+impl TimeUnitsLike for TimeUnits {
+    fn is_empty(&self) -> bool {
+        false
+    }
+
+    // cov:excl-start
+    fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
+        self.get_with_constraints(identifier)
+            .map(|(time_unit, multiplier, _)| (time_unit, multiplier))
+    }
+    // cov:excl-stop
+
+    fn get_with_constraints(
+        &self,
+        identifier: &str,
+    ) -> Option<(TimeUnit, Multiplier, TimeUnitConstraints)> {
+        let constraints = TimeUnitConstraints::new();
+        match identifier {
+            "s" => Some((TimeUnit::Second, Multiplier::default(), constraints)),
+            "S" => Some((
+                TimeUnit::Second,
+                Multiplier::default(),
+                constraints.disable_fraction(),
+            )),
+            "x" => Some((
+                TimeUnit::Second,
+                Multiplier::default(),
+                constraints.disable_exponent(),
+            )),
+            "min" => Some((
+                TimeUnit::Minute,
+                Multiplier::default(),
+                constraints.integer_only().range(0, 60),
+            )),
+            "h" => Some((
+                TimeUnit::Hour,
+                Multiplier::default(),
+                constraints.number_is_optional(true),
+            )),
+            "d" => Some((
+                TimeUnit::Day,
+                Multiplier::default(),
+                constraints.number_is_optional(false),
+            )),
+            _ => None,
+        }
+    }
+}
+
+fn parse(config: Config, source: &str) -> Result<Duration, ParseError> {
+    Parser::with_config(config).parse(source, &TimeUnits {}, None, None)
+}
+
+#[rstest]
+#[case::unconstrained("1.5e1s", Duration::positive(15, 0))]
+#[case::no_fraction_with_integer("15S", Duration::positive(15, 0))]
+#[case::no_fraction_with_exponent("15e1S", Duration::positive(150, 0))]
+#[case::no_exponent_with_fraction("1.5x", Duration::positive(1, 500_000_000))]
+#[case::integer_min("59min", Duration::positive(59 * MINUTE, 0))]
+#[case::integer_with_zero_fraction("1.000min", Duration::positive(MINUTE, 0))]
+#[case::integer_with_exponent("1.5e1min", Duration::positive(15 * MINUTE, 0))]
+#[case::integer_with_negative_exponent("100e-2min", Duration::positive(MINUTE, 0))]
+#[case::integer_zero("0min", Duration::ZERO)]
+#[case::integer_zero_with_negative_exponent("0.0e-5min", Duration::ZERO)]
+#[case::range_with_leading_zeros("0059min", Duration::positive(59 * MINUTE, 0))]
+#[case::number_is_optional_overrides_config("h", Duration::positive(HOUR, 0))]
+#[case::number_is_optional_with_number("2h", Duration::positive(2 * HOUR, 0))]
+#[case::default_unit_is_unconstrained("1.5", Duration::positive(1, 500_000_000))]
+fn test_parse_with_constraints(#[case] source: &str, #[case] expected: Duration) {
+    assert_eq!(parse(Config::new(), source), Ok(expected));
+}
+
+#[cfg(feature = "alloc")]
+#[rstest]
+#[case::fraction(
+    "1.5S",
+    ParseError::Syntax(1, "No fraction allowed for this time unit".to_string())
+)]
+#[case::fraction_without_digits(
+    "1.S",
+    ParseError::Syntax(1, "No fraction allowed for this time unit".to_string())
+)]
+#[case::fraction_with_sign(
+    "+.5S",
+    ParseError::Syntax(1, "No fraction allowed for this time unit".to_string())
+)]
+#[case::exponent(
+    "1e1x",
+    ParseError::Syntax(1, "No exponent allowed for this time unit".to_string())
+)]
+#[case::exponent_after_fraction(
+    "1.5e0x",
+    ParseError::Syntax(3, "No exponent allowed for this time unit".to_string())
+)]
+#[case::not_integer(
+    "1.5min",
+    ParseError::TimeUnit(
+        0,
+        "The number must be an integer for this time unit but was: '1.5'".to_string()
+    )
+)]
+#[case::not_integer_with_exponent(
+    "15e-1min",
+    ParseError::TimeUnit(
+        0,
+        "The number must be an integer for this time unit but was: '15e-1'".to_string()
+    )
+)]
+#[case::not_integer_below_nano_seconds(
+    "1.0000000000001min",
+    ParseError::TimeUnit(
+        0,
+        "The number must be an integer for this time unit but was: '1.0000000000001'".to_string()
+    )
+)]
+#[case::out_of_range(
+    "60min",
+    ParseError::TimeUnit(
+        0,
+        "The number is out of the range of this time unit: '60'".to_string()
+    )
+)]
+#[case::out_of_range_with_exponent(
+    "6e1min",
+    ParseError::TimeUnit(
+        0,
+        "The number is out of the range of this time unit: '6e1'".to_string()
+    )
+)]
+#[case::out_of_range_with_sign(
+    "-60min",
+    ParseError::TimeUnit(
+        1,
+        "The number is out of the range of this time unit: '60'".to_string()
+    )
+)]
+#[case::out_of_range_huge(
+    "1e100min",
+    ParseError::TimeUnit(
+        0,
+        "The number is out of the range of this time unit: '1e100'".to_string()
+    )
+)]
+fn test_parse_with_constraints_then_error(#[case] source: &str, #[case] expected: ParseError) {
+    let config = ConfigBuilder::new().allow_negative().build();
+    assert_eq!(parse(config, source), Err(expected));
+}
+
+#[cfg(not(feature = "alloc"))]
+#[rstest]
+#[case::fraction(
+    "1.5S",
+    ParseError::StaticSyntax(1, "No fraction allowed for this time unit")
+)]
+#[case::exponent(
+    "1e1x",
+    ParseError::StaticSyntax(1, "No exponent allowed for this time unit")
+)]
+#[case::not_integer(
+    "1.5min",
+    ParseError::StaticTimeUnit(0, "The number must be an integer for this time unit")
+)]
+#[case::out_of_range(
+    "60min",
+    ParseError::StaticTimeUnit(0, "The number is out of the range of this time unit")
+)]
+#[case::requires_number("d", ParseError::StaticInvalidInput("Unexpected input"))]
+fn test_parse_with_constraints_then_static_error(
+    #[case] source: &str,
+    #[case] expected: ParseError,
+) {
+    let config = ConfigBuilder::new().allow_negative().build();
+    assert_eq!(parse(config, source), Err(expected));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_parse_with_range_constraint_below_minimum_then_error() {
+    struct Hours {}
+    impl TimeUnitsLike for Hours {
+        fn is_empty(&self) -> bool {
+            false
+        }
+
+        // cov:excl-start
+        fn get(&self, _: &str) -> Option<(TimeUnit, Multiplier)> {
+            unreachable!()
+        }
+        // cov:excl-stop
+
+        fn get_with_constraints(
+            &self,
+            identifier: &str,
+        ) -> Option<(TimeUnit, Multiplier, TimeUnitConstraints)> {
+            (identifier == "h").then_some((
+                TimeUnit::Hour,
+                Multiplier::default(),
+                TimeUnitConstraints::new().range(1, 13),
+            ))
+        }
+    }
+
+    let parser = Parser::new();
+    assert_eq!(
+        parser.parse("1h", &Hours {}, None, None),
+        Ok(Duration::positive(HOUR, 0))
+    );
+    assert_eq!(
+        parser.parse("12.9h", &Hours {}, None, None),
+        Ok(Duration::positive(12 * HOUR + 54 * MINUTE, 0))
+    );
+    assert_eq!(
+        parser.parse("0.999h", &Hours {}, None, None),
+        Err(ParseError::TimeUnit(
+            0,
+            "The number is out of the range of this time unit: '0.999'".to_string()
+        ))
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[rstest]
+#[case::config_not_optional(Config::new(), "d")]
+#[case::config_optional(ConfigBuilder::new().number_is_optional().build(), "d")]
+fn test_parse_when_time_unit_requires_number_then_error(
+    #[case] config: Config,
+    #[case] source: &str,
+) {
+    assert_eq!(
+        parse(config, source),
+        Err(ParseError::InvalidInput("d".to_string()))
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_parse_when_config_number_is_optional_then_unconstrained_time_unit_without_number() {
+    let config = ConfigBuilder::new().number_is_optional().build();
+    assert_eq!(parse(config, "s"), Ok(Duration::positive(1, 0)));
+    assert_eq!(
+        parse(Config::new(), "s"),
+        Err(ParseError::InvalidInput("s".to_string()))
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[rstest]
+#[case::valid("1s 59min h", Ok(Duration::positive(HOUR + 59 * MINUTE + 1, 0)))]
+#[case::fraction(
+    "1s 1.5S",
+    Err(ParseError::Syntax(4, "No fraction allowed for this time unit".to_string()))
+)]
+#[case::out_of_range(
+    "1s 61min",
+    Err(ParseError::TimeUnit(
+        3,
+        "The number is out of the range of this time unit: '61'".to_string()
+    ))
+)]
+#[case::requires_number("1s d", Err(ParseError::InvalidInput("d".to_string())))]
+fn test_parse_multiple_with_constraints(
+    #[case] source: &str,
+    #[case] expected: Result<Duration, ParseError>,
+) {
+    let config = ConfigBuilder::new().parse_multiple(None).build();
+    assert_eq!(parse(config, source), expected);
+}
+
+#[cfg(feature = "alloc")]
+#[rstest]
+#[case::valid("30min rest", Ok((Duration::positive(30 * MINUTE, 0), " rest")))]
+#[case::out_of_range(
+    "90min rest",
+    Err(ParseError::TimeUnit(
+        0,
+        "The number is out of the range of this time unit: '90'".to_string()
+    ))
+)]
+fn test_parse_prefix_with_constraints(
+    #[case] source: &str,
+    #[case] expected: Result<(Duration, &str), ParseError>,
+) {
+    assert_eq!(
+        Parser::new().parse_prefix(source, &TimeUnits {}, None, None),
+        expected
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_parse_many_with_constraints_then_same_as_parse() {
+    let sources = ["30min", "60min", "1.5S", "1S"];
+    let parser = Parser::new();
+    let expected = sources
+        .iter()
+        .map(|source| parser.parse(source, &TimeUnits {}, None, None))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        parser.parse_many(&sources, &TimeUnits {}, None, None),
+        expected
+    );
+}
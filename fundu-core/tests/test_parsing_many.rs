@@ -0,0 +1,135 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+#![cfg(feature = "alloc")]
+
+use fundu_core::config::{Config, ConfigBuilder};
+use fundu_core::parse::Parser;
+use fundu_core::time::{Duration, Multiplier, TimeUnit, TimeUnitsLike};
+use rstest::rstest;
+
+struct TimeUnits {}
+impl TimeUnitsLike for TimeUnits {
+    fn is_empty(&self) -> bool {
+        false
+    }
+
+    fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
+        match identifier {
+            "s" => Some((TimeUnit::Second, Multiplier::default())),
+            "ms" => Some((TimeUnit::MilliSecond, Multiplier::default())),
+            "h" => Some((TimeUnit::Hour, Multiplier::default())),
+            "µs" => Some((TimeUnit::MicroSecond, Multiplier::default())),
+            "back" => Some((TimeUnit::Second, Multiplier(-1, 0))),
+            _ => None,
+        }
+    }
+}
+
+struct NoTimeUnits {}
+impl TimeUnitsLike for NoTimeUnits {
+    fn is_empty(&self) -> bool {
+        true
+    }
+
+    fn get(&self, _: &str) -> Option<(TimeUnit, Multiplier)> {
+        None
+    }
+}
+
+const SOURCES: &[&str] = &[
+    "",
+    "0",
+    "000",
+    "1",
+    "0001",
+    "123",
+    "12345678",
+    "1234567890123456789",
+    "12345678901234567890123456789",
+    "1s",
+    "10ms",
+    "007h",
+    "5µs",
+    "1back",
+    "1e3",
+    "1E3s",
+    "1each",
+    "1.5s",
+    "1.",
+    ".5",
+    "-1s",
+    "+1s",
+    "1 s",
+    "1 s ago",
+    "1s 2s",
+    "1s2ms",
+    "1x",
+    "1sx",
+    "1s ",
+    " 1s",
+    "s",
+    "inf",
+    "1\u{1f600}",
+];
+
+fn assert_parse_many_eq_parse(config: Config, time_units: &dyn TimeUnitsLike) {
+    let parser = Parser::with_config(config);
+    let expected: Vec<_> = SOURCES
+        .iter()
+        .map(|source| parser.parse(source, time_units, None, None))
+        .collect();
+    assert_eq!(parser.parse_many(SOURCES, time_units, None, None), expected);
+}
+
+#[rstest]
+#[case::default(Config::new())]
+#[case::allow_negative(ConfigBuilder::new().allow_negative().build())]
+#[case::default_unit(ConfigBuilder::new().default_unit(TimeUnit::MilliSecond).build())]
+#[case::disable_exponent(ConfigBuilder::new().disable_exponent().build())]
+#[case::time_unit_delimiter(ConfigBuilder::new().allow_time_unit_delimiter().build())]
+#[case::ago(ConfigBuilder::new().allow_ago().allow_time_unit_delimiter().build())]
+#[case::number_is_optional(ConfigBuilder::new().number_is_optional().build())]
+#[case::multiple(ConfigBuilder::new().parse_multiple(None).build())]
+#[case::multiple_with_ago(
+    ConfigBuilder::new().parse_multiple(Some(&["and"])).allow_ago().allow_negative().build()
+)]
+#[case::letter_as_inner_delimiter(
+    ConfigBuilder::new().allow_time_unit_delimiter().inner_delimiter(|byte| byte == b'm').build()
+)]
+#[case::letter_as_outer_delimiter(
+    ConfigBuilder::new().parse_multiple(None).outer_delimiter(|byte| byte == b's').build()
+)]
+fn test_parse_many_when_time_units_then_same_as_parse(#[case] config: Config) {
+    assert_parse_many_eq_parse(config, &TimeUnits {});
+}
+
+#[rstest]
+#[case::default(Config::new())]
+#[case::multiple(ConfigBuilder::new().parse_multiple(None).build())]
+fn test_parse_many_when_no_time_units_then_same_as_parse(#[case] config: Config) {
+    assert_parse_many_eq_parse(config, &NoTimeUnits {});
+}
+
+#[test]
+fn test_parse_many_into_appends_results() {
+    let parser = Parser::new();
+    let mut results = vec![Ok(Duration::ZERO)];
+    parser.parse_many_into(
+        &["1s".to_owned(), "2h".to_owned()],
+        &TimeUnits {},
+        None,
+        None,
+        &mut results,
+    );
+    assert_eq!(
+        results,
+        vec![
+            Ok(Duration::ZERO),
+            Ok(Duration::positive(1, 0)),
+            Ok(Duration::positive(2 * 60 * 60, 0))
+        ]
+    );
+}
@@ -0,0 +1,314 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use fundu_core::config::{Config, ConfigBuilder};
+use fundu_core::error::ParseError;
+use fundu_core::parse::Parser;
+use fundu_core::relative::RelativeDuration;
+use fundu_core::time::{Duration, Multiplier, TimeUnit, TimeUnitsLike};
+use rstest::rstest;
+
+const MINUTE: u64 = 60;
+const HOUR: u64 = 60 * MINUTE;
+const DAY: u64 = 24 * HOUR;
+
+struct TimeUnits {}
+impl TimeUnitsLike for TimeUnits {
+    fn is_empty(&self) -> bool {
+        false
+    }
+
+    fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
+        match identifier {
+            "ms" => Some((TimeUnit::MilliSecond, Multiplier::default())),
+            "s" | "sec" => Some((TimeUnit::Second, Multiplier::default())),
+            "m" => Some((TimeUnit::Minute, Multiplier::default())),
+            "h" => Some((TimeUnit::Hour, Multiplier::default())),
+            "d" => Some((TimeUnit::Day, Multiplier::default())),
+            _ => None,
+        }
+    }
+}
+
+struct Keywords {}
+impl TimeUnitsLike for Keywords {
+    fn is_empty(&self) -> bool {
+        false
+    }
+
+    fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
+        match identifier {
+            "yesterday" => Some((TimeUnit::Day, Multiplier(-1, 0))),
+            "tomorrow" => Some((TimeUnit::Day, Multiplier(1, 0))),
+            _ => None,
+        }
+    }
+}
+
+const CONJUNCTIONS: &[&str] = &["and"];
+
+fn parse(config: Config, source: &str) -> Result<Duration, ParseError> {
+    Parser::with_config(config).parse(source, &TimeUnits {}, Some(&Keywords {}), None)
+}
+
+fn descending_order() -> Config<'static> {
+    ConfigBuilder::new()
+        .parse_multiple(Some(CONJUNCTIONS))
+        .require_descending_order()
+        .build()
+}
+
+fn no_duplicates() -> Config<'static> {
+    ConfigBuilder::new()
+        .parse_multiple(Some(CONJUNCTIONS))
+        .disable_duplicate_units()
+        .build()
+}
+
+fn no_mixed_signs() -> Config<'static> {
+    ConfigBuilder::new()
+        .parse_multiple(Some(CONJUNCTIONS))
+        .allow_ago()
+        .disable_mixed_signs()
+        .build()
+}
+
+#[rstest]
+#[case::unordered("1s 1h 1s", Duration::positive(HOUR + 2, 0))]
+#[case::mixed_signs("1h -5m", Duration::positive(HOUR - 5 * MINUTE, 0))]
+fn test_parse_multiple_without_validation(#[case] source: &str, #[case] expected: Duration) {
+    let config = ConfigBuilder::new()
+        .parse_multiple(None)
+        .allow_negative()
+        .build();
+    assert_eq!(parse(config, source), Ok(expected));
+}
+
+#[rstest]
+#[case::single("1h", Duration::positive(HOUR, 0))]
+#[case::descending("1h 30m 10s", Duration::positive(HOUR + 30 * MINUTE + 10, 0))]
+#[case::without_delimiter("1d1h1m1s", Duration::positive(DAY + HOUR + MINUTE + 1, 0))]
+#[case::equal_units("1h 1h", Duration::positive(2 * HOUR, 0))]
+#[case::same_base_unit("1s 1sec", Duration::positive(2, 0))]
+#[case::default_unit("1m 30", Duration::positive(MINUTE + 30, 0))]
+#[case::conjunction("1m and 1ms", Duration::positive(MINUTE, 1_000_000))]
+#[case::infinity_is_ignored("1h inf 1s", Duration::MAX)]
+fn test_parse_multiple_with_descending_order(#[case] source: &str, #[case] expected: Duration) {
+    assert_eq!(parse(descending_order(), source), Ok(expected));
+}
+
+#[cfg(feature = "alloc")]
+#[rstest]
+#[case::ascending("10s 1h", 4, "1h")]
+#[case::later_component("1m 1s 1ms 1h", 10, "1h")]
+#[case::without_delimiter("1s1h", 2, "1h")]
+#[case::default_unit("30 1m", 3, "1m")]
+#[case::conjunction("1s and 1d", 7, "1d")]
+#[case::keyword("1h tomorrow", 3, "tomorrow")]
+fn test_parse_multiple_with_descending_order_when_error(
+    #[case] source: &str,
+    #[case] column: usize,
+    #[case] duration: &str,
+) {
+    assert_eq!(
+        parse(descending_order(), source),
+        Err(ParseError::TimeUnit(
+            column,
+            format!(
+                "Time units must be in descending order but the time unit of '{duration}' is \
+                 greater than the previous time unit"
+            )
+        ))
+    );
+}
+
+#[rstest]
+#[case::unique("1s 1h", Duration::positive(HOUR + 1, 0))]
+#[case::unique_unordered("1ms 1d 1m", Duration::positive(DAY + MINUTE, 1_000_000))]
+#[case::infinity_is_ignored("1s inf inf", Duration::MAX)]
+fn test_parse_multiple_without_duplicates(#[case] source: &str, #[case] expected: Duration) {
+    assert_eq!(parse(no_duplicates(), source), Ok(expected));
+}
+
+#[cfg(feature = "alloc")]
+#[rstest]
+#[case::duplicate("1s 1h 1s", 6, "1s")]
+#[case::same_base_unit("1s 1sec", 3, "1sec")]
+#[case::without_delimiter("1s1s", 2, "1s")]
+#[case::default_unit("1s 2", 3, "2")]
+#[case::conjunction("1s and 1s", 7, "1s")]
+#[case::multiple_delimiters("1s  \t 1.5s", 6, "1.5s")]
+fn test_parse_multiple_without_duplicates_when_error(
+    #[case] source: &str,
+    #[case] column: usize,
+    #[case] duration: &str,
+) {
+    assert_eq!(
+        parse(no_duplicates(), source),
+        Err(ParseError::TimeUnit(
+            column,
+            format!("Duplicate time unit: The time unit of '{duration}' occurred already")
+        ))
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_parse_multiple_with_descending_order_and_without_duplicates() {
+    let config = ConfigBuilder::new()
+        .parse_multiple(None)
+        .require_descending_order()
+        .disable_duplicate_units()
+        .build();
+
+    assert_eq!(
+        parse(config.clone(), "1h 1m 1s"),
+        Ok(Duration::positive(HOUR + MINUTE + 1, 0))
+    );
+    assert_eq!(
+        parse(config, "1h 1h"),
+        Err(ParseError::TimeUnit(
+            3,
+            "Duplicate time unit: The time unit of '1h' occurred already".to_string()
+        ))
+    );
+}
+
+#[rstest]
+#[case::positive("1h 5m", Duration::positive(HOUR + 5 * MINUTE, 0))]
+#[case::negative("-1h -5m", Duration::negative(HOUR + 5 * MINUTE, 0))]
+#[case::ago("1h ago 5m ago", Duration::negative(HOUR + 5 * MINUTE, 0))]
+#[case::zero_is_ignored("1h -0m 0s 5m", Duration::positive(HOUR + 5 * MINUTE, 0))]
+#[case::leading_zero_is_ignored("0s -1h", Duration::negative(HOUR, 0))]
+#[case::keyword("-1d yesterday", Duration::negative(2 * DAY, 0))]
+fn test_parse_multiple_without_mixed_signs(#[case] source: &str, #[case] expected: Duration) {
+    assert_eq!(parse(no_mixed_signs(), source), Ok(expected));
+}
+
+#[cfg(feature = "alloc")]
+#[rstest]
+#[case::negative_after_positive("1h -5m", 3, "-5m")]
+#[case::positive_after_negative("-1h 5m", 4, "5m")]
+#[case::explicit_positive("-1h +5m", 4, "+5m")]
+#[case::ago("1h 5m ago", 3, "5m ago")]
+#[case::keyword("1d yesterday", 3, "yesterday")]
+#[case::conjunction("1h and -5m", 7, "-5m")]
+#[case::after_zero("0s 1h -5m", 6, "-5m")]
+fn test_parse_multiple_without_mixed_signs_when_error(
+    #[case] source: &str,
+    #[case] column: usize,
+    #[case] duration: &str,
+) {
+    assert_eq!(
+        parse(no_mixed_signs(), source),
+        Err(ParseError::Syntax(
+            column,
+            format!(
+                "Mixed signs are not allowed but the sign of '{duration}' differs from the \
+                 previous durations"
+            )
+        ))
+    );
+}
+
+#[test]
+fn test_parse_multiple_when_negative_number_is_reported_before_mixed_signs() {
+    let config = ConfigBuilder::new()
+        .parse_multiple(None)
+        .disable_mixed_signs()
+        .build();
+
+    assert_eq!(parse(config, "1h -5m"), Err(ParseError::NegativeNumber));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_parse_relative_with_validation() {
+    let config = ConfigBuilder::new()
+        .parse_multiple(None)
+        .allow_negative()
+        .require_descending_order()
+        .disable_duplicate_units()
+        .disable_mixed_signs()
+        .build();
+    let parser = Parser::with_config(config);
+    let time_units = TimeUnits {};
+
+    assert_eq!(
+        parser.parse_relative("1d 1h", &time_units, None, None),
+        Ok(RelativeDuration::new(
+            0,
+            0,
+            0,
+            1,
+            Duration::positive(HOUR, 0)
+        ))
+    );
+    assert_eq!(
+        parser.parse_relative("1h 1d", &time_units, None, None),
+        Err(ParseError::TimeUnit(
+            3,
+            "Time units must be in descending order but the time unit of '1d' is greater than \
+             the previous time unit"
+                .to_string()
+        ))
+    );
+    assert_eq!(
+        parser.parse_relative("1d 1d", &time_units, None, None),
+        Err(ParseError::TimeUnit(
+            3,
+            "Duplicate time unit: The time unit of '1d' occurred already".to_string()
+        ))
+    );
+    assert_eq!(
+        parser.parse_relative("1d -1h", &time_units, None, None),
+        Err(ParseError::Syntax(
+            3,
+            "Mixed signs are not allowed but the sign of '-1h' differs from the previous \
+             durations"
+                .to_string()
+        ))
+    );
+}
+
+#[cfg(not(feature = "alloc"))]
+#[test]
+fn test_parse_relative_with_validation_without_alloc() {
+    let config = ConfigBuilder::new()
+        .parse_multiple(None)
+        .allow_negative()
+        .require_descending_order()
+        .disable_duplicate_units()
+        .disable_mixed_signs()
+        .build();
+    let parser = Parser::with_config(config);
+    let time_units = TimeUnits {};
+
+    assert_eq!(
+        parser.parse_relative("1d 1h", &time_units, None, None),
+        Ok(RelativeDuration::new(
+            0,
+            0,
+            0,
+            1,
+            Duration::positive(HOUR, 0)
+        ))
+    );
+    assert_eq!(
+        parser.parse_relative("1h 1d", &time_units, None, None),
+        Err(ParseError::StaticTimeUnit(
+            3,
+            "Time units must be in descending order"
+        ))
+    );
+    assert_eq!(
+        parser.parse_relative("1d 1d", &time_units, None, None),
+        Err(ParseError::StaticTimeUnit(3, "Duplicate time unit"))
+    );
+    assert_eq!(
+        parser.parse_relative("1d -1h", &time_units, None, None),
+        Err(ParseError::StaticSyntax(3, "Mixed signs are not allowed"))
+    );
+}
@@ -175,6 +175,7 @@ fn test_parse_with_numerals_when_number_is_optional(
     );
 }
 
+#[cfg(feature = "alloc")]
 #[rstest]
 #[case::numeral_without_delimiter("nextsecond", ParseError::InvalidInput("nextsecond".to_owned()))]
 #[case::numeral_with_wrong_delimiter("next\nsecond", ParseError::InvalidInput("next\nsecond".to_owned()))]
@@ -207,6 +208,42 @@ fn test_parse_with_numerals_when_invalid(
     );
 }
 
+#[cfg(not(feature = "alloc"))]
+#[rstest]
+#[case::just_numeral("next", ParseError::StaticInvalidInput("Unexpected input"))]
+#[case::numeral_end_with_delimiter(
+    "next    ",
+    ParseError::StaticSyntax(4, "Input may not end with a delimiter")
+)]
+#[case::numeral_with_keyword(
+    "next tomorrow",
+    ParseError::StaticTimeUnit(5, "Found numeral without a time unit")
+)]
+fn test_parse_with_numerals_when_invalid_without_alloc(
+    #[case] input: &str,
+    #[case] expected: ParseError,
+    two_time_units: Box<dyn TimeUnitsLike>,
+    tomorrow_keyword: Box<dyn TimeUnitsLike>,
+    two_numerals: Box<dyn NumbersLike>,
+    space_delimiter: Delimiter,
+) {
+    let config = ConfigBuilder::new()
+        .allow_time_unit_delimiter()
+        .inner_delimiter(space_delimiter)
+        .build();
+    let parser = Parser::with_config(config);
+    assert_eq!(
+        parser.parse(
+            input,
+            two_time_units.as_ref(),
+            Some(tomorrow_keyword.as_ref()),
+            Some(two_numerals.as_ref())
+        ),
+        Err(expected)
+    );
+}
+
+#[cfg(feature = "alloc")]
 #[rstest]
 #[case::numeral_without_time_unit("next 1", ParseError::TimeUnit(5, "Found numeral 'next' without a time unit".to_owned()))]
 #[case::numeral_without_time_unit_plus("next +", ParseError::TimeUnit(5, "Found numeral 'next' without a time unit".to_owned()))]
@@ -237,6 +274,7 @@ fn test_parse_with_numerals_when_invalid_parse_multiple(
     );
 }
 
+#[cfg(feature = "alloc")]
 #[rstest]
 #[case::numeral_with_impossible_time_unit(
     "next second",
@@ -0,0 +1,159 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use fundu_core::config::{Config, ConfigBuilder, NumbersLike};
+use fundu_core::error::ParseError;
+use fundu_core::parse::Parser;
+use fundu_core::time::{Duration, Multiplier, TimeUnit, TimeUnitsLike};
+use rstest::rstest;
+
+const HOUR: u64 = 60 * 60;
+const DAY: u64 = 24 * HOUR;
+
+struct TimeUnits {}
+impl TimeUnitsLike for TimeUnits {
+    fn is_empty(&self) -> bool {
+        false
+    }
+
+    fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
+        match identifier {
+            "s" => Some((TimeUnit::Second, Multiplier::default())),
+            "h" => Some((TimeUnit::Hour, Multiplier::default())),
+            "d" => Some((TimeUnit::Day, Multiplier::default())),
+            "µs" => Some((TimeUnit::MicroSecond, Multiplier::default())),
+            _ => None,
+        }
+    }
+}
+
+struct Keywords {}
+impl TimeUnitsLike for Keywords {
+    // cov:excl-start
+    fn is_empty(&self) -> bool {
+        false
+    }
+    // cov:excl-stop
+
+    fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
+        match identifier {
+            "yesterday" => Some((TimeUnit::Day, Multiplier(-1, 0))),
+            _ => None,
+        }
+    }
+}
+
+struct Numerals {}
+impl NumbersLike for Numerals {
+    fn get(&self, input: &str) -> Option<Multiplier> {
+        match input {
+            "next" => Some(Multiplier(1, 0)),
+            _ => None,
+        }
+    }
+}
+
+fn parse_prefix<'s>(config: Config, source: &'s str) -> Result<(Duration, &'s str), ParseError> {
+    Parser::with_config(config).parse_prefix(
+        source,
+        &TimeUnits {},
+        Some(&Keywords {}),
+        Some(&Numerals {}),
+    )
+}
+
+#[rstest]
+#[case::only_duration("1s", Duration::positive(1, 0), "")]
+#[case::word_after_delimiter("5h then fail", Duration::positive(5 * HOUR, 0), " then fail")]
+#[case::punctuation("5h,6s", Duration::positive(5 * HOUR, 0), ",6s")]
+#[case::second_duration("1s2s", Duration::positive(1, 0), "2s")]
+#[case::number_without_time_unit("3x every", Duration::positive(3, 0), "x every")]
+#[case::time_unit_delimiter("1 h later", Duration::positive(HOUR, 0), " later")]
+#[case::delimiter_without_time_unit("1 apples", Duration::positive(1, 0), " apples")]
+#[case::trailing_delimiter("1 ", Duration::positive(1, 0), " ")]
+#[case::multibyte_time_unit("1µs;", Duration::positive(0, 1000), ";")]
+#[case::time_unit_followed_by_letters("1hour", Duration::positive(1, 0), "hour")]
+#[case::time_unit_delimiter_and_word("5 days later", Duration::positive(5, 0), " days later")]
+#[case::multibyte_time_unit_followed_by_letters("1µsé", Duration::positive(1, 0), "µsé")]
+#[case::time_unit_followed_by_digit("1h2", Duration::positive(HOUR, 0), "2")]
+#[case::without_time_unit_prefix("1yes", Duration::positive(1, 0), "yes")]
+#[case::fraction("1.5d.", Duration::positive(DAY + DAY / 2, 0), ".")]
+#[case::fraction_without_digits("1.x", Duration::positive(1, 0), "x")]
+#[case::exponent("2e3s rest", Duration::positive(2000, 0), " rest")]
+#[case::negative_exponent("2e-1s", Duration::positive(0, 200_000_000), "")]
+#[case::not_an_exponent("2each", Duration::positive(2, 0), "each")]
+#[case::not_an_exponent_sign("2e-x", Duration::positive(2, 0), "e-x")]
+#[case::negative("-1s!", Duration::negative(1, 0), "!")]
+#[case::ago("1 h ago, then", Duration::negative(HOUR, 0), ", then")]
+#[case::ago_inside_word("1 h agony", Duration::positive(HOUR, 0), " agony")]
+#[case::infinity_short("inf rest", Duration::MAX, " rest")]
+#[case::infinity_long("infinity.", Duration::MAX, ".")]
+#[case::keyword("yesterday is", Duration::negative(DAY, 0), " is")]
+#[case::numeral("next d or", Duration::positive(DAY, 0), " or")]
+#[case::without_number("h later", Duration::positive(HOUR, 0), " later")]
+fn test_parse_prefix_single(
+    #[case] source: &str,
+    #[case] expected: Duration,
+    #[case] remainder: &str,
+) {
+    let config = ConfigBuilder::new()
+        .allow_negative()
+        .allow_time_unit_delimiter()
+        .allow_ago()
+        .number_is_optional()
+        .build();
+    assert_eq!(parse_prefix(config, source), Ok((expected, remainder)));
+}
+
+#[cfg(feature = "alloc")]
+#[rstest]
+#[case::empty("", ParseError::Empty)]
+#[case::word("then 5s", ParseError::InvalidInput("then 5s".to_owned()))]
+#[case::delimiter(" 5s", ParseError::InvalidInput(" 5s".to_owned()))]
+#[case::only_point(".s", ParseError::Syntax(0, "Either the whole number part or the fraction must be present".to_owned()))]
+#[case::infinity_inside_word(
+    "infinite",
+    ParseError::Syntax(0, "Error parsing infinity: Expected 'inf' or 'infinity'".to_owned())
+)]
+#[case::negative("-1s", ParseError::NegativeNumber)]
+fn test_parse_prefix_when_invalid_start_then_error(
+    #[case] source: &str,
+    #[case] expected: ParseError,
+) {
+    assert_eq!(parse_prefix(Config::new(), source), Err(expected));
+}
+
+#[rstest]
+#[case::all("1d 2h", Duration::positive(DAY + 2 * HOUR, 0), "")]
+#[case::remainder("1d 2h rest", Duration::positive(DAY + 2 * HOUR, 0), " rest")]
+#[case::without_delimiter("1d2h", Duration::positive(DAY + 2 * HOUR, 0), "")]
+#[case::conjunction("1d and 2h, then", Duration::positive(DAY + 2 * HOUR, 0), ", then")]
+#[case::conjunction_before_digit("1d and2h", Duration::positive(DAY + 2 * HOUR, 0), "")]
+#[case::trailing_conjunction("1d and rest", Duration::positive(DAY, 0), " and rest")]
+#[case::trailing_conjunction_at_end("1d and", Duration::positive(DAY, 0), " and")]
+#[case::trailing_delimiter("1d ", Duration::positive(DAY, 0), " ")]
+#[case::number_without_time_unit("1d 2x", Duration::positive(DAY + 2, 0), "x")]
+#[case::negative_keyword("1d yesterday", Duration::positive(DAY, 0), " yesterday")]
+#[case::infinity("1d inf!", Duration::MAX, "!")]
+#[case::invalid_second_duration("1d .x", Duration::positive(DAY, 0), " .x")]
+#[case::negative_second_duration("1d -2h", Duration::positive(DAY, 0), " -2h")]
+fn test_parse_prefix_multiple(
+    #[case] source: &str,
+    #[case] expected: Duration,
+    #[case] remainder: &str,
+) {
+    let config = ConfigBuilder::new().parse_multiple(Some(&["and"])).build();
+    assert_eq!(parse_prefix(config, source), Ok((expected, remainder)));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_parse_prefix_when_time_unit_without_number_inside_word_then_error() {
+    let config = ConfigBuilder::new().number_is_optional().build();
+    assert_eq!(
+        parse_prefix(config, "hello"),
+        Err(ParseError::InvalidInput("hello".to_owned()))
+    );
+}
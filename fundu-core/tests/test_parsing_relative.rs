@@ -0,0 +1,211 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use fundu_core::config::{Config, ConfigBuilder, NumbersLike};
+use fundu_core::error::ParseError;
+use fundu_core::parse::Parser;
+use fundu_core::relative::RelativeDuration;
+use fundu_core::time::{Duration, Multiplier, TimeUnit, TimeUnitsLike};
+use rstest::{fixture, rstest};
+
+struct CalendarTimeUnits {}
+impl TimeUnitsLike for CalendarTimeUnits {
+    fn is_empty(&self) -> bool {
+        false
+    }
+
+    fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
+        match identifier {
+            "s" => Some((TimeUnit::Second, Multiplier::default())),
+            "h" => Some((TimeUnit::Hour, Multiplier::default())),
+            "d" => Some((TimeUnit::Day, Multiplier::default())),
+            "w" => Some((TimeUnit::Week, Multiplier::default())),
+            "fortnight" => Some((TimeUnit::Week, Multiplier(2, 0))),
+            "M" => Some((TimeUnit::Month, Multiplier::default())),
+            "quarter" => Some((TimeUnit::Month, Multiplier(3, 0))),
+            "y" => Some((TimeUnit::Year, Multiplier::default())),
+            _ => None,
+        }
+    }
+}
+
+struct Keywords {}
+impl TimeUnitsLike for Keywords {
+    // cov:excl-start
+    fn is_empty(&self) -> bool {
+        false
+    }
+    // cov:excl-stop
+
+    fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
+        match identifier {
+            "yesterday" => Some((TimeUnit::Day, Multiplier(-1, 0))),
+            "today" => Some((TimeUnit::Day, Multiplier(0, 0))),
+            _ => None,
+        }
+    }
+}
+
+struct Numerals {}
+impl NumbersLike for Numerals {
+    fn get(&self, input: &str) -> Option<Multiplier> {
+        match input {
+            "next" => Some(Multiplier(1, 0)),
+            "last" => Some(Multiplier(-1, 0)),
+            _ => None,
+        }
+    }
+}
+
+#[fixture]
+fn time_units() -> Box<dyn TimeUnitsLike> {
+    Box::new(CalendarTimeUnits {})
+}
+
+#[fixture]
+fn multiple_config() -> Config<'static> {
+    ConfigBuilder::new()
+        .allow_negative()
+        .allow_time_unit_delimiter()
+        .number_is_optional()
+        .parse_multiple(None)
+        .build()
+}
+
+#[rstest]
+#[case::seconds("1s", RelativeDuration::from_exact(Duration::positive(1, 0)))]
+#[case::default_time_unit("1", RelativeDuration::from_exact(Duration::positive(1, 0)))]
+#[case::days("2d", RelativeDuration::new(0, 0, 0, 2, Duration::ZERO))]
+#[case::weeks("2w", RelativeDuration::new(0, 0, 2, 0, Duration::ZERO))]
+#[case::months("2M", RelativeDuration::new(0, 2, 0, 0, Duration::ZERO))]
+#[case::years("2y", RelativeDuration::new(2, 0, 0, 0, Duration::ZERO))]
+#[case::negative_years("-2y", RelativeDuration::new(-2, 0, 0, 0, Duration::ZERO))]
+#[case::multiplier_week("3fortnight", RelativeDuration::new(0, 0, 6, 0, Duration::ZERO))]
+#[case::multiplier_month("2quarter", RelativeDuration::new(0, 6, 0, 0, Duration::ZERO))]
+#[case::exponent("1e2M", RelativeDuration::new(0, 100, 0, 0, Duration::ZERO))]
+#[case::negative_exponent("10e-1y", RelativeDuration::new(1, 0, 0, 0, Duration::ZERO))]
+#[case::fraction_day(
+    "1.5d",
+    RelativeDuration::new(0, 0, 0, 1, Duration::positive(43_200, 0))
+)]
+#[case::negative_fraction_day("-1.5d", RelativeDuration::new(0, 0, 0, -1, Duration::negative(43_200, 0)))]
+#[case::only_fraction_week(
+    ".5w",
+    RelativeDuration::new(0, 0, 0, 0, Duration::positive(302_400, 0))
+)]
+#[case::fraction_year(
+    "1.5y",
+    RelativeDuration::new(1, 0, 0, 0, Duration::positive(15_778_800, 0))
+)]
+#[case::saturating_years(
+    "10000000000000000000y",
+    RelativeDuration::new(i64::MAX, 0, 0, 0, Duration::ZERO)
+)]
+#[case::saturating_negative_years(
+    "-10000000000000000000y",
+    RelativeDuration::new(i64::MIN, 0, 0, 0, Duration::ZERO)
+)]
+#[case::infinity("inf", RelativeDuration::from_exact(Duration::MAX))]
+#[case::keyword("yesterday", RelativeDuration::new(0, 0, 0, -1, Duration::ZERO))]
+#[case::keyword_zero("today", RelativeDuration::ZERO)]
+#[case::numeral("last M", RelativeDuration::new(0, -1, 0, 0, Duration::ZERO))]
+#[case::numeral_multiplier("next quarter", RelativeDuration::new(0, 3, 0, 0, Duration::ZERO))]
+#[case::without_number("y", RelativeDuration::new(1, 0, 0, 0, Duration::ZERO))]
+fn test_parse_relative_single(
+    #[case] input: &str,
+    #[case] expected: RelativeDuration,
+    time_units: Box<dyn TimeUnitsLike>,
+) {
+    let config = ConfigBuilder::new()
+        .allow_negative()
+        .allow_time_unit_delimiter()
+        .number_is_optional()
+        .build();
+    let parser = Parser::with_config(config);
+    assert_eq!(
+        parser.parse_relative(
+            input,
+            time_units.as_ref(),
+            Some(&Keywords {}),
+            Some(&Numerals {})
+        ),
+        Ok(expected)
+    );
+}
+
+#[rstest]
+#[case::all_units(
+    "1y 2M 3w 4d 5h 6s",
+    RelativeDuration::new(1, 2, 3, 4, Duration::positive(5 * 3600 + 6, 0))
+)]
+#[case::accumulate("1y 1y 1M -2M", RelativeDuration::new(2, -1, 0, 0, Duration::ZERO))]
+#[case::mixed_signs("1y -1d", RelativeDuration::new(1, 0, 0, -1, Duration::ZERO))]
+#[case::keyword_and_units(
+    "yesterday 1h",
+    RelativeDuration::new(0, 0, 0, -1, Duration::positive(3600, 0))
+)]
+#[case::saturating(
+    "9223372036854775807M 1M",
+    RelativeDuration::new(0, i64::MAX, 0, 0, Duration::ZERO)
+)]
+fn test_parse_relative_multiple(
+    #[case] input: &str,
+    #[case] expected: RelativeDuration,
+    time_units: Box<dyn TimeUnitsLike>,
+    multiple_config: Config<'static>,
+) {
+    let parser = Parser::with_config(multiple_config);
+    assert_eq!(
+        parser.parse_relative(input, time_units.as_ref(), Some(&Keywords {}), None),
+        Ok(expected)
+    );
+}
+
+#[rstest]
+#[case::negative_year("-1y", ParseError::NegativeNumber)]
+#[case::negative_keyword("yesterday", ParseError::NegativeNumber)]
+#[cfg_attr(
+    feature = "alloc",
+    case::invalid_time_unit("1x", ParseError::TimeUnit(1, "Invalid time unit: 'x'".to_owned()))
+)]
+#[cfg_attr(
+    not(feature = "alloc"),
+    case::invalid_time_unit("1x", ParseError::StaticTimeUnit(1, "Invalid time unit"))
+)]
+#[case::empty("", ParseError::Empty)]
+fn test_parse_relative_when_invalid(
+    #[case] input: &str,
+    #[case] expected: ParseError,
+    time_units: Box<dyn TimeUnitsLike>,
+) {
+    let parser = Parser::new();
+    assert_eq!(
+        parser.parse_relative(input, time_units.as_ref(), Some(&Keywords {}), None),
+        Err(expected)
+    );
+}
+
+#[rstest]
+#[case::mixed("1y -1y", ParseError::NegativeNumber)]
+#[cfg_attr(
+    feature = "alloc",
+    case::invalid_time_unit("1y 1x", ParseError::InvalidInput("x".to_owned()))
+)]
+#[cfg_attr(
+    not(feature = "alloc"),
+    case::invalid_time_unit("1y 1x", ParseError::StaticInvalidInput("Unexpected input"))
+)]
+fn test_parse_relative_multiple_when_invalid(
+    #[case] input: &str,
+    #[case] expected: ParseError,
+    time_units: Box<dyn TimeUnitsLike>,
+) {
+    let config = ConfigBuilder::new().parse_multiple(None).build();
+    let parser = Parser::with_config(config);
+    assert_eq!(
+        parser.parse_relative(input, time_units.as_ref(), None, None),
+        Err(expected)
+    );
+}
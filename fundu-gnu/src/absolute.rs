@@ -0,0 +1,1348 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use fundu_core::config::NumbersLike;
+use fundu_core::error::ParseError;
+use fundu_core::relative::days_in_month;
+use fundu_core::time::{Duration, Multiplier, TimeUnitsLike};
+
+use crate::util::{shift_error_position, trim_whitespace};
+use crate::{Clock, DateTime, RelativeTimeParser, NUMERALS, TIME_KEYWORDS, TIME_UNITS};
+
+const SECS_PER_HOUR: i64 = 3600;
+const MINUTES_PER_DAY: u64 = 24 * 60;
+const MAX_NANOS_DIGITS: usize = 9;
+
+const MONTHS: [(&str, u8); 24] = [
+    ("january", 1),
+    ("jan", 1),
+    ("february", 2),
+    ("feb", 2),
+    ("march", 3),
+    ("mar", 3),
+    ("april", 4),
+    ("apr", 4),
+    ("may", 5),
+    ("june", 6),
+    ("jun", 6),
+    ("july", 7),
+    ("jul", 7),
+    ("august", 8),
+    ("aug", 8),
+    ("september", 9),
+    ("sept", 9),
+    ("sep", 9),
+    ("october", 10),
+    ("oct", 10),
+    ("november", 11),
+    ("nov", 11),
+    ("december", 12),
+    ("dec", 12),
+];
+
+/// The days of the week counted like `tm_wday` from `0` (sunday) to `6` (saturday)
+const WEEKDAYS: [(&str, u8); 18] = [
+    ("sunday", 0),
+    ("sun", 0),
+    ("monday", 1),
+    ("mon", 1),
+    ("tuesday", 2),
+    ("tues", 2),
+    ("tue", 2),
+    ("wednesday", 3),
+    ("wednes", 3),
+    ("wed", 3),
+    ("thursday", 4),
+    ("thurs", 4),
+    ("thur", 4),
+    ("thu", 4),
+    ("friday", 5),
+    ("fri", 5),
+    ("saturday", 6),
+    ("sat", 6),
+];
+
+/// The time zone abbreviations known to gnu with their offset to UTC in seconds
+const TIME_ZONES: [(&str, i64); 51] = [
+    ("gmt", 0),
+    ("ut", 0),
+    ("utc", 0),
+    ("z", 0),
+    ("wet", 0),
+    ("west", SECS_PER_HOUR),
+    ("bst", SECS_PER_HOUR),
+    ("art", -3 * SECS_PER_HOUR),
+    ("brt", -3 * SECS_PER_HOUR),
+    ("brst", -2 * SECS_PER_HOUR),
+    ("nst", -(3 * SECS_PER_HOUR + 1800)),
+    ("ndt", -(2 * SECS_PER_HOUR + 1800)),
+    ("ast", -4 * SECS_PER_HOUR),
+    ("adt", -3 * SECS_PER_HOUR),
+    ("clt", -4 * SECS_PER_HOUR),
+    ("clst", -3 * SECS_PER_HOUR),
+    ("est", -5 * SECS_PER_HOUR),
+    ("edt", -4 * SECS_PER_HOUR),
+    ("cst", -6 * SECS_PER_HOUR),
+    ("cdt", -5 * SECS_PER_HOUR),
+    ("mst", -7 * SECS_PER_HOUR),
+    ("mdt", -6 * SECS_PER_HOUR),
+    ("pst", -8 * SECS_PER_HOUR),
+    ("pdt", -7 * SECS_PER_HOUR),
+    ("akst", -9 * SECS_PER_HOUR),
+    ("akdt", -8 * SECS_PER_HOUR),
+    ("hst", -10 * SECS_PER_HOUR),
+    ("hast", -10 * SECS_PER_HOUR),
+    ("hadt", -9 * SECS_PER_HOUR),
+    ("sst", -11 * SECS_PER_HOUR),
+    ("wat", SECS_PER_HOUR),
+    ("cet", SECS_PER_HOUR),
+    ("cest", 2 * SECS_PER_HOUR),
+    ("met", SECS_PER_HOUR),
+    ("mez", SECS_PER_HOUR),
+    ("mest", 2 * SECS_PER_HOUR),
+    ("mesz", 2 * SECS_PER_HOUR),
+    ("eet", 2 * SECS_PER_HOUR),
+    ("eest", 3 * SECS_PER_HOUR),
+    ("cat", 2 * SECS_PER_HOUR),
+    ("sast", 2 * SECS_PER_HOUR),
+    ("eat", 3 * SECS_PER_HOUR),
+    ("msk", 3 * SECS_PER_HOUR),
+    ("msd", 4 * SECS_PER_HOUR),
+    ("ist", 5 * SECS_PER_HOUR + 1800),
+    ("sgt", 8 * SECS_PER_HOUR),
+    ("kst", 9 * SECS_PER_HOUR),
+    ("jst", 9 * SECS_PER_HOUR),
+    ("gst", 10 * SECS_PER_HOUR),
+    ("nzst", 12 * SECS_PER_HOUR),
+    ("nzdt", 13 * SECS_PER_HOUR),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Meridian {
+    Am,
+    Pm,
+}
+
+const MERIDIANS: [(&str, Meridian); 2] = [("am", Meridian::Am), ("pm", Meridian::Pm)];
+
+/// Compare the `word` case insensitive with the lowercase `name` ignoring all periods in `word`
+///
+/// This allows abbreviations like `Fri.`, `Oct.` or `a.m.`
+fn eq_ignore_periods(word: &str, name: &str) -> bool {
+    let mut word = word.bytes().filter(|byte| *byte != b'.');
+    let mut name = name.bytes();
+    loop {
+        match (word.next(), name.next()) {
+            (Some(lhs), Some(rhs)) if lhs.to_ascii_lowercase() == rhs => {}
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
+
+fn lookup<T: Copy>(table: &[(&str, T)], word: &str) -> Option<T> {
+    table
+        .iter()
+        .find(|(name, _)| eq_ignore_periods(word, name))
+        .map(|(_, value)| *value)
+}
+
+#[inline]
+const fn is_whitespace(byte: u8) -> bool {
+    byte == b' ' || byte.wrapping_sub(9) < 5
+}
+
+fn is_time_unit(word: &str) -> bool {
+    TIME_UNITS.get(word).is_some()
+}
+
+fn offset_to_duration(offset: i64) -> Duration {
+    if offset.is_negative() {
+        Duration::negative(offset.unsigned_abs(), 0)
+    } else {
+        Duration::positive(offset.unsigned_abs(), 0)
+    }
+}
+
+/// Convert a year with two digits like gnu does. `69` to `99` are years of the 20th century and
+/// `00` to `68` years of the 21th century.
+fn year_from_digits(year: u64, digits: usize) -> Result<i64, ParseError> {
+    let year = i64::try_from(year).map_err(|_| ParseError::Overflow)?;
+    Ok(match (digits, year) {
+        (2, 0..=68) => year + 2000,
+        (2, _) => year + 1900,
+        _ => year,
+    })
+}
+
+/// A day of the week item like `friday`, `next fri` or `third wednesday` found in a source string
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct WeekdayItem {
+    /// The start of the item including the ordinal
+    pub(crate) start: usize,
+    /// The end of the item including an optional trailing comma
+    pub(crate) end: usize,
+    pub(crate) ordinal: i64,
+    /// The day of the week counted from `0` (sunday) to `6` (saturday)
+    pub(crate) weekday: u8,
+}
+
+/// Find the first day of the week item in the `source` string
+pub(crate) fn find_weekday_item(source: &str) -> Option<WeekdayItem> {
+    let bytes = source.as_bytes();
+    let mut position = 0;
+    while position < bytes.len() {
+        if !bytes[position].is_ascii_alphabetic() {
+            position += 1;
+            continue;
+        }
+
+        let start = position;
+        while bytes
+            .get(position)
+            .map_or(false, |byte| byte.is_ascii_alphabetic() || *byte == b'.')
+        {
+            position += 1;
+        }
+        if let Some(weekday) = lookup(&WEEKDAYS, &source[start..position]) {
+            let (start, ordinal) = find_weekday_ordinal(source, start);
+            return Some(WeekdayItem {
+                start,
+                end: if bytes.get(position) == Some(&b',') {
+                    position + 1
+                } else {
+                    position
+                },
+                ordinal,
+                weekday,
+            });
+        }
+    }
+    None
+}
+
+/// Find the optional ordinal in front of the day of the week starting at `position`
+///
+/// The ordinal is either a numeral like `next` or `third` or an unsigned number. Returns the start
+/// of the ordinal and its value or `position` and `0` if there is no ordinal.
+fn find_weekday_ordinal(source: &str, position: usize) -> (usize, i64) {
+    let bytes = source.as_bytes();
+    let mut end = position;
+    while end > 0 && is_whitespace(bytes[end - 1]) {
+        end -= 1;
+    }
+
+    let mut start = end;
+    if start > 0 && bytes[start - 1].is_ascii_alphabetic() {
+        while start > 0 && bytes[start - 1].is_ascii_alphabetic() {
+            start -= 1;
+        }
+        if let Some(Multiplier(ordinal, _)) = NUMERALS.get(&source[start..end]) {
+            return (start, ordinal);
+        }
+    } else {
+        while start > 0 && bytes[start - 1].is_ascii_digit() {
+            start -= 1;
+        }
+        if start < end && (start == 0 || is_whitespace(bytes[start - 1])) {
+            return (start, source[start..end].parse().unwrap_or(i64::MAX));
+        }
+    }
+    (position, 0)
+}
+
+/// The date and time items collected from the source string
+#[derive(Debug, Default)]
+struct DateTimeItems {
+    timestamp: Option<Duration>,
+    year: Option<i64>,
+    month_day: Option<(u8, u8)>,
+    time: Option<(u8, u8, u8, u32)>,
+    /// The offset to UTC in seconds
+    zone: Option<i64>,
+    /// The ordinal and the day of the week
+    weekday: Option<(i64, u8)>,
+    years: i64,
+    months: i64,
+    duration: Duration,
+    has_relative: bool,
+}
+
+impl DateTimeItems {
+    /// Resolve the items in the same order as gnu does
+    ///
+    /// The calendar date and the time of day replace the respective parts of the `base` date in
+    /// the given time zone. Then the day of the week moves the date, then the relative years,
+    /// months and days are added in the calendar and finally the rest of the relative items.
+    fn resolve(&self, base: DateTime) -> Result<DateTime, ParseError> {
+        if let Some(timestamp) = self.timestamp {
+            return DateTime::UNIX_EPOCH
+                .checked_add_duration(&timestamp)
+                .ok_or(ParseError::Overflow);
+        }
+
+        let offset = offset_to_duration(self.zone.unwrap_or_default());
+        let local = base
+            .checked_add_duration(&offset)
+            .ok_or(ParseError::Overflow)?;
+
+        let (year, month, day) = match self.month_day {
+            Some((month, day)) => {
+                let (year, _, _) = local.to_gregorian_date().ok_or(ParseError::Overflow)?;
+                let year = self.year.unwrap_or(year);
+                if day > days_in_month(year, month) {
+                    return Err(ParseError::InvalidInput(format!(
+                        "Invalid date: The month {month} of the year {year} has no day {day}"
+                    )));
+                }
+                (year, month, day)
+            }
+            None => local.to_gregorian_date().ok_or(ParseError::Overflow)?,
+        };
+        let (hour, minute, second, nanos) = match self.time {
+            Some(time) => time,
+            None if self.has_relative && self.month_day.is_none() && self.weekday.is_none() => {
+                local.as_hmsn()
+            }
+            None => (0, 0, 0, 0),
+        };
+
+        let mut local =
+            DateTime::try_from_gregorian_date_time(year, month, day, hour, minute, second, nanos)
+                .ok_or(ParseError::Overflow)?;
+
+        if let (Some((ordinal, weekday)), None) = (self.weekday, self.month_day) {
+            local = local
+                .checked_add_weekday(ordinal, weekday)
+                .ok_or(ParseError::Overflow)?;
+        }
+
+        if self.years != 0 || self.months != 0 {
+            local = local
+                .checked_add_gregorian(self.years, self.months, 0)
+                .ok_or(ParseError::Overflow)?;
+        }
+
+        local
+            .checked_sub_duration(&offset)
+            .and_then(|date_time| date_time.checked_add_duration(&self.duration))
+            .ok_or(ParseError::Overflow)
+    }
+}
+
+/// The parser of the single date and time items in the source string
+struct ItemsParser<'s, 'p, 'a> {
+    source: &'s str,
+    bytes: &'s [u8],
+    position: usize,
+    relative: &'p RelativeTimeParser<'a>,
+    items: DateTimeItems,
+}
+
+impl<'s, 'p, 'a> ItemsParser<'s, 'p, 'a> {
+    fn new(source: &'s str, relative: &'p RelativeTimeParser<'a>) -> Self {
+        Self {
+            source,
+            bytes: source.as_bytes(),
+            position: 0,
+            relative,
+            items: DateTimeItems::default(),
+        }
+    }
+
+    fn parse(mut self) -> Result<DateTimeItems, ParseError> {
+        if self.bytes.first() == Some(&b'@') {
+            self.parse_timestamp()?;
+            return Ok(self.items);
+        }
+
+        loop {
+            self.skip_whitespace_and_comments()?;
+            if self.position >= self.bytes.len() {
+                break Ok(self.items);
+            }
+            self.parse_item()?;
+        }
+    }
+
+    fn skip_whitespace(&self, mut position: usize) -> usize {
+        while self
+            .bytes
+            .get(position)
+            .map_or(false, |byte| is_whitespace(*byte))
+        {
+            position += 1;
+        }
+        position
+    }
+
+    /// Skip whitespace and comments, which are enclosed in possibly nested parentheses
+    fn skip_whitespace_and_comments(&mut self) -> Result<(), ParseError> {
+        loop {
+            self.position = self.skip_whitespace(self.position);
+            if self.bytes.get(self.position) != Some(&b'(') {
+                return Ok(());
+            }
+
+            let start = self.position;
+            let mut depth = 0usize;
+            loop {
+                match self.bytes.get(self.position) {
+                    Some(b'(') => depth += 1,
+                    Some(b')') if depth == 1 => {
+                        self.position += 1;
+                        break;
+                    }
+                    Some(b')') => depth -= 1,
+                    Some(_) => {}
+                    None => {
+                        return Err(ParseError::Syntax(
+                            start,
+                            "Unbalanced parentheses: Comment is not closed".to_owned(),
+                        ));
+                    }
+                }
+                self.position += 1;
+            }
+        }
+    }
+
+    fn is_digit_at(&self, position: usize) -> bool {
+        self.bytes.get(position).map_or(false, u8::is_ascii_digit)
+    }
+
+    /// Scan the digits at `position` returning the value (saturating at `u64::MAX`), the amount
+    /// of digits and the position after the last digit
+    fn scan_number(&self, position: usize) -> Option<(u64, usize, usize)> {
+        let mut end = position;
+        let mut value = 0u64;
+        while let Some(byte) = self.bytes.get(end).filter(|byte| byte.is_ascii_digit()) {
+            value = value
+                .saturating_mul(10)
+                .saturating_add(u64::from(*byte - b'0'));
+            end += 1;
+        }
+        (end > position).then_some((value, end - position, end))
+    }
+
+    /// Scan the fractional digits at `position` returning the nano seconds and the position after
+    /// the last digit. Digits beyond the nano second resolution are ignored.
+    fn scan_fraction(&self, position: usize) -> (u32, usize) {
+        let mut end = position;
+        let mut nanos = 0u32;
+        while let Some(byte) = self.bytes.get(end).filter(|byte| byte.is_ascii_digit()) {
+            if end - position < MAX_NANOS_DIGITS {
+                nanos = nanos * 10 + u32::from(*byte - b'0');
+            }
+            end += 1;
+        }
+        for _ in (end - position)..MAX_NANOS_DIGITS {
+            nanos *= 10;
+        }
+        (nanos, end)
+    }
+
+    /// Return the word (ascii letters and periods) after optional whitespace at `position`
+    /// together with the start and end of the word
+    fn peek_word(&self, position: usize) -> Option<(&'s str, usize, usize)> {
+        let start = self.skip_whitespace(position);
+        if !self.bytes.get(start).map_or(false, u8::is_ascii_alphabetic) {
+            return None;
+        }
+
+        let mut end = start + 1;
+        while self
+            .bytes
+            .get(end)
+            .map_or(false, |byte| byte.is_ascii_alphabetic() || *byte == b'.')
+        {
+            end += 1;
+        }
+        Some((&self.source[start..end], start, end))
+    }
+
+    /// Return the end of a relative item if the word at `position` is a time unit
+    ///
+    /// The end includes the optional `ago` suffix.
+    fn relative_item_end(&self, position: usize) -> Option<usize> {
+        self.peek_word(position)
+            .filter(|(word, _, _)| is_time_unit(word))
+            .map(|(_, _, end)| self.ago_end(end))
+    }
+
+    fn ago_end(&self, position: usize) -> usize {
+        match self.peek_word(position) {
+            Some((word, _, end)) if word.eq_ignore_ascii_case("ago") => end,
+            _ => position,
+        }
+    }
+
+    /// Return the position after the optional fraction of a number at `position`
+    fn skip_fraction(&self, position: usize) -> usize {
+        if self.bytes.get(position) == Some(&b'.') && self.is_digit_at(position + 1) {
+            self.scan_fraction(position + 1).1
+        } else {
+            position
+        }
+    }
+
+    fn parse_item(&mut self) -> Result<(), ParseError> {
+        let start = self.position;
+        match self.bytes[start] {
+            b'0'..=b'9' => self.parse_number_item(start),
+            b'+' | b'-' if self.is_digit_at(start + 1) => self.parse_signed_item(start),
+            b'+' | b'-' if self.relative_item_end(start + 1).is_some() => {
+                self.parse_relative(start, self.relative_item_end(start + 1).unwrap())
+            }
+            byte if byte.is_ascii_alphabetic() => self.parse_word_item(start),
+            _ => Err(ParseError::Syntax(
+                start,
+                format!(
+                    "Invalid character: '{}'",
+                    self.source[start..].chars().next().unwrap()
+                ),
+            )),
+        }
+    }
+
+    fn parse_timestamp(&mut self) -> Result<(), ParseError> {
+        let mut position = self.skip_whitespace(1);
+        let is_negative = match self.bytes.get(position) {
+            Some(b'-') => {
+                position += 1;
+                true
+            }
+            Some(b'+') => {
+                position += 1;
+                false
+            }
+            _ => false,
+        };
+        let (seconds, _, mut end) = self
+            .scan_number(position)
+            .ok_or_else(|| ParseError::Syntax(position, "Expected seconds after '@'".to_owned()))?;
+        let mut nanos = 0;
+        if matches!(self.bytes.get(end), Some(b'.' | b',')) && self.is_digit_at(end + 1) {
+            (nanos, end) = self.scan_fraction(end + 1);
+        }
+
+        let end = self.skip_whitespace(end);
+        if end < self.bytes.len() {
+            return Err(ParseError::Syntax(
+                end,
+                format!(
+                    "Expected end of input after a timestamp but found: '{}'",
+                    &self.source[end..]
+                ),
+            ));
+        }
+
+        self.position = end;
+        self.items.timestamp = Some(if is_negative {
+            Duration::negative(seconds, nanos)
+        } else {
+            Duration::positive(seconds, nanos)
+        });
+        Ok(())
+    }
+
+    fn parse_number_item(&mut self, start: usize) -> Result<(), ParseError> {
+        let (value, digits, end) = self.scan_number(start).unwrap();
+        match self.bytes.get(end) {
+            Some(b':') => return self.parse_time(start, value, end),
+            Some(b'-') if self.is_digit_at(end + 1) => {
+                return self.parse_iso_date(start, value, digits, end);
+            }
+            Some(b'/') => return self.parse_slash_date(start, value, digits, end),
+            Some(b'.') if self.is_digit_at(end + 1) => {
+                return match self.relative_item_end(self.skip_fraction(end)) {
+                    Some(end) => self.parse_relative(start, end),
+                    None => Err(ParseError::Syntax(
+                        start,
+                        "A number with a fraction is only allowed together with a time unit"
+                            .to_owned(),
+                    )),
+                };
+            }
+            Some(b'-') => {
+                if let Some((month, word_end)) = self
+                    .peek_word(end + 1)
+                    .filter(|(_, word_start, _)| *word_start == end + 1)
+                    .and_then(|(word, _, word_end)| lookup(&MONTHS, word).map(|m| (m, word_end)))
+                {
+                    return self.parse_day_month(start, value, month, word_end);
+                }
+            }
+            _ => {}
+        }
+
+        if let Some((word, _, word_end)) = self.peek_word(end) {
+            if let Some(meridian) = lookup(&MERIDIANS, word) {
+                self.position = word_end;
+                return self.set_time(start, value, 0, 0, 0, Some(meridian));
+            }
+            if let Some(month) = lookup(&MONTHS, word) {
+                return self.parse_day_month(start, value, month, word_end);
+            }
+            if let Some(weekday) = lookup(&WEEKDAYS, word) {
+                let ordinal = i64::try_from(value).map_err(|_| ParseError::Overflow)?;
+                return self.parse_weekday(start, ordinal, weekday, word_end);
+            }
+            if is_time_unit(word) {
+                return self.parse_relative(start, self.ago_end(word_end));
+            }
+        }
+
+        // Like gnu, a number followed by a numeric time zone offset is the hour of the day
+        let sign = self.skip_whitespace(end);
+        if self.is_zone_offset_at(sign) {
+            self.set_time(start, value, 0, 0, 0, None)?;
+            let offset = self.parse_zone_offset(sign)?;
+            return self.set_zone(sign, offset);
+        }
+
+        self.position = end;
+        self.parse_plain_number(start, value, digits)
+    }
+
+    /// A number without any other context follows the gnu rules:
+    ///
+    /// * If a date without a year was already seen, the number is the year if a time of day was
+    ///   seen before, too, or if the number has more than 2 digits
+    /// * Otherwise, if the number has more than 4 digits, the number is a date in the form
+    ///   `YYYYMMDD`
+    /// * Otherwise, the number is a time of day in the form `HHMM` or `HH`
+    fn parse_plain_number(
+        &mut self,
+        start: usize,
+        value: u64,
+        digits: usize,
+    ) -> Result<(), ParseError> {
+        if self.items.month_day.is_some()
+            && self.items.year.is_none()
+            && !self.items.has_relative
+            && (self.items.time.is_some() || digits > 2)
+        {
+            self.items.year = Some(year_from_digits(value, digits)?);
+            Ok(())
+        } else if digits > 4 {
+            self.set_date(
+                start,
+                Some((value / 10000, digits - 4)),
+                value / 100 % 100,
+                value % 100,
+            )
+        } else if digits > 2 {
+            self.set_time(start, value / 100, value % 100, 0, 0, None)
+        } else {
+            self.set_time(start, value, 0, 0, 0, None)
+        }
+    }
+
+    fn parse_signed_item(&mut self, start: usize) -> Result<(), ParseError> {
+        let (_, _, end) = self.scan_number(start + 1).unwrap();
+        if let Some(end) = self.relative_item_end(self.skip_fraction(end)) {
+            return self.parse_relative(start, end);
+        }
+
+        // Like gnu, a numeric time zone offset is only allowed after a time of day or time zone
+        Err(ParseError::Syntax(
+            start,
+            "Expected a time unit after the number or a time of day before the time zone offset"
+                .to_owned(),
+        ))
+    }
+
+    fn parse_word_item(&mut self, start: usize) -> Result<(), ParseError> {
+        let (word, _, end) = self.peek_word(start).unwrap();
+
+        if let Some(month) = lookup(&MONTHS, word) {
+            return self.parse_month_day(start, month, end);
+        }
+        if let Some(weekday) = lookup(&WEEKDAYS, word) {
+            return self.parse_weekday(start, 0, weekday, end);
+        }
+        if let Some(offset) = lookup(&TIME_ZONES, word) {
+            return self.parse_zone(start, offset, end);
+        }
+        if let Some(Multiplier(ordinal, _)) = NUMERALS.get(word) {
+            if let Some((weekday, end)) = self
+                .peek_word(end)
+                .and_then(|(word, _, end)| lookup(&WEEKDAYS, word).map(|weekday| (weekday, end)))
+            {
+                return self.parse_weekday(start, ordinal, weekday, end);
+            }
+            return self.parse_relative(start, self.relative_item_end(end).unwrap_or(end));
+        }
+        if is_time_unit(word) {
+            return self.parse_relative(start, self.ago_end(end));
+        }
+        if TIME_KEYWORDS.get(word).is_some() {
+            return self.parse_relative(start, end);
+        }
+
+        Err(ParseError::Syntax(start, format!("Invalid word: '{word}'")))
+    }
+
+    fn parse_relative(&mut self, start: usize, end: usize) -> Result<(), ParseError> {
+        let (years, months, duration) = self
+            .relative
+            .parse_fuzzy(&self.source[start..end])
+            .map_err(|error| shift_error_position(error, start))?;
+
+        self.items.years = self.items.years.saturating_add(years);
+        self.items.months = self.items.months.saturating_add(months);
+        self.items.duration = self.items.duration.saturating_add(duration);
+        self.items.has_relative = true;
+        self.position = end;
+        Ok(())
+    }
+
+    /// Parse the time of day in the form `HH:MM[:SS[.NNNNNNNNN]]` with an optional `am` or `pm`
+    fn parse_time(&mut self, start: usize, hour: u64, colon: usize) -> Result<(), ParseError> {
+        let (minute, _, mut end) = self
+            .scan_number(colon + 1)
+            .ok_or_else(|| ParseError::Syntax(colon + 1, "Expected minutes".to_owned()))?;
+
+        let mut second = 0;
+        let mut nanos = 0;
+        if self.bytes.get(end) == Some(&b':') {
+            (second, _, end) = self
+                .scan_number(end + 1)
+                .ok_or_else(|| ParseError::Syntax(end + 1, "Expected seconds".to_owned()))?;
+            if matches!(self.bytes.get(end), Some(b'.' | b',')) && self.is_digit_at(end + 1) {
+                (nanos, end) = self.scan_fraction(end + 1);
+            }
+        }
+
+        let meridian = match self.peek_word(end) {
+            Some((word, _, word_end)) => lookup(&MERIDIANS, word).map(|meridian| {
+                end = word_end;
+                meridian
+            }),
+            None => None,
+        };
+
+        self.position = end;
+        self.set_time(start, hour, minute, second, nanos, meridian)?;
+
+        // Like gnu, a signed integer directly after a time of day without `am` or `pm` is a time
+        // zone offset even if a time unit follows, so `14:00 +3 days` is `14:00 +03:00 +1 day`
+        let sign = self.skip_whitespace(end);
+        if meridian.is_some()
+            || !matches!(self.bytes.get(sign), Some(b'+' | b'-'))
+            || !self.is_digit_at(sign + 1)
+        {
+            return Ok(());
+        }
+        let number_end = self.scan_number(sign + 1).unwrap().2;
+        if self.skip_fraction(number_end) == number_end {
+            let offset = self.parse_zone_offset(sign)?;
+            self.set_zone(sign, offset)?;
+        }
+        Ok(())
+    }
+
+    /// Parse an iso 8601 date in the form `YYYY-MM-DD` optionally followed by a `T` and the time
+    fn parse_iso_date(
+        &mut self,
+        start: usize,
+        year: u64,
+        digits: usize,
+        dash: usize,
+    ) -> Result<(), ParseError> {
+        let (month, _, end) = self.scan_number(dash + 1).unwrap();
+        if self.bytes.get(end) != Some(&b'-') || !self.is_digit_at(end + 1) {
+            return Err(ParseError::Syntax(
+                end,
+                "Invalid date: Expected a date in the form YYYY-MM-DD".to_owned(),
+            ));
+        }
+        let (day, _, end) = self.scan_number(end + 1).unwrap();
+        self.set_date(start, Some((year, digits)), month, day)?;
+        self.position = end;
+
+        if matches!(self.bytes.get(end), Some(b'T' | b't')) && self.is_digit_at(end + 1) {
+            let (hour, _, colon) = self.scan_number(end + 1).unwrap();
+            if self.bytes.get(colon) != Some(&b':') {
+                return Err(ParseError::Syntax(
+                    end + 1,
+                    "Expected a time of day in the form HH:MM after 'T'".to_owned(),
+                ));
+            }
+            return self.parse_time(end + 1, hour, colon);
+        }
+        Ok(())
+    }
+
+    /// Parse a date in the form `MM/DD`, `MM/DD/YYYY` or `YYYY/MM/DD`
+    fn parse_slash_date(
+        &mut self,
+        start: usize,
+        first: u64,
+        first_digits: usize,
+        slash: usize,
+    ) -> Result<(), ParseError> {
+        let (second, _, end) = self.scan_number(slash + 1).ok_or_else(|| {
+            ParseError::Syntax(
+                slash + 1,
+                "Invalid date: Expected a number after '/'".to_owned(),
+            )
+        })?;
+
+        if self.bytes.get(end) != Some(&b'/') {
+            self.position = end;
+            return self.set_date(start, None, first, second);
+        }
+
+        let (third, third_digits, third_end) = self.scan_number(end + 1).ok_or_else(|| {
+            ParseError::Syntax(
+                end + 1,
+                "Invalid date: Expected a number after '/'".to_owned(),
+            )
+        })?;
+        self.position = third_end;
+        if first_digits >= 4 {
+            self.set_date(start, Some((first, first_digits)), second, third)
+        } else {
+            self.set_date(start, Some((third, third_digits)), first, second)
+        }
+    }
+
+    /// Parse the optional year after a date like `16 Oct` or `Oct 16,`
+    ///
+    /// A number is only a year if it doesn't belong to another item like a time of day.
+    fn scan_year(&self, position: usize) -> Option<(u64, usize, usize)> {
+        let start = self.skip_whitespace(position);
+        let (value, digits, end) = self.scan_number(start)?;
+        if matches!(self.bytes.get(end), Some(b':' | b'/' | b'-' | b'.')) {
+            return None;
+        }
+        match self.peek_word(end) {
+            Some((word, _, _))
+                if is_time_unit(word)
+                    || lookup(&MERIDIANS, word).is_some()
+                    || lookup(&MONTHS, word).is_some()
+                    || lookup(&WEEKDAYS, word).is_some() =>
+            {
+                None
+            }
+            _ => Some((value, digits, end)),
+        }
+    }
+
+    /// Parse a date in the form `DD month [YYYY]` or `DD-month-YYYY`
+    fn parse_day_month(
+        &mut self,
+        start: usize,
+        day: u64,
+        month: u8,
+        end: usize,
+    ) -> Result<(), ParseError> {
+        let year = if self.bytes.get(end) == Some(&b'-') && self.is_digit_at(end + 1) {
+            self.scan_number(end + 1)
+        } else {
+            self.scan_year(end)
+        };
+
+        self.position = year.map_or(end, |(_, _, end)| end);
+        self.set_date(
+            start,
+            year.map(|(year, digits, _)| (year, digits)),
+            u64::from(month),
+            day,
+        )
+    }
+
+    /// Parse a date in the form `month DD [[,] YYYY]` or `month-DD-YYYY`
+    fn parse_month_day(&mut self, start: usize, month: u8, end: usize) -> Result<(), ParseError> {
+        if self.bytes.get(end) == Some(&b'-') && self.is_digit_at(end + 1) {
+            let (day, _, end) = self.scan_number(end + 1).unwrap();
+            if self.bytes.get(end) != Some(&b'-') || !self.is_digit_at(end + 1) {
+                return Err(ParseError::Syntax(
+                    end,
+                    "Invalid date: Expected a date in the form month-DD-YYYY".to_owned(),
+                ));
+            }
+            let (year, digits, end) = self.scan_number(end + 1).unwrap();
+            self.position = end;
+            return self.set_date(start, Some((year, digits)), u64::from(month), day);
+        }
+
+        let day_start = self.skip_whitespace(end);
+        let (day, _, end) = self.scan_number(day_start).ok_or_else(|| {
+            ParseError::Syntax(
+                day_start,
+                "Invalid date: Expected a day after the month".to_owned(),
+            )
+        })?;
+
+        let end = if self.bytes.get(end) == Some(&b',') {
+            end + 1
+        } else {
+            end
+        };
+        let year = self.scan_year(end);
+        self.position = year.map_or(end, |(_, _, end)| end);
+        self.set_date(
+            start,
+            year.map(|(year, digits, _)| (year, digits)),
+            u64::from(month),
+            day,
+        )
+    }
+
+    fn parse_weekday(
+        &mut self,
+        start: usize,
+        ordinal: i64,
+        weekday: u8,
+        end: usize,
+    ) -> Result<(), ParseError> {
+        if self.items.weekday.is_some() {
+            return Err(ParseError::Syntax(
+                start,
+                "Multiple days of the week".to_owned(),
+            ));
+        }
+        self.items.weekday = Some((ordinal, weekday));
+        self.position = if self.bytes.get(end) == Some(&b',') {
+            end + 1
+        } else {
+            end
+        };
+        Ok(())
+    }
+
+    /// Parse a time zone abbreviation optionally followed by `DST` or a correction like in `UTC+2`
+    fn parse_zone(&mut self, start: usize, offset: i64, end: usize) -> Result<(), ParseError> {
+        self.position = end;
+        let correction = match self.peek_word(end) {
+            Some((word, _, end)) if word.eq_ignore_ascii_case("dst") => {
+                self.position = end;
+                SECS_PER_HOUR
+            }
+            _ => {
+                let sign = self.skip_whitespace(end);
+                if self.is_zone_offset_at(sign) {
+                    self.parse_zone_offset(sign)?
+                } else {
+                    0
+                }
+            }
+        };
+        self.set_zone(start, offset + correction)
+    }
+
+    /// Return true if a signed number without a time unit starts at the `position`
+    fn is_zone_offset_at(&self, position: usize) -> bool {
+        matches!(self.bytes.get(position), Some(b'+' | b'-'))
+            && self.is_digit_at(position + 1)
+            && self
+                .relative_item_end(self.skip_fraction(self.scan_number(position + 1).unwrap().2))
+                .is_none()
+    }
+
+    /// Parse a numeric time zone offset in the form `+HH`, `+HHMM` or `+HH:MM` (or with a `-`)
+    fn parse_zone_offset(&mut self, start: usize) -> Result<i64, ParseError> {
+        let is_negative = self.bytes[start] == b'-';
+        let (value, digits, mut end) = self.scan_number(start + 1).unwrap();
+
+        let (hours, minutes) = if self.bytes.get(end) == Some(&b':') && digits <= 2 {
+            let (minutes, minutes_digits, minutes_end) =
+                self.scan_number(end + 1).ok_or_else(|| {
+                    ParseError::Syntax(
+                        end + 1,
+                        "Expected minutes of the time zone offset".to_owned(),
+                    )
+                })?;
+            if minutes_digits != 2 {
+                return Err(ParseError::Syntax(
+                    end + 1,
+                    "Invalid time zone offset: Minutes must have 2 digits".to_owned(),
+                ));
+            }
+            end = minutes_end;
+            (value, minutes)
+        } else if digits <= 2 {
+            (value, 0)
+        } else if digits <= 4 {
+            (value / 100, value % 100)
+        } else {
+            (u64::MAX, 0)
+        };
+
+        if minutes > 59 || hours.saturating_mul(60).saturating_add(minutes) > MINUTES_PER_DAY {
+            return Err(ParseError::Syntax(
+                start,
+                format!("Invalid time zone offset: '{}'", &self.source[start..end]),
+            ));
+        }
+
+        self.position = end;
+        // The unwrap is safe because the offset is not greater than 24 hours
+        let offset = i64::try_from((hours * 60 + minutes) * 60).unwrap();
+        Ok(if is_negative { -offset } else { offset })
+    }
+
+    fn set_zone(&mut self, start: usize, offset: i64) -> Result<(), ParseError> {
+        if self.items.zone.is_some() {
+            return Err(ParseError::Syntax(start, "Multiple time zones".to_owned()));
+        }
+        self.items.zone = Some(offset);
+        Ok(())
+    }
+
+    fn set_date(
+        &mut self,
+        start: usize,
+        year: Option<(u64, usize)>,
+        month: u64,
+        day: u64,
+    ) -> Result<(), ParseError> {
+        if self.items.month_day.is_some() {
+            return Err(ParseError::Syntax(start, "Multiple dates".to_owned()));
+        }
+        if !(1..=12).contains(&month) {
+            return Err(ParseError::Syntax(
+                start,
+                format!("Invalid date: Invalid month '{month}'"),
+            ));
+        }
+        if !(1..=31).contains(&day) {
+            return Err(ParseError::Syntax(
+                start,
+                format!("Invalid date: Invalid day '{day}'"),
+            ));
+        }
+
+        if let Some((year, digits)) = year {
+            self.items.year = Some(year_from_digits(year, digits)?);
+        }
+        // The unwraps are safe because of the validation above
+        self.items.month_day = Some((u8::try_from(month).unwrap(), u8::try_from(day).unwrap()));
+        Ok(())
+    }
+
+    fn set_time(
+        &mut self,
+        start: usize,
+        hour: u64,
+        minute: u64,
+        second: u64,
+        nanos: u32,
+        meridian: Option<Meridian>,
+    ) -> Result<(), ParseError> {
+        if self.items.time.is_some() {
+            return Err(ParseError::Syntax(
+                start,
+                "Multiple times of day".to_owned(),
+            ));
+        }
+
+        let hour = match meridian {
+            None if hour <= 23 => hour,
+            Some(Meridian::Am) if (1..=12).contains(&hour) => hour % 12,
+            Some(Meridian::Pm) if (1..=12).contains(&hour) => hour % 12 + 12,
+            _ => {
+                return Err(ParseError::Syntax(
+                    start,
+                    format!("Invalid time: Invalid hour '{hour}'"),
+                ));
+            }
+        };
+        if minute > 59 {
+            return Err(ParseError::Syntax(
+                start,
+                format!("Invalid time: Invalid minute '{minute}'"),
+            ));
+        }
+        if second > 59 {
+            return Err(ParseError::Syntax(
+                start,
+                format!("Invalid time: Invalid second '{second}'"),
+            ));
+        }
+
+        // The unwraps are safe because of the validation above
+        self.items.time = Some((
+            u8::try_from(hour).unwrap(),
+            u8::try_from(minute).unwrap(),
+            u8::try_from(second).unwrap(),
+            nanos,
+        ));
+        Ok(())
+    }
+}
+
+/// The gnu date and time parser for absolute and relative items in date strings
+///
+/// This parser understands the date strings of gnu's `date -d` (or `date --date`) and resolves
+/// them to a [`DateTime`]. The source string consists of items, separated by optional whitespace
+/// or comments in parentheses:
+///
+/// * Calendar date items in the form `2023-10-16`, `10/16/2023`, `2023/10/16`, `10/16`, `16 Oct
+///   2023`, `16-Oct-2023`, `October 16, 2023`, `Oct-16-2023` or `20231016`. Month names are case
+///   insensitive and can be abbreviated with three letters. A two-digit year from `69` to `99`
+///   means `1969` to `1999`, from `00` to `68` it means `2000` to `2068`. If the year is missing,
+///   the year of the base date is used.
+/// * Time of day items in the form `14:30`, `14:30:00`, `14:30:00.123456789`, `2pm`, `2:30 p.m.` or
+///   as a plain number like `1430`
+/// * Time zone items like `UTC`, `Z`, `EST`, `CEST` or a corrected time zone like `UTC+2`. A time
+///   zone followed by `DST` adds one hour. A numeric offset like `+0200`, `-05:00` or `+2` is only
+///   allowed directly after a time of day like in `14:00 +0200` or `14 +02`, but not alone or after
+///   a date. Like in gnu, a signed integer directly after a time of day like `14:00` is always a
+///   numeric offset, so `14:00 +3 days` means `14:00` in UTC +3 plus `1 day` and not `14:00` plus
+///   `3 days`.
+/// * Day of the week items like `friday`, `fri`, `next friday`, `last fri` or `third wednesday`. A
+///   day of the week moves the date forward to that day (or stays at the same day if it is already
+///   that day of the week). With an ordinal the date moves by this amount of weeks, where `next` is
+///   `1`, `last` is `-1` and `this` is `0`.
+/// * Relative items like `+3 days`, `2 hours ago`, `next month` or `yesterday` as described in the
+///   crate level documentation
+/// * The seconds since the unix epoch like `@1697414400` or `@-1.5`. This item is only allowed
+///   alone.
+///
+/// The items are resolved in the same order as gnu does. The date, time and time zone replace
+/// the respective parts of the base date (which is `now` unless specified otherwise). Unless the
+/// source consists only of relative items and time zones, the time of day defaults to midnight, so
+/// a lone `UTC` is the start of the day in UTC like in gnu. Without a time zone item, dates and
+/// times are interpreted in UTC +0. Then the day of the week is applied, followed by the relative
+/// `years` and `months` (which are added fuzzy like in [`RelativeTimeParser::parse_with_date`])
+/// and finally the rest of the relative items. Like in gnu, an empty string resolves to the start
+/// of the day of the base date.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_gnu::{DateTime, DateTimeParser};
+///
+/// let parser = DateTimeParser::new();
+/// let base = DateTime::from_gregorian_date_time(2023, 10, 16, 10, 20, 30, 0);
+///
+/// assert_eq!(
+///     parser.parse_with_date("2023-10-16 14:00 3 days", Some(base)),
+///     Ok(DateTime::from_gregorian_date_time(
+///         2023, 10, 19, 14, 0, 0, 0
+///     ))
+/// );
+/// // The `+3` is the time zone offset like in gnu
+/// assert_eq!(
+///     parser.parse_with_date("2023-10-16 14:00 +3 days", Some(base)),
+///     Ok(DateTime::from_gregorian_date_time(
+///         2023, 10, 17, 11, 0, 0, 0
+///     ))
+/// );
+/// assert_eq!(
+///     parser.parse_with_date("16 Oct 2023 14:00 +0200", Some(base)),
+///     Ok(DateTime::from_gregorian_date_time(
+///         2023, 10, 16, 12, 0, 0, 0
+///     ))
+/// );
+/// assert_eq!(
+///     parser.parse_with_date("next friday", Some(base)),
+///     Ok(DateTime::from_gregorian_date_time(2023, 10, 20, 0, 0, 0, 0))
+/// );
+/// assert_eq!(
+///     parser.parse_with_date("@1697414400", Some(base)),
+///     Ok(DateTime::from_gregorian_date_time(2023, 10, 16, 0, 0, 0, 0))
+/// );
+/// assert_eq!(
+///     parser.parse_with_date("1 hour ago", Some(base)),
+///     Ok(DateTime::from_gregorian_date_time(
+///         2023, 10, 16, 9, 20, 30, 0
+///     ))
+/// );
+/// ```
+#[derive(Debug, Eq, PartialEq)]
+pub struct DateTimeParser<'a> {
+    relative: RelativeTimeParser<'a>,
+}
+
+impl<'a> DateTimeParser<'a> {
+    /// Create a new `DateTimeParser`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_gnu::{DateTime, DateTimeParser};
+    ///
+    /// let parser = DateTimeParser::new();
+    /// assert_eq!(
+    ///     parser.parse("1970-01-01 00:00:00 UTC"),
+    ///     Ok(DateTime::UNIX_EPOCH)
+    /// );
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            relative: RelativeTimeParser::new(),
+        }
+    }
+
+    /// Create a new `DateTimeParser` using the [`Clock`] to get the date and time of `now`
+    ///
+    /// The `clock` is used whenever no date is given, for example in [`DateTimeParser::parse`].
+    /// The default clock is the [`crate::SystemClock`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_gnu::{DateTime, DateTimeParser, FixedClock};
+    ///
+    /// let clock = FixedClock::new(DateTime::from_gregorian_date_time(
+    ///     2023, 10, 16, 10, 0, 0, 0,
+    /// ));
+    /// let parser = DateTimeParser::with_clock(&clock);
+    /// assert_eq!(
+    ///     parser.parse("next friday"),
+    ///     Ok(DateTime::from_gregorian_date_time(2023, 10, 20, 0, 0, 0, 0))
+    /// );
+    /// ```
+    pub const fn with_clock(clock: &'a (dyn Clock + Sync)) -> Self {
+        Self {
+            relative: RelativeTimeParser::with_clock(clock),
+        }
+    }
+
+    /// Set the [`Clock`] to get the date and time of `now`
+    ///
+    /// See also [`DateTimeParser::with_clock`].
+    pub fn set_clock(&mut self, clock: &'a (dyn Clock + Sync)) {
+        self.relative.set_clock(clock);
+    }
+
+    /// Parse the `source` string into a [`DateTime`] relative to the date and time of `now`
+    ///
+    /// See [`DateTimeParser::parse_with_date`] and the documentation of the [`DateTimeParser`] for
+    /// a description of the format.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if an error during the parsing process occurred or the resulting
+    /// [`DateTime`] overflows
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_gnu::{DateTime, DateTimeParser};
+    ///
+    /// let parser = DateTimeParser::new();
+    /// assert_eq!(
+    ///     parser.parse("2023-10-16T14:30:00Z"),
+    ///     Ok(DateTime::from_gregorian_date_time(
+    ///         2023, 10, 16, 14, 30, 0, 0
+    ///     ))
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse(&self, source: &str) -> Result<DateTime, ParseError> {
+        self.parse_with_date(source, None)
+    }
+
+    /// Parse the `source` string into a [`DateTime`] relative to the optionally given `date`
+    ///
+    /// If the `date` is `None`, then the time of `now` of the [`Clock`] is assumed (see
+    /// [`DateTimeParser::with_clock`]). Any leading and trailing whitespace is ignored. See the
+    /// documentation of the [`DateTimeParser`] for a description of the format.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if an error during the parsing process occurred or the resulting
+    /// [`DateTime`] overflows
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_gnu::{DateTime, DateTimeParser};
+    ///
+    /// let parser = DateTimeParser::new();
+    /// let date = DateTime::from_gregorian_date_time(2023, 10, 16, 10, 0, 0, 0);
+    /// assert_eq!(
+    ///     parser.parse_with_date("14:30", Some(date)),
+    ///     Ok(DateTime::from_gregorian_date_time(
+    ///         2023, 10, 16, 14, 30, 0, 0
+    ///     ))
+    /// );
+    /// assert_eq!(
+    ///     parser.parse_with_date("Oct 31 12:00 EST", Some(date)),
+    ///     Ok(DateTime::from_gregorian_date_time(
+    ///         2023, 10, 31, 17, 0, 0, 0
+    ///     ))
+    /// );
+    /// assert_eq!(
+    ///     parser.parse_with_date("last monday", Some(date)),
+    ///     Ok(DateTime::from_gregorian_date_time(2023, 10, 9, 0, 0, 0, 0))
+    /// );
+    /// ```
+    pub fn parse_with_date(
+        &self,
+        source: &str,
+        date: Option<DateTime>,
+    ) -> Result<DateTime, ParseError> {
+        let items = ItemsParser::new(trim_whitespace(source), &self.relative).parse()?;
+        items.resolve(date.unwrap_or_else(|| self.relative.clock.now()))
+    }
+}
+
+impl Default for DateTimeParser<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_date_time_parser_new() {
+        assert_eq!(DateTimeParser::new(), DateTimeParser::default());
+    }
+
+    #[rstest]
+    #[case::equal("oct", "oct", true)]
+    #[case::upper_case("OCT", "oct", true)]
+    #[case::trailing_period("Oct.", "oct", true)]
+    #[case::periods("a.m.", "am", true)]
+    #[case::shorter("oc", "oct", false)]
+    #[case::longer("octo", "oct", false)]
+    fn test_eq_ignore_periods(#[case] word: &str, #[case] name: &str, #[case] expected: bool) {
+        assert_eq!(eq_ignore_periods(word, name), expected);
+    }
+
+    #[rstest]
+    #[case::only_weekday("friday", Some(WeekdayItem { start: 0, end: 6, ordinal: 0, weekday: 5 }))]
+    #[case::abbreviation("1 sec Fri. 2 sec", Some(WeekdayItem { start: 6, end: 10, ordinal: 0, weekday: 5 }))]
+    #[case::comma("sun, 1 sec", Some(WeekdayItem { start: 0, end: 4, ordinal: 0, weekday: 0 }))]
+    #[case::numeral("next monday", Some(WeekdayItem { start: 0, end: 11, ordinal: 1, weekday: 1 }))]
+    #[case::last("1 day last  tue", Some(WeekdayItem { start: 6, end: 15, ordinal: -1, weekday: 2 }))]
+    #[case::number("3 wed", Some(WeekdayItem { start: 0, end: 5, ordinal: 3, weekday: 3 }))]
+    #[case::number_attached("3wed", Some(WeekdayItem { start: 0, end: 4, ordinal: 3, weekday: 3 }))]
+    #[case::number_too_large(
+        "99999999999999999999 wed",
+        Some(WeekdayItem { start: 0, end: 24, ordinal: i64::MAX, weekday: 3 })
+    )]
+    #[case::signed_number_is_no_ordinal("-3 sat", Some(WeekdayItem { start: 3, end: 6, ordinal: 0, weekday: 6 }))]
+    #[case::fraction_is_no_ordinal("1.3 sat", Some(WeekdayItem { start: 4, end: 7, ordinal: 0, weekday: 6 }))]
+    #[case::time_unit_is_no_ordinal("week thu", Some(WeekdayItem { start: 5, end: 8, ordinal: 0, weekday: 4 }))]
+    #[case::no_weekday("1 month 2 days", None)]
+    #[case::part_of_word("sundays", None)]
+    #[case::empty("", None)]
+    fn test_find_weekday_item(#[case] source: &str, #[case] expected: Option<WeekdayItem>) {
+        assert_eq!(find_weekday_item(source), expected);
+    }
+
+    #[rstest]
+    #[case::two_digits_21th_century(68, 2, 2068)]
+    #[case::two_digits_20th_century(69, 2, 1969)]
+    #[case::one_digit(5, 1, 5)]
+    #[case::three_digits(68, 3, 68)]
+    #[case::four_digits(2023, 4, 2023)]
+    fn test_year_from_digits(#[case] year: u64, #[case] digits: usize, #[case] expected: i64) {
+        assert_eq!(year_from_digits(year, digits), Ok(expected));
+    }
+}
@@ -0,0 +1,225 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::fmt::Debug;
+
+use fundu_core::time::Duration;
+
+use crate::DateTime;
+
+/// The source of the date and time of `now` used by the date-aware parsers
+///
+/// Parsers like the [`crate::RelativeTimeParser`] and the [`crate::DateTimeParser`] ask the clock
+/// for the current time if no date is given explicitly. By default, the [`SystemClock`] is used.
+/// Setting a [`FixedClock`] pins `now` to a specific [`DateTime`] which is useful in tests.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_gnu::{DateTime, Duration, FixedClock, RelativeTimeParser};
+///
+/// // February of 1972 has 29 days
+/// let clock = FixedClock::new(DateTime::from_gregorian_date_time(1972, 2, 1, 0, 0, 0, 0));
+/// let parser = RelativeTimeParser::with_clock(&clock);
+/// assert_eq!(
+///     parser.parse("1 month"),
+///     Ok(Duration::positive(29 * 86400, 0))
+/// );
+/// ```
+///
+/// Implementing a custom clock:
+///
+/// ```rust
+/// use fundu_gnu::{Clock, DateTime, DateTimeParser};
+///
+/// struct EpochClock;
+///
+/// impl Clock for EpochClock {
+///     fn now(&self) -> DateTime {
+///         DateTime::UNIX_EPOCH
+///     }
+/// }
+///
+/// let parser = DateTimeParser::with_clock(&EpochClock);
+/// assert_eq!(
+///     parser.parse("tomorrow"),
+///     Ok(DateTime::from_gregorian_date_time(1970, 1, 2, 0, 0, 0, 0))
+/// );
+/// ```
+pub trait Clock {
+    /// Return the current date and time in UTC
+    fn now(&self) -> DateTime;
+}
+
+impl<T: Clock + ?Sized> Clock for &T {
+    fn now(&self) -> DateTime {
+        (**self).now()
+    }
+}
+
+/// The [`Clock`] returning the current system time
+///
+/// See also [`DateTime::now_utc`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime {
+        DateTime::now_utc()
+    }
+}
+
+/// A [`Clock`] which always returns the same [`DateTime`]
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_gnu::{Clock, DateTime, FixedClock};
+///
+/// let clock = FixedClock::new(DateTime::UNIX_EPOCH);
+/// assert_eq!(clock.now(), DateTime::UNIX_EPOCH);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FixedClock(DateTime);
+
+impl FixedClock {
+    /// Create a new `FixedClock` returning the `date_time` as `now`
+    pub const fn new(date_time: DateTime) -> Self {
+        Self(date_time)
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime {
+        self.0
+    }
+}
+
+/// A [`Clock`] adding a fixed [`Duration`] to the time of another [`Clock`]
+///
+/// Note the calculation of `now` panics if adding the `offset` to the time of the other clock
+/// overflows.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_gnu::{Clock, DateTime, Duration, FixedClock, OffsetClock};
+///
+/// let clock = OffsetClock::new(
+///     FixedClock::new(DateTime::UNIX_EPOCH),
+///     Duration::negative(3600, 0),
+/// );
+/// assert_eq!(
+///     clock.now(),
+///     DateTime::from_gregorian_date_time(1969, 12, 31, 23, 0, 0, 0)
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct OffsetClock<C> {
+    clock: C,
+    offset: Duration,
+}
+
+impl<C: Clock> OffsetClock<C> {
+    /// Create a new `OffsetClock` adding the `offset` to the time of the `clock`
+    pub const fn new(clock: C, offset: Duration) -> Self {
+        Self { clock, offset }
+    }
+}
+
+impl<C: Clock> Clock for OffsetClock<C> {
+    fn now(&self) -> DateTime {
+        self.clock
+            .now()
+            .checked_add_duration(&self.offset)
+            .expect("Overflow when adding the offset to the time of the clock")
+    }
+}
+
+/// The clock of a parser which is the [`SystemClock`] if not set otherwise
+#[derive(Copy, Clone)]
+pub(crate) struct ClockRef<'a>(Option<&'a (dyn Clock + Sync)>);
+
+impl<'a> ClockRef<'a> {
+    pub(crate) const SYSTEM: Self = Self(None);
+
+    pub(crate) const fn new(clock: &'a (dyn Clock + Sync)) -> Self {
+        Self(Some(clock))
+    }
+
+    pub(crate) fn now(&self) -> DateTime {
+        self.0.map_or_else(DateTime::now_utc, Clock::now)
+    }
+}
+
+impl Debug for ClockRef<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(_) => f.write_str("ClockRef(Custom)"),
+            None => f.write_str("ClockRef(SystemClock)"),
+        }
+    }
+}
+
+/// Clocks are equal if they are the same clock instance
+impl PartialEq for ClockRef<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.0, other.0) {
+            (Some(this), Some(other)) => std::ptr::eq(
+                (this as *const (dyn Clock + Sync)).cast::<u8>(),
+                (other as *const (dyn Clock + Sync)).cast::<u8>(),
+            ),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ClockRef<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_fixed_clock() {
+        let date_time = DateTime::from_gregorian_date_time(2023, 10, 16, 12, 0, 0, 0);
+        assert_eq!(FixedClock::new(date_time).now(), date_time);
+    }
+
+    #[rstest]
+    #[case::zero(Duration::ZERO, DateTime::UNIX_EPOCH)]
+    #[case::positive(
+        Duration::positive(86400, 500),
+        DateTime::from_gregorian_date_time(1970, 1, 2, 0, 0, 0, 500)
+    )]
+    #[case::negative(
+        Duration::negative(1, 0),
+        DateTime::from_gregorian_date_time(1969, 12, 31, 23, 59, 59, 0)
+    )]
+    fn test_offset_clock(#[case] offset: Duration, #[case] expected: DateTime) {
+        let clock = OffsetClock::new(FixedClock::new(DateTime::UNIX_EPOCH), offset);
+        assert_eq!(clock.now(), expected);
+    }
+
+    #[test]
+    fn test_system_clock_in_tests_is_unix_epoch() {
+        assert_eq!(SystemClock.now(), DateTime::UNIX_EPOCH);
+        assert_eq!(ClockRef::SYSTEM.now(), DateTime::UNIX_EPOCH);
+    }
+
+    #[test]
+    fn test_clock_ref_eq() {
+        let clock = FixedClock::new(DateTime::UNIX_EPOCH);
+        let other = FixedClock::new(DateTime::UNIX_EPOCH);
+
+        assert_eq!(ClockRef::SYSTEM, ClockRef::SYSTEM);
+        assert_eq!(ClockRef::new(&clock), ClockRef::new(&clock));
+        assert_ne!(ClockRef::new(&clock), ClockRef::new(&other));
+        assert_ne!(ClockRef::new(&clock), ClockRef::SYSTEM);
+    }
+}
@@ -8,6 +8,7 @@ use std::time::{Duration as StdDuration, SystemTime};
 
 #[cfg(feature = "chrono")]
 use chrono::{Datelike, Timelike};
+use fundu_core::relative::{checked_add_months, CheckedAddRelative, RelativeDuration};
 use fundu_core::time::Duration;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -15,6 +16,7 @@ use serde::{Deserialize, Serialize};
 use time::{OffsetDateTime, PrimitiveDateTime};
 
 use crate::util::{self, floor_div};
+use crate::{TimeZone, UtcOffset};
 
 const DAYS_IN_PREVIOUS_MONTH: [u16; 12] = [306, 337, 0, 31, 61, 92, 122, 153, 184, 214, 245, 275];
 const ORDINAL_TO_MONTH: [u8; 366] = [
@@ -43,8 +45,11 @@ const SECS_PER_MINUTE_I64: i64 = 60;
 const SECS_PER_MINUTE_U64: u64 = SECS_PER_MINUTE_I64 as u64;
 const SECS_PER_HOUR_I64: i64 = 3600;
 const SECS_PER_HOUR_U64: u64 = SECS_PER_HOUR_I64 as u64;
+const SECS_PER_DAY_I64: i64 = 86_400;
 
 const JD_BASE: i64 = 1_721_119;
+const JD_UNIX_EPOCH: i64 = 2_440_588;
+const DAYS_PER_WEEK: i64 = 7;
 
 /// Store a proleptic gregorian date as [`JulianDay`]
 ///
@@ -464,6 +469,57 @@ impl DateTime {
         Self { days, time }
     }
 
+    /// Create a `DateTime` from a given proleptic gregorian date and time
+    ///
+    /// Like [`DateTime::from_gregorian_date_time`] but returns `None` instead of panicking if the
+    /// calculation of the [`JulianDay`] overflows.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the input arguments are invalid. See
+    /// [`DateTime::from_gregorian_date_time`] for the valid ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_gnu::DateTime;
+    ///
+    /// assert_eq!(
+    ///     DateTime::try_from_gregorian_date_time(1970, 1, 1, 0, 0, 0, 0),
+    ///     Some(DateTime::UNIX_EPOCH)
+    /// );
+    /// assert_eq!(
+    ///     DateTime::try_from_gregorian_date_time(i64::MAX, 1, 1, 0, 0, 0, 0),
+    ///     None
+    /// );
+    /// ```
+    pub const fn try_from_gregorian_date_time(
+        year: i64,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanos: u32,
+    ) -> Option<Self> {
+        validate!(hour <= 23);
+        validate!(minute <= 59);
+        validate!(second <= 59);
+        validate!(nanos <= 999_999_999);
+
+        match JulianDay::try_from_gregorian(year, month, day) {
+            Some(days) => Some(Self {
+                days,
+                time: (hour as u64 * SECS_PER_HOUR_U64
+                    + minute as u64 * SECS_PER_MINUTE_U64
+                    + second as u64)
+                    * NANOS_PER_SEC_U64
+                    + nanos as u64,
+            }),
+            None => None,
+        }
+    }
+
     /// Return the proleptic gregorian date
     ///
     /// # Examples
@@ -716,6 +772,57 @@ impl DateTime {
         })
     }
 
+    /// Return the day of the week counted from `0` (sunday) to `6` (saturday)
+    pub(crate) const fn weekday(&self) -> u8 {
+        // The julian day 0 is a monday. The cast is safe because the value is in the range 0..7
+        #[allow(clippy::cast_possible_truncation)]
+        #[allow(clippy::cast_sign_loss)]
+        let weekday = ((self.days.0.rem_euclid(DAYS_PER_WEEK) + 1) % DAYS_PER_WEEK) as u8;
+        weekday
+    }
+
+    /// Return the `DateTime` at the start (midnight) of the same day
+    pub(crate) const fn start_of_day(self) -> Self {
+        Self {
+            days: self.days,
+            time: 0,
+        }
+    }
+
+    /// Move the date to the given day of the week like gnu does with day of the week items
+    ///
+    /// The `weekday` is counted from `0` (sunday) to `6` (saturday). With an `ordinal` of `0`, the
+    /// date moves forward to the `weekday` or stays at the same date if it already is this day of
+    /// the week. A positive `ordinal` moves to the `ordinal`th `weekday` after the date and a
+    /// negative `ordinal` to the `ordinal`th `weekday` before the date. The time of day is kept.
+    /// Returns `None` on overflow.
+    pub(crate) fn checked_add_weekday(self, ordinal: i64, weekday: u8) -> Option<Self> {
+        let current = i64::from(self.weekday());
+        let weekday = i64::from(weekday);
+        let days = ordinal
+            .checked_sub(i64::from(ordinal > 0 && current != weekday))
+            .and_then(|weeks| weeks.checked_mul(DAYS_PER_WEEK))
+            .and_then(|days| {
+                days.checked_add((weekday - current + DAYS_PER_WEEK) % DAYS_PER_WEEK)
+            })?;
+        self.days.checked_add_days(days).map(|days| Self {
+            days,
+            time: self.time,
+        })
+    }
+
+    /// Return the seconds since the unix epoch saturating at `i64::MIN` and `i64::MAX`
+    pub(crate) fn as_unix_seconds_saturating(&self) -> i64 {
+        let seconds = (i128::from(self.days.0) - i128::from(JD_UNIX_EPOCH))
+            * i128::from(SECS_PER_DAY_I64)
+            + i128::from(self.time / NANOS_PER_SEC_U64);
+        i64::try_from(seconds).unwrap_or(if seconds.is_negative() {
+            i64::MIN
+        } else {
+            i64::MAX
+        })
+    }
+
     /// Calculate the [`Duration`] between this `DateTime` and another `DateTime`
     ///
     /// If the other `DateTime` is greater than this `DateTime` the [`Duration`] is negative. This
@@ -765,6 +872,28 @@ impl DateTime {
     }
 }
 
+/// Add a [`RelativeDuration`] to the `DateTime`
+///
+/// The `years` and `months` clamp the day to the last day of the resulting month, so
+/// `2023-01-31` plus `1 month` is `2023-02-28`. See also [`RelativeDuration`].
+impl CheckedAddRelative for DateTime {
+    fn checked_add_relative(&self, relative: &RelativeDuration) -> Option<Self> {
+        let months = relative.total_months()?;
+        let days = if months == 0 {
+            self.days
+        } else {
+            let (year, month, day) = self.days.to_gregorian()?;
+            let (year, month, day) = checked_add_months(year, month, day, months)?;
+            JulianDay::try_from_gregorian(year, month, day)?
+        };
+        Self {
+            days: days.checked_add_days(relative.total_days()?)?,
+            time: self.time,
+        }
+        .checked_add_duration(&relative.exact)
+    }
+}
+
 #[cfg(feature = "time")]
 impl From<OffsetDateTime> for DateTime {
     fn from(value: OffsetDateTime) -> Self {
@@ -812,6 +941,118 @@ impl From<chrono::NaiveDateTime> for DateTime {
     }
 }
 
+/// A [`DateTime`] in a [`TimeZone`]
+///
+/// The `ZonedDateTime` stores the point in time as UTC [`DateTime`] together with the
+/// [`TimeZone`] to calculate the local wall-clock time. The [`TimeZone`] can have a fixed
+/// [`UtcOffset`] or follow the rules of the local time zone database including daylight saving
+/// time. Use [`crate::RelativeTimeParser::parse_with_zoned_date`] to parse relative times like
+/// `"1 day"` or `"next month"` in wall-clock time.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_gnu::{DateTime, TimeZone, UtcOffset, ZonedDateTime};
+///
+/// let utc = DateTime::from_gregorian_date_time(2000, 12, 31, 23, 0, 0, 0);
+/// let date_time = ZonedDateTime::with_offset(utc, UtcOffset::from_hms(2, 0, 0).unwrap());
+/// assert_eq!(
+///     date_time.local(),
+///     Some(DateTime::from_gregorian_date_time(2001, 1, 1, 1, 0, 0, 0))
+/// );
+///
+/// let zone = TimeZone::from_tz_string("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+/// let local = DateTime::from_gregorian_date_time(2023, 7, 1, 12, 0, 0, 0);
+/// let date_time = ZonedDateTime::from_local(local, zone).unwrap();
+/// assert_eq!(
+///     date_time.utc(),
+///     DateTime::from_gregorian_date_time(2023, 7, 1, 10, 0, 0, 0)
+/// );
+/// assert_eq!(date_time.offset().as_seconds(), 2 * 3600);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZonedDateTime {
+    date_time: DateTime,
+    zone: TimeZone,
+}
+
+impl ZonedDateTime {
+    /// Create a new `ZonedDateTime` from the UTC `date_time` in the time `zone`
+    pub const fn new(date_time: DateTime, zone: TimeZone) -> Self {
+        Self { date_time, zone }
+    }
+
+    /// Create a new `ZonedDateTime` from the UTC `date_time` with a fixed `offset`
+    pub const fn with_offset(date_time: DateTime, offset: UtcOffset) -> Self {
+        Self::new(date_time, TimeZone::fixed(offset))
+    }
+
+    /// Create a new `ZonedDateTime` from the local wall-clock `date_time` in the time `zone`
+    ///
+    /// See [`TimeZone::offset_at_local`] for how ambiguous and non-existent local times are
+    /// resolved. Returns `None` on overflow.
+    pub fn from_local(date_time: DateTime, zone: TimeZone) -> Option<Self> {
+        zone.local_to_utc(date_time)
+            .map(|date_time| Self::new(date_time, zone))
+    }
+
+    /// Return the current `ZonedDateTime` in the time `zone`
+    ///
+    /// See also [`DateTime::now_utc`].
+    pub fn now(zone: TimeZone) -> Self {
+        Self::new(DateTime::now_utc(), zone)
+    }
+
+    /// Return the point in time as UTC [`DateTime`]
+    pub const fn utc(&self) -> DateTime {
+        self.date_time
+    }
+
+    /// Return the local wall-clock time as [`DateTime`] or `None` on overflow
+    pub fn local(&self) -> Option<DateTime> {
+        self.zone.utc_to_local(self.date_time)
+    }
+
+    /// Return the [`UtcOffset`] of the [`TimeZone`] at this point in time
+    pub fn offset(&self) -> UtcOffset {
+        self.zone.offset_at(&self.date_time)
+    }
+
+    /// Return the [`TimeZone`]
+    pub const fn time_zone(&self) -> &TimeZone {
+        &self.zone
+    }
+}
+
+impl From<DateTime> for ZonedDateTime {
+    fn from(date_time: DateTime) -> Self {
+        Self::new(date_time, TimeZone::UTC)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<OffsetDateTime> for ZonedDateTime {
+    fn from(value: OffsetDateTime) -> Self {
+        // The unwrap is safe because the offsets of `time` don't exceed `+-25:59:59`
+        let offset = UtcOffset::from_seconds(value.offset().whole_seconds()).unwrap();
+        // The conversion into a `DateTime` keeps the local date and time, so the offset is removed
+        // here. The unwrap is safe because the date range of `time` is much smaller.
+        let date_time = DateTime::from(value)
+            .checked_sub_duration(&offset.as_duration())
+            .unwrap();
+        Self::with_offset(date_time, offset)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::FixedOffset>> for ZonedDateTime {
+    fn from(value: chrono::DateTime<chrono::FixedOffset>) -> Self {
+        // The unwrap is safe because the offsets of `chrono` are less than a day
+        let offset = UtcOffset::from_seconds(value.offset().local_minus_utc()).unwrap();
+        Self::with_offset(value.into(), offset)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(feature = "chrono")]
@@ -1294,6 +1535,102 @@ mod tests {
         assert_eq!(datetime.checked_add_gregorian(ymd.0, ymd.1, ymd.2), None);
     }
 
+    #[rstest]
+    #[case::zero(
+        DateTime::from_gregorian_date_time(2023, 1, 31, 12, 0, 0, 0),
+        RelativeDuration::ZERO,
+        DateTime::from_gregorian_date_time(2023, 1, 31, 12, 0, 0, 0)
+    )]
+    #[case::month_clamped(
+        DateTime::from_gregorian_date_time(2023, 1, 31, 12, 0, 0, 0),
+        RelativeDuration::new(0, 1, 0, 0, Duration::ZERO),
+        DateTime::from_gregorian_date_time(2023, 2, 28, 12, 0, 0, 0)
+    )]
+    #[case::month_clamped_leap_year(
+        DateTime::from_gregorian_date_time(2024, 3, 31, 12, 0, 0, 0),
+        RelativeDuration::new(0, -1, 0, 0, Duration::ZERO),
+        DateTime::from_gregorian_date_time(2024, 2, 29, 12, 0, 0, 0),
+    )]
+    #[case::year_from_leap_day(
+        DateTime::from_gregorian_date_time(2024, 2, 29, 0, 0, 0, 0),
+        RelativeDuration::new(1, 0, 0, 0, Duration::ZERO),
+        DateTime::from_gregorian_date_time(2025, 2, 28, 0, 0, 0, 0)
+    )]
+    #[case::weeks_and_days(
+        DateTime::from_gregorian_date_time(2023, 2, 28, 0, 0, 0, 0),
+        RelativeDuration::new(0, 0, 1, -1, Duration::ZERO),
+        DateTime::from_gregorian_date_time(2023, 3, 6, 0, 0, 0, 0),
+    )]
+    #[case::all(
+        DateTime::from_gregorian_date_time(2023, 1, 31, 23, 0, 0, 0),
+        RelativeDuration::new(1, 1, 0, 1, Duration::positive(3600, 1)),
+        DateTime::from_gregorian_date_time(2024, 3, 2, 0, 0, 0, 1)
+    )]
+    fn test_date_time_checked_add_relative(
+        #[case] datetime: DateTime,
+        #[case] relative: RelativeDuration,
+        #[case] expected: DateTime,
+    ) {
+        assert_eq!(datetime.checked_add_relative(&relative), Some(expected));
+        assert_eq!(relative.checked_add_to(&datetime), Some(expected));
+    }
+
+    #[rstest]
+    #[case::max_years(RelativeDuration::new(i64::MAX, 0, 0, 0, Duration::ZERO))]
+    #[case::max_days(RelativeDuration::new(0, 0, 0, i64::MAX, Duration::ZERO))]
+    #[case::max_weeks(RelativeDuration::new(0, 0, i64::MAX, 0, Duration::ZERO))]
+    fn test_date_time_checked_add_relative_then_none(#[case] relative: RelativeDuration) {
+        assert_eq!(DateTime::UNIX_EPOCH.checked_add_relative(&relative), None);
+    }
+
+    #[rstest]
+    #[case::thursday(DateTime::UNIX_EPOCH, 4)]
+    #[case::sunday(DateTime::from_gregorian_date_time(2023, 10, 15, 23, 59, 59, 0), 0)]
+    #[case::monday(DateTime::from_gregorian_date_time(2023, 10, 16, 0, 0, 0, 0), 1)]
+    #[case::saturday(DateTime::from_gregorian_date_time(-4713, 11, 29, 0, 0, 0, 0), 6)]
+    #[case::negative_julian_day(DateTime::from_gregorian_date_time(-4713, 11, 23, 0, 0, 0, 0), 0)]
+    fn test_date_time_weekday(#[case] datetime: DateTime, #[case] expected: u8) {
+        assert_eq!(datetime.weekday(), expected);
+    }
+
+    #[rstest]
+    #[case::same_day(0, 1, DateTime::from_gregorian_date_time(2023, 10, 16, 10, 0, 0, 0))]
+    #[case::later_day(0, 5, DateTime::from_gregorian_date_time(2023, 10, 20, 10, 0, 0, 0))]
+    #[case::earlier_day(0, 0, DateTime::from_gregorian_date_time(2023, 10, 22, 10, 0, 0, 0))]
+    #[case::next_same_day(1, 1, DateTime::from_gregorian_date_time(2023, 10, 23, 10, 0, 0, 0))]
+    #[case::next_later_day(1, 5, DateTime::from_gregorian_date_time(2023, 10, 20, 10, 0, 0, 0))]
+    #[case::last_same_day(-1, 1, DateTime::from_gregorian_date_time(2023, 10, 9, 10, 0, 0, 0))]
+    #[case::last_later_day(-1, 5, DateTime::from_gregorian_date_time(2023, 10, 13, 10, 0, 0, 0))]
+    #[case::last_earlier_day(-1, 0, DateTime::from_gregorian_date_time(2023, 10, 15, 10, 0, 0, 0))]
+    #[case::third(3, 3, DateTime::from_gregorian_date_time(2023, 11, 1, 10, 0, 0, 0))]
+    fn test_date_time_checked_add_weekday(
+        #[case] ordinal: i64,
+        #[case] weekday: u8,
+        #[case] expected: DateTime,
+    ) {
+        // A monday
+        let datetime = DateTime::from_gregorian_date_time(2023, 10, 16, 10, 0, 0, 0);
+        assert_eq!(
+            datetime.checked_add_weekday(ordinal, weekday),
+            Some(expected)
+        );
+    }
+
+    #[rstest]
+    #[case::max(i64::MAX)]
+    #[case::min(i64::MIN)]
+    fn test_date_time_checked_add_weekday_then_none(#[case] ordinal: i64) {
+        assert_eq!(DateTime::UNIX_EPOCH.checked_add_weekday(ordinal, 0), None);
+    }
+
+    #[test]
+    fn test_date_time_start_of_day() {
+        assert_eq!(
+            DateTime::from_gregorian_date_time(2023, 10, 16, 10, 20, 30, 400).start_of_day(),
+            DateTime::from_gregorian_date_time(2023, 10, 16, 0, 0, 0, 0)
+        );
+    }
+
     #[rstest]
     #[case::one_nano(
         DateTime::from_gregorian_date_time(1970, 1, 2, 0, 0, 0, 2),
@@ -1519,4 +1856,77 @@ mod tests {
             DateTime::from_gregorian_date_time(year.into(), 12, 31, 23, 59, 59, 999_999_999)
         );
     }
+
+    #[rstest]
+    #[case::unix_epoch(DateTime::UNIX_EPOCH, 0)]
+    #[case::before_unix_epoch(
+        DateTime::from_gregorian_date_time(1969, 12, 31, 23, 59, 59, 999_999_999),
+        -1
+    )]
+    #[case::after_unix_epoch(
+        DateTime::from_gregorian_date_time(2023, 3, 26, 1, 0, 0, 0),
+        1_679_792_400
+    )]
+    #[case::saturate_max(DateTime { days: JulianDay(i64::MAX), time: 0 }, i64::MAX)]
+    #[case::saturate_min(DateTime { days: JulianDay(i64::MIN), time: 0 }, i64::MIN)]
+    fn test_date_time_as_unix_seconds_saturating(
+        #[case] date_time: DateTime,
+        #[case] expected: i64,
+    ) {
+        assert_eq!(date_time.as_unix_seconds_saturating(), expected);
+    }
+
+    #[test]
+    fn test_zoned_date_time_from_local_and_back() {
+        let zone = crate::TimeZone::from_tz_string("EST5EDT").unwrap();
+        let local = DateTime::from_gregorian_date_time(2023, 7, 4, 12, 0, 0, 0);
+        let date_time = ZonedDateTime::from_local(local, zone.clone()).unwrap();
+
+        assert_eq!(
+            date_time.utc(),
+            DateTime::from_gregorian_date_time(2023, 7, 4, 16, 0, 0, 0)
+        );
+        assert_eq!(date_time.local(), Some(local));
+        assert_eq!(
+            date_time.offset(),
+            crate::UtcOffset::from_hms(-4, 0, 0).unwrap()
+        );
+        assert_eq!(date_time.time_zone(), &zone);
+    }
+
+    #[test]
+    fn test_zoned_date_time_from_date_time_is_utc() {
+        let date_time = ZonedDateTime::from(DateTime::UNIX_EPOCH);
+        assert_eq!(date_time.offset(), crate::UtcOffset::UTC);
+        assert_eq!(date_time.local(), Some(DateTime::UNIX_EPOCH));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_zoned_date_time_from_offset_date_time() {
+        let date_time = ZonedDateTime::from(datetime!(2000-12-31 23:00:00 +02:30));
+        assert_eq!(
+            date_time.utc(),
+            DateTime::from_gregorian_date_time(2000, 12, 31, 20, 30, 0, 0)
+        );
+        assert_eq!(
+            date_time.offset(),
+            crate::UtcOffset::from_hms(2, 30, 0).unwrap()
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_zoned_date_time_from_chrono_date_time() {
+        let chrono_date = FixedOffset::west_opt(3600)
+            .unwrap()
+            .with_ymd_and_hms(2000, 12, 31, 23, 0, 0)
+            .unwrap();
+        let date_time = ZonedDateTime::from(chrono_date);
+        assert_eq!(
+            date_time.utc(),
+            DateTime::from_gregorian_date_time(2001, 1, 1, 0, 0, 0, 0)
+        );
+        assert_eq!(date_time.offset().as_seconds(), -3600_i32);
+    }
 }
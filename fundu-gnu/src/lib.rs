@@ -48,6 +48,12 @@
 //! each worth a zero duration are allowed, too. These keywords count as a full duration and don't
 //! accept a number, time unit or the `ago` time unit suffix.
 //!
+//! A single day of the week item like `"friday"`, `"next fri"` or `"third wednesday"` moves the
+//! date to the midnight of the specified day of the week. Like in gnu, `"monday"` is today if
+//! today is a monday, `"next monday"` is a week later and `"last monday"` a week ago. A day of the
+//! week needs a date and is therefore rejected by [`RelativeTimeParser::parse_fuzzy`] and
+//! [`RelativeTimeParser::parse_relative`].
+//!
 //! Summary of the rest of the format:
 //!
 //! - Only numbers like `"123 days"` and without exponent (like `"3e9 days"`) are allowed. Only
@@ -164,6 +170,42 @@
 //! assert_eq!(parse("1sec3min"), Ok(Duration::positive(1 + 3 * 60, 0)));
 //! ```
 //!
+//! Besides the relative items, the [`DateTimeParser`] (and the global [`parse_date_time`]) parses
+//! the full gnu date input format as understood by `date -d` including calendar dates, times of
+//! day, time zones, days of the week and the seconds since the unix epoch into a [`DateTime`]:
+//!
+//! ```rust
+//! use fundu_gnu::{parse_date_time, DateTime};
+//!
+//! assert_eq!(
+//!     parse_date_time("2023-10-16 14:00 +0200 +3 days"),
+//!     Ok(DateTime::from_gregorian_date_time(
+//!         2023, 10, 19, 12, 0, 0, 0
+//!     ))
+//! );
+//! assert_eq!(
+//!     parse_date_time("@1697414400"),
+//!     Ok(DateTime::from_gregorian_date_time(2023, 10, 16, 0, 0, 0, 0))
+//! );
+//! ```
+//!
+//! A [`DateTime`] is always in UTC. To apply calendar time units like `1 day` or `next month` to
+//! the local wall-clock time (like gnu does), use a [`ZonedDateTime`] with a [`TimeZone`] with a
+//! fixed [`UtcOffset`] or a zone like `Europe/Berlin` read from the local time zone database:
+//!
+//! ```rust
+//! use fundu_gnu::{parse_with_zoned_date, DateTime, Duration, TimeZone, ZonedDateTime};
+//!
+//! // The clock is turned forward on 2023-03-26, so this day has only 23 hours
+//! let zone = TimeZone::from_tz_string("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+//! let local = DateTime::from_gregorian_date_time(2023, 3, 25, 12, 0, 0, 0);
+//! let date = ZonedDateTime::from_local(local, zone).unwrap();
+//! assert_eq!(
+//!     parse_with_zoned_date("1 day", &date),
+//!     Ok(Duration::positive(23 * 60 * 60, 0))
+//! );
+//! ```
+//!
 //! Convert fundu's `Duration` into a [`std::time::Duration`]. Converting to [`chrono::Duration`] or
 //! [`time::Duration`] works the same but needs the `chrono` or `time` feature activated.
 //!
@@ -244,21 +286,34 @@ macro_rules! validate {
     }};
 }
 
+mod absolute;
+mod clock;
 mod datetime;
 mod util;
+mod zone;
 
-pub use datetime::{DateTime, JulianDay};
+use absolute::find_weekday_item;
+pub use absolute::DateTimeParser;
+use clock::ClockRef;
+pub use clock::{Clock, FixedClock, OffsetClock, SystemClock};
+pub use datetime::{DateTime, JulianDay, ZonedDateTime};
 use fundu_core::config::{Config, ConfigBuilder, Delimiter, NumbersLike};
 pub use fundu_core::error::{ParseError, TryFromDurationError};
 use fundu_core::parse::{
     DurationRepr, Fract, Parser, ReprParserMultiple, ReprParserTemplate, Whole,
 };
+pub use fundu_core::relative::{CheckedAddRelative, RelativeDuration};
+pub use fundu_core::scan::{Match, Matches, Scanner};
+pub use fundu_core::stream::{
+    BufReadParser, ParseDuration, ParseDurationPrefix, StreamItem, StreamIter, StreamParser,
+};
 use fundu_core::time::TimeUnit::*;
 pub use fundu_core::time::{Duration, SaturatingInto};
 use fundu_core::time::{Multiplier, TimeUnit, TimeUnitsLike};
 #[cfg(test)]
 pub use rstest_reuse;
-use util::{to_lowercase_u64, trim_whitespace};
+use util::{shift_error_position, to_lowercase_u64, trim_whitespace};
+pub use zone::{TimeZone, TimeZoneError, UtcOffset};
 
 // whitespace definition of: b' ', b'\x09', b'\x0A', b'\x0B', b'\x0C', b'\x0D'
 const DELIMITER: Delimiter = |byte| byte == b' ' || byte.wrapping_sub(9) < 5;
@@ -280,6 +335,11 @@ const TIME_UNITS: TimeUnits = TimeUnits {};
 const TIME_KEYWORDS: TimeKeywords = TimeKeywords {};
 const NUMERALS: Numerals = Numerals {};
 
+/// The fuzzy `years` and `months` and the rest of the time units as [`Duration`]
+type Fuzzy = (i64, i64, Duration);
+/// A day of the week item as ordinal and day of the week
+type Weekday = (i64, u8);
+
 const SECOND_UNIT: (TimeUnit, Multiplier) = (Second, Multiplier(1, 0));
 const MINUTE_UNIT: (TimeUnit, Multiplier) = (Minute, Multiplier(1, 0));
 const HOUR_UNIT: (TimeUnit, Multiplier) = (Hour, Multiplier(1, 0));
@@ -290,6 +350,7 @@ const MONTH_UNIT: (TimeUnit, Multiplier) = (Month, Multiplier(1, 0));
 const YEAR_UNIT: (TimeUnit, Multiplier) = (Year, Multiplier(1, 0));
 
 const PARSER: RelativeTimeParser<'static> = RelativeTimeParser::new();
+const DATE_TIME_PARSER: DateTimeParser<'static> = DateTimeParser::new();
 
 enum FuzzyUnit {
     Month,
@@ -386,66 +447,57 @@ impl<'a> DurationReprParser<'a> {
             _ => return self.parse().map(ParseFuzzyOutput::Duration),
         };
 
+        self.parse_calendar_value().map(|value| {
+            ParseFuzzyOutput::FuzzyTime(FuzzyTime {
+                unit: fuzzy_unit,
+                value,
+            })
+        })
+    }
+
+    fn parse_relative(&mut self) -> Result<RelativeDuration, ParseError> {
+        let mut relative = RelativeDuration::ZERO;
+        match self.0.unit {
+            Some(Day) => relative.days = self.parse_calendar_value()?,
+            Some(Week) => relative.weeks = self.parse_calendar_value()?,
+            Some(Month) => relative.months = self.parse_calendar_value()?,
+            Some(Year) => relative.years = self.parse_calendar_value()?,
+            _ => relative.exact = self.parse()?,
+        }
+        Ok(relative)
+    }
+
+    /// Parse the amount of a calendar time unit like `year` or `day` saturating at the minimum and
+    /// maximum of `i64`
+    fn parse_calendar_value(&self) -> Result<i64, ParseError> {
         if self.0.fract.is_some() {
             return Err(ParseError::InvalidInput(
                 "Fraction only allowed together with seconds as time unit".to_owned(),
             ));
         }
 
-        match self.0.whole {
-            None if self.0.numeral.is_some() => {
-                let Multiplier(coefficient, _) = self.0.numeral.unwrap() * self.0.multiplier;
-                Ok(ParseFuzzyOutput::FuzzyTime(FuzzyTime {
-                    unit: fuzzy_unit,
-                    value: if self.0.is_negative.unwrap_or_default() {
-                        coefficient.saturating_neg()
-                    } else {
-                        coefficient
-                    },
-                }))
+        let is_negative = self.0.is_negative.unwrap_or_default();
+        let value = match self.0.whole {
+            None => {
+                // Without a number, a numeral or just the time unit (or keyword) was encountered
+                let Multiplier(coefficient, _) =
+                    self.0.numeral.unwrap_or(Multiplier(1, 0)) * self.0.multiplier;
+                i128::from(coefficient)
             }
-            // We're here when we've encountered just a time unit
-            None => Ok(ParseFuzzyOutput::FuzzyTime(FuzzyTime {
-                unit: fuzzy_unit,
-                value: if self.0.is_negative.unwrap_or_default() ^ self.0.multiplier.is_negative() {
-                    -1
-                } else {
-                    1
-                },
-            })),
             Some(whole) => {
-                let is_negative =
-                    self.0.is_negative.unwrap_or_default() ^ self.0.multiplier.is_negative();
-                match Whole::parse(&self.0.input[whole.0..whole.1], None, None) {
-                    Some(value) => match i64::try_from(value) {
-                        Ok(value) if is_negative => Ok(ParseFuzzyOutput::FuzzyTime(FuzzyTime {
-                            unit: fuzzy_unit,
-                            value: -value,
-                        })),
-                        Ok(value) => Ok(ParseFuzzyOutput::FuzzyTime(FuzzyTime {
-                            unit: fuzzy_unit,
-                            value,
-                        })),
-                        Err(_) if is_negative => Ok(ParseFuzzyOutput::FuzzyTime(FuzzyTime {
-                            unit: fuzzy_unit,
-                            value: i64::MIN,
-                        })),
-                        Err(_) => Ok(ParseFuzzyOutput::FuzzyTime(FuzzyTime {
-                            unit: fuzzy_unit,
-                            value: i64::MAX,
-                        })),
-                    },
-                    None if is_negative => Ok(ParseFuzzyOutput::FuzzyTime(FuzzyTime {
-                        unit: fuzzy_unit,
-                        value: i64::MIN,
-                    })),
-                    None => Ok(ParseFuzzyOutput::FuzzyTime(FuzzyTime {
-                        unit: fuzzy_unit,
-                        value: i64::MAX,
-                    })),
-                }
+                let Multiplier(coefficient, _) = self.0.multiplier;
+                Whole::parse(&self.0.input[whole.0..whole.1], None, None)
+                    .map_or(i128::from(u64::MAX), i128::from)
+                    * i128::from(coefficient)
             }
-        }
+        };
+
+        let value = if is_negative { -value } else { value };
+        Ok(i64::try_from(value).unwrap_or(if value.is_negative() {
+            i64::MIN
+        } else {
+            i64::MAX
+        }))
     }
 }
 
@@ -506,6 +558,7 @@ impl<'a> DurationReprParser<'a> {
 #[derive(Debug, Eq, PartialEq)]
 pub struct RelativeTimeParser<'a> {
     raw: Parser<'a>,
+    clock: ClockRef<'a>,
 }
 
 impl<'a> RelativeTimeParser<'a> {
@@ -530,8 +583,54 @@ impl<'a> RelativeTimeParser<'a> {
     pub const fn new() -> Self {
         Self {
             raw: Parser::with_config(CONFIG),
+            clock: ClockRef::SYSTEM,
         }
     }
+
+    /// Create a new `RelativeTimeParser` using the [`Clock`] to get the date and time of `now`
+    ///
+    /// The `clock` is used whenever no date is given, for example in
+    /// [`RelativeTimeParser::parse`]. The default clock is the [`SystemClock`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_gnu::{DateTime, Duration, FixedClock, RelativeTimeParser};
+    ///
+    /// let clock = FixedClock::new(DateTime::from_gregorian_date_time(1970, 2, 1, 0, 0, 0, 0));
+    /// let parser = RelativeTimeParser::with_clock(&clock);
+    /// assert_eq!(
+    ///     parser.parse("+1month"),
+    ///     Ok(Duration::positive(28 * 86400, 0))
+    /// );
+    /// ```
+    pub const fn with_clock(clock: &'a (dyn Clock + Sync)) -> Self {
+        Self {
+            raw: Parser::with_config(CONFIG),
+            clock: ClockRef::new(clock),
+        }
+    }
+
+    /// Set the [`Clock`] to get the date and time of `now`
+    ///
+    /// See also [`RelativeTimeParser::with_clock`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_gnu::{DateTime, Duration, FixedClock, RelativeTimeParser};
+    ///
+    /// let clock = FixedClock::new(DateTime::from_gregorian_date_time(1972, 2, 1, 0, 0, 0, 0));
+    /// let mut parser = RelativeTimeParser::new();
+    /// parser.set_clock(&clock);
+    /// assert_eq!(
+    ///     parser.parse("+1month"),
+    ///     Ok(Duration::positive(29 * 86400, 0))
+    /// );
+    /// ```
+    pub fn set_clock(&mut self, clock: &'a (dyn Clock + Sync)) {
+        self.clock = ClockRef::new(clock);
+    }
     /// Parse the `source` string into a [`Duration`] relative to the date and time of `now`
     ///
     /// Any leading and trailing whitespace is ignored. The parser saturates at the maximum of
@@ -566,11 +665,72 @@ impl<'a> RelativeTimeParser<'a> {
         self.parse_with_date(source, None)
     }
 
+    /// Parse the longest valid duration at the start of `source` and return the unconsumed rest
+    ///
+    /// Like [`RelativeTimeParser::parse`], leading whitespace is ignored. The duration has to end
+    /// at a word boundary, so in front of whitespace, punctuation or the end of the `source`, and
+    /// the remainder starts with the byte after the duration. So, `5 months later` is parsed as
+    /// `5 months` with the remainder ` later` but `5minthen` has no valid prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if there's no valid duration at the start of the `source`. The
+    /// error is the error of parsing the whole `source`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_gnu::{Duration, RelativeTimeParser};
+    ///
+    /// let parser = RelativeTimeParser::new();
+    /// assert_eq!(
+    ///     parser.parse_prefix("2 hours ago, then retry"),
+    ///     Ok((Duration::negative(2 * 60 * 60, 0), ", then retry"))
+    /// );
+    /// assert_eq!(
+    ///     parser.parse_prefix("  1 day later"),
+    ///     Ok((Duration::positive(86400, 0), " later"))
+    /// );
+    /// assert!(parser.parse_prefix("later 1 day").is_err());
+    /// ```
+    pub fn parse_prefix<'s>(&self, source: &'s str) -> Result<(Duration, &'s str), ParseError> {
+        let trimmed = source.trim_start_matches(util::is_whitespace);
+        let leading = source.len() - trimmed.len();
+
+        let mut ends = Vec::new();
+        let mut chars = trimmed.char_indices().peekable();
+        while let Some((index, c)) = chars.next() {
+            let is_end = chars
+                .peek()
+                .map_or(true, |(_, next)| !next.is_alphanumeric());
+            if !util::is_whitespace(c) && is_end {
+                ends.push(index + c.len_utf8());
+            }
+        }
+
+        let mut error = None;
+        for end in ends.into_iter().rev() {
+            match self.parse(&trimmed[..end]) {
+                Ok(duration) => return Ok((duration, &trimmed[end..])),
+                Err(parse_error) => {
+                    error.get_or_insert(parse_error);
+                }
+            }
+        }
+        Err(error.map_or(ParseError::Empty, |error| {
+            shift_error_position(error, leading)
+        }))
+    }
+
     /// Parse the `source` string into a [`Duration`] relative to the optionally given `date`
     ///
-    /// If the `date` is `None`, then the system time of `now` is assumed. Time units of `year` and
-    /// `month` are parsed fuzzy since years and months are not all of equal length. Any leading and
-    /// trailing whitespace is ignored. The parser saturates at the maximum of [`Duration::MAX`].
+    /// If the `date` is `None`, then the time of `now` of the [`Clock`] is assumed (see
+    /// [`RelativeTimeParser::with_clock`]). Time units of `year` and `month` are parsed fuzzy since
+    /// years and months are not all of equal length. Any leading and trailing whitespace is
+    /// ignored. The parser saturates at the maximum of [`Duration::MAX`].
+    ///
+    /// A day of the week item (like `"next friday"`) moves the `date` to the midnight of that day
+    /// before the other time units are applied. Only a single day of the week item is allowed.
     ///
     /// # Errors
     ///
@@ -608,20 +768,35 @@ impl<'a> RelativeTimeParser<'a> {
     ///     parser.parse_with_date("+1year", Some(date_time)),
     ///     Ok(Duration::positive(366 * 86400, 0))
     /// );
+    ///
+    /// // 1972-02-01 was a tuesday
+    /// let date_time = DateTime::from_gregorian_date_time(1972, 2, 1, 12, 0, 0, 0);
+    /// assert_eq!(
+    ///     parser.parse_with_date("next friday 2 hours", Some(date_time)),
+    ///     Ok(Duration::positive(3 * 86400 - 10 * 60 * 60, 0))
+    /// );
     /// ```
     pub fn parse_with_date(
         &self,
         source: &str,
         date: Option<DateTime>,
     ) -> Result<Duration, ParseError> {
-        let (years, months, duration) = self.parse_fuzzy(source)?;
-        if years == 0 && months == 0 {
+        let ((years, months, duration), weekday) = self.parse_fuzzy_items(source)?;
+        if years == 0 && months == 0 && weekday.is_none() {
             return Ok(duration);
         }
 
-        // Delay the costly system call to get the utc time as late as possible
-        let orig = date.unwrap_or_else(DateTime::now_utc);
-        orig.checked_add_duration(&duration)
+        // Delay the possibly costly system call to get the utc time as late as possible
+        let orig = date.unwrap_or_else(|| self.clock.now());
+        let start = match weekday {
+            Some((ordinal, weekday)) => orig
+                .start_of_day()
+                .checked_add_weekday(ordinal, weekday)
+                .ok_or(ParseError::Overflow)?,
+            None => orig,
+        };
+        start
+            .checked_add_duration(&duration)
             .and_then(|date| {
                 date.checked_add_gregorian(years, months, 0)
                     .and_then(|date| date.duration_since(orig))
@@ -629,6 +804,70 @@ impl<'a> RelativeTimeParser<'a> {
             .ok_or(ParseError::Overflow)
     }
 
+    /// Parse the `source` string into a [`Duration`] relative to the [`ZonedDateTime`] `date`
+    ///
+    /// Unlike [`RelativeTimeParser::parse_with_date`], the time units `year`, `month`,
+    /// `fortnight`, `week`, `day` and day of the week items are applied to the local wall-clock
+    /// time of the `date` like gnu does. So, `"1 day"` is `23 hours` if the clock is turned forward
+    /// during this day. The time units `hour`, `minute` and `second` are always exact. The returned
+    /// [`Duration`] is the elapsed time between the `date` and the resulting point in time.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if an error during the parsing process occurred or the calculation
+    /// of the resulting date overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_gnu::{DateTime, Duration, RelativeTimeParser, TimeZone, ZonedDateTime};
+    ///
+    /// let parser = RelativeTimeParser::new();
+    /// let zone = TimeZone::from_tz_string("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+    ///
+    /// // The clock is turned forward on 2023-03-26
+    /// let local = DateTime::from_gregorian_date_time(2023, 3, 25, 12, 0, 0, 0);
+    /// let date = ZonedDateTime::from_local(local, zone).unwrap();
+    /// assert_eq!(
+    ///     parser.parse_with_zoned_date("1 day", &date),
+    ///     Ok(Duration::positive(23 * 60 * 60, 0))
+    /// );
+    /// assert_eq!(
+    ///     parser.parse_with_zoned_date("24 hours", &date),
+    ///     Ok(Duration::positive(24 * 60 * 60, 0))
+    /// );
+    /// ```
+    pub fn parse_with_zoned_date(
+        &self,
+        source: &str,
+        date: &ZonedDateTime,
+    ) -> Result<Duration, ParseError> {
+        let (relative, weekday) = self.parse_items(
+            source,
+            Self::parse_relative_trimmed,
+            RelativeDuration::saturating_add,
+        )?;
+        let days = relative.total_days().ok_or(ParseError::Overflow)?;
+        if relative.years == 0 && relative.months == 0 && days == 0 && weekday.is_none() {
+            return Ok(relative.exact);
+        }
+
+        let local = date.local().ok_or(ParseError::Overflow)?;
+        let start = match weekday {
+            Some((ordinal, weekday)) => local
+                .start_of_day()
+                .checked_add_weekday(ordinal, weekday)
+                .ok_or(ParseError::Overflow)?,
+            None => local,
+        };
+        start
+            .checked_add_gregorian(relative.years, relative.months, days)
+            .and_then(|local| date.time_zone().local_to_utc(local))
+            .and_then(|utc| utc.checked_add_duration(&relative.exact))
+            .and_then(|utc| utc.duration_since(date.utc()))
+            .ok_or(ParseError::Overflow)
+    }
+
     /// Parse the `source` string extracting `year` and `month` time units from the [`Duration`]
     ///
     /// Unlike [`RelativeTimeParser::parse`] and [`RelativeTimeParser::parse_with_date`] this method
@@ -641,7 +880,8 @@ impl<'a> RelativeTimeParser<'a> {
     ///
     /// # Errors
     ///
-    /// Returns a [`ParseError`] if an error during the parsing process occurred.
+    /// Returns a [`ParseError`] if an error during the parsing process occurred or the `source`
+    /// string contains a day of the week.
     ///
     /// # Examples
     ///
@@ -658,10 +898,80 @@ impl<'a> RelativeTimeParser<'a> {
     ///     Ok((-10, 123, Duration::positive(2 * 60 * 60, 0)))
     /// );
     /// ```
-    #[allow(clippy::missing_panics_doc)]
     pub fn parse_fuzzy(&self, source: &str) -> Result<(i64, i64, Duration), ParseError> {
-        let trimmed = trim_whitespace(source);
+        match self.parse_fuzzy_items(source)? {
+            (fuzzy, None) => Ok(fuzzy),
+            (_, Some(_)) => Err(ParseError::InvalidInput(
+                "A day of the week is only allowed when parsing relative to a date".to_owned(),
+            )),
+        }
+    }
+
+    /// Parse the `source` string into the fuzzy `years` and `months`, the rest of the time units
+    /// as [`Duration`] and the optional day of the week item as ordinal and day of the week
+    fn parse_fuzzy_items(&self, source: &str) -> Result<(Fuzzy, Option<Weekday>), ParseError> {
+        self.parse_items(
+            source,
+            Self::parse_fuzzy_trimmed,
+            |(years, months, duration), (other_years, other_months, other_duration)| {
+                (
+                    years.saturating_add(other_years),
+                    months.saturating_add(other_months),
+                    duration.saturating_add(other_duration),
+                )
+            },
+        )
+    }
 
+    /// Parse the `source` string split at the day of the week item
+    ///
+    /// The parts before and after the day of the week item are parsed with `parse` and combined
+    /// with `add`. Returns the combined result and the optional day of the week item as ordinal and
+    /// day of the week.
+    fn parse_items<T: Default>(
+        &self,
+        source: &str,
+        parse: fn(&Self, &str) -> Result<T, ParseError>,
+        add: fn(T, T) -> T,
+    ) -> Result<(T, Option<Weekday>), ParseError> {
+        let mut remainder = trim_whitespace(source);
+        let mut offset = 0;
+        let mut items = T::default();
+        let mut weekday = None;
+
+        loop {
+            let item = find_weekday_item(remainder);
+            let chunk = item
+                .as_ref()
+                .map_or(remainder, |item| &remainder[..item.start]);
+            let trimmed = trim_whitespace(chunk);
+            // Without any day of the week item, the source string is parsed as a whole, so empty
+            // source strings are still an error
+            if !trimmed.is_empty() || (item.is_none() && weekday.is_none()) {
+                let leading = chunk.len() - chunk.trim_start_matches(util::is_whitespace).len();
+                let parsed = parse(self, trimmed)
+                    .map_err(|error| shift_error_position(error, offset + leading))?;
+                items = add(items, parsed);
+            }
+
+            match item {
+                Some(item) if weekday.is_some() => {
+                    return Err(ParseError::Syntax(
+                        offset + item.start,
+                        "Multiple days of the week".to_owned(),
+                    ));
+                }
+                Some(item) => {
+                    weekday = Some((item.ordinal, item.weekday));
+                    offset += item.end;
+                    remainder = &remainder[item.end..];
+                }
+                None => break Ok((items, weekday)),
+            }
+        }
+    }
+
+    fn parse_fuzzy_trimmed(&self, trimmed: &str) -> Result<(i64, i64, Duration), ParseError> {
         let mut duration = Duration::ZERO;
         let mut years = 0i64;
         let mut months = 0i64;
@@ -697,6 +1007,70 @@ impl<'a> RelativeTimeParser<'a> {
             }
         }
     }
+
+    /// Parse the `source` string into a [`RelativeDuration`] keeping calendar time units symbolic
+    ///
+    /// Similar to [`RelativeTimeParser::parse_fuzzy`] but additionally the `days`, `weeks` and
+    /// `fortnights` time units and the keywords `yesterday`, `tomorrow`, `today` and `now` are
+    /// kept symbolic as calendar days and weeks. The returned [`RelativeDuration`] can be applied
+    /// to a [`DateTime`] (or `chrono` and `time` date times) with
+    /// [`RelativeDuration::checked_add_to`]. Note that applying a [`RelativeDuration`] clamps the
+    /// day to the end of the month (`2023-01-31` plus `1 month` is `2023-02-28`), unlike
+    /// [`RelativeTimeParser::parse_with_date`] which follows the gnu behaviour to carry the
+    /// remaining days into the next month (`2023-01-31` plus `1 month` is `2023-03-03`).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if an error during the parsing process occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_gnu::{DateTime, Duration, RelativeDuration, RelativeTimeParser};
+    ///
+    /// let parser = RelativeTimeParser::new();
+    /// assert_eq!(
+    ///     parser.parse_relative("1 year 2 months 3 fortnights yesterday 4 hours"),
+    ///     Ok(RelativeDuration::new(
+    ///         1,
+    ///         2,
+    ///         6,
+    ///         -1,
+    ///         Duration::positive(4 * 60 * 60, 0)
+    ///     ))
+    /// );
+    ///
+    /// let relative = parser.parse_relative("1 month").unwrap();
+    /// let date_time = DateTime::from_gregorian_date_time(2023, 1, 31, 0, 0, 0, 0);
+    /// assert_eq!(
+    ///     relative.checked_add_to(&date_time),
+    ///     Some(DateTime::from_gregorian_date_time(2023, 2, 28, 0, 0, 0, 0))
+    /// );
+    /// ```
+    #[allow(clippy::missing_panics_doc)]
+    pub fn parse_relative(&self, source: &str) -> Result<RelativeDuration, ParseError> {
+        self.parse_relative_trimmed(trim_whitespace(source))
+    }
+
+    fn parse_relative_trimmed(&self, trimmed: &str) -> Result<RelativeDuration, ParseError> {
+        let mut relative = RelativeDuration::ZERO;
+        let mut parser = &mut ReprParserMultiple::new(trimmed);
+
+        loop {
+            let (duration_repr, maybe_parser) = parser.parse(
+                &self.raw.config,
+                &TIME_UNITS,
+                Some(&TIME_KEYWORDS),
+                Some(&NUMERALS),
+            )?;
+
+            relative = relative.saturating_add(DurationReprParser(duration_repr).parse_relative()?);
+            match maybe_parser {
+                Some(p) => parser = p,
+                None => break Ok(relative),
+            }
+        }
+    }
 }
 
 impl<'a> Default for RelativeTimeParser<'a> {
@@ -705,6 +1079,21 @@ impl<'a> Default for RelativeTimeParser<'a> {
     }
 }
 
+impl ParseDuration for RelativeTimeParser<'_> {
+    fn parse_duration(&self, source: &str) -> Result<Duration, ParseError> {
+        self.parse(source)
+    }
+}
+
+impl ParseDurationPrefix for RelativeTimeParser<'_> {
+    fn parse_duration_prefix<'s>(
+        &self,
+        source: &'s str,
+    ) -> Result<(Duration, &'s str), ParseError> {
+        self.parse_prefix(source)
+    }
+}
+
 /// This struct is used internally to hold the time units used by gnu
 struct TimeUnits {}
 
@@ -935,6 +1324,35 @@ pub fn parse_with_date(source: &str, date: Option<DateTime>) -> Result<Duration,
     PARSER.parse_with_date(source, date)
 }
 
+/// Parse the `source` string into a [`Duration`] relative to the [`ZonedDateTime`] `date`
+///
+/// Calendar time units like `month` or `day` are applied to the local wall-clock time of the
+/// `date`. This method is equivalent to [`RelativeTimeParser::parse_with_zoned_date`]. See also the
+/// documentation of [`RelativeTimeParser::parse_with_zoned_date`].
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if an error during the parsing process occurred or the calculation
+/// of the resulting date overflows.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_gnu::{parse_with_zoned_date, DateTime, Duration, TimeZone, ZonedDateTime};
+///
+/// // The clock is turned back on 2023-10-29
+/// let zone = TimeZone::from_tz_string("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+/// let local = DateTime::from_gregorian_date_time(2023, 10, 28, 12, 0, 0, 0);
+/// let date = ZonedDateTime::from_local(local, zone).unwrap();
+/// assert_eq!(
+///     parse_with_zoned_date("tomorrow", &date),
+///     Ok(Duration::positive(25 * 60 * 60, 0))
+/// );
+/// ```
+pub fn parse_with_zoned_date(source: &str, date: &ZonedDateTime) -> Result<Duration, ParseError> {
+    PARSER.parse_with_zoned_date(source, date)
+}
+
 /// Parse the `source` string extracting `year` and `month` time units from the [`Duration`]
 ///
 /// Unlike [`RelativeTimeParser::parse`] and [`RelativeTimeParser::parse_with_date`] this method
@@ -970,6 +1388,103 @@ pub fn parse_fuzzy(source: &str) -> Result<(i64, i64, Duration), ParseError> {
     PARSER.parse_fuzzy(source)
 }
 
+/// Parse the `source` string into a [`RelativeDuration`] keeping calendar time units symbolic
+///
+/// This method is equivalent to [`RelativeTimeParser::parse_relative`]. See also the documentation
+/// of [`RelativeTimeParser::parse_relative`].
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if an error during the parsing process occurred.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_gnu::{parse_relative, Duration, RelativeDuration};
+///
+/// assert_eq!(
+///     parse_relative("2hours +123month -10years 2 days ago"),
+///     Ok(RelativeDuration::new(
+///         -10,
+///         123,
+///         0,
+///         -2,
+///         Duration::positive(2 * 60 * 60, 0)
+///     ))
+/// );
+/// ```
+pub fn parse_relative(source: &str) -> Result<RelativeDuration, ParseError> {
+    PARSER.parse_relative(source)
+}
+
+/// Parse the `source` string into a [`DateTime`] like gnu's `date -d`
+///
+/// Any leading and trailing whitespace is ignored. Missing parts of the date and time are taken
+/// from the system time of `now` in UTC +0.
+///
+/// This method is equivalent to [`DateTimeParser::parse`]. See also the documentation of
+/// [`DateTimeParser`] for a description of the format.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if an error during the parsing process occurred or the resulting
+/// [`DateTime`] overflows
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_gnu::{parse_date_time, DateTime};
+///
+/// assert_eq!(
+///     parse_date_time("2023-10-16 14:00:00 +0200"),
+///     Ok(DateTime::from_gregorian_date_time(
+///         2023, 10, 16, 12, 0, 0, 0
+///     ))
+/// );
+/// assert_eq!(
+///     parse_date_time("@1697414400"),
+///     Ok(DateTime::from_gregorian_date_time(2023, 10, 16, 0, 0, 0, 0))
+/// );
+/// ```
+pub fn parse_date_time(source: &str) -> Result<DateTime, ParseError> {
+    DATE_TIME_PARSER.parse(source)
+}
+
+/// Parse the `source` string into a [`DateTime`] relative to the optionally given `date`
+///
+/// If the `date` is `None`, then the system time of `now` is assumed. Any leading and trailing
+/// whitespace is ignored.
+///
+/// This method is equivalent to [`DateTimeParser::parse_with_date`]. See also the documentation
+/// of [`DateTimeParser`] for a description of the format.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if an error during the parsing process occurred or the resulting
+/// [`DateTime`] overflows
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_gnu::{parse_date_time_with_date, DateTime};
+///
+/// let date = DateTime::from_gregorian_date_time(2023, 10, 16, 10, 0, 0, 0);
+/// assert_eq!(
+///     parse_date_time_with_date("tomorrow 9am", Some(date)),
+///     Ok(DateTime::from_gregorian_date_time(2023, 10, 17, 9, 0, 0, 0))
+/// );
+/// assert_eq!(
+///     parse_date_time_with_date("friday 10/20 +1 week", Some(date)),
+///     Ok(DateTime::from_gregorian_date_time(2023, 10, 27, 0, 0, 0, 0))
+/// );
+/// ```
+pub fn parse_date_time_with_date(
+    source: &str,
+    date: Option<DateTime>,
+) -> Result<DateTime, ParseError> {
+    DATE_TIME_PARSER.parse_with_date(source, date)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -979,6 +1494,16 @@ mod tests {
         assert_eq!(RelativeTimeParser::new(), RelativeTimeParser::default());
     }
 
+    #[test]
+    fn test_relative_time_parser_with_clock_and_set_clock() {
+        let clock = FixedClock::new(DateTime::UNIX_EPOCH);
+        let mut parser = RelativeTimeParser::new();
+        parser.set_clock(&clock);
+
+        assert_eq!(parser, RelativeTimeParser::with_clock(&clock));
+        assert_ne!(parser, RelativeTimeParser::new());
+    }
+
     #[test]
     fn test_time_units_is_empty_returns_false() {
         assert!(!TimeUnits {}.is_empty());
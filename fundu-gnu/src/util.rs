@@ -5,6 +5,8 @@
 
 use std::time::SystemTime;
 
+use fundu_core::error::ParseError;
+
 pub(crate) const fn floor_div(lhs: i64, rhs: i64) -> i64 {
     let d = lhs / rhs;
     let r = lhs % rhs;
@@ -15,6 +17,17 @@ pub(crate) const fn floor_div(lhs: i64, rhs: i64) -> i64 {
     }
 }
 
+/// Shift the position of [`ParseError::Syntax`] and [`ParseError::TimeUnit`] errors by `offset`
+///
+/// This is useful when only a part of the source string, starting at `offset`, was parsed.
+pub(crate) fn shift_error_position(error: ParseError, offset: usize) -> ParseError {
+    match error {
+        ParseError::Syntax(position, message) => ParseError::Syntax(offset + position, message),
+        ParseError::TimeUnit(position, message) => ParseError::TimeUnit(offset + position, message),
+        error => error,
+    }
+}
+
 // cov:excl-start
 #[inline]
 #[cfg(all(not(test), not(miri)))]
@@ -28,6 +41,12 @@ pub(crate) fn now() -> SystemTime {
     SystemTime::UNIX_EPOCH
 }
 
+/// Return true if `c` is a whitespace character in the posix definition
+#[inline]
+pub(crate) const fn is_whitespace(c: char) -> bool {
+    c == ' ' || (c as u32).wrapping_sub(9) < 5
+}
+
 // This is a faster alternative to str::trim_matches. We're exploiting that we're using the posix
 // definition of whitespace which only contains ascii characters as whitespace
 pub(crate) fn trim_whitespace(source: &str) -> &str {
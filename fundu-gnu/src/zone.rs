@@ -0,0 +1,1138 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::time::Duration as StdDuration;
+use std::{env, fs, io};
+
+use fundu_core::relative::{days_in_month, is_leap_year};
+use fundu_core::time::Duration;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{DateTime, JulianDay};
+
+const SECS_PER_MINUTE: i32 = 60;
+const SECS_PER_HOUR: i32 = 3600;
+const SECS_PER_DAY: i64 = 86_400;
+const UNIX_EPOCH_JULIAN_DAY: i64 = 2_440_588;
+
+const DEFAULT_ZONEINFO_DIR: &str = "/usr/share/zoneinfo";
+const LOCALTIME_PATH: &str = "/etc/localtime";
+
+const TZIF_MAGIC: &[u8] = b"TZif";
+const TZIF_HEADER_LEN: usize = 44;
+
+/// The transition time of posix TZ rules if not specified otherwise (`02:00:00`)
+const DEFAULT_RULE_TIME: i32 = 2 * SECS_PER_HOUR;
+/// The rules of posix TZ strings with daylight saving time but without explicit rules
+const DEFAULT_RULE_START: RuleDate = RuleDate::MonthWeekDay(3, 2, 0);
+const DEFAULT_RULE_END: RuleDate = RuleDate::MonthWeekDay(11, 1, 0);
+
+/// A fixed offset from UTC in seconds
+///
+/// Positive offsets are east of UTC (like `+01:00` for central european time) and negative offsets
+/// west of UTC. Like in the `TZif` format, the offset is limited to `-24:59:59` up to `+25:59:59`.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_gnu::UtcOffset;
+///
+/// let offset = UtcOffset::from_hms(-5, -30, 0).unwrap();
+/// assert_eq!(offset.as_seconds(), -(5 * 3600 + 30 * 60));
+///
+/// assert_eq!(UtcOffset::from_seconds(0), Some(UtcOffset::UTC));
+/// assert_eq!(UtcOffset::from_seconds(26 * 3600), None);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UtcOffset(i32);
+
+impl UtcOffset {
+    /// The offset of UTC +0
+    pub const UTC: Self = Self(0);
+
+    /// The minimum offset of `-24:59:59`
+    pub const MIN: Self = Self(-89_999);
+
+    /// The maximum offset of `+25:59:59`
+    pub const MAX: Self = Self(93_599);
+
+    /// Create a new `UtcOffset` from `seconds` east of UTC
+    ///
+    /// Returns `None` if the `seconds` are out of bounds (see [`UtcOffset::MIN`] and
+    /// [`UtcOffset::MAX`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_gnu::UtcOffset;
+    ///
+    /// assert_eq!(UtcOffset::from_seconds(3600).unwrap().as_seconds(), 3600);
+    /// assert_eq!(UtcOffset::from_seconds(93_600), None);
+    /// ```
+    pub const fn from_seconds(seconds: i32) -> Option<Self> {
+        if Self::MIN.0 <= seconds && seconds <= Self::MAX.0 {
+            Some(Self(seconds))
+        } else {
+            None
+        }
+    }
+
+    /// Create a new `UtcOffset` from `hours`, `minutes` and `seconds` east of UTC
+    ///
+    /// All components need to have the same sign and `minutes` and `seconds` must be in the range
+    /// of `-59` to `59`. Returns `None` if the input is invalid or the offset is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_gnu::UtcOffset;
+    ///
+    /// assert_eq!(
+    ///     UtcOffset::from_hms(5, 30, 0),
+    ///     UtcOffset::from_seconds(5 * 3600 + 30 * 60)
+    /// );
+    /// assert_eq!(UtcOffset::from_hms(-5, 30, 0), None);
+    /// assert_eq!(UtcOffset::from_hms(0, 60, 0), None);
+    /// ```
+    pub const fn from_hms(hours: i8, minutes: i8, seconds: i8) -> Option<Self> {
+        if minutes <= -60 || minutes >= 60 || seconds <= -60 || seconds >= 60 {
+            return None;
+        }
+        let is_negative = hours < 0 || minutes < 0 || seconds < 0;
+        let is_positive = hours > 0 || minutes > 0 || seconds > 0;
+        if is_negative && is_positive {
+            return None;
+        }
+
+        Self::from_seconds(
+            hours as i32 * SECS_PER_HOUR + minutes as i32 * SECS_PER_MINUTE + seconds as i32,
+        )
+    }
+
+    /// Return the offset in seconds east of UTC
+    pub const fn as_seconds(self) -> i32 {
+        self.0
+    }
+
+    /// Return true if this offset is UTC +0
+    pub const fn is_utc(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Return the offset as [`Duration`]
+    pub(crate) fn as_duration(self) -> Duration {
+        Duration::from_std(
+            self.0 < 0,
+            StdDuration::from_secs(u64::from(self.0.unsigned_abs())),
+        )
+    }
+}
+
+/// The error returned when loading or reading a [`TimeZone`] fails
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TimeZoneError {
+    /// Reading the time zone file failed
+    Io(io::Error),
+    /// The time zone data is not in a valid `TZif` format
+    InvalidTzif(String),
+    /// The posix TZ string (like `CET-1CEST,M3.5.0,M10.5.0/3`) was invalid
+    InvalidTzString(String),
+    /// The time zone name is not a valid time zone name like `Europe/Berlin`
+    InvalidName(String),
+}
+
+impl Error for TimeZoneError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl Display for TimeZoneError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            Self::Io(error) => format!("Failed to read time zone: {error}"),
+            Self::InvalidTzif(reason) => format!("Invalid TZif data: {reason}"),
+            Self::InvalidTzString(tz) => format!("Invalid TZ string: '{tz}'"),
+            Self::InvalidName(name) => format!("Invalid time zone name: '{name}'"),
+        };
+        f.write_str(&msg)
+    }
+}
+
+impl From<io::Error> for TimeZoneError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// A date within a year of the rules of a posix TZ string
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum RuleDate {
+    /// `Jn`: The julian day `1 <= n <= 365` not counting the 29th of february
+    Julian(u16),
+    /// `n`: The zero-based day `0 <= n <= 365` counting the 29th of february
+    Ordinal(u16),
+    /// `Mm.w.d`: The day of the week `d` (sunday = `0`) of week `w` (`5` = last) in month `m`
+    MonthWeekDay(u8, u8, u8),
+}
+
+impl RuleDate {
+    /// Return the days since the unix epoch of this date in the given `year`
+    fn as_unix_days(self, year: i64) -> Option<i64> {
+        let first_day = JulianDay::try_from_gregorian(year, 1, 1)?.0 - UNIX_EPOCH_JULIAN_DAY;
+        match self {
+            Self::Julian(day) => {
+                let day = i64::from(day) - 1;
+                Some(first_day + day + i64::from(is_leap_year(year) && day >= 59))
+            }
+            Self::Ordinal(day) => Some(first_day + i64::from(day)),
+            Self::MonthWeekDay(month, week, weekday) => {
+                let first_of_month =
+                    JulianDay::try_from_gregorian(year, month, 1)?.0 - UNIX_EPOCH_JULIAN_DAY;
+                // The unix epoch was a thursday
+                let first_weekday = (first_of_month + 4).rem_euclid(7);
+                let mut day =
+                    (i64::from(weekday) - first_weekday).rem_euclid(7) + (i64::from(week) - 1) * 7;
+                if day >= i64::from(days_in_month(year, month)) {
+                    day -= 7;
+                }
+                Some(first_of_month + day)
+            }
+        }
+    }
+}
+
+/// The offset of a local time together with its abbreviation like `CEST`
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LocalTimeType {
+    offset: UtcOffset,
+    abbreviation: Option<Cow<'static, str>>,
+}
+
+impl LocalTimeType {
+    const fn new(offset: UtcOffset) -> Self {
+        Self {
+            offset,
+            abbreviation: None,
+        }
+    }
+}
+
+/// The daylight saving time part of a posix TZ string
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DstRule {
+    local: LocalTimeType,
+    start: RuleDate,
+    /// The local time of the transition in seconds in standard time
+    start_time: i32,
+    end: RuleDate,
+    /// The local time of the transition in seconds in daylight saving time
+    end_time: i32,
+}
+
+/// The rule of a posix TZ string like `CET-1CEST,M3.5.0,M10.5.0/3`
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PosixRule {
+    local: LocalTimeType,
+    dst: Option<DstRule>,
+}
+
+impl PosixRule {
+    fn parse(tz: &str) -> Option<Self> {
+        let mut parser = TzStringParser {
+            bytes: tz.as_bytes(),
+            position: 0,
+        };
+        let rule = parser.parse_rule()?;
+        (parser.position == parser.bytes.len()).then_some(rule)
+    }
+
+    fn local_time_type_at(&self, seconds: i64) -> &LocalTimeType {
+        match &self.dst {
+            Some(dst) if self.is_dst(dst, seconds).unwrap_or_default() => &dst.local,
+            _ => &self.local,
+        }
+    }
+
+    fn is_dst(&self, dst: &DstRule, seconds: i64) -> Option<bool> {
+        let offset = self.local.offset.as_seconds();
+        let local_days = seconds
+            .checked_add(i64::from(offset))?
+            .div_euclid(SECS_PER_DAY);
+        let (year, _, _) =
+            JulianDay(local_days.checked_add(UNIX_EPOCH_JULIAN_DAY)?).to_gregorian()?;
+
+        let start = dst
+            .start
+            .as_unix_days(year)?
+            .checked_mul(SECS_PER_DAY)?
+            .checked_add(i64::from(dst.start_time - offset))?;
+        let end = dst
+            .end
+            .as_unix_days(year)?
+            .checked_mul(SECS_PER_DAY)?
+            .checked_add(i64::from(dst.end_time - dst.local.offset.as_seconds()))?;
+
+        Some(if start < end {
+            start <= seconds && seconds < end
+        } else {
+            // Like on the southern hemisphere, daylight saving time spans the turn of the year
+            !(end <= seconds && seconds < start)
+        })
+    }
+}
+
+/// A minimal parser for posix TZ strings as they occur in the footer of `TZif` files
+struct TzStringParser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl TzStringParser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        let is_byte = self.peek() == Some(byte);
+        if is_byte {
+            self.position += 1;
+        }
+        is_byte
+    }
+
+    fn parse_rule(&mut self) -> Option<PosixRule> {
+        let abbreviation = self.parse_name()?;
+        // The posix offset is west of UTC, so the sign is inverted
+        let offset = UtcOffset::from_seconds(-self.parse_time(24)?)?;
+        let local = LocalTimeType {
+            offset,
+            abbreviation: Some(abbreviation),
+        };
+        if self.peek().is_none() {
+            return Some(PosixRule { local, dst: None });
+        }
+
+        let dst_abbreviation = self.parse_name()?;
+        let dst_offset = match self.peek() {
+            Some(b',') | None => UtcOffset::from_seconds(offset.as_seconds() + SECS_PER_HOUR)?,
+            Some(_) => UtcOffset::from_seconds(-self.parse_time(24)?)?,
+        };
+        let ((start, start_time), (end, end_time)) = if self.eat(b',') {
+            let start = self.parse_rule_date_time()?;
+            if !self.eat(b',') {
+                return None;
+            }
+            (start, self.parse_rule_date_time()?)
+        } else {
+            (
+                (DEFAULT_RULE_START, DEFAULT_RULE_TIME),
+                (DEFAULT_RULE_END, DEFAULT_RULE_TIME),
+            )
+        };
+
+        Some(PosixRule {
+            local,
+            dst: Some(DstRule {
+                local: LocalTimeType {
+                    offset: dst_offset,
+                    abbreviation: Some(dst_abbreviation),
+                },
+                start,
+                start_time,
+                end,
+                end_time,
+            }),
+        })
+    }
+
+    /// Parse a time zone abbreviation like `CET` or a quoted abbreviation like `<+03>`
+    ///
+    /// Returns the abbreviation without the quotes.
+    fn parse_name(&mut self) -> Option<Cow<'static, str>> {
+        let quoted = self.eat(b'<');
+        let start = self.position;
+        while self.peek().map_or(false, |b| {
+            b.is_ascii_alphabetic() || (quoted && (b.is_ascii_digit() || b == b'+' || b == b'-'))
+        }) {
+            self.position += 1;
+        }
+        let end = self.position;
+        if (quoted && !self.eat(b'>')) || end - start < 3 {
+            return None;
+        }
+        // The unwrap is safe because the abbreviation consists only of ascii characters
+        let name = std::str::from_utf8(&self.bytes[start..end]).unwrap();
+        Some(Cow::Owned(name.to_owned()))
+    }
+
+    fn parse_number(&mut self, max: i32) -> Option<i32> {
+        let start = self.position;
+        let mut number = 0i32;
+        while let Some(digit) = self.peek().filter(u8::is_ascii_digit) {
+            number = number
+                .checked_mul(10)?
+                .checked_add(i32::from(digit - b'0'))?;
+            self.position += 1;
+        }
+        (self.position > start && number <= max).then_some(number)
+    }
+
+    /// Parse a signed time `[+-]hh[:mm[:ss]]` with hours up to `max_hours` into seconds
+    fn parse_time(&mut self, max_hours: i32) -> Option<i32> {
+        let sign = if self.eat(b'-') {
+            -1_i32
+        } else {
+            self.eat(b'+');
+            1_i32
+        };
+        let mut seconds = self.parse_number(max_hours)? * SECS_PER_HOUR;
+        if self.eat(b':') {
+            seconds += self.parse_number(59)? * SECS_PER_MINUTE;
+            if self.eat(b':') {
+                seconds += self.parse_number(59)?;
+            }
+        }
+        Some(sign * seconds)
+    }
+
+    fn parse_rule_date_time(&mut self) -> Option<(RuleDate, i32)> {
+        let date = if self.eat(b'J') {
+            RuleDate::Julian(
+                u16::try_from(self.parse_number(365)?)
+                    .ok()
+                    .filter(|d| *d >= 1)?,
+            )
+        } else if self.eat(b'M') {
+            let month = self.parse_number(12)?;
+            if month < 1_i32 || !self.eat(b'.') {
+                return None;
+            }
+            let week = self.parse_number(5)?;
+            if week < 1_i32 || !self.eat(b'.') {
+                return None;
+            }
+            let weekday = self.parse_number(6)?;
+            RuleDate::MonthWeekDay(
+                u8::try_from(month).ok()?,
+                u8::try_from(week).ok()?,
+                u8::try_from(weekday).ok()?,
+            )
+        } else {
+            RuleDate::Ordinal(u16::try_from(self.parse_number(365)?).ok()?)
+        };
+
+        // RFC 8536 extends the hours of the transition time to the range of -167 to 167
+        let time = if self.eat(b'/') {
+            self.parse_time(167)?
+        } else {
+            DEFAULT_RULE_TIME
+        };
+        Some((date, time))
+    }
+}
+
+/// The counts of the header of a `TZif` file
+struct TzifHeader {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl TzifHeader {
+    fn parse(bytes: &[u8]) -> Result<Self, TimeZoneError> {
+        let header = bytes
+            .get(..TZIF_HEADER_LEN)
+            .ok_or_else(|| TimeZoneError::InvalidTzif("Unexpected end of data".to_owned()))?;
+        if &header[..4] != TZIF_MAGIC {
+            return Err(TimeZoneError::InvalidTzif("Invalid magic".to_owned()));
+        }
+
+        let count = |index: usize| -> usize {
+            let start = 20 + index * 4;
+            // The unwrap is safe because the slice has exactly 4 bytes
+            u32::from_be_bytes(header[start..start + 4].try_into().unwrap()) as usize
+        };
+        Ok(Self {
+            version: header[4],
+            isutcnt: count(0),
+            isstdcnt: count(1),
+            leapcnt: count(2),
+            timecnt: count(3),
+            typecnt: count(4),
+            charcnt: count(5),
+        })
+    }
+
+    /// The length of the data block following the header with the size of the transition times
+    fn data_len(&self, time_size: usize) -> usize {
+        self.timecnt * time_size
+            + self.timecnt
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * (time_size + 4)
+            + self.isstdcnt
+            + self.isutcnt
+    }
+}
+
+/// A time zone with a fixed offset or the rules of a `TZif` file like in `/usr/share/zoneinfo`
+///
+/// The time zone database is read only locally and never fetched over the network. The zone files
+/// are searched in the directory given by the `TZDIR` environment variable or else in
+/// `/usr/share/zoneinfo`. Leap seconds (like in the `right/` zones) are ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_gnu::{DateTime, TimeZone, UtcOffset};
+///
+/// let zone = TimeZone::fixed(UtcOffset::from_hms(2, 0, 0).unwrap());
+/// assert_eq!(
+///     zone.offset_at(&DateTime::UNIX_EPOCH),
+///     UtcOffset::from_seconds(7200).unwrap()
+/// );
+///
+/// // Central european time with daylight saving time
+/// let zone = TimeZone::from_tz_string("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+/// let summer = DateTime::from_gregorian_date_time(2023, 7, 1, 0, 0, 0, 0);
+/// let winter = DateTime::from_gregorian_date_time(2023, 12, 1, 0, 0, 0, 0);
+/// assert_eq!(zone.offset_at(&summer).as_seconds(), 7200);
+/// assert_eq!(zone.offset_at(&winter).as_seconds(), 3600);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeZone {
+    /// The transitions as seconds since the unix epoch and the local time starting at this time
+    transitions: Vec<(i64, LocalTimeType)>,
+    /// The local time before the first transition
+    initial: LocalTimeType,
+    /// The rule for all times after the last transition
+    rule: Option<PosixRule>,
+}
+
+impl TimeZone {
+    /// The time zone of UTC +0 with the abbreviation `UTC`
+    pub const UTC: Self = Self {
+        transitions: Vec::new(),
+        initial: LocalTimeType {
+            offset: UtcOffset::UTC,
+            abbreviation: Some(Cow::Borrowed("UTC")),
+        },
+        rule: None,
+    };
+
+    /// Create a new `TimeZone` with a fixed [`UtcOffset`]
+    ///
+    /// Unlike [`TimeZone::UTC`], the time zone has no abbreviation.
+    pub const fn fixed(offset: UtcOffset) -> Self {
+        Self {
+            transitions: Vec::new(),
+            initial: LocalTimeType::new(offset),
+            rule: None,
+        }
+    }
+
+    /// Create a new `TimeZone` from a posix TZ string like `EST5EDT,M3.2.0,M11.1.0`
+    ///
+    /// The format is the same as in the `TZ` environment variable and in the footer of `TZif`
+    /// files. Note that the offsets in TZ strings are west of UTC, so `EST5` is `UTC -5`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TimeZoneError::InvalidTzString`] if the `tz` string is invalid
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_gnu::{DateTime, TimeZone};
+    ///
+    /// let zone = TimeZone::from_tz_string("<+0530>-5:30").unwrap();
+    /// assert_eq!(
+    ///     zone.offset_at(&DateTime::UNIX_EPOCH).as_seconds(),
+    ///     5 * 3600 + 30 * 60
+    /// );
+    /// ```
+    pub fn from_tz_string(tz: &str) -> Result<Self, TimeZoneError> {
+        let rule =
+            PosixRule::parse(tz).ok_or_else(|| TimeZoneError::InvalidTzString(tz.to_owned()))?;
+        Ok(Self {
+            transitions: Vec::new(),
+            initial: rule.local.clone(),
+            rule: Some(rule),
+        })
+    }
+
+    /// Create a new `TimeZone` from the content of a `TZif` file (see [RFC 8536])
+    ///
+    /// All versions of the `TZif` format are supported. If present, the posix TZ string of the
+    /// footer is used for times after the last transition.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TimeZoneError::InvalidTzif`] if the `bytes` are not valid `TZif` data
+    ///
+    /// [RFC 8536]: https://datatracker.ietf.org/doc/html/rfc8536
+    #[allow(clippy::missing_panics_doc)]
+    pub fn from_tzif(bytes: &[u8]) -> Result<Self, TimeZoneError> {
+        let truncated = || TimeZoneError::InvalidTzif("Unexpected end of data".to_owned());
+
+        let header = TzifHeader::parse(bytes)?;
+        let (header, data, time_size, footer) = if header.version >= b'2' {
+            // Skip the version 1 data block, it's superseded by the version 2+ data block
+            let bytes = bytes
+                .get(TZIF_HEADER_LEN + header.data_len(4)..)
+                .ok_or_else(truncated)?;
+            let header = TzifHeader::parse(bytes)?;
+            let end = TZIF_HEADER_LEN + header.data_len(8);
+            let data = bytes.get(TZIF_HEADER_LEN..end).ok_or_else(truncated)?;
+            (header, data, 8, Some(&bytes[end..]))
+        } else {
+            let end = TZIF_HEADER_LEN + header.data_len(4);
+            let data = bytes.get(TZIF_HEADER_LEN..end).ok_or_else(truncated)?;
+            (header, data, 4, None)
+        };
+        if header.typecnt == 0 {
+            return Err(TimeZoneError::InvalidTzif("No local time types".to_owned()));
+        }
+
+        let (times, data) = data.split_at(header.timecnt * time_size);
+        let (indices, data) = data.split_at(header.timecnt);
+        let (types, data) = data.split_at(header.typecnt * 6);
+        let chars = &data[..header.charcnt];
+        let types = types
+            .chunks_exact(6)
+            .map(|chunk| {
+                // The unwrap is safe because the chunk has exactly 6 bytes
+                let seconds = i32::from_be_bytes(chunk[..4].try_into().unwrap());
+                let offset = UtcOffset::from_seconds(seconds).ok_or_else(|| {
+                    TimeZoneError::InvalidTzif(format!("Invalid utc offset: '{seconds}'"))
+                })?;
+                Ok(LocalTimeType {
+                    offset,
+                    abbreviation: Self::parse_abbreviation(chars, chunk[5])?,
+                })
+            })
+            .collect::<Result<Vec<LocalTimeType>, TimeZoneError>>()?;
+
+        let mut transitions = Vec::with_capacity(header.timecnt);
+        for (time, index) in times.chunks_exact(time_size).zip(indices) {
+            // The unwraps are safe because the chunks have exactly the size of the time
+            let time = if time_size == 8 {
+                i64::from_be_bytes(time.try_into().unwrap())
+            } else {
+                i64::from(i32::from_be_bytes(time.try_into().unwrap()))
+            };
+            if transitions.last().map_or(false, |(last, _)| *last >= time) {
+                return Err(TimeZoneError::InvalidTzif(
+                    "Transition times are not in ascending order".to_owned(),
+                ));
+            }
+            let local = types.get(usize::from(*index)).ok_or_else(|| {
+                TimeZoneError::InvalidTzif(format!("Invalid local time type index: '{index}'"))
+            })?;
+            transitions.push((time, local.clone()));
+        }
+
+        let rule = match footer {
+            Some(footer) => Self::parse_footer(footer)?,
+            None => None,
+        };
+        Ok(Self {
+            transitions,
+            initial: types[0].clone(),
+            rule,
+        })
+    }
+
+    /// Return the nul-terminated abbreviation starting at `index` in the abbreviation `chars`
+    ///
+    /// Empty abbreviations are `None`.
+    fn parse_abbreviation(
+        chars: &[u8],
+        index: u8,
+    ) -> Result<Option<Cow<'static, str>>, TimeZoneError> {
+        let invalid =
+            || TimeZoneError::InvalidTzif(format!("Invalid abbreviation index: '{index}'"));
+        let chars = chars.get(usize::from(index)..).ok_or_else(invalid)?;
+        let end = chars.iter().position(|b| *b == 0).unwrap_or(chars.len());
+        let abbreviation = std::str::from_utf8(&chars[..end]).map_err(|_| invalid())?;
+        Ok((!abbreviation.is_empty()).then(|| Cow::Owned(abbreviation.to_owned())))
+    }
+
+    /// Parse the footer `\n<posix TZ string>\n` of `TZif` files of version 2 and above
+    fn parse_footer(footer: &[u8]) -> Result<Option<PosixRule>, TimeZoneError> {
+        let invalid = || TimeZoneError::InvalidTzif("Invalid footer".to_owned());
+
+        let footer = footer.strip_prefix(b"\n").ok_or_else(invalid)?;
+        let end = footer
+            .iter()
+            .position(|b| *b == b'\n')
+            .ok_or_else(invalid)?;
+        if end == 0 {
+            return Ok(None);
+        }
+
+        let tz = std::str::from_utf8(&footer[..end]).map_err(|_| invalid())?;
+        PosixRule::parse(tz)
+            .map(Some)
+            .ok_or_else(|| TimeZoneError::InvalidTzif(format!("Invalid TZ string: '{tz}'")))
+    }
+
+    /// Read the `TZif` file at `path` into a new `TimeZone`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TimeZoneError::Io`] if the file could not be read and
+    /// [`TimeZoneError::InvalidTzif`] if the file is not a valid `TZif` file
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, TimeZoneError> {
+        Self::from_tzif(&fs::read(path)?)
+    }
+
+    /// Load the time zone with the given `name` like `Europe/Berlin` from the local time zone
+    /// database
+    ///
+    /// The zone files are searched in the directory of the `TZDIR` environment variable or in
+    /// `/usr/share/zoneinfo` if `TZDIR` is not set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TimeZoneError::InvalidName`] if the `name` is not a valid time zone name and the
+    /// errors of [`TimeZone::from_file`] if the zone file could not be read.
+    pub fn from_name(name: &str) -> Result<Self, TimeZoneError> {
+        let is_valid = !name.is_empty()
+            && !name.starts_with('/')
+            && name.split('/').all(|component| {
+                !component.is_empty()
+                    && component != ".."
+                    && component
+                        .bytes()
+                        .all(|b| b.is_ascii_alphanumeric() || b"_-+.".contains(&b))
+            });
+        if !is_valid {
+            return Err(TimeZoneError::InvalidName(name.to_owned()));
+        }
+
+        let dir = env::var_os("TZDIR")
+            .filter(|dir| !dir.is_empty())
+            .map_or_else(|| PathBuf::from(DEFAULT_ZONEINFO_DIR), PathBuf::from);
+        Self::from_file(dir.join(name))
+    }
+
+    /// Load the local time zone of the system
+    ///
+    /// Like the c library, the `TZ` environment variable is considered first. It may contain an
+    /// absolute path to a zone file, a time zone name like `Europe/Berlin` or a posix TZ string.
+    /// An empty `TZ` is UTC. Without `TZ`, the local time zone is read from `/etc/localtime`. If
+    /// this file doesn't exist, UTC is assumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TimeZoneError`] if the local time zone could not be loaded
+    pub fn local() -> Result<Self, TimeZoneError> {
+        match env::var_os("TZ") {
+            Some(tz) => {
+                let tz = tz
+                    .to_str()
+                    .ok_or_else(|| TimeZoneError::InvalidName(tz.to_string_lossy().into_owned()))?;
+                let tz = tz.strip_prefix(':').unwrap_or(tz);
+                if tz.is_empty() {
+                    Ok(Self::UTC)
+                } else if tz.starts_with('/') {
+                    Self::from_file(tz)
+                } else {
+                    Self::from_name(tz).or_else(|error| Self::from_tz_string(tz).map_err(|_| error))
+                }
+            }
+            None => match Self::from_file(LOCALTIME_PATH) {
+                Err(TimeZoneError::Io(error)) if error.kind() == io::ErrorKind::NotFound => {
+                    Ok(Self::UTC)
+                }
+                result => result,
+            },
+        }
+    }
+
+    /// Return the [`UtcOffset`] of this time zone at the given UTC `date_time`
+    pub fn offset_at(&self, date_time: &DateTime) -> UtcOffset {
+        self.offset_at_unix(date_time.as_unix_seconds_saturating())
+    }
+
+    /// Return the abbreviation of this time zone like `CEST` at the given UTC `date_time`
+    ///
+    /// The abbreviation is the one of the `TZif` file or posix TZ string. Time zones with a
+    /// [`TimeZone::fixed`] offset have no abbreviation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_gnu::{DateTime, TimeZone, UtcOffset};
+    ///
+    /// let zone = TimeZone::from_tz_string("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+    /// let summer = DateTime::from_gregorian_date_time(2023, 7, 1, 0, 0, 0, 0);
+    /// let winter = DateTime::from_gregorian_date_time(2023, 12, 1, 0, 0, 0, 0);
+    /// assert_eq!(zone.abbreviation_at(&summer), Some("CEST"));
+    /// assert_eq!(zone.abbreviation_at(&winter), Some("CET"));
+    ///
+    /// assert_eq!(TimeZone::UTC.abbreviation_at(&summer), Some("UTC"));
+    /// assert_eq!(
+    ///     TimeZone::fixed(UtcOffset::from_hms(2, 0, 0).unwrap()).abbreviation_at(&summer),
+    ///     None
+    /// );
+    /// ```
+    pub fn abbreviation_at(&self, date_time: &DateTime) -> Option<&str> {
+        self.local_time_type_at_unix(date_time.as_unix_seconds_saturating())
+            .abbreviation
+            .as_deref()
+    }
+
+    /// Return the [`UtcOffset`] of this time zone at the given local wall-clock `date_time`
+    ///
+    /// Local times can be ambiguous when the clock is turned back, in which case the earlier
+    /// offset is returned. Local times which don't exist because the clock is turned forward
+    /// resolve with the offset before the transition, so the time moves forward by the length of
+    /// the gap.
+    pub fn offset_at_local(&self, date_time: &DateTime) -> UtcOffset {
+        let local = date_time.as_unix_seconds_saturating();
+        let before = self.offset_at_unix(local.saturating_sub(SECS_PER_DAY));
+        let after = self.offset_at_unix(local.saturating_add(SECS_PER_DAY));
+
+        let is_valid = |offset: UtcOffset| {
+            self.offset_at_unix(local.saturating_sub(i64::from(offset.as_seconds()))) == offset
+        };
+        match (is_valid(before), is_valid(after)) {
+            // The earlier utc time has the greater offset
+            (true, true) => before.max(after),
+            (false, true) => after,
+            _ => before,
+        }
+    }
+
+    /// Convert the UTC `date_time` into the local wall-clock time of this time zone
+    pub(crate) fn utc_to_local(&self, date_time: DateTime) -> Option<DateTime> {
+        date_time.checked_add_duration(&self.offset_at(&date_time).as_duration())
+    }
+
+    /// Convert the local wall-clock `date_time` of this time zone into UTC
+    pub(crate) fn local_to_utc(&self, date_time: DateTime) -> Option<DateTime> {
+        date_time.checked_sub_duration(&self.offset_at_local(&date_time).as_duration())
+    }
+
+    fn offset_at_unix(&self, seconds: i64) -> UtcOffset {
+        self.local_time_type_at_unix(seconds).offset
+    }
+
+    fn local_time_type_at_unix(&self, seconds: i64) -> &LocalTimeType {
+        let index = self
+            .transitions
+            .partition_point(|(time, _)| *time <= seconds);
+        match (index, &self.rule) {
+            (index, Some(rule)) if index == self.transitions.len() => {
+                rule.local_time_type_at(seconds)
+            }
+            (0, _) => &self.initial,
+            (index, _) => &self.transitions[index - 1].1,
+        }
+    }
+}
+
+impl Default for TimeZone {
+    fn default() -> Self {
+        Self::UTC
+    }
+}
+
+impl From<UtcOffset> for TimeZone {
+    fn from(offset: UtcOffset) -> Self {
+        Self::fixed(offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Build a `TZif` file of version `2` with the given transitions and local time types
+    fn tzif_v2(transitions: &[(i64, u8)], offsets: &[i32], footer: &str) -> Vec<u8> {
+        let types = offsets
+            .iter()
+            .map(|offset| (*offset, 0))
+            .collect::<Vec<_>>();
+        tzif_v2_with_abbreviations(transitions, &types, b"\0", footer)
+    }
+
+    /// Like [`tzif_v2`] but the local time types have an index into the abbreviation `chars`
+    fn tzif_v2_with_abbreviations(
+        transitions: &[(i64, u8)],
+        types: &[(i32, u8)],
+        chars: &[u8],
+        footer: &str,
+    ) -> Vec<u8> {
+        let header = |time_count: usize, type_count: usize, char_count: usize| {
+            let mut header = b"TZif2".to_vec();
+            header.extend_from_slice(&[0; 15]);
+            for count in [0, 0, 0, time_count, type_count, char_count] {
+                header.extend_from_slice(&u32::try_from(count).unwrap().to_be_bytes());
+            }
+            header
+        };
+
+        // A minimal version 1 data block with a single local time type
+        let mut bytes = header(0, 1, 1);
+        bytes.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0]);
+
+        bytes.extend(header(transitions.len(), types.len(), chars.len()));
+        for (time, _) in transitions {
+            bytes.extend_from_slice(&time.to_be_bytes());
+        }
+        bytes.extend(transitions.iter().map(|(_, index)| *index));
+        for (offset, abbreviation) in types {
+            bytes.extend_from_slice(&offset.to_be_bytes());
+            bytes.extend_from_slice(&[0, *abbreviation]);
+        }
+        bytes.extend_from_slice(chars);
+        bytes.extend_from_slice(format!("\n{footer}\n").as_bytes());
+        bytes
+    }
+
+    fn utc(year: i64, month: u8, day: u8, hour: u8, minute: u8) -> DateTime {
+        DateTime::from_gregorian_date_time(year, month, day, hour, minute, 0, 0)
+    }
+
+    #[rstest]
+    #[case::zero(0, 0, 0, Some(UtcOffset(0)))]
+    #[case::positive(5, 30, 0, Some(UtcOffset(19_800)))]
+    #[case::negative(-5, -30, -1, Some(UtcOffset(-19_801)))]
+    #[case::max(25, 59, 59, Some(UtcOffset(93_599)))]
+    #[case::min(-24, -59, -59, Some(UtcOffset(-89_999)))]
+    #[case::too_large(26, 0, 0, None)]
+    #[case::too_small(-25, 0, 0, None)]
+    #[case::mixed_signs(1, -1, 0, None)]
+    #[case::invalid_minutes(0, 60, 0, None)]
+    #[case::invalid_seconds(0, 0, -60, None)]
+    fn test_utc_offset_from_hms(
+        #[case] hours: i8,
+        #[case] minutes: i8,
+        #[case] seconds: i8,
+        #[case] expected: Option<UtcOffset>,
+    ) {
+        assert_eq!(UtcOffset::from_hms(hours, minutes, seconds), expected);
+    }
+
+    #[rstest]
+    #[case::utc("UTC0", utc(2023, 7, 1, 0, 0), UtcOffset(0))]
+    #[case::east("<+0530>-5:30", utc(2023, 7, 1, 0, 0), UtcOffset(19_800))]
+    #[case::west("EST5", utc(2023, 7, 1, 0, 0), UtcOffset(-18_000))]
+    #[case::default_rules_summer("EST5EDT", utc(2023, 7, 1, 0, 0), UtcOffset(-14_400))]
+    #[case::default_rules_winter("EST5EDT", utc(2023, 1, 1, 0, 0), UtcOffset(-18_000))]
+    #[case::before_dst_start(
+        "CET-1CEST,M3.5.0,M10.5.0/3",
+        utc(2023, 3, 26, 0, 59),
+        UtcOffset(3600)
+    )]
+    #[case::at_dst_start("CET-1CEST,M3.5.0,M10.5.0/3", utc(2023, 3, 26, 1, 0), UtcOffset(7200))]
+    #[case::before_dst_end(
+        "CET-1CEST,M3.5.0,M10.5.0/3",
+        utc(2023, 10, 29, 0, 59),
+        UtcOffset(7200)
+    )]
+    #[case::at_dst_end("CET-1CEST,M3.5.0,M10.5.0/3", utc(2023, 10, 29, 1, 0), UtcOffset(3600))]
+    #[case::southern_summer(
+        "AEST-10AEDT,M10.1.0,M4.1.0/3",
+        utc(2023, 1, 1, 0, 0),
+        UtcOffset(39_600)
+    )]
+    #[case::southern_winter(
+        "AEST-10AEDT,M10.1.0,M4.1.0/3",
+        utc(2023, 7, 1, 0, 0),
+        UtcOffset(36_000)
+    )]
+    #[case::julian_day("XXX3YYY,J60/0,J300/0", utc(2024, 3, 1, 3, 0), UtcOffset(-7200))]
+    #[case::julian_day_before("XXX3YYY,J60/0,J300/0", utc(2024, 2, 29, 3, 0), UtcOffset(-10_800))]
+    #[case::ordinal_day("XXX3YYY,59/0,300/0", utc(2024, 2, 29, 3, 0), UtcOffset(-7200))]
+    #[case::all_year_dst("EST5EDT,0/0,J365/25", utc(2023, 12, 31, 12, 0), UtcOffset(-14_400))]
+    #[case::negative_time("XXX3YYY,M3.2.0/-1,M11.1.0", utc(2023, 3, 12, 2, 0), UtcOffset(-7200))]
+    fn test_time_zone_from_tz_string(
+        #[case] tz: &str,
+        #[case] date_time: DateTime,
+        #[case] expected: UtcOffset,
+    ) {
+        let zone = TimeZone::from_tz_string(tz).unwrap();
+        assert_eq!(zone.offset_at(&date_time), expected);
+    }
+
+    #[rstest]
+    #[case::no_dst("EST5", utc(2023, 7, 1, 0, 0), "EST")]
+    #[case::quoted("<+0530>-5:30", utc(2023, 7, 1, 0, 0), "+0530")]
+    #[case::dst("CET-1CEST,M3.5.0,M10.5.0/3", utc(2023, 7, 1, 0, 0), "CEST")]
+    #[case::standard("CET-1CEST,M3.5.0,M10.5.0/3", utc(2023, 12, 1, 0, 0), "CET")]
+    fn test_time_zone_abbreviation_at_with_tz_string(
+        #[case] tz: &str,
+        #[case] date_time: DateTime,
+        #[case] expected: &str,
+    ) {
+        let zone = TimeZone::from_tz_string(tz).unwrap();
+        assert_eq!(zone.abbreviation_at(&date_time), Some(expected));
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::name_too_short("AB1")]
+    #[case::missing_offset("UTC")]
+    #[case::offset_too_large("XXX25")]
+    #[case::missing_end_rule("CET-1CEST,M3.5.0")]
+    #[case::invalid_month("CET-1CEST,M13.5.0,M10.5.0")]
+    #[case::invalid_week("CET-1CEST,M3.6.0,M10.5.0")]
+    #[case::invalid_weekday("CET-1CEST,M3.5.7,M10.5.0")]
+    #[case::invalid_julian_day("CET-1CEST,J0,M10.5.0")]
+    #[case::trailing_garbage("UTC0 ")]
+    #[case::unclosed_quote("<+03-3")]
+    fn test_time_zone_from_tz_string_when_invalid(#[case] tz: &str) {
+        assert!(matches!(
+            TimeZone::from_tz_string(tz),
+            Err(TimeZoneError::InvalidTzString(_))
+        ));
+    }
+
+    #[test]
+    fn test_time_zone_from_tzif() {
+        // Europe/Berlin around 2023 with the rule of the footer after the last transition
+        let bytes = tzif_v2(
+            &[(1_679_792_400, 1), (1_698_541_200, 0)],
+            &[3600_i32, 7200_i32],
+            "CET-1CEST,M3.5.0,M10.5.0/3",
+        );
+        let zone = TimeZone::from_tzif(&bytes).unwrap();
+
+        assert_eq!(zone.offset_at(&utc(2000, 1, 1, 0, 0)), UtcOffset(3600));
+        assert_eq!(zone.offset_at(&utc(2023, 3, 26, 0, 59)), UtcOffset(3600));
+        assert_eq!(zone.offset_at(&utc(2023, 3, 26, 1, 0)), UtcOffset(7200));
+        assert_eq!(zone.offset_at(&utc(2023, 10, 29, 1, 0)), UtcOffset(3600));
+        // From the footer
+        assert_eq!(zone.offset_at(&utc(2030, 7, 1, 0, 0)), UtcOffset(7200));
+        assert_eq!(zone.offset_at(&utc(2030, 12, 1, 0, 0)), UtcOffset(3600));
+    }
+
+    #[test]
+    fn test_time_zone_from_tzif_without_footer_rule() {
+        let bytes = tzif_v2(&[(0, 1)], &[0_i32, -3600_i32], "");
+        let zone = TimeZone::from_tzif(&bytes).unwrap();
+
+        assert_eq!(zone.offset_at(&utc(1969, 12, 31, 23, 59)), UtcOffset(0));
+        assert_eq!(zone.offset_at(&utc(1970, 1, 1, 0, 0)), UtcOffset(-3600));
+        assert_eq!(zone.offset_at(&utc(3000, 1, 1, 0, 0)), UtcOffset(-3600));
+    }
+
+    #[test]
+    fn test_time_zone_abbreviation_at_with_tzif() {
+        let bytes = tzif_v2_with_abbreviations(
+            &[(1_679_792_400, 1), (1_698_541_200, 0)],
+            &[(3600_i32, 0), (7200_i32, 4)],
+            b"CET\0CEST\0",
+            "",
+        );
+        let zone = TimeZone::from_tzif(&bytes).unwrap();
+
+        assert_eq!(zone.abbreviation_at(&utc(2000, 1, 1, 0, 0)), Some("CET"));
+        assert_eq!(zone.abbreviation_at(&utc(2023, 7, 1, 0, 0)), Some("CEST"));
+        assert_eq!(zone.abbreviation_at(&utc(2023, 12, 1, 0, 0)), Some("CET"));
+        // Empty abbreviations are not set
+        let zone = TimeZone::from_tzif(&tzif_v2(&[], &[0_i32], "")).unwrap();
+        assert_eq!(zone.abbreviation_at(&utc(2000, 1, 1, 0, 0)), None);
+    }
+
+    #[rstest]
+    #[case::empty(Vec::new())]
+    #[case::invalid_abbreviation_index(tzif_v2_with_abbreviations(&[], &[(0_i32, 5)], b"UTC\0", ""))]
+    #[case::invalid_magic(b"TZip".repeat(20))]
+    #[case::truncated(tzif_v2(&[(0, 0)], &[0_i32], "")[..80].to_vec())]
+    #[case::invalid_index(tzif_v2(&[(0, 1)], &[0_i32], ""))]
+    #[case::invalid_offset(tzif_v2(&[], &[100_000_i32], ""))]
+    #[case::not_ascending(tzif_v2(&[(10, 0), (10, 0)], &[0_i32], ""))]
+    #[case::invalid_footer(tzif_v2(&[], &[0_i32], "CET"))]
+    fn test_time_zone_from_tzif_when_invalid(#[case] bytes: Vec<u8>) {
+        assert!(matches!(
+            TimeZone::from_tzif(&bytes),
+            Err(TimeZoneError::InvalidTzif(_))
+        ));
+    }
+
+    #[rstest]
+    #[case::normal(utc(2023, 7, 1, 12, 0), UtcOffset(7200))]
+    #[case::ambiguous_takes_earlier(utc(2023, 10, 29, 2, 30), UtcOffset(7200))]
+    #[case::after_ambiguous(utc(2023, 10, 29, 3, 0), UtcOffset(3600))]
+    #[case::gap_takes_offset_before(utc(2023, 3, 26, 2, 30), UtcOffset(3600))]
+    #[case::after_gap(utc(2023, 3, 26, 3, 0), UtcOffset(7200))]
+    fn test_time_zone_offset_at_local(#[case] local: DateTime, #[case] expected: UtcOffset) {
+        let zone = TimeZone::from_tz_string("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+        assert_eq!(zone.offset_at_local(&local), expected);
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::absolute("/etc/passwd")]
+    #[case::parent("../etc/passwd")]
+    #[case::parent_in_between("Europe/../../etc/passwd")]
+    #[case::invalid_character("Europe/Berlin\0")]
+    fn test_time_zone_from_name_when_invalid(#[case] name: &str) {
+        assert!(matches!(
+            TimeZone::from_name(name),
+            Err(TimeZoneError::InvalidName(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_time_zone_from_name_with_system_database() {
+        // The time zone database is not installed everywhere
+        if !Path::new(DEFAULT_ZONEINFO_DIR)
+            .join("Europe/Berlin")
+            .exists()
+            || env::var_os("TZDIR").is_some()
+        {
+            return;
+        }
+
+        let zone = TimeZone::from_name("Europe/Berlin").unwrap();
+        assert_eq!(zone.offset_at(&utc(2023, 7, 1, 0, 0)), UtcOffset(7200));
+        assert_eq!(zone.offset_at(&utc(2023, 12, 1, 0, 0)), UtcOffset(3600));
+        assert_eq!(zone.offset_at(&utc(2100, 7, 1, 0, 0)), UtcOffset(7200));
+        assert!(matches!(
+            TimeZone::from_name("Does/Not/Exist"),
+            Err(TimeZoneError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_time_zone_error_display() {
+        assert_eq!(
+            TimeZoneError::InvalidName("../x".to_owned()).to_string(),
+            "Invalid time zone name: '../x'"
+        );
+        assert_eq!(
+            TimeZoneError::InvalidTzString("X".to_owned()).to_string(),
+            "Invalid TZ string: 'X'"
+        );
+        assert_eq!(
+            TimeZoneError::InvalidTzif("Invalid magic".to_owned()).to_string(),
+            "Invalid TZif data: Invalid magic"
+        );
+    }
+}
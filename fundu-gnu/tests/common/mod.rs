@@ -0,0 +1,44 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! The reference outputs of gnu's `date -d` shared by the test runners of the parsers
+
+use fundu_gnu::{DateTime, Duration};
+
+/// The reference outputs of gnu's `date -d` (see `fixtures/generate_gnu_date.sh`)
+pub const GNU_DATE_FIXTURE: &str = include_str!("../fixtures/gnu_date.txt");
+
+/// The time of `now` of the gnu date fixture
+pub const GNU_DATE_NOW: DateTime =
+    DateTime::from_gregorian_date_time(2023, 10, 16, 12, 34, 56, 789_012_345);
+
+/// The known differences to gnu's `date -d` of the `RelativeTimeParser` and `DateTimeParser`
+const GNU_DATE_DIFFERENCES: &str = include_str!("../fixtures/gnu_date_differences.txt");
+
+/// Return the inputs of the known differences to gnu's `date -d` of the `parser`
+pub fn known_gnu_date_differences(parser: &str) -> Vec<String> {
+    GNU_DATE_DIFFERENCES
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .map(|line| line.split('\t').collect::<Vec<&str>>())
+        .filter(|fields| fields[0] == "all" || fields[0] == parser)
+        .map(|fields| fields[1].to_owned())
+        .collect()
+}
+
+/// Parse the output of `date +%s.%N` prefixed with `@` into a `DateTime`
+pub fn parse_unix_seconds(source: &str) -> DateTime {
+    let (seconds, nanos) = source.trim_start_matches('@').split_once('.').unwrap();
+    let seconds: i64 = seconds.parse().unwrap();
+    let duration = if seconds < 0 {
+        Duration::negative(seconds.unsigned_abs(), 0)
+    } else {
+        Duration::positive(seconds.unsigned_abs(), 0)
+    };
+    DateTime::UNIX_EPOCH
+        .checked_add_duration(&duration)
+        .and_then(|d| d.checked_add_duration(&Duration::positive(0, nanos.parse().unwrap())))
+        .unwrap()
+}
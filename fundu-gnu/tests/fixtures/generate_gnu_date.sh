@@ -0,0 +1,51 @@
+#!/bin/sh
+# Generate the reference outputs of gnu's `date -d` in `gnu_date.txt`
+#
+# Requires gnu coreutils and a c compiler. The clock of `date` is fixed at
+# @1697459696.789012345 (Mon 2023-10-16 12:34:56.789012345 UTC) with a small `LD_PRELOAD` library
+# overriding `clock_gettime` and the time zone is fixed at UTC. Run this script from within the
+# fixtures directory after upgrading coreutils and review the diff of `gnu_date.txt`.
+
+set -e
+
+NOW_NSEC=1697459696789012345
+OUTPUT=gnu_date.txt
+TMP_DIR=$(mktemp -d)
+trap 'rm -rf "$TMP_DIR"' EXIT
+
+cat > "$TMP_DIR/fakeclock.c" << 'CODE'
+#include <stdlib.h>
+#include <time.h>
+int clock_gettime(clockid_t clk, struct timespec *ts) {
+    (void) clk;
+    unsigned long long nsec = strtoull(getenv("FAKE_NOW_NSEC"), NULL, 10);
+    ts->tv_sec = nsec / 1000000000ULL;
+    ts->tv_nsec = nsec % 1000000000ULL;
+    return 0;
+}
+CODE
+cc -shared -fPIC -o "$TMP_DIR/fakeclock.so" "$TMP_DIR/fakeclock.c"
+
+VERSION=$(date --version | head -n 1)
+
+{
+    echo "# Reference outputs of \`date -d\` ($VERSION)"
+    echo "#"
+    echo "# Generated with generate_gnu_date.sh. The clock was fixed at @1697459696.789012345"
+    echo "# (Mon 2023-10-16 12:34:56.789012345 UTC) and the time zone at UTC. Every line consists of"
+    echo "# the kind of the input (\`relative\` if it contains only relative items or \`absolute\`), the"
+    echo "# input and the seconds since the unix epoch printed by date (\`+%s.%N\`) or \`error\` separated"
+    echo "# by a tab. A tab within the input is written as \`\\\\t\` and a newline as \`\\\\n\`."
+    while IFS="$(printf '\t')" read -r kind input; do
+        case "$kind" in
+        '' | '#'*) continue ;;
+        esac
+        source=$(printf '%b' "$input")
+        if result=$(FAKE_NOW_NSEC=$NOW_NSEC LD_PRELOAD="$TMP_DIR/fakeclock.so" TZ=UTC0 \
+            date -d "$source" +%s.%N 2> /dev/null); then
+            printf '%s\t%s\t@%s\n' "$kind" "$input" "$result"
+        else
+            printf '%s\t%s\terror\n' "$kind" "$input"
+        fi
+    done < gnu_date.inputs
+} > "$OUTPUT"
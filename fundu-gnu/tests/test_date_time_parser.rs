@@ -0,0 +1,355 @@
+// spell-checker: ignore wednes thurs
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+mod common;
+
+use common::{known_gnu_date_differences, parse_unix_seconds, GNU_DATE_FIXTURE, GNU_DATE_NOW};
+use fundu_gnu::{
+    parse_date_time, parse_date_time_with_date, DateTime, DateTimeParser, FixedClock, ParseError,
+};
+use rstest::{fixture, rstest};
+
+/// Monday, 2023-10-16 10:20:30.5 UTC
+#[fixture]
+fn base() -> DateTime {
+    DateTime::from_gregorian_date_time(2023, 10, 16, 10, 20, 30, 500_000_000)
+}
+
+fn date_time(year: i64, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> DateTime {
+    DateTime::from_gregorian_date_time(year, month, day, hour, minute, second, 0)
+}
+
+#[rstest]
+#[case::empty("", date_time(2023, 10, 16, 0, 0, 0))]
+#[case::only_whitespace("  \n ", date_time(2023, 10, 16, 0, 0, 0))]
+#[case::iso_date("2023-10-17", date_time(2023, 10, 17, 0, 0, 0))]
+#[case::iso_date_two_digit_year("23-10-17", date_time(2023, 10, 17, 0, 0, 0))]
+#[case::iso_date_two_digit_year_20th_century("69-10-17", date_time(1969, 10, 17, 0, 0, 0))]
+#[case::iso_date_leap_day("2024-02-29", date_time(2024, 2, 29, 0, 0, 0))]
+#[case::us_date("10/17/2023", date_time(2023, 10, 17, 0, 0, 0))]
+#[case::us_date_without_year("12/24", date_time(2023, 12, 24, 0, 0, 0))]
+#[case::slash_date_year_first("2023/10/17", date_time(2023, 10, 17, 0, 0, 0))]
+#[case::day_month_year("17 Oct 2023", date_time(2023, 10, 17, 0, 0, 0))]
+#[case::day_month_year_with_dashes("17-Oct-2023", date_time(2023, 10, 17, 0, 0, 0))]
+#[case::day_month("17 october", date_time(2023, 10, 17, 0, 0, 0))]
+#[case::month_day_year("Oct 17 2023", date_time(2023, 10, 17, 0, 0, 0))]
+#[case::month_day_comma_year("October 17, 1999", date_time(1999, 10, 17, 0, 0, 0))]
+#[case::month_day_year_with_dashes("Oct-17-2023", date_time(2023, 10, 17, 0, 0, 0))]
+#[case::month_abbreviation_with_period("Sept. 1", date_time(2023, 9, 1, 0, 0, 0))]
+#[case::month_day("dec 24", date_time(2023, 12, 24, 0, 0, 0))]
+#[case::compact_date("20231017", date_time(2023, 10, 17, 0, 0, 0))]
+#[case::time("14:30", date_time(2023, 10, 16, 14, 30, 0))]
+#[case::time_with_seconds("14:30:59", date_time(2023, 10, 16, 14, 30, 59))]
+#[case::time_with_fraction(
+    "14:30:59.123",
+    DateTime::from_gregorian_date_time(2023, 10, 16, 14, 30, 59, 123_000_000)
+)]
+#[case::time_with_comma_fraction(
+    "14:30:59,1234567891",
+    DateTime::from_gregorian_date_time(2023, 10, 16, 14, 30, 59, 123_456_789)
+)]
+#[case::time_pm("2pm", date_time(2023, 10, 16, 14, 0, 0))]
+#[case::time_am_with_periods("2 a.m.", date_time(2023, 10, 16, 2, 0, 0))]
+#[case::time_twelve_am("12am", date_time(2023, 10, 16, 0, 0, 0))]
+#[case::time_twelve_pm("12:15 PM", date_time(2023, 10, 16, 12, 15, 0))]
+#[case::time_number_hhmm("1430", date_time(2023, 10, 16, 14, 30, 0))]
+#[case::time_number_hh("14", date_time(2023, 10, 16, 14, 0, 0))]
+#[case::date_and_time("2023-10-17 14:30:00", date_time(2023, 10, 17, 14, 30, 0))]
+#[case::iso_date_time("2023-10-17T14:30:00", date_time(2023, 10, 17, 14, 30, 0))]
+#[case::iso_date_time_zulu("2023-10-17T14:30:00Z", date_time(2023, 10, 17, 14, 30, 0))]
+#[case::year_after_time("Oct 17 14:30 2022", date_time(2022, 10, 17, 14, 30, 0))]
+#[case::zone_utc("14:30 UTC", date_time(2023, 10, 16, 14, 30, 0))]
+#[case::zone_est("14:30 EST", date_time(2023, 10, 16, 19, 30, 0))]
+#[case::zone_dst("14:30 CET DST", date_time(2023, 10, 16, 12, 30, 0))]
+#[case::zone_with_correction("14:30 UTC+2", date_time(2023, 10, 16, 12, 30, 0))]
+#[case::zone_half_hour("14:30 IST", date_time(2023, 10, 16, 9, 0, 0))]
+#[case::offset_hhmm("14:30 +0200", date_time(2023, 10, 16, 12, 30, 0))]
+#[case::offset_with_colon("14:30 -05:30", date_time(2023, 10, 16, 20, 0, 0))]
+#[case::offset_hours("14:30-3", date_time(2023, 10, 16, 17, 30, 0))]
+#[case::offset_date_change("2023-10-17 01:00 +0200", date_time(2023, 10, 16, 23, 0, 0))]
+#[case::zone_without_time("UTC", date_time(2023, 10, 16, 0, 0, 0))]
+#[case::zone_without_time_est("EST", date_time(2023, 10, 16, 5, 0, 0))]
+#[case::zone_and_relative(
+    "UTC 1 day",
+    DateTime::from_gregorian_date_time(2023, 10, 17, 10, 20, 30, 500_000_000)
+)]
+#[case::offset_after_hour("14 +02", date_time(2023, 10, 16, 12, 0, 0))]
+#[case::hour_and_signed_relative("14 +3 days", date_time(2023, 10, 19, 14, 0, 0))]
+#[case::weekday_same_day("monday", date_time(2023, 10, 16, 0, 0, 0))]
+#[case::weekday_later("friday", date_time(2023, 10, 20, 0, 0, 0))]
+#[case::weekday_earlier("sun", date_time(2023, 10, 22, 0, 0, 0))]
+#[case::weekday_with_comma("fri,", date_time(2023, 10, 20, 0, 0, 0))]
+#[case::weekday_abbreviation("wednes", date_time(2023, 10, 18, 0, 0, 0))]
+#[case::weekday_abbreviation_period("Thurs.", date_time(2023, 10, 19, 0, 0, 0))]
+#[case::next_weekday("next friday", date_time(2023, 10, 20, 0, 0, 0))]
+#[case::next_weekday_same_day("next monday", date_time(2023, 10, 23, 0, 0, 0))]
+#[case::last_weekday("last friday", date_time(2023, 10, 13, 0, 0, 0))]
+#[case::last_weekday_same_day("last monday", date_time(2023, 10, 9, 0, 0, 0))]
+#[case::this_weekday("this tuesday", date_time(2023, 10, 17, 0, 0, 0))]
+#[case::third_weekday("third wednesday", date_time(2023, 11, 1, 0, 0, 0))]
+#[case::number_weekday("2 sat", date_time(2023, 10, 28, 0, 0, 0))]
+#[case::weekday_and_time("friday 14:00", date_time(2023, 10, 20, 14, 0, 0))]
+#[case::weekday_ignored_with_date("friday 2023-10-17", date_time(2023, 10, 17, 0, 0, 0))]
+#[case::relative_keeps_time(
+    "+3 days",
+    DateTime::from_gregorian_date_time(2023, 10, 19, 10, 20, 30, 500_000_000)
+)]
+#[case::relative_ago(
+    "2 hours ago",
+    DateTime::from_gregorian_date_time(2023, 10, 16, 8, 20, 30, 500_000_000)
+)]
+#[case::relative_keyword(
+    "yesterday",
+    DateTime::from_gregorian_date_time(2023, 10, 15, 10, 20, 30, 500_000_000)
+)]
+#[case::relative_numeral(
+    "next week",
+    DateTime::from_gregorian_date_time(2023, 10, 23, 10, 20, 30, 500_000_000)
+)]
+#[case::relative_unit(
+    "month",
+    DateTime::from_gregorian_date_time(2023, 11, 16, 10, 20, 30, 500_000_000)
+)]
+#[case::relative_sign_without_number(
+    "- day",
+    DateTime::from_gregorian_date_time(2023, 10, 15, 10, 20, 30, 500_000_000)
+)]
+#[case::relative_fraction(
+    "1.5 sec",
+    DateTime::from_gregorian_date_time(2023, 10, 16, 10, 20, 32, 0)
+)]
+#[case::relative_month_carries("2023-01-31 +1 month", date_time(2023, 3, 3, 0, 0, 0))]
+#[case::date_time_relative("2023-10-16 14:00 3 days", date_time(2023, 10, 19, 14, 0, 0))]
+#[case::date_time_signed_relative("2023-10-16 14:00 +3 days", date_time(2023, 10, 17, 11, 0, 0))]
+#[case::date_time_signed_relative_ago(
+    "2023-10-16 14:00:00 -1 hour ago",
+    date_time(2023, 10, 16, 14, 0, 0)
+)]
+#[case::date_time_signed_fraction(
+    "2023-10-16 14:00 +1.5 sec",
+    DateTime::from_gregorian_date_time(2023, 10, 16, 14, 0, 1, 500_000_000)
+)]
+#[case::date_time_zone_relative(
+    "2023-10-16 14:00 +0200 -1 hour",
+    date_time(2023, 10, 16, 11, 0, 0)
+)]
+#[case::all_items(
+    "Tue, 17 Oct 2023 08:00:00 -0400 +1 year 2 days",
+    date_time(2024, 10, 19, 12, 0, 0)
+)]
+#[case::tomorrow_and_time("tomorrow 9am", date_time(2023, 10, 17, 9, 0, 0))]
+#[case::comment(
+    "2023-10-17 (the date) 14:30 (the (nested) time)",
+    date_time(2023, 10, 17, 14, 30, 0)
+)]
+#[case::case_insensitive("OCT 17 2023 2PM utc", date_time(2023, 10, 17, 14, 0, 0))]
+#[case::timestamp("@1697414400", date_time(2023, 10, 16, 0, 0, 0))]
+#[case::timestamp_zero("@0", DateTime::UNIX_EPOCH)]
+#[case::timestamp_negative("@-86400", date_time(1969, 12, 31, 0, 0, 0))]
+#[case::timestamp_fraction(
+    "@1.5",
+    DateTime::from_gregorian_date_time(1970, 1, 1, 0, 0, 1, 500_000_000)
+)]
+#[case::timestamp_surrounding_whitespace("  @ 10  ", date_time(1970, 1, 1, 0, 0, 10))]
+fn test_date_time_parser_parse_with_date(
+    #[case] source: &str,
+    #[case] expected: DateTime,
+    base: DateTime,
+) {
+    let parser = DateTimeParser::new();
+    assert_eq!(parser.parse_with_date(source, Some(base)), Ok(expected));
+    assert_eq!(parse_date_time_with_date(source, Some(base)), Ok(expected));
+}
+
+#[rstest]
+#[case::invalid_character("2023-10-16 !", ParseError::Syntax(11, "Invalid character: '!'".to_owned()))]
+#[case::invalid_word("2023-10-16 foo", ParseError::Syntax(11, "Invalid word: 'foo'".to_owned()))]
+#[case::invalid_month("2023-13-01", ParseError::Syntax(0, "Invalid date: Invalid month '13'".to_owned()))]
+#[case::invalid_day("2023-10-32", ParseError::Syntax(0, "Invalid date: Invalid day '32'".to_owned()))]
+#[case::day_not_in_month(
+    "2023-02-29",
+    ParseError::InvalidInput("Invalid date: The month 2 of the year 2023 has no day 29".to_owned())
+)]
+#[case::incomplete_iso_date(
+    "2023-10",
+    ParseError::Syntax(7, "Invalid date: Expected a date in the form YYYY-MM-DD".to_owned())
+)]
+#[case::month_without_day(
+    "Oct",
+    ParseError::Syntax(3, "Invalid date: Expected a day after the month".to_owned())
+)]
+#[case::invalid_hour("24:00", ParseError::Syntax(0, "Invalid time: Invalid hour '24'".to_owned()))]
+#[case::invalid_hour_with_meridian(
+    "13pm",
+    ParseError::Syntax(0, "Invalid time: Invalid hour '13'".to_owned())
+)]
+#[case::invalid_minute(
+    "12:60",
+    ParseError::Syntax(0, "Invalid time: Invalid minute '60'".to_owned())
+)]
+#[case::invalid_second(
+    "12:00:60",
+    ParseError::Syntax(0, "Invalid time: Invalid second '60'".to_owned())
+)]
+#[case::missing_minutes("12:", ParseError::Syntax(3, "Expected minutes".to_owned()))]
+#[case::invalid_offset(
+    "12:00 +2500",
+    ParseError::Syntax(6, "Invalid time zone offset: '+2500'".to_owned())
+)]
+#[case::multiple_dates(
+    "2023-10-16 2023-10-17",
+    ParseError::Syntax(11, "Multiple dates".to_owned())
+)]
+#[case::multiple_times("12:00 13:00", ParseError::Syntax(6, "Multiple times of day".to_owned()))]
+#[case::multiple_zones("12:00 UTC EST", ParseError::Syntax(10, "Multiple time zones".to_owned()))]
+#[case::multiple_weekdays(
+    "monday friday",
+    ParseError::Syntax(7, "Multiple days of the week".to_owned())
+)]
+#[case::unbalanced_comment(
+    "12:00 (comment",
+    ParseError::Syntax(6, "Unbalanced parentheses: Comment is not closed".to_owned())
+)]
+#[case::fraction_without_time_unit(
+    "1.5",
+    ParseError::Syntax(
+        0,
+        "A number with a fraction is only allowed together with a time unit".to_owned()
+    )
+)]
+#[case::offset_alone(
+    "+0200",
+    ParseError::Syntax(
+        0,
+        "Expected a time unit after the number or a time of day before the time zone offset"
+            .to_owned()
+    )
+)]
+#[case::signed_number_after_date(
+    "2023-10-16 +1",
+    ParseError::Syntax(
+        11,
+        "Expected a time unit after the number or a time of day before the time zone offset"
+            .to_owned()
+    )
+)]
+#[case::signed_number_before_weekday(
+    "-2 monday",
+    ParseError::Syntax(
+        0,
+        "Expected a time unit after the number or a time of day before the time zone offset"
+            .to_owned()
+    )
+)]
+#[case::offset_after_meridian(
+    "2pm +0200",
+    ParseError::Syntax(
+        4,
+        "Expected a time unit after the number or a time of day before the time zone offset"
+            .to_owned()
+    )
+)]
+#[case::offset_after_invalid_hour(
+    "1400 +02",
+    ParseError::Syntax(0, "Invalid time: Invalid hour '1400'".to_owned())
+)]
+#[case::timestamp_with_other_items(
+    "@10 UTC",
+    ParseError::Syntax(4, "Expected end of input after a timestamp but found: 'UTC'".to_owned())
+)]
+#[case::timestamp_without_number("@", ParseError::Syntax(1, "Expected seconds after '@'".to_owned()))]
+#[case::relative_fraction_day(
+    "2023-10-16 1.5 days",
+    ParseError::InvalidInput("Fraction only allowed together with seconds as time unit".to_owned())
+)]
+fn test_date_time_parser_parse_with_date_when_invalid(
+    #[case] source: &str,
+    #[case] expected: ParseError,
+    base: DateTime,
+) {
+    let parser = DateTimeParser::new();
+    assert_eq!(parser.parse_with_date(source, Some(base)), Err(expected));
+}
+
+#[rstest]
+#[case::date("2023-10-16", date_time(2023, 10, 16, 0, 0, 0))]
+#[case::date_time_zone("1970-01-01 00:00:00 +0100", date_time(1969, 12, 31, 23, 0, 0))]
+#[case::timestamp("@86400", date_time(1970, 1, 2, 0, 0, 0))]
+fn test_parse_date_time_with_absolute_items(#[case] source: &str, #[case] expected: DateTime) {
+    assert_eq!(parse_date_time(source), Ok(expected));
+    assert_eq!(DateTimeParser::new().parse(source), Ok(expected));
+}
+
+#[test]
+fn test_date_time_parser_when_overflow() {
+    let date = DateTime::from_gregorian_date_time(2023, 10, 16, 0, 0, 0, 0);
+    assert_eq!(
+        parse_date_time_with_date("9223372036854775807 years", Some(date)),
+        Err(ParseError::Overflow)
+    );
+    assert_eq!(
+        parse_date_time_with_date("99999999999999999999 friday", Some(date)),
+        Err(ParseError::Overflow)
+    );
+}
+
+#[rstest]
+#[case::empty("", DateTime::from_gregorian_date_time(2023, 10, 16, 0, 0, 0, 0))]
+#[case::time_of_day(
+    "14:30",
+    DateTime::from_gregorian_date_time(2023, 10, 16, 14, 30, 0, 0)
+)]
+#[case::relative(
+    "1 hour ago",
+    DateTime::from_gregorian_date_time(2023, 10, 16, 9, 20, 30, 0)
+)]
+#[case::weekday("friday", DateTime::from_gregorian_date_time(2023, 10, 20, 0, 0, 0, 0))]
+#[case::absolute(
+    "2000-01-01",
+    DateTime::from_gregorian_date_time(2000, 1, 1, 0, 0, 0, 0)
+)]
+fn test_date_time_parser_with_fixed_clock(#[case] input: &str, #[case] expected: DateTime) {
+    let clock = FixedClock::new(DateTime::from_gregorian_date_time(
+        2023, 10, 16, 10, 20, 30, 0,
+    ));
+    assert_eq!(
+        DateTimeParser::with_clock(&clock).parse(input),
+        Ok(expected)
+    );
+
+    let mut parser = DateTimeParser::new();
+    parser.set_clock(&clock);
+    assert_eq!(parser.parse(input), Ok(expected));
+}
+
+#[test]
+fn test_date_time_parser_matches_gnu_date() {
+    let parser = DateTimeParser::new();
+    let known_differences = known_gnu_date_differences("date_time");
+    let mut mismatches = vec![];
+    for line in GNU_DATE_FIXTURE
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+    {
+        let fields: Vec<&str> = line.split('\t').collect();
+        let (source, expected) = (
+            fields[1].replace("\\t", "\t").replace("\\n", "\n"),
+            fields[2],
+        );
+        let actual = parser.parse_with_date(&source, Some(GNU_DATE_NOW));
+        let is_match = match expected {
+            "error" => actual.is_err(),
+            expected => actual == Ok(parse_unix_seconds(expected)),
+        };
+        let is_known = known_differences.contains(&fields[1].to_owned());
+        if is_match == is_known {
+            mismatches.push(format!(
+                "'{source}': expected {expected}, got {actual:?} (known difference: {is_known})"
+            ));
+        }
+    }
+    assert!(mismatches.is_empty(), "{mismatches:#?}");
+}
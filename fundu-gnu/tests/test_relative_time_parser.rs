@@ -4,12 +4,17 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+mod common;
+
 use std::time::Duration as StdDuration;
 
+use common::{known_gnu_date_differences, parse_unix_seconds, GNU_DATE_FIXTURE, GNU_DATE_NOW};
 use fundu_core::time::TimeUnit::*;
 use fundu_core::time::{Multiplier, TimeUnit};
 use fundu_gnu::{
-    parse, parse_fuzzy, parse_with_date, DateTime, Duration, ParseError, RelativeTimeParser,
+    parse, parse_fuzzy, parse_relative, parse_with_date, parse_with_zoned_date, DateTime, Duration,
+    FixedClock, OffsetClock, ParseError, RelativeDuration, RelativeTimeParser, StreamParser,
+    TimeZone, UtcOffset, ZonedDateTime,
 };
 use rstest::rstest;
 pub use rstest_reuse;
@@ -312,6 +317,64 @@ fn test_parser_parse_fuzzy(#[case] input: &str, #[case] expected: (i64, i64, Dur
     assert_eq!(parse_fuzzy(input), Ok(expected));
 }
 
+#[rstest]
+#[case::zero("0", RelativeDuration::ZERO)]
+#[case::seconds("10 seconds", RelativeDuration::from_exact(Duration::positive(10, 0)))]
+#[case::days("2 days", RelativeDuration::new(0, 0, 0, 2, Duration::ZERO))]
+#[case::day_without_number("day", RelativeDuration::new(0, 0, 0, 1, Duration::ZERO))]
+#[case::weeks("2 weeks", RelativeDuration::new(0, 0, 2, 0, Duration::ZERO))]
+#[case::fortnight("fortnight", RelativeDuration::new(0, 0, 2, 0, Duration::ZERO))]
+#[case::fortnights_ago("3 fortnights ago", RelativeDuration::new(0, 0, -6, 0, Duration::ZERO))]
+#[case::months("2 months", RelativeDuration::new(0, 2, 0, 0, Duration::ZERO))]
+#[case::years("-2 years", RelativeDuration::new(-2, 0, 0, 0, Duration::ZERO))]
+#[case::yesterday("yesterday", RelativeDuration::new(0, 0, 0, -1, Duration::ZERO))]
+#[case::tomorrow("tomorrow", RelativeDuration::new(0, 0, 0, 1, Duration::ZERO))]
+#[case::today("today", RelativeDuration::ZERO)]
+#[case::now("now", RelativeDuration::ZERO)]
+#[case::numeral_last_month("last month", RelativeDuration::new(0, -1, 0, 0, Duration::ZERO))]
+#[case::numeral_third_week("third week", RelativeDuration::new(0, 0, 3, 0, Duration::ZERO))]
+#[case::numeral_next_day_ago("next day ago", RelativeDuration::new(0, 0, 0, -1, Duration::ZERO))]
+#[case::mixed(
+    "1 year 2 months 3 weeks 4 days 5 hours 6 secs",
+    RelativeDuration::new(1, 2, 3, 4, Duration::positive(5 * 3600 + 6, 0))
+)]
+#[case::accumulate(
+    "1 day 2 days -1 day",
+    RelativeDuration::new(0, 0, 0, 2, Duration::ZERO)
+)]
+#[case::days_saturate(
+    &format!("{}days", "2".repeat(20)),
+    RelativeDuration::new(0, 0, 0, i64::MAX, Duration::ZERO)
+)]
+#[case::negative_days_saturate(
+    &format!("-{}days", "2".repeat(20)),
+    RelativeDuration::new(0, 0, 0, i64::MIN, Duration::ZERO)
+)]
+fn test_parser_parse_relative(#[case] input: &str, #[case] expected: RelativeDuration) {
+    assert_eq!(
+        RelativeTimeParser::new().parse_relative(input),
+        Ok(expected)
+    );
+    assert_eq!(parse_relative(input), Ok(expected));
+}
+
+#[rstest]
+#[case::fraction_with_days(
+    "1.5 days",
+    ParseError::InvalidInput("Fraction only allowed together with seconds as time unit".to_owned())
+)]
+#[case::fraction_with_years(
+    "1.5 years",
+    ParseError::InvalidInput("Fraction only allowed together with seconds as time unit".to_owned())
+)]
+#[case::invalid_time_unit("1 lightyear", ParseError::InvalidInput("lightyear".to_owned()))]
+fn test_parser_parse_relative_when_invalid(#[case] input: &str, #[case] expected: ParseError) {
+    assert_eq!(
+        RelativeTimeParser::new().parse_relative(input),
+        Err(expected)
+    );
+}
+
 #[rstest]
 fn test_parser_numerals_with_not_fuzzy_time_units(
     #[values(
@@ -422,3 +485,295 @@ fn test_parser_with_fuzzy_time_unit_when_ago_or_sign(
         Ok(expected)
     );
 }
+
+#[rstest]
+#[case::same_weekday("monday", Duration::negative(12 * 60 * 60, 0))]
+#[case::next_day("tuesday", Duration::positive(12 * 60 * 60, 0))]
+#[case::previous_day_of_week("sunday", Duration::positive((6 * 24 - 12) * 60 * 60, 0))]
+#[case::abbreviation("wed", Duration::positive((2 * 24 - 12) * 60 * 60, 0))]
+#[case::abbreviation_with_period("Thur.", Duration::positive((3 * 24 - 12) * 60 * 60, 0))]
+#[case::comma("fri,", Duration::positive((4 * 24 - 12) * 60 * 60, 0))]
+#[case::this_same_weekday("this monday", Duration::negative(12 * 60 * 60, 0))]
+#[case::next_same_weekday("next monday", Duration::positive((7 * 24 - 12) * 60 * 60, 0))]
+#[case::next_friday("next friday", Duration::positive((4 * 24 - 12) * 60 * 60, 0))]
+#[case::last_same_weekday("last monday", Duration::negative((7 * 24 + 12) * 60 * 60, 0))]
+#[case::last_friday("last friday", Duration::negative((3 * 24 + 12) * 60 * 60, 0))]
+#[case::third_wednesday("third wednesday", Duration::positive((16 * 24 - 12) * 60 * 60, 0))]
+#[case::number_ordinal("2 sat", Duration::positive((12 * 24 - 12) * 60 * 60, 0))]
+#[case::weekday_and_time_units("friday 2 hours", Duration::positive((4 * 24 - 10) * 60 * 60, 0))]
+#[case::time_units_and_weekday("2 hours friday", Duration::positive((4 * 24 - 10) * 60 * 60, 0))]
+#[case::surrounded_by_time_units(
+    "1 hour next friday 1 hour",
+    Duration::positive((4 * 24 - 10) * 60 * 60, 0)
+)]
+#[case::weekday_and_fuzzy_time_units(
+    "monday next month",
+    Duration::positive((31 * 24 - 12) * 60 * 60, 0)
+)]
+#[case::weekday_and_ago("sunday 1 day ago", Duration::positive((5 * 24 - 12) * 60 * 60, 0))]
+fn test_parser_parse_with_date_when_day_of_week(#[case] input: &str, #[case] expected: Duration) {
+    // Monday, 2023-10-16
+    let date = DateTime::from_gregorian_date_time(2023, 10, 16, 12, 0, 0, 0);
+    assert_eq!(
+        RelativeTimeParser::new().parse_with_date(input, Some(date)),
+        Ok(expected)
+    );
+    assert_eq!(parse_with_date(input, Some(date)), Ok(expected));
+}
+
+#[rstest]
+#[case::multiple_weekdays(
+    "monday friday",
+    ParseError::Syntax(7, "Multiple days of the week".to_owned())
+)]
+#[case::multiple_weekdays_with_time_units(
+    "1 day next monday 2 days last friday",
+    ParseError::Syntax(25, "Multiple days of the week".to_owned())
+)]
+#[case::exponent_after_weekday(
+    "monday 1 hour 1e2",
+    ParseError::Syntax(15, "No exponent allowed".to_owned())
+)]
+fn test_parser_parse_with_date_when_day_of_week_then_error(
+    #[case] input: &str,
+    #[case] expected: ParseError,
+) {
+    let date = DateTime::from_gregorian_date_time(2023, 10, 16, 12, 0, 0, 0);
+    assert_eq!(
+        RelativeTimeParser::new().parse_with_date(input, Some(date)),
+        Err(expected)
+    );
+}
+
+#[rstest]
+#[case::only_weekday("monday")]
+#[case::weekday_and_time_units("1 hour next friday")]
+fn test_parser_parse_fuzzy_when_day_of_week_then_error(#[case] input: &str) {
+    let expected = ParseError::InvalidInput(
+        "A day of the week is only allowed when parsing relative to a date".to_owned(),
+    );
+    assert_eq!(
+        RelativeTimeParser::new().parse_fuzzy(input),
+        Err(expected.clone())
+    );
+    assert_eq!(parse_fuzzy(input), Err(expected));
+}
+
+const CENTRAL_EUROPEAN_TIME: &str = "CET-1CEST,M3.5.0,M10.5.0/3";
+
+fn zoned_date(tz: &str, year: i64, month: u8, day: u8, hour: u8) -> ZonedDateTime {
+    let local = DateTime::from_gregorian_date_time(year, month, day, hour, 0, 0, 0);
+    ZonedDateTime::from_local(local, TimeZone::from_tz_string(tz).unwrap()).unwrap()
+}
+
+#[rstest]
+#[case::no_calendar_units("2 hours", 3, 25, Duration::positive(2 * 60 * 60, 0))]
+#[case::hours_over_dst_start("24 hours", 3, 25, Duration::positive(24 * 60 * 60, 0))]
+#[case::day_over_dst_start("1 day", 3, 25, Duration::positive(23 * 60 * 60, 0))]
+#[case::tomorrow_over_dst_start("tomorrow", 3, 25, Duration::positive(23 * 60 * 60, 0))]
+#[case::day_and_hours_over_dst_start("1 day 2 hours", 3, 25, Duration::positive(25 * 60 * 60, 0))]
+#[case::yesterday_before_dst_start("yesterday", 3, 25, Duration::negative(24 * 60 * 60, 0))]
+#[case::week_over_dst_start("week", 3, 25, Duration::positive((7 * 24 - 1) * 60 * 60, 0))]
+#[case::fortnight_over_dst_start("fortnight", 3, 25, Duration::positive((14 * 24 - 1) * 60 * 60, 0))]
+#[case::month_over_dst_start("next month", 3, 25, Duration::positive((31 * 24 - 1) * 60 * 60, 0))]
+#[case::year_over_dst_start("1 year", 3, 25, Duration::positive(366 * 24 * 60 * 60, 0))]
+#[case::weekday_over_dst_start("monday", 3, 25, Duration::positive((2 * 24 - 13) * 60 * 60, 0))]
+#[case::day_over_dst_end("1 day", 10, 28, Duration::positive(25 * 60 * 60, 0))]
+#[case::day_ago_after_dst_end("1 day ago", 10, 29, Duration::negative(25 * 60 * 60, 0))]
+#[case::month_over_dst_end("month", 10, 15, Duration::positive((31 * 24 + 1) * 60 * 60, 0))]
+#[case::day_without_dst_change("1 day", 7, 1, Duration::positive(24 * 60 * 60, 0))]
+fn test_parser_parse_with_zoned_date(
+    #[case] input: &str,
+    #[case] month: u8,
+    #[case] day: u8,
+    #[case] expected: Duration,
+) {
+    let date = zoned_date(CENTRAL_EUROPEAN_TIME, 2023, month, day, 12);
+    assert_eq!(
+        RelativeTimeParser::new().parse_with_zoned_date(input, &date),
+        Ok(expected)
+    );
+    assert_eq!(parse_with_zoned_date(input, &date), Ok(expected));
+}
+
+#[test]
+fn test_parser_parse_with_zoned_date_when_fixed_offset() {
+    // The local date is already the 1st of february while it's still january in UTC
+    let date = ZonedDateTime::with_offset(
+        DateTime::from_gregorian_date_time(2023, 1, 31, 20, 0, 0, 0),
+        UtcOffset::from_hms(5, 30, 0).unwrap(),
+    );
+    assert_eq!(
+        parse_with_zoned_date("1 month", &date),
+        Ok(Duration::positive(28 * 24 * 60 * 60, 0))
+    );
+    assert_eq!(
+        parse_with_date("1 month", Some(date.utc())),
+        Ok(Duration::positive(31 * 24 * 60 * 60, 0))
+    );
+}
+
+#[test]
+fn test_parser_parse_with_zoned_date_when_utc_then_same_as_parse_with_date() {
+    let date_time = DateTime::from_gregorian_date_time(2024, 2, 29, 12, 30, 0, 0);
+    for input in [
+        "1 year",
+        "-1 month 2 hours",
+        "next friday 10 seconds",
+        "yesterday",
+    ] {
+        assert_eq!(
+            parse_with_zoned_date(input, &date_time.into()),
+            parse_with_date(input, Some(date_time)),
+            "{input}"
+        );
+    }
+}
+
+#[rstest]
+#[case::empty("", ParseError::Empty)]
+#[case::multiple_weekdays(
+    "monday friday",
+    ParseError::Syntax(7, "Multiple days of the week".to_owned())
+)]
+#[case::overflow(&format!("{} days", u64::MAX), ParseError::Overflow)]
+fn test_parser_parse_with_zoned_date_then_error(#[case] input: &str, #[case] expected: ParseError) {
+    let date = zoned_date(CENTRAL_EUROPEAN_TIME, 2023, 3, 25, 12);
+    assert_eq!(parse_with_zoned_date(input, &date), Err(expected));
+}
+
+#[rstest]
+#[case::no_fuzzy_units("2 hours", Duration::positive(2 * 60 * 60, 0))]
+#[case::month_in_leap_year("1 month", Duration::positive(29 * 24 * 60 * 60, 0))]
+#[case::year_in_leap_year("1 year", Duration::positive(366 * 24 * 60 * 60, 0))]
+#[case::weekday("next friday", Duration::positive((3 * 24 - 12) * 60 * 60, 0))]
+fn test_parser_parse_with_fixed_clock(#[case] input: &str, #[case] expected: Duration) {
+    // Tuesday, 1972-02-01
+    let clock = FixedClock::new(DateTime::from_gregorian_date_time(1972, 2, 1, 12, 0, 0, 0));
+    let parser = RelativeTimeParser::with_clock(&clock);
+    assert_eq!(parser.parse(input), Ok(expected));
+
+    let mut parser = RelativeTimeParser::new();
+    parser.set_clock(&clock);
+    assert_eq!(parser.parse(input), Ok(expected));
+}
+
+#[test]
+fn test_parser_parse_with_date_when_date_given_then_clock_is_ignored() {
+    let clock = FixedClock::new(DateTime::from_gregorian_date_time(1972, 2, 1, 0, 0, 0, 0));
+    let parser = RelativeTimeParser::with_clock(&clock);
+    assert_eq!(
+        parser.parse_with_date(
+            "1 month",
+            Some(DateTime::from_gregorian_date_time(1970, 2, 1, 0, 0, 0, 0))
+        ),
+        Ok(Duration::positive(28 * 24 * 60 * 60, 0))
+    );
+}
+
+#[test]
+fn test_parser_parse_with_offset_clock() {
+    // One day before the 1st of march 1972 is the 29th of february
+    let clock = OffsetClock::new(
+        FixedClock::new(DateTime::from_gregorian_date_time(1972, 3, 1, 0, 0, 0, 0)),
+        Duration::negative(24 * 60 * 60, 0),
+    );
+    let parser = RelativeTimeParser::with_clock(&clock);
+    assert_eq!(
+        parser.parse("1 month"),
+        Ok(Duration::positive(29 * 24 * 60 * 60, 0))
+    );
+}
+
+#[test]
+fn test_parser_parse_with_date_matches_gnu_date() {
+    let parser = RelativeTimeParser::new();
+    let known_differences = known_gnu_date_differences("relative");
+    let mut mismatches = vec![];
+    for line in GNU_DATE_FIXTURE
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+    {
+        let fields: Vec<&str> = line.split('\t').collect();
+        if fields[0] != "relative" {
+            continue;
+        }
+        let (source, expected) = (
+            fields[1].replace("\\t", "\t").replace("\\n", "\n"),
+            fields[2],
+        );
+        let actual = parser
+            .parse_with_date(&source, Some(GNU_DATE_NOW))
+            .map(|duration| GNU_DATE_NOW.checked_add_duration(&duration));
+        let is_match = match expected {
+            "error" => actual.is_err(),
+            expected => actual == Ok(Some(parse_unix_seconds(expected))),
+        };
+        let is_known = known_differences.contains(&fields[1].to_owned());
+        if is_match == is_known {
+            mismatches.push(format!(
+                "'{source}': expected {expected}, got {actual:?} (known difference: {is_known})"
+            ));
+        }
+    }
+    assert!(mismatches.is_empty(), "{mismatches:#?}");
+}
+
+#[rstest]
+#[case::only_duration("2 hours", Duration::positive(2 * 3600, 0), "")]
+#[case::leading_whitespace("\t 1 day, then", Duration::positive(86400, 0), ", then")]
+#[case::trailing_whitespace("1 day ", Duration::positive(86400, 0), " ")]
+#[case::ago("3 mins ago later", Duration::negative(3 * 60, 0), " later")]
+#[case::multiple("1 hour 30 minutes; rest", Duration::positive(5400, 0), "; rest")]
+#[case::months("5 months later", Duration::positive(151 * 86400, 0), " later")]
+#[case::weekday("next friday 2 hours.", Duration::positive(86400 + 2 * 3600, 0), ".")]
+#[case::keyword("yesterday is", Duration::negative(86400, 0), " is")]
+#[case::fraction("1.5 seconds or", Duration::positive(1, 500_000_000), " or")]
+fn test_relative_time_parser_parse_prefix(
+    #[case] source: &str,
+    #[case] expected: Duration,
+    #[case] remainder: &str,
+) {
+    // 1970-01-01 was a thursday
+    let clock = FixedClock::new(DateTime::from_gregorian_date_time(1970, 1, 1, 0, 0, 0, 0));
+    assert_eq!(
+        RelativeTimeParser::with_clock(&clock).parse_prefix(source),
+        Ok((expected, remainder))
+    );
+}
+
+#[rstest]
+#[case::empty("", ParseError::Empty)]
+#[case::only_whitespace("  ", ParseError::Empty)]
+#[case::word("later 1 day", ParseError::InvalidInput("later 1 day".to_owned()))]
+#[case::time_unit_followed_by_letters("5minthen", ParseError::InvalidInput("minthen".to_owned()))]
+fn test_relative_time_parser_parse_prefix_when_error(
+    #[case] source: &str,
+    #[case] expected: ParseError,
+) {
+    assert_eq!(
+        RelativeTimeParser::new().parse_prefix(source),
+        Err(expected)
+    );
+}
+
+#[test]
+fn test_relative_time_parser_with_stream_parser() {
+    let mut stream = StreamParser::with_separator(RelativeTimeParser::new(), |byte| byte == b',');
+
+    let mut items: Vec<_> = stream.feed(b"2 days ago,next ").collect();
+    items.extend(stream.feed(b"week,yesterday"));
+    items.extend(stream.finish());
+    let durations = items
+        .into_iter()
+        .map(|item| item.duration)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        durations,
+        vec![
+            Duration::negative(2 * 86400, 0),
+            Duration::positive(7 * 86400, 0),
+            Duration::negative(86400, 0)
+        ]
+    );
+}
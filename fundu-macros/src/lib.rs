@@ -0,0 +1,246 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Procedural macros of `fundu` and `fundu-systemd` which parse durations at compile time
+//!
+//! The macros of this crate are not meant to be used directly. Use the `duration!` and
+//! `std_duration!` macros of `fundu` and the `timespan!` and `std_timespan!` macros of
+//! `fundu-systemd` instead, which pass their crate path to the macros of this crate.
+//!
+//! Every macro takes the crate path followed by a comma and a string literal. The literal is
+//! parsed at compile time and the macro expands to a constant expression of the resulting
+//! duration. If the literal is not a valid duration, the macro expands to a `compile_error!` with
+//! the span of the literal and a message which marks the offending column of the duration.
+
+#![warn(clippy::pedantic)]
+#![warn(clippy::default_numeric_fallback)]
+#![warn(clippy::dbg_macro)]
+#![warn(clippy::else_if_without_else)]
+#![warn(clippy::get_unwrap)]
+#![warn(clippy::if_then_some_else_none)]
+#![warn(clippy::str_to_string)]
+#![warn(clippy::todo)]
+#![warn(clippy::try_err)]
+#![allow(clippy::enum_glob_use)]
+#![allow(clippy::module_name_repetitions)]
+
+mod literal;
+mod standard;
+mod systemd;
+
+use fundu_core::error::ParseError;
+use fundu_core::time::Duration;
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// The type of the duration a macro expands to
+#[derive(Debug, Clone, Copy)]
+enum Output {
+    /// A `fundu::Duration` which is also used by `fundu-systemd`
+    Fundu,
+    /// A `std::time::Duration`
+    Std,
+}
+
+/// Parse the string literal like `fundu::DurationParser::new()` with multiple durations into a
+/// `fundu::Duration`
+#[proc_macro]
+pub fn duration(input: TokenStream) -> TokenStream {
+    expand(input, standard::parse, Output::Fundu)
+}
+
+/// Parse the string literal like `fundu::DurationParser::new()` with multiple durations into a
+/// `std::time::Duration`
+#[proc_macro]
+pub fn std_duration(input: TokenStream) -> TokenStream {
+    expand(input, standard::parse, Output::Std)
+}
+
+/// Parse the string literal like `fundu_systemd::TimeSpanParser::new()` into a `fundu::Duration`
+#[proc_macro]
+pub fn timespan(input: TokenStream) -> TokenStream {
+    expand(input, systemd::parse, Output::Fundu)
+}
+
+/// Parse the string literal like `fundu_systemd::TimeSpanParser::new()` into a
+/// `std::time::Duration`
+#[proc_macro]
+pub fn std_timespan(input: TokenStream) -> TokenStream {
+    expand(input, systemd::parse, Output::Std)
+}
+
+fn expand(
+    input: TokenStream,
+    parse: fn(&str) -> Result<Duration, ParseError>,
+    output: Output,
+) -> TokenStream {
+    let mut tokens = input.into_iter();
+    let krate: TokenStream = tokens
+        .by_ref()
+        .take_while(|token| !matches!(token, TokenTree::Punct(punct) if punct.as_char() == ','))
+        .collect();
+    let (source, span) = match parse_input(&tokens.collect::<Vec<_>>()) {
+        Ok(input) => input,
+        Err((message, span)) => return compile_error(&message, span),
+    };
+
+    let duration = match parse(&source) {
+        Ok(duration) => duration,
+        Err(error) => return compile_error(&error_message(&source, &error), span),
+    };
+    match output {
+        Output::Fundu => {
+            let inner = std::time::Duration::try_from(duration.abs())
+                .expect("The absolute value of a duration is not negative");
+            let mut expanded = krate;
+            expanded.extend(
+                format!(
+                    "::__private::Duration::from_std({}, {})",
+                    duration.is_negative(),
+                    std_duration_expr(inner)
+                )
+                .parse::<TokenStream>()
+                .expect("The expression is valid rust code"),
+            );
+            expanded
+        }
+        Output::Std => match std::time::Duration::try_from(duration) {
+            Ok(duration) => std_duration_expr(duration)
+                .parse()
+                .expect("The expression is valid rust code"),
+            Err(_) => compile_error(
+                "Negative durations can't be converted into a std::time::Duration",
+                span,
+            ),
+        },
+    }
+}
+
+/// Return the string literal of the `tokens` and its span
+fn parse_input(tokens: &[TokenTree]) -> Result<(String, Span), (String, Span)> {
+    match tokens {
+        [TokenTree::Literal(literal)] => literal::parse_str_literal(&literal.to_string())
+            .map(|source| (source, literal.span()))
+            .map_err(|message| (message, literal.span())),
+        // Literals passed through a `macro_rules` macro may be wrapped in an invisible group
+        [TokenTree::Group(group)] if group.delimiter() == Delimiter::None => {
+            parse_input(&group.stream().into_iter().collect::<Vec<_>>())
+        }
+        [] => Err((
+            "Expected a string literal like \"1h\"".to_owned(),
+            Span::call_site(),
+        )),
+        [token, ..] => Err((
+            "Expected a single string literal like \"1h\"".to_owned(),
+            token.span(),
+        )),
+    }
+}
+
+/// Return the message of the parse `error` with a marker below the offending column of `source`
+///
+/// The span of a token can't point into a string literal on stable rust, so the column is marked
+/// in the message itself. Sources with control characters like newlines are not marked.
+fn error_message(source: &str, error: &ParseError) -> String {
+    let message = format!("Invalid duration: {error}");
+    match error_column(source, error) {
+        Some(column) if !source.contains(char::is_control) => {
+            let offset = source.get(..column).map_or(column, |s| s.chars().count());
+            format!("{message}\n{source}\n{:offset$}^", "")
+        }
+        _ => message,
+    }
+}
+
+/// Return the column of the `source` where the `error` occurred if known
+fn error_column(source: &str, error: &ParseError) -> Option<usize> {
+    match error {
+        ParseError::Syntax(column, _) | ParseError::TimeUnit(column, _) => Some(*column),
+        // The reason is the rest of the source which couldn't be parsed
+        ParseError::InvalidInput(rest) if !rest.is_empty() => {
+            source.strip_suffix(rest.as_str()).map(str::len)
+        }
+        _ => None,
+    }
+}
+
+fn std_duration_expr(duration: std::time::Duration) -> String {
+    format!(
+        "::core::time::Duration::new({}u64, {}u32)",
+        duration.as_secs(),
+        duration.subsec_nanos()
+    )
+}
+
+/// Return a `compile_error!` invocation with the `message` which points to the `span`
+fn compile_error(message: &str, span: Span) -> TokenStream {
+    let mut punct = Punct::new('!', Spacing::Alone);
+    punct.set_span(span);
+    let mut literal = Literal::string(message);
+    literal.set_span(span);
+    let mut group = Group::new(
+        Delimiter::Parenthesis,
+        TokenStream::from(TokenTree::Literal(literal)),
+    );
+    group.set_span(span);
+
+    [
+        TokenTree::Ident(Ident::new("compile_error", span)),
+        TokenTree::Punct(punct),
+        TokenTree::Group(group),
+    ]
+    .into_iter()
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::syntax(
+        "1.5e",
+        ParseError::Syntax(4, "Unexpected end of input".to_owned()),
+        "Invalid duration: Syntax error: Unexpected end of input at column 4\n1.5e\n    ^"
+    )]
+    #[case::time_unit(
+        "1µs 1years",
+        ParseError::TimeUnit(6, "Invalid time unit: 'years'".to_owned()),
+        "Invalid duration: Time unit error: Invalid time unit: 'years' at column 6\n1µs \
+         1years\n     ^"
+    )]
+    #[case::invalid_input(
+        "1min 5ns",
+        ParseError::InvalidInput("ns".to_owned()),
+        "Invalid duration: Invalid input: ns\n1min 5ns\n      ^"
+    )]
+    #[case::control_character(
+        "1s\n1e",
+        ParseError::Syntax(5, "Unexpected end of input".to_owned()),
+        "Invalid duration: Syntax error: Unexpected end of input at column 5"
+    )]
+    #[case::without_column(
+        "-1s",
+        ParseError::NegativeNumber,
+        "Invalid duration: Number was negative"
+    )]
+    fn test_error_message(#[case] source: &str, #[case] error: ParseError, #[case] expected: &str) {
+        assert_eq!(error_message(source, &error), expected);
+    }
+
+    #[rstest]
+    #[case::without_whitespace("1e3s", 1)]
+    #[case::leading_whitespace(" \t1e3s", 3)]
+    fn test_systemd_parse_when_syntax_error_then_column_in_source(
+        #[case] source: &str,
+        #[case] column: usize,
+    ) {
+        assert_eq!(
+            systemd::parse(source),
+            Err(ParseError::Syntax(column, "No exponent allowed".to_owned()))
+        );
+    }
+}
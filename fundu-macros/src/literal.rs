@@ -0,0 +1,115 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Extract the value of string literals like `"1h"` or `r#"1h"#` from their source code
+
+/// Return the value of the string literal given as source code `literal`
+///
+/// Normal string literals with all their escapes and raw string literals are supported. Other
+/// literals like byte strings, characters, numbers or literals with a suffix result in an error
+/// message.
+pub fn parse_str_literal(literal: &str) -> Result<String, String> {
+    if let Some(raw) = literal.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let delimiter = &raw[..hashes];
+        return raw[hashes..]
+            .strip_prefix('"')
+            .and_then(|raw| raw.strip_suffix(delimiter))
+            .and_then(|raw| raw.strip_suffix('"'))
+            .map(ToOwned::to_owned)
+            .ok_or_else(expected_str_literal);
+    }
+
+    literal
+        .strip_prefix('"')
+        .and_then(|escaped| escaped.strip_suffix('"'))
+        .ok_or_else(expected_str_literal)
+        .and_then(unescape)
+}
+
+fn expected_str_literal() -> String {
+    "Expected a string literal like \"1h\"".to_owned()
+}
+
+/// Resolve the escapes of the content of a normal string literal
+fn unescape(escaped: &str) -> Result<String, String> {
+    let mut unescaped = String::with_capacity(escaped.len());
+    let mut chars = escaped.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('t') => unescaped.push('\t'),
+            Some('0') => unescaped.push('\0'),
+            Some(c @ ('\\' | '\'' | '"')) => unescaped.push(c),
+            Some('x') => {
+                let code: String = chars.by_ref().take(2).collect();
+                let c = u8::from_str_radix(&code, 16)
+                    .ok()
+                    .filter(u8::is_ascii)
+                    .ok_or_else(|| format!("Invalid ascii escape: '\\x{code}'"))?;
+                unescaped.push(char::from(c));
+            }
+            Some('u') => {
+                let code: String = chars.by_ref().take_while(|c| *c != '}').collect();
+                let c = code
+                    .strip_prefix('{')
+                    .and_then(|code| u32::from_str_radix(&code.replace('_', ""), 16).ok())
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| format!("Invalid unicode escape: '\\u{code}}}'"))?;
+                unescaped.push(c);
+            }
+            Some('\n') => while chars.next_if(|c| c.is_whitespace()).is_some() {},
+            Some(c) => return Err(format!("Invalid escape: '\\{c}'")),
+            None => return Err(expected_str_literal()),
+        }
+    }
+    Ok(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::empty(r#""""#, "")]
+    #[case::simple(r#""1h 30m""#, "1h 30m")]
+    #[case::quote_escape(r#""\"1s\"""#, "\"1s\"")]
+    #[case::whitespace_escapes(r#""1s\t\n\r2s""#, "1s\t\n\r2s")]
+    #[case::backslash(r#""\\""#, "\\")]
+    #[case::null(r#""\0""#, "\0")]
+    #[case::single_quote(r#""\'""#, "'")]
+    #[case::ascii_escape(r#""1\x73""#, "1s")]
+    #[case::unicode_escape(r#""1\u{b5}s""#, "1\u{b5}s")]
+    #[case::unicode_escape_with_underscore(r#""\u{0_3bc}""#, "\u{3bc}")]
+    #[case::line_continuation("\"1s \\\n    2s\"", "1s 2s")]
+    #[case::raw(r#"r"1\s""#, r"1\s")]
+    #[case::raw_with_hashes(r###"r##"1"#s"##"###, r##"1"#s"##)]
+    fn test_parse_str_literal(#[case] literal: &str, #[case] expected: &str) {
+        assert_eq!(parse_str_literal(literal), Ok(expected.to_owned()));
+    }
+
+    #[rstest]
+    #[case::number("1", "Expected a string literal like \"1h\"")]
+    #[case::char("'1'", "Expected a string literal like \"1h\"")]
+    #[case::byte_string(r#"b"1s""#, "Expected a string literal like \"1h\"")]
+    #[case::suffix(r#""1s"suffix"#, "Expected a string literal like \"1h\"")]
+    #[case::raw_with_missing_hash(r##"r##"1s"#"##, "Expected a string literal like \"1h\"")]
+    #[case::invalid_escape(r#""\q""#, "Invalid escape: '\\q'")]
+    #[case::invalid_ascii_escape(r#""\xff""#, "Invalid ascii escape: '\\xff'")]
+    #[case::invalid_unicode_escape(r#""\u{d800}""#, "Invalid unicode escape: '\\u{d800}'")]
+    fn test_parse_str_literal_when_invalid_then_error(
+        #[case] literal: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(parse_str_literal(literal), Err(expected.to_owned()));
+    }
+}
@@ -0,0 +1,41 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! The grammar of the default `fundu::DurationParser`
+//!
+//! `fundu` depends on this crate, so the parser can't be used here. The time units are shared with
+//! `fundu::DurationParser::new()` through [`fundu_core::presets`]. Unlike the default
+//! `DurationParser`, multiple durations separated by whitespace like `1h 30m` are accepted.
+
+use fundu_core::config::ConfigBuilder;
+use fundu_core::error::ParseError;
+use fundu_core::parse::Parser;
+use fundu_core::presets::STANDARD_TIME_UNITS;
+use fundu_core::time::{Duration, Multiplier, TimeUnit, TimeUnitsLike};
+
+const PARSER: Parser<'static> =
+    Parser::with_config(ConfigBuilder::new().parse_multiple(None).build());
+
+/// The default time units of the `DurationParser`
+struct TimeUnits;
+
+impl TimeUnitsLike for TimeUnits {
+    fn is_empty(&self) -> bool {
+        false
+    }
+
+    fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
+        STANDARD_TIME_UNITS
+            .iter()
+            .find(|time_unit| time_unit.default_identifier() == identifier)
+            .map(|time_unit| (*time_unit, Multiplier::default()))
+    }
+}
+
+/// Parse the `source` like `fundu::DurationParser::new().parse(source)` but allow multiple
+/// durations like `DurationParserBuilder::parse_multiple(None)`
+pub fn parse(source: &str) -> Result<Duration, ParseError> {
+    PARSER.parse(source, &TimeUnits, None, None)
+}
@@ -0,0 +1,56 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! The grammar of the default `fundu_systemd::TimeSpanParser`
+//!
+//! `fundu-systemd` depends on this crate, so the parser can't be used here. The time units and the
+//! configuration are shared with `fundu_systemd::TimeSpanParser::new()` through
+//! [`fundu_core::presets`].
+
+use fundu_core::error::ParseError;
+use fundu_core::parse::Parser;
+use fundu_core::presets::{
+    systemd_time_unit, SYSTEMD_CONFIG, SYSTEMD_DELIMITER, SYSTEMD_MAX_MICRO_DURATION,
+};
+use fundu_core::time::TimeUnit::*;
+use fundu_core::time::{Duration, Multiplier, TimeUnit, TimeUnitsLike};
+
+const PARSER: Parser<'static> = Parser::with_config(SYSTEMD_CONFIG);
+
+/// The time units of systemd without the nano second time units
+struct TimeUnits;
+
+impl TimeUnitsLike for TimeUnits {
+    fn is_empty(&self) -> bool {
+        false
+    }
+
+    fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
+        systemd_time_unit(identifier)
+            .filter(|time_unit| *time_unit != NanoSecond)
+            .map(|time_unit| (time_unit, Multiplier(1, 0)))
+    }
+}
+
+/// Parse the `source` like `fundu_systemd::TimeSpanParser::new().parse(source)`
+///
+/// Unlike the `TimeSpanParser`, the column of an error is the column in the untrimmed `source`.
+pub fn parse(source: &str) -> Result<Duration, ParseError> {
+    let is_delimiter = |c: char| c.is_ascii() && SYSTEMD_DELIMITER(c as u8);
+    let trimmed_start = source.trim_start_matches(is_delimiter);
+    let offset = source.len() - trimmed_start.len();
+    let trimmed = trimmed_start.trim_end_matches(is_delimiter);
+    if trimmed == "infinity" {
+        return Ok(SYSTEMD_MAX_MICRO_DURATION);
+    }
+    PARSER
+        .parse(trimmed, &TimeUnits, None, None)
+        .map(|duration| duration.min(SYSTEMD_MAX_MICRO_DURATION))
+        .map_err(|error| match error {
+            ParseError::Syntax(column, reason) => ParseError::Syntax(offset + column, reason),
+            ParseError::TimeUnit(column, reason) => ParseError::TimeUnit(offset + column, reason),
+            error => error,
+        })
+}
@@ -0,0 +1,261 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use fundu::{Config, Delimiter, Duration, Parser, TimeUnit};
+use fundu_core::presets::SYSTEMD_CONFIG;
+
+use crate::TimeSpanParser;
+
+/// A builder for a [`TimeSpanParser`] which deviates from the systemd time span syntax
+///
+/// Without any changes, the built [`TimeSpanParser`] is equal to [`TimeSpanParser::new`] and
+/// parses exactly like systemd. The builder is meant for applications which embed the systemd
+/// syntax in their own configuration language and need small deviations like negative time spans
+/// or additional time unit aliases. Like the [`TimeSpanParser`], the builder can be used in const
+/// context.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu::{Duration, TimeUnit};
+/// use fundu_systemd::{TimeSpanParser, TimeSpanParserBuilder};
+///
+/// const PARSER: TimeSpanParser = TimeSpanParserBuilder::new()
+///     .allow_negative()
+///     .allow_exponent()
+///     .aliases(&[("sek", TimeUnit::Second), ("std", TimeUnit::Hour)])
+///     .build();
+///
+/// assert_eq!(PARSER.parse("-1min"), Ok(Duration::negative(60, 0)));
+/// assert_eq!(PARSER.parse("1e3ms"), Ok(Duration::positive(1, 0)));
+/// assert_eq!(PARSER.parse("2std 30sek"), Ok(Duration::positive(7230, 0)));
+///
+/// assert_eq!(TimeSpanParserBuilder::new().build(), TimeSpanParser::new());
+/// ```
+#[derive(Debug, Eq, PartialEq)]
+pub struct TimeSpanParserBuilder<'a> {
+    config: Config<'a>,
+    aliases: &'a [(&'a str, TimeUnit)],
+    max: Option<Duration>,
+}
+
+impl Default for TimeSpanParserBuilder<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> TimeSpanParserBuilder<'a> {
+    /// Create a new `TimeSpanParserBuilder` with the settings of systemd
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::Duration;
+    /// use fundu_systemd::TimeSpanParserBuilder;
+    ///
+    /// let parser = TimeSpanParserBuilder::new().build();
+    /// assert_eq!(parser.parse("1min 30s"), Ok(Duration::positive(90, 0)));
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            config: SYSTEMD_CONFIG,
+            aliases: &[],
+            max: None,
+        }
+    }
+
+    /// Set the default [`TimeUnit`] of numbers without a time unit (Default: `Second`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{Duration, TimeUnit};
+    /// use fundu_systemd::TimeSpanParserBuilder;
+    ///
+    /// let parser = TimeSpanParserBuilder::new()
+    ///     .default_unit(TimeUnit::MilliSecond)
+    ///     .build();
+    /// assert_eq!(parser.parse("1500"), Ok(Duration::positive(1, 500_000_000)));
+    /// ```
+    pub const fn default_unit(mut self, time_unit: TimeUnit) -> Self {
+        self.config.default_unit = time_unit;
+        self
+    }
+
+    /// Allow negative time spans like `-1min` or `1min -30s`
+    ///
+    /// Negative time spans saturate at the negated maximum [`Duration`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::Duration;
+    /// use fundu_systemd::TimeSpanParserBuilder;
+    ///
+    /// let parser = TimeSpanParserBuilder::new().allow_negative().build();
+    /// assert_eq!(parser.parse("-1min"), Ok(Duration::negative(60, 0)));
+    /// assert_eq!(parser.parse("1min -30s"), Ok(Duration::positive(30, 0)));
+    /// ```
+    pub const fn allow_negative(mut self) -> Self {
+        self.config.allow_negative = true;
+        self
+    }
+
+    /// Allow an exponent in numbers like `1e3ms` or `2.5E-1s`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::Duration;
+    /// use fundu_systemd::TimeSpanParserBuilder;
+    ///
+    /// let parser = TimeSpanParserBuilder::new().allow_exponent().build();
+    /// assert_eq!(parser.parse("1e3ms"), Ok(Duration::positive(1, 0)));
+    /// assert_eq!(parser.parse("2.5E-1s"), Ok(Duration::positive(0, 250_000_000)));
+    /// ```
+    pub const fn allow_exponent(mut self) -> Self {
+        self.config.disable_exponent = false;
+        self
+    }
+
+    /// Add additional identifiers for the time units
+    ///
+    /// The `aliases` are tried after the time units of systemd, so they can't replace them. They
+    /// are recognized by [`TimeSpanParser::parse`] and [`TimeSpanParser::parse_nanos`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{Duration, TimeUnit};
+    /// use fundu_systemd::TimeSpanParserBuilder;
+    ///
+    /// let parser = TimeSpanParserBuilder::new()
+    ///     .aliases(&[("sek", TimeUnit::Second), ("tage", TimeUnit::Day)])
+    ///     .build();
+    /// assert_eq!(parser.parse("1tage 10sek"), Ok(Duration::positive(86_410, 0)));
+    /// ```
+    pub const fn aliases(mut self, aliases: &'a [(&'a str, TimeUnit)]) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// Set the [`Delimiter`] between the time spans and between a number and its time unit
+    /// (Default: the posix whitespace)
+    ///
+    /// The ascii characters matching the `delimiter` are also trimmed from the start and end of the
+    /// input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::Duration;
+    /// use fundu_systemd::TimeSpanParserBuilder;
+    ///
+    /// let parser = TimeSpanParserBuilder::new()
+    ///     .delimiter(|byte| byte == b' ' || byte == b'_')
+    ///     .build();
+    /// assert_eq!(parser.parse("_1min_30s"), Ok(Duration::positive(90, 0)));
+    /// assert!(parser.parse("1min\t30s").is_err());
+    /// ```
+    pub const fn delimiter(mut self, delimiter: Delimiter) -> Self {
+        self.config.inner_delimiter = delimiter;
+        self.config.outer_delimiter = delimiter;
+        self
+    }
+
+    /// Set the maximum [`Duration`] of [`TimeSpanParser::parse`] and
+    /// [`TimeSpanParser::parse_nanos`] (Default: [`crate::SYSTEMD_MAX_MICRO_DURATION`] and
+    /// [`crate::SYSTEMD_MAX_NANOS_DURATION`])
+    ///
+    /// The time spans saturate at the `max` [`Duration`] and `infinity` evaluates to it.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `max` is a negative [`Duration`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::Duration;
+    /// use fundu_systemd::TimeSpanParserBuilder;
+    ///
+    /// let parser = TimeSpanParserBuilder::new()
+    ///     .max(Duration::positive(3600, 0))
+    ///     .build();
+    /// assert_eq!(parser.parse("2h"), Ok(Duration::positive(3600, 0)));
+    /// assert_eq!(parser.parse("infinity"), Ok(Duration::positive(3600, 0)));
+    /// assert_eq!(parser.parse_nanos("infinity"), Ok(Duration::positive(3600, 0)));
+    /// ```
+    pub const fn max(mut self, max: Duration) -> Self {
+        assert!(max.is_positive());
+        self.max = Some(max);
+        self
+    }
+
+    /// Build the [`TimeSpanParser`] with the current settings
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_systemd::{TimeSpanParser, TimeSpanParserBuilder};
+    ///
+    /// const PARSER: TimeSpanParser = TimeSpanParserBuilder::new().allow_negative().build();
+    /// ```
+    pub const fn build(self) -> TimeSpanParser<'a> {
+        TimeSpanParser {
+            raw: Parser::with_config(self.config),
+            aliases: self.aliases,
+            max: self.max,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fundu::TimeUnit::*;
+
+    use super::*;
+
+    #[test]
+    fn test_builder_new() {
+        let builder = TimeSpanParserBuilder::new();
+        assert_eq!(builder.config, SYSTEMD_CONFIG);
+        assert_eq!(builder.aliases, &[]);
+        assert_eq!(builder.max, None);
+        assert_eq!(builder, TimeSpanParserBuilder::default());
+    }
+
+    #[test]
+    fn test_builder_settings() {
+        fn delimiter(byte: u8) -> bool {
+            byte == b','
+        }
+        let aliases = [("sek", Second)];
+        let builder = TimeSpanParserBuilder::new()
+            .default_unit(MilliSecond)
+            .allow_negative()
+            .allow_exponent()
+            .aliases(&aliases)
+            .delimiter(delimiter)
+            .max(Duration::positive(1, 0));
+
+        let mut config = SYSTEMD_CONFIG;
+        config.default_unit = MilliSecond;
+        config.allow_negative = true;
+        config.disable_exponent = false;
+        config.inner_delimiter = delimiter;
+        config.outer_delimiter = delimiter;
+        assert_eq!(builder.config, config);
+        assert_eq!(builder.aliases, &aliases);
+        assert_eq!(builder.max, Some(Duration::positive(1, 0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: max.is_positive()")]
+    fn test_builder_max_when_negative_then_panic() {
+        let _ = TimeSpanParserBuilder::new().max(Duration::negative(1, 0));
+    }
+}
@@ -0,0 +1,1293 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// spell-checker: ignore semiannually
+
+use std::fmt::{self, Display};
+use std::iter::FusedIterator;
+use std::str::FromStr;
+
+use fundu::{Duration, ParseError};
+use fundu_gnu::{DateTime, JulianDay, TimeZone, UtcOffset};
+
+use crate::is_whitespace;
+
+const USEC_PER_SEC: u32 = 1_000_000;
+const USEC_PER_MINUTE: u32 = 60 * USEC_PER_SEC;
+const NANOS_PER_USEC: u32 = 1000;
+
+/// The latest year systemd accepts in calendar events
+const MAX_YEAR: i64 = 2199;
+
+/// The names of the days of the week starting with monday like in systemd
+pub(crate) const WEEKDAYS: [(&str, &str); 7] = [
+    ("Monday", "Mon"),
+    ("Tuesday", "Tue"),
+    ("Wednesday", "Wed"),
+    ("Thursday", "Thu"),
+    ("Friday", "Fri"),
+    ("Saturday", "Sat"),
+    ("Sunday", "Sun"),
+];
+
+/// The special expressions and their normalized form
+const SHORTHANDS: [(&str, &str); 9] = [
+    ("minutely", "*-*-* *:*:00"),
+    ("hourly", "*-*-* *:00:00"),
+    ("daily", "*-*-* 00:00:00"),
+    ("monthly", "*-*-01 00:00:00"),
+    ("weekly", "Mon *-*-* 00:00:00"),
+    ("yearly", "*-01-01 00:00:00"),
+    ("annually", "*-01-01 00:00:00"),
+    ("quarterly", "*-01,04,07,10-01 00:00:00"),
+    ("semiannually", "*-01,07-01 00:00:00"),
+];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Field {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl Field {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Year => "year",
+            Self::Month => "month",
+            Self::Day => "day",
+            Self::Hour => "hour",
+            Self::Minute => "minute",
+            Self::Second => "second",
+        }
+    }
+
+    /// The inclusive range of valid values. Seconds are stored in micro seconds.
+    const fn range(self) -> (u32, u32) {
+        match self {
+            Self::Year => (1970, 2199),
+            Self::Month => (1, 12),
+            Self::Day => (1, 31),
+            Self::Hour => (0, 23),
+            Self::Minute => (0, 59),
+            Self::Second => (0, USEC_PER_MINUTE - 1),
+        }
+    }
+}
+
+/// A single value, range or repetition like `5`, `1..3` or `0/15` of a calendar event
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Component {
+    start: u32,
+    stop: Option<u32>,
+    repeat: u32,
+}
+
+impl Component {
+    const fn new(start: u32) -> Self {
+        Self {
+            start,
+            stop: None,
+            repeat: 0,
+        }
+    }
+
+    /// Return the smallest value `>= value` this component matches with the resolved `start` and
+    /// `stop`
+    fn find(&self, value: u32, start: u32, stop: Option<u32>) -> Option<u32> {
+        if start >= value {
+            return Some(start);
+        }
+        let steps = (value - start + self.repeat - 1).checked_div(self.repeat)?;
+        let next = start + steps * self.repeat;
+        stop.map_or(true, |stop| next <= stop).then_some(next)
+    }
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>, width: usize, unit: u32) -> fmt::Result {
+        write!(f, "{:0width$}", self.start / unit)?;
+        if self.start % unit > 0 {
+            write!(f, ".{:06}", self.start % unit)?;
+        }
+        if let Some(stop) = self.stop {
+            write!(f, "..{:0width$}", stop / unit)?;
+            if stop % unit > 0 {
+                write!(f, ".{:06}", stop % unit)?;
+            }
+        }
+        if self.repeat > 0 && !(self.stop.is_some() && self.repeat == unit) {
+            write!(f, "/{}", self.repeat / unit)?;
+        }
+        if self.repeat % unit > 0 {
+            write!(f, ".{:06}", self.repeat % unit)?;
+        }
+        Ok(())
+    }
+}
+
+/// A comma separated list of [`Component`]s. An empty chain matches any value (`*`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+struct Chain(Vec<Component>);
+
+impl Chain {
+    fn constant(value: u32) -> Self {
+        Self(vec![Component::new(value)])
+    }
+
+    /// Normalize the chain like systemd
+    ///
+    /// The `stop` of a range is lowered to the last value the repetition hits and ranges which
+    /// repeat at most once are reduced to their `start`.
+    fn normalize(&mut self) {
+        for component in &mut self.0 {
+            if let Some(stop) = component.stop {
+                let mut stop = stop;
+                if stop > component.start && component.repeat > 0 {
+                    stop -= (stop - component.start) % component.repeat;
+                }
+                if stop == component.start
+                    || (stop > component.start && stop - component.start < component.repeat)
+                {
+                    component.stop = None;
+                    component.repeat = 0;
+                } else {
+                    component.stop = Some(stop);
+                }
+            }
+        }
+        self.0.sort_unstable();
+        self.0.dedup();
+    }
+
+    /// Turn two digit years into full years like systemd (`12` -> `2012` and `89` -> `1989`)
+    fn fix_year(&mut self) {
+        let fix = |year: u32| match year {
+            0..=69 => year + 2000,
+            70..=99 => year + 1900,
+            _ => year,
+        };
+        for component in &mut self.0 {
+            component.start = fix(component.start);
+            component.stop = component.stop.map(fix);
+        }
+    }
+
+    fn is_valid(&self, field: Field, end_of_month: bool) -> bool {
+        let (from, mut to) = field.range();
+        // Counting backwards from the end of the month is limited to the shortest month
+        if end_of_month {
+            to = 28;
+        }
+        self.0.iter().all(|c| {
+            from <= c.start
+                && c.start <= to
+                && c.repeat <= to - from
+                && match c.stop {
+                    Some(stop) => from <= stop && stop <= to && c.start + c.repeat <= stop,
+                    None if end_of_month => c.start >= from + c.repeat,
+                    None => c.start + c.repeat <= to,
+                }
+        })
+    }
+
+    /// Return the smallest value `>= value` matching this chain
+    ///
+    /// If `days_in_month` is given, the components count the days backwards from the end of the
+    /// month.
+    fn find(&self, value: u32, days_in_month: Option<u32>) -> Option<u32> {
+        if self.0.is_empty() {
+            return Some(value);
+        }
+        self.0
+            .iter()
+            .filter_map(|component| match days_in_month {
+                Some(days) => {
+                    let from_end = |day: u32| (days + 1).checked_sub(day).filter(|day| *day > 0);
+                    let start = from_end(component.start)?;
+                    match component.stop {
+                        Some(stop) => {
+                            let stop = from_end(stop)?;
+                            component.find(value, stop, Some(start))
+                        }
+                        None => component.find(value, start, None),
+                    }
+                }
+                None => component.find(value, component.start, component.stop),
+            })
+            .min()
+    }
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>, width: usize, usec: bool) -> fmt::Result {
+        let unit = if usec { USEC_PER_SEC } else { 1 };
+        match self.0.as_slice() {
+            [] => f.write_str("*"),
+            [component] if usec && *component == SECONDS_ANY => f.write_str("*"),
+            components => {
+                for (index, component) in components.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(",")?;
+                    }
+                    component.fmt(f, width, unit)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A `*` in the seconds matches every full second
+const SECONDS_ANY: Component = Component {
+    start: 0,
+    stop: None,
+    repeat: USEC_PER_SEC,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CalendarZone {
+    Local,
+    Utc,
+    Named(String, TimeZone),
+}
+
+/// The date and time split into its fields with the seconds in micro seconds
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Fields {
+    year: i64,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+}
+
+impl Fields {
+    fn from_date_time(date_time: DateTime) -> Option<Self> {
+        let (year, month, day, hour, minute, second, nanos) = date_time.to_gregorian_date_time()?;
+        Some(Self {
+            year,
+            month: month.into(),
+            day: day.into(),
+            hour: hour.into(),
+            minute: minute.into(),
+            second: u32::from(second) * USEC_PER_SEC + nanos / NANOS_PER_USEC,
+        })
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn to_date_time(self) -> Option<DateTime> {
+        DateTime::try_from_gregorian_date_time(
+            self.year,
+            self.month as u8,
+            self.day as u8,
+            self.hour as u8,
+            self.minute as u8,
+            (self.second / USEC_PER_SEC) as u8,
+            self.second % USEC_PER_SEC * NANOS_PER_USEC,
+        )
+    }
+
+    fn reset_time(&mut self) {
+        self.hour = 0;
+        self.minute = 0;
+        self.second = 0;
+    }
+
+    fn reset_date(&mut self) {
+        self.month = 1;
+        self.day = 1;
+        self.reset_time();
+    }
+
+    /// Carry over the fields which are out of bounds into the next higher field
+    fn carry(&mut self) {
+        if self.second >= USEC_PER_MINUTE {
+            self.second -= USEC_PER_MINUTE;
+            self.minute += 1;
+        }
+        if self.minute >= 60 {
+            self.minute -= 60;
+            self.hour += 1;
+        }
+        if self.hour >= 24 {
+            self.hour -= 24;
+            self.day += 1;
+        }
+        if self.month > 12 {
+            self.month -= 12;
+            self.year += 1;
+        }
+        if self.day > days_in_month(self.year, self.month) {
+            self.day = 1;
+            self.month += 1;
+            if self.month > 12 {
+                self.month = 1;
+                self.year += 1;
+            }
+        }
+    }
+
+    /// Like `mktime`, move local times within a gap of the time `zone` forward by the length of
+    /// the gap
+    fn normalize(mut self, zone: &TimeZone) -> Option<Self> {
+        self.carry();
+        let local = self.to_date_time()?;
+        Self::from_date_time(utc_to_local(zone, local_to_utc(zone, local)?)?)
+    }
+}
+
+const fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+const fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+pub(crate) fn offset_to_duration(offset: UtcOffset) -> Duration {
+    let seconds = offset.as_seconds();
+    if seconds < 0 {
+        Duration::negative(u64::from(seconds.unsigned_abs()), 0)
+    } else {
+        Duration::positive(u64::from(seconds.unsigned_abs()), 0)
+    }
+}
+
+pub(crate) fn utc_to_local(zone: &TimeZone, date_time: DateTime) -> Option<DateTime> {
+    date_time.checked_add_duration(&offset_to_duration(zone.offset_at(&date_time)))
+}
+
+pub(crate) fn local_to_utc(zone: &TimeZone, date_time: DateTime) -> Option<DateTime> {
+    date_time.checked_sub_duration(&offset_to_duration(zone.offset_at_local(&date_time)))
+}
+
+/// A systemd calendar event like `Mon..Fri *-*-* 09:00:00` or `daily`
+///
+/// Calendar events describe points in time which may elapse repeatedly as they are used in the
+/// `OnCalendar=` setting of systemd timer units. The format follows the
+/// [systemd documentation](https://www.freedesktop.org/software/systemd/man/systemd.time.html#Calendar%20Events)
+/// and is in its normalized form
+///
+/// `[WEEKDAYS] YEAR-MONTH-DAY HOUR:MINUTE:SECOND [TIME ZONE]`
+///
+/// * The days of the week are given in english like `Mon` or `Monday` as a list `Sat,Sun` or range
+///   `Mon..Fri`
+/// * Every component of the date and time is either a `*` matching any value or a comma separated
+///   list of values (`1,3`), ranges (`1..3`) and repetitions (`0/15` or `1..9/2`)
+/// * A `~` instead of the `-` between the month and day counts the days backwards from the last day
+///   of the month (`*-02~01` is the last day of february)
+/// * The seconds can have a fraction with up to six digits
+/// * The date or the time may be omitted. A missing date matches any date, a missing time means
+///   `00:00:00`. If the seconds are missing, they are `00`.
+/// * The time zone is either `UTC` or a name like `Europe/Berlin` of the system's time zone
+///   database. Without a time zone, the local time zone is used.
+/// * The special expressions `minutely`, `hourly`, `daily`, `monthly`, `weekly`, `yearly`,
+///   `annually`, `quarterly` and `semiannually` and timestamps like `@1697443200` are supported
+/// * Like in systemd, whitespace around the calendar event is not allowed
+///
+/// The [`Display`] implementation prints the normalized form of the calendar event just like
+/// `systemd-analyze calendar`.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_systemd::{CalendarSpec, DateTime};
+///
+/// let spec = CalendarSpec::parse("Mon..Fri 9:00 UTC").unwrap();
+/// assert_eq!(spec.to_string(), "Mon..Fri *-*-* 09:00:00 UTC");
+///
+/// // 2023-10-14 is a saturday
+/// let mut elapses =
+///     spec.next_elapse(DateTime::from_gregorian_date_time(2023, 10, 14, 0, 0, 0, 0));
+/// assert_eq!(
+///     elapses.next(),
+///     Some(DateTime::from_gregorian_date_time(2023, 10, 16, 9, 0, 0, 0))
+/// );
+/// assert_eq!(
+///     elapses.next(),
+///     Some(DateTime::from_gregorian_date_time(2023, 10, 17, 9, 0, 0, 0))
+/// );
+///
+/// let spec: CalendarSpec = "quarterly".parse().unwrap();
+/// assert_eq!(spec.to_string(), "*-01,04,07,10-01 00:00:00");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarSpec {
+    /// The days of the week as bits starting with monday as the lowest bit
+    weekdays: Option<u8>,
+    year: Chain,
+    month: Chain,
+    day: Chain,
+    end_of_month: bool,
+    hour: Chain,
+    minute: Chain,
+    second: Chain,
+    zone: CalendarZone,
+}
+
+impl CalendarSpec {
+    /// Parse the `source` string into a `CalendarSpec`
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the `source` is not a valid calendar event
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::ParseError;
+    /// use fundu_systemd::CalendarSpec;
+    ///
+    /// let spec = CalendarSpec::parse("Sat,Sun 10:00").unwrap();
+    /// assert_eq!(spec.to_string(), "Sat,Sun *-*-* 10:00:00");
+    ///
+    /// let spec = CalendarSpec::parse("*:0/15").unwrap();
+    /// assert_eq!(spec.to_string(), "*-*-* *:00/15:00");
+    ///
+    /// assert_eq!(
+    ///     CalendarSpec::parse("*-13-01"),
+    ///     Err(ParseError::Syntax(2, "Invalid month".to_string()))
+    /// );
+    /// ```
+    pub fn parse(source: &str) -> Result<Self, ParseError> {
+        if source.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        // Like systemd, whitespace around the calendar event is not allowed
+        let mut end = source.len();
+        let mut zone = CalendarZone::Local;
+        if let Some(index) = source.rfind(' ') {
+            let name = &source[index + 1..];
+            let found = if name.eq_ignore_ascii_case("UTC") {
+                Some(CalendarZone::Utc)
+            } else if name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                TimeZone::from_name(name)
+                    .ok()
+                    .map(|time_zone| CalendarZone::Named(name.to_owned(), time_zone))
+            } else {
+                None
+            };
+            if let Some(found) = found {
+                zone = found;
+                end = index;
+            }
+        }
+
+        if end == 0 {
+            return Err(ParseError::Syntax(0, "Expected a number".to_owned()));
+        }
+
+        if let Some((_, expansion)) = SHORTHANDS.iter().find(|(name, _)| *name == &source[..end]) {
+            let mut spec = CalendarSpecParser::new(expansion, expansion.len()).parse()?;
+            spec.zone = zone;
+            return Ok(spec);
+        }
+
+        let mut spec = CalendarSpecParser::new(source, end).parse()?;
+        if spec.zone == CalendarZone::Local {
+            spec.zone = zone;
+        }
+        Ok(spec)
+    }
+
+    /// Return an iterator over the times at which this calendar event elapses after `after`
+    ///
+    /// The times are returned in UTC and are strictly later than `after`. Calendar events without
+    /// a time zone are evaluated in the local time zone (see [`TimeZone::local`]) or in UTC if the
+    /// local time zone cannot be determined. The iterator ends if there is no further elapse before
+    /// the end of the year `2199`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_systemd::{CalendarSpec, DateTime};
+    ///
+    /// let spec = CalendarSpec::parse("*-*-01 00:00:00 UTC").unwrap();
+    /// let elapses: Vec<DateTime> = spec
+    ///     .next_elapse(DateTime::from_gregorian_date_time(
+    ///         2023, 10, 16, 12, 0, 0, 0,
+    ///     ))
+    ///     .take(3)
+    ///     .collect();
+    /// assert_eq!(
+    ///     elapses,
+    ///     vec![
+    ///         DateTime::from_gregorian_date_time(2023, 11, 1, 0, 0, 0, 0),
+    ///         DateTime::from_gregorian_date_time(2023, 12, 1, 0, 0, 0, 0),
+    ///         DateTime::from_gregorian_date_time(2024, 1, 1, 0, 0, 0, 0),
+    ///     ]
+    /// );
+    /// ```
+    pub fn next_elapse(&self, after: DateTime) -> NextElapse<'_> {
+        self.next_elapse_with_local_zone(after, TimeZone::local().unwrap_or_default())
+    }
+
+    /// Like [`CalendarSpec::next_elapse`] but use `local_zone` if this calendar event doesn't
+    /// specify a time zone
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_systemd::{CalendarSpec, DateTime, TimeZone};
+    ///
+    /// let zone = TimeZone::from_tz_string("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+    /// let spec = CalendarSpec::parse("daily").unwrap();
+    /// let mut elapses = spec.next_elapse_with_local_zone(
+    ///     DateTime::from_gregorian_date_time(2023, 10, 16, 12, 0, 0, 0),
+    ///     zone,
+    /// );
+    /// assert_eq!(
+    ///     elapses.next(),
+    ///     Some(DateTime::from_gregorian_date_time(
+    ///         2023, 10, 16, 22, 0, 0, 0
+    ///     ))
+    /// );
+    /// ```
+    pub fn next_elapse_with_local_zone(
+        &self,
+        after: DateTime,
+        local_zone: TimeZone,
+    ) -> NextElapse<'_> {
+        let zone = match &self.zone {
+            CalendarZone::Local => local_zone,
+            CalendarZone::Utc => TimeZone::UTC,
+            CalendarZone::Named(_, zone) => zone.clone(),
+        };
+        NextElapse {
+            spec: self,
+            zone,
+            after: Some(after),
+        }
+    }
+
+    fn find_next_after(&self, after: DateTime, zone: &TimeZone) -> Option<DateTime> {
+        // Like systemd, calculate with micro second precision starting right after `after`
+        let nanos = after.as_hmsn().3 % NANOS_PER_USEC;
+        let start = after
+            .checked_sub_duration(&Duration::positive(0, nanos))?
+            .checked_add_duration(&Duration::positive(0, NANOS_PER_USEC))?;
+        let mut fields = Fields::from_date_time(utc_to_local(zone, start)?)?;
+        loop {
+            fields = self.find_next(fields, zone)?;
+            let local = fields.to_date_time()?;
+            let utc = local_to_utc(zone, local)?;
+            if utc > after {
+                return Some(utc);
+            }
+
+            // Ambiguous local times resolve to the earlier time but we might already be past it
+            let later = local.checked_sub_duration(&offset_to_duration(zone.offset_at(&after)))?;
+            if later > after && utc_to_local(zone, later) == Some(local) {
+                return Some(later);
+            }
+            fields.second += 1;
+        }
+    }
+
+    /// Find the next local time `>= fields` matching this calendar event
+    ///
+    /// This follows closely the algorithm of systemd to produce the same results.
+    #[allow(clippy::too_many_lines)]
+    fn find_next(&self, mut fields: Fields, zone: &TimeZone) -> Option<Fields> {
+        loop {
+            fields = fields.normalize(zone)?;
+            if fields.year > MAX_YEAR {
+                return None;
+            }
+
+            let year = u32::try_from(fields.year).unwrap_or_default();
+            match self.year.find(year, None) {
+                Some(found) if found != year => {
+                    fields.year = found.into();
+                    fields.reset_date();
+                }
+                Some(_) => {}
+                None => return None,
+            }
+            if fields.year > MAX_YEAR {
+                return None;
+            }
+
+            match self
+                .month
+                .find(fields.month, None)
+                .filter(|month| *month <= 12)
+            {
+                Some(month) if month != fields.month => {
+                    fields.month = month;
+                    fields.day = 1;
+                    fields.reset_time();
+                }
+                Some(_) => {}
+                None => {
+                    fields.year += 1;
+                    fields.reset_date();
+                    continue;
+                }
+            }
+
+            let days = days_in_month(fields.year, fields.month);
+            match self
+                .day
+                .find(fields.day, self.end_of_month.then_some(days))
+                .filter(|day| *day <= days)
+            {
+                Some(day) if day != fields.day => {
+                    fields.day = day;
+                    fields.reset_time();
+                }
+                Some(_) => {}
+                None => {
+                    fields.month += 1;
+                    fields.day = 1;
+                    fields.reset_time();
+                    continue;
+                }
+            }
+
+            if !self.matches_weekday(&fields) {
+                fields.day += 1;
+                fields.reset_time();
+                continue;
+            }
+
+            match self.hour.find(fields.hour, None).filter(|hour| *hour < 24) {
+                Some(hour) if hour != fields.hour => {
+                    fields.hour = hour;
+                    fields.minute = 0;
+                    fields.second = 0;
+                }
+                Some(_) => {}
+                None => {
+                    fields.day += 1;
+                    fields.reset_time();
+                    continue;
+                }
+            }
+
+            match self
+                .minute
+                .find(fields.minute, None)
+                .filter(|minute| *minute < 60)
+            {
+                Some(minute) if minute != fields.minute => {
+                    fields.minute = minute;
+                    fields.second = 0;
+                }
+                Some(_) => {}
+                None => {
+                    fields.hour += 1;
+                    fields.minute = 0;
+                    fields.second = 0;
+                    continue;
+                }
+            }
+
+            if let Some(second) = self
+                .second
+                .find(fields.second, None)
+                .filter(|second| *second < USEC_PER_MINUTE)
+            {
+                fields.second = second;
+            } else {
+                fields.minute += 1;
+                fields.second = 0;
+                continue;
+            }
+
+            // The local time doesn't exist because the clock was turned forward
+            if fields.normalize(zone)? != fields {
+                fields.minute += 1;
+                fields.second = 0;
+                continue;
+            }
+
+            return Some(fields);
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn matches_weekday(&self, fields: &Fields) -> bool {
+        self.weekdays.map_or(true, |bits| {
+            // The julian day 0 is a monday
+            let weekday =
+                JulianDay::from_gregorian(fields.year, fields.month as u8, fields.day as u8)
+                    .as_days()
+                    .rem_euclid(7);
+            bits & (1 << weekday) != 0
+        })
+    }
+
+    fn fmt_weekdays(f: &mut fmt::Formatter<'_>, bits: u8) -> fmt::Result {
+        let mut need_comma = false;
+        let mut range_start = None;
+        for day in 0..=WEEKDAYS.len() {
+            let is_set = day < WEEKDAYS.len() && bits & (1 << day) != 0;
+            match (is_set, range_start) {
+                (true, None) => {
+                    if need_comma {
+                        f.write_str(",")?;
+                    }
+                    need_comma = true;
+                    f.write_str(WEEKDAYS[day].1)?;
+                    range_start = Some(day);
+                }
+                (false, Some(start)) => {
+                    if day > start + 1 {
+                        f.write_str(if day > start + 2 { ".." } else { "," })?;
+                        f.write_str(WEEKDAYS[day - 1].1)?;
+                    }
+                    range_start = None;
+                }
+                _ => {}
+            }
+        }
+        f.write_str(" ")
+    }
+}
+
+impl FromStr for CalendarSpec {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl Display for CalendarSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(bits) = self.weekdays {
+            Self::fmt_weekdays(f, bits)?;
+        }
+        self.year.fmt(f, 4, false)?;
+        f.write_str("-")?;
+        self.month.fmt(f, 2, false)?;
+        f.write_str(if self.end_of_month { "~" } else { "-" })?;
+        self.day.fmt(f, 2, false)?;
+        f.write_str(" ")?;
+        self.hour.fmt(f, 2, false)?;
+        f.write_str(":")?;
+        self.minute.fmt(f, 2, false)?;
+        f.write_str(":")?;
+        self.second.fmt(f, 2, true)?;
+        match &self.zone {
+            CalendarZone::Local => Ok(()),
+            CalendarZone::Utc => f.write_str(" UTC"),
+            CalendarZone::Named(name, _) => write!(f, " {name}"),
+        }
+    }
+}
+
+/// The iterator over the elapses of a [`CalendarSpec`]
+///
+/// This iterator is created with [`CalendarSpec::next_elapse`] or
+/// [`CalendarSpec::next_elapse_with_local_zone`].
+#[derive(Debug, Clone)]
+pub struct NextElapse<'a> {
+    spec: &'a CalendarSpec,
+    zone: TimeZone,
+    after: Option<DateTime>,
+}
+
+impl Iterator for NextElapse<'_> {
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.spec.find_next_after(self.after?, &self.zone);
+        self.after = next;
+        next
+    }
+}
+
+impl FusedIterator for NextElapse<'_> {}
+
+struct CalendarSpecParser<'a> {
+    source: &'a str,
+    position: usize,
+    end: usize,
+}
+
+impl<'a> CalendarSpecParser<'a> {
+    const fn new(source: &'a str, end: usize) -> Self {
+        Self {
+            source,
+            position: 0,
+            end,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        (self.position < self.end).then(|| self.source.as_bytes()[self.position])
+    }
+
+    fn remainder(&self) -> &'a str {
+        &self.source[self.position..self.end]
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.peek() == Some(b' ') {
+            self.position += 1;
+        }
+    }
+
+    fn invalid_character(&self) -> ParseError {
+        ParseError::Syntax(self.position, "Invalid character".to_owned())
+    }
+
+    fn parse(mut self) -> Result<CalendarSpec, ParseError> {
+        if self.peek() == Some(b'@') {
+            return self.parse_timestamp();
+        }
+
+        let mut spec = CalendarSpec {
+            weekdays: self.parse_weekdays()?,
+            year: Chain::default(),
+            month: Chain::default(),
+            day: Chain::default(),
+            end_of_month: false,
+            hour: Chain::default(),
+            minute: Chain::default(),
+            second: Chain::default(),
+            zone: CalendarZone::Local,
+        };
+        self.parse_date(&mut spec)?;
+        self.parse_time(&mut spec)?;
+        if self.position < self.end {
+            return Err(self.invalid_character());
+        }
+
+        // A `~` without days matches any day
+        spec.end_of_month &= !spec.day.0.is_empty();
+        Ok(spec)
+    }
+
+    /// Parse a timestamp like `@1697457600` in seconds since the unix epoch
+    ///
+    /// Like `strtoull` in systemd, leading whitespace and a `+` sign are allowed.
+    fn parse_timestamp(&mut self) -> Result<CalendarSpec, ParseError> {
+        let start = self.position;
+        self.position += 1;
+        while self
+            .peek()
+            .map_or(false, |byte| is_whitespace(char::from(byte)))
+        {
+            self.position += 1;
+        }
+        if self.peek() == Some(b'+') {
+            self.position += 1;
+        }
+
+        let position = self.position;
+        let digits = self.parse_digits();
+        if digits.is_empty() {
+            return Err(ParseError::Syntax(position, "Expected a number".to_owned()));
+        }
+        if self.position < self.end {
+            return Err(self.invalid_character());
+        }
+
+        let fields = digits
+            .parse::<u64>()
+            .ok()
+            .and_then(|seconds| {
+                DateTime::UNIX_EPOCH.checked_add_duration(&Duration::positive(seconds, 0))
+            })
+            .and_then(Fields::from_date_time)
+            .filter(|fields| fields.year <= MAX_YEAR)
+            .ok_or_else(|| ParseError::Syntax(start, "Timestamp out of range".to_owned()))?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(CalendarSpec {
+            weekdays: None,
+            year: Chain::constant(fields.year as u32),
+            month: Chain::constant(fields.month),
+            day: Chain::constant(fields.day),
+            end_of_month: false,
+            hour: Chain::constant(fields.hour),
+            minute: Chain::constant(fields.minute),
+            second: Chain::constant(fields.second),
+            zone: CalendarZone::Utc,
+        })
+    }
+
+    /// Return the index of the day of the week starting with `0` (monday) at the current position
+    fn find_weekday(&self) -> Result<Option<(usize, usize)>, ParseError> {
+        let remainder = self.remainder().as_bytes();
+        for (day, names) in WEEKDAYS.iter().enumerate() {
+            for name in [names.0, names.1] {
+                if remainder.len() >= name.len()
+                    && remainder[..name.len()].eq_ignore_ascii_case(name.as_bytes())
+                {
+                    return match remainder.get(name.len()) {
+                        None | Some(b'-' | b'.' | b',' | b' ') => Ok(Some((day, name.len()))),
+                        Some(_) => Err(ParseError::Syntax(
+                            self.position,
+                            "Invalid day of the week".to_owned(),
+                        )),
+                    };
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    fn parse_weekdays(&mut self) -> Result<Option<u8>, ParseError> {
+        let mut bits = 0u8;
+        let mut range_start: Option<usize> = None;
+        loop {
+            let position = self.position;
+            let (day, len) = match self.find_weekday()? {
+                Some(found) => found,
+                None if bits == 0 => return Ok(None),
+                None => {
+                    return Err(ParseError::Syntax(
+                        position,
+                        "Invalid day of the week".to_owned(),
+                    ));
+                }
+            };
+
+            bits |= 1 << day;
+            if let Some(start) = range_start {
+                if start > day {
+                    return Err(ParseError::Syntax(
+                        position,
+                        "Invalid range of days of the week".to_owned(),
+                    ));
+                }
+                for day in start + 1..day {
+                    bits |= 1 << day;
+                }
+            }
+            self.position += len;
+
+            match self.peek() {
+                None => return Ok(Some(bits)),
+                Some(b' ') => {
+                    self.skip_spaces();
+                    return Ok(Some(bits));
+                }
+                Some(b'.') if range_start.is_none() && self.remainder().starts_with("..") => {
+                    range_start = Some(day);
+                    self.position += 2;
+                }
+                Some(b'-') if range_start.is_none() => {
+                    range_start = Some(day);
+                    self.position += 1;
+                }
+                Some(b',') => {
+                    range_start = None;
+                    self.position += 1;
+                }
+                Some(_) => return Err(self.invalid_character()),
+            }
+
+            // A trailing comma is allowed but not an open range
+            if matches!(self.peek(), None | Some(b' ')) {
+                if range_start.is_some() {
+                    return Err(ParseError::Syntax(
+                        self.position,
+                        "Invalid range of days of the week".to_owned(),
+                    ));
+                }
+                self.skip_spaces();
+                return Ok(Some(bits));
+            }
+        }
+    }
+
+    fn parse_date(&mut self, spec: &mut CalendarSpec) -> Result<(), ParseError> {
+        if self.peek().is_none() {
+            return Ok(());
+        }
+
+        let first_position = self.position;
+        let first = self.parse_chain(false)?;
+        match self.peek() {
+            // This is the time and not the date
+            None | Some(b':') => {
+                self.position = first_position;
+                return Ok(());
+            }
+            Some(b'~') => spec.end_of_month = true,
+            Some(b'-') => {}
+            Some(_) => return Err(self.invalid_character()),
+        }
+        self.position += 1;
+
+        let second_position = self.position;
+        let second = self.parse_chain(false)?;
+        match self.peek() {
+            None | Some(b' ') => {
+                self.skip_spaces();
+                spec.month = Self::validate(first, first_position, Field::Month, false)?;
+                spec.day = Self::validate(second, second_position, Field::Day, spec.end_of_month)?;
+                return Ok(());
+            }
+            Some(b'~') if !spec.end_of_month => spec.end_of_month = true,
+            Some(b'-') if !spec.end_of_month => {}
+            Some(_) => return Err(self.invalid_character()),
+        }
+        self.position += 1;
+
+        let third_position = self.position;
+        let third = self.parse_chain(false)?;
+        if !matches!(self.peek(), None | Some(b' ')) {
+            return Err(self.invalid_character());
+        }
+        self.skip_spaces();
+
+        let mut year = first;
+        year.fix_year();
+        spec.year = Self::validate(year, first_position, Field::Year, false)?;
+        spec.month = Self::validate(second, second_position, Field::Month, false)?;
+        spec.day = Self::validate(third, third_position, Field::Day, spec.end_of_month)?;
+        Ok(())
+    }
+
+    fn parse_time(&mut self, spec: &mut CalendarSpec) -> Result<(), ParseError> {
+        if self.peek().is_none() {
+            spec.hour = Chain::constant(0);
+            spec.minute = Chain::constant(0);
+            spec.second = Chain::constant(0);
+            return Ok(());
+        }
+
+        let position = self.position;
+        let hour = self.parse_chain(false)?;
+        spec.hour = Self::validate(hour, position, Field::Hour, false)?;
+        self.expect_colon()?;
+
+        let position = self.position;
+        let minute = self.parse_chain(false)?;
+        spec.minute = Self::validate(minute, position, Field::Minute, false)?;
+        if self.peek().is_none() {
+            spec.second = Chain::constant(0);
+            return Ok(());
+        }
+        self.expect_colon()?;
+
+        let position = self.position;
+        let second = self.parse_chain(true)?;
+        spec.second = Self::validate(second, position, Field::Second, false)?;
+        Ok(())
+    }
+
+    fn expect_colon(&mut self) -> Result<(), ParseError> {
+        if self.peek() == Some(b':') {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(self.invalid_character())
+        }
+    }
+
+    fn validate(
+        mut chain: Chain,
+        position: usize,
+        field: Field,
+        end_of_month: bool,
+    ) -> Result<Chain, ParseError> {
+        chain.normalize();
+        if chain.is_valid(field, end_of_month) {
+            Ok(chain)
+        } else {
+            Err(ParseError::Syntax(
+                position,
+                format!("Invalid {}", field.name()),
+            ))
+        }
+    }
+
+    fn parse_chain(&mut self, usec: bool) -> Result<Chain, ParseError> {
+        if self.peek() == Some(b'*') {
+            self.position += 1;
+            return Ok(if usec {
+                Chain(vec![SECONDS_ANY])
+            } else {
+                Chain::default()
+            });
+        }
+
+        let mut components = vec![self.parse_component(usec)?];
+        while self.peek() == Some(b',') {
+            self.position += 1;
+            components.push(self.parse_component(usec)?);
+        }
+        Ok(Chain(components))
+    }
+
+    fn parse_component(&mut self, usec: bool) -> Result<Component, ParseError> {
+        let position = self.position;
+        let mut component = Component::new(self.parse_number(usec)?);
+        if self.remainder().starts_with("..") {
+            self.position += 2;
+            component.stop = Some(self.parse_number(usec)?);
+            component.repeat = if usec { USEC_PER_SEC } else { 1 };
+        }
+        match self.peek() {
+            Some(b'/') => {
+                self.position += 1;
+                let position = self.position;
+                component.repeat = self.parse_number(usec)?;
+                if component.repeat == 0 {
+                    return Err(ParseError::Syntax(
+                        position,
+                        "Repetition must not be zero".to_owned(),
+                    ));
+                }
+            }
+            // Ranges of seconds without repetition need to span at least one second
+            _ if usec
+                && component
+                    .stop
+                    .map_or(false, |stop| component.start + component.repeat > stop) =>
+            {
+                return Err(ParseError::Syntax(position, "Invalid second".to_owned()));
+            }
+            _ => {}
+        }
+
+        match self.peek() {
+            None | Some(b' ' | b',' | b'-' | b'~' | b':') => Ok(component),
+            Some(_) => Err(self.invalid_character()),
+        }
+    }
+
+    fn parse_digits(&mut self) -> &'a str {
+        let start = self.position;
+        while self.peek().map_or(false, |byte| byte.is_ascii_digit()) {
+            self.position += 1;
+        }
+        &self.source[start..self.position]
+    }
+
+    /// Parse a number which is in micro seconds if `usec` is true
+    ///
+    /// Like in systemd, the fraction is rounded to six digits. Numbers which are too large saturate
+    /// and are rejected by the validation of the component.
+    fn parse_number(&mut self, usec: bool) -> Result<u32, ParseError> {
+        let position = self.position;
+        let digits = self.parse_digits();
+        if digits.is_empty() {
+            return Err(ParseError::Syntax(position, "Expected a number".to_owned()));
+        }
+
+        let to_number = |digits: &str| {
+            digits.bytes().fold(0u64, |acc, digit| {
+                acc.saturating_mul(10)
+                    .saturating_add(u64::from(digit - b'0'))
+            })
+        };
+        let mut number = to_number(digits);
+        if usec {
+            number = number.saturating_mul(USEC_PER_SEC.into());
+            if self.peek() == Some(b'.')
+                && self.remainder()[1..].starts_with(|c: char| c.is_ascii_digit())
+            {
+                self.position += 1;
+                let fraction = self.parse_digits();
+                let mut micros = to_number(&format!("{fraction:0<6.6}"));
+                if fraction.len() > 6 && fraction.as_bytes()[6] >= b'5' {
+                    micros += 1;
+                }
+                number = number.saturating_add(micros);
+            }
+        }
+        Ok(u32::try_from(number).unwrap_or(u32::MAX))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::start_matches(Component::new(5), 5, Some(5))]
+    #[case::start_is_later(Component::new(5), 3, Some(5))]
+    #[case::start_is_earlier(Component::new(5), 6, None)]
+    #[case::repeat(Component { start: 0, stop: None, repeat: 15 }, 16, Some(30))]
+    #[case::repeat_exact(Component { start: 0, stop: None, repeat: 15 }, 45, Some(45))]
+    #[case::range(Component { start: 1, stop: Some(3), repeat: 1 }, 2, Some(2))]
+    #[case::range_exceeded(Component { start: 1, stop: Some(3), repeat: 1 }, 4, None)]
+    fn test_component_find(
+        #[case] component: Component,
+        #[case] value: u32,
+        #[case] expected: Option<u32>,
+    ) {
+        assert_eq!(
+            component.find(value, component.start, component.stop),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case::last_day(Component::new(1), 1, Some(30))]
+    #[case::third_last_day(Component::new(3), 1, Some(28))]
+    #[case::out_of_month(Component::new(31), 1, None)]
+    #[case::range(Component { start: 1, stop: Some(3), repeat: 1 }, 1, Some(28))]
+    #[case::repeat(Component { start: 7, stop: None, repeat: 2 }, 25, Some(26))]
+    fn test_chain_find_end_of_month(
+        #[case] component: Component,
+        #[case] value: u32,
+        #[case] expected: Option<u32>,
+    ) {
+        assert_eq!(Chain(vec![component]).find(value, Some(30)), expected);
+    }
+
+    #[rstest]
+    #[case::two_digits_2000(12, 2012)]
+    #[case::two_digits_1900(89, 1989)]
+    #[case::four_digits(2023, 2023)]
+    fn test_chain_fix_year(#[case] year: u32, #[case] expected: u32) {
+        let mut chain = Chain::constant(year);
+        chain.fix_year();
+        assert_eq!(chain, Chain::constant(expected));
+    }
+
+    #[rstest]
+    #[case::january(2023, 1, 31)]
+    #[case::april(2023, 4, 30)]
+    #[case::february(2023, 2, 28)]
+    #[case::february_leap_year(2024, 2, 29)]
+    #[case::february_not_leap_year(2100, 2, 28)]
+    #[case::february_leap_year_400(2000, 2, 29)]
+    fn test_days_in_month(#[case] year: i64, #[case] month: u32, #[case] expected: u32) {
+        assert_eq!(days_in_month(year, month), expected);
+    }
+
+    #[test]
+    fn test_fields_carry() {
+        let mut fields = Fields {
+            year: 2023,
+            month: 12,
+            day: 31,
+            hour: 23,
+            minute: 59,
+            second: USEC_PER_MINUTE,
+        };
+        fields.carry();
+        assert_eq!(
+            fields,
+            Fields {
+                year: 2024,
+                month: 1,
+                day: 1,
+                hour: 0,
+                minute: 0,
+                second: 0
+            }
+        );
+    }
+}
@@ -0,0 +1,373 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::borrow::Cow;
+
+use fundu::TimeUnit::*;
+use fundu::{Duration, ParseError, TimeUnit};
+
+use crate::{
+    trim_whitespace, TimeSpanParser, SYSTEMD_MAX_MICRO_DURATION, SYSTEMD_MAX_NANOS_DURATION,
+};
+
+/// How a directive treats the special values `infinity` and `0`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Infinity {
+    /// `infinity` evaluates to the maximum [`Duration`] of the directive
+    Allowed,
+    /// Like [`Infinity::Allowed`] but also a time span of `0` evaluates to the maximum [`Duration`]
+    ///
+    /// This is the behavior of the timeouts like `TimeoutSec=0` which disable the timeout.
+    ZeroIsInfinity,
+}
+
+/// The rules of how a systemd directive like `RestartSec=` parses its time span value
+///
+/// The directives of systemd interpret their values differently: numbers without a time unit are
+/// seconds in `RestartSec=` but nano seconds in `TimerSlackNSec=`, some directives accept the nano
+/// second time units and the timeouts treat `0` like `infinity`. A `Directive` bundles these rules,
+/// so the value is parsed like systemd does. See [`Directive::from_name`] and [`DIRECTIVES`] for
+/// the known directives.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu::{Duration, TimeUnit};
+/// use fundu_systemd::{Directive, Infinity, SYSTEMD_MAX_MICRO_DURATION};
+///
+/// let directive = Directive::from_name("TimeoutStartSec").unwrap();
+/// assert_eq!(directive.default_unit, TimeUnit::Second);
+/// assert_eq!(directive.infinity, Infinity::ZeroIsInfinity);
+/// assert_eq!(directive.parse("90"), Ok(Duration::positive(90, 0)));
+/// assert_eq!(directive.parse("0"), Ok(SYSTEMD_MAX_MICRO_DURATION));
+///
+/// // A custom directive
+/// let directive = Directive {
+///     default_unit: TimeUnit::MilliSecond,
+///     nanos: false,
+///     max: Duration::positive(60, 0),
+///     infinity: Infinity::Allowed,
+/// };
+/// assert_eq!(directive.parse("1500"), Ok(Duration::positive(1, 500_000_000)));
+/// assert_eq!(directive.parse("infinity"), Ok(Duration::positive(60, 0)));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Directive {
+    /// The [`TimeUnit`] of numbers without a time unit
+    pub default_unit: TimeUnit,
+    /// If `true`, the nano second time units `ns` and `nsec` are accepted
+    pub nanos: bool,
+    /// The maximum [`Duration`] at which the time span saturates and to which `infinity` evaluates
+    pub max: Duration,
+    /// How `infinity` and `0` are treated
+    pub infinity: Infinity,
+}
+
+/// A directive with seconds as default unit
+const SECONDS: Directive = Directive::new(Second);
+/// A timeout with seconds as default unit where `0` disables the timeout
+const TIMEOUT: Directive = Directive {
+    infinity: Infinity::ZeroIsInfinity,
+    ..SECONDS
+};
+/// A directive with micro seconds as default unit like the `*USec` properties
+const MICROS: Directive = Directive::new(MicroSecond);
+/// A directive with nano seconds as default unit like `TimerSlackNSec=`
+const NANOS: Directive = Directive {
+    default_unit: NanoSecond,
+    nanos: true,
+    max: SYSTEMD_MAX_NANOS_DURATION,
+    infinity: Infinity::Allowed,
+};
+
+/// The time span directives of the systemd unit files sorted by name
+///
+/// Directives which are not in this list are still recognized by [`Directive::from_name`] if
+/// their name ends with `Sec`, `USec` or `NSec`.
+pub const DIRECTIVES: &[(&str, Directive)] = &[
+    ("AccuracySec", SECONDS),
+    ("CPUQuotaPeriodSec", SECONDS),
+    ("DeferAcceptSec", SECONDS),
+    ("JobRunningTimeoutSec", TIMEOUT),
+    ("JobTimeoutSec", TIMEOUT),
+    ("KeepAliveIntervalSec", SECONDS),
+    ("KeepAliveTimeSec", SECONDS),
+    ("LogRateLimitIntervalSec", SECONDS),
+    ("OnActiveSec", SECONDS),
+    ("OnBootSec", SECONDS),
+    ("OnStartupSec", SECONDS),
+    ("OnUnitActiveSec", SECONDS),
+    ("OnUnitInactiveSec", SECONDS),
+    ("RandomizedDelaySec", SECONDS),
+    ("RestartMaxDelaySec", SECONDS),
+    ("RestartSec", SECONDS),
+    ("RuntimeMaxSec", SECONDS),
+    ("RuntimeRandomizedExtraSec", SECONDS),
+    ("StartLimitIntervalSec", SECONDS),
+    ("TimeoutAbortSec", TIMEOUT),
+    ("TimeoutCleanSec", SECONDS),
+    ("TimeoutSec", TIMEOUT),
+    ("TimeoutStartSec", TIMEOUT),
+    ("TimeoutStopSec", TIMEOUT),
+    ("TimerSlackNSec", NANOS),
+    ("TriggerLimitIntervalSec", SECONDS),
+    ("WatchdogSec", SECONDS),
+];
+
+impl Directive {
+    /// Create a new `Directive` with the `default_unit` and the systemd maximum of `u64::MAX`
+    /// micro seconds
+    const fn new(default_unit: TimeUnit) -> Self {
+        Self {
+            default_unit,
+            nanos: false,
+            max: SYSTEMD_MAX_MICRO_DURATION,
+            infinity: Infinity::Allowed,
+        }
+    }
+
+    /// Return the `Directive` with the given `name` like `RestartSec` (without the `=`)
+    ///
+    /// The `name` is searched case-sensitive in the [`DIRECTIVES`]. Other names are recognized by
+    /// their suffix like the properties of `systemctl show`: Names ending with `NSec` have nano
+    /// seconds as default unit and accept the nano second time units, names ending with `USec` have
+    /// micro seconds as default unit and names ending with `Sec` seconds. Returns `None` if the
+    /// `name` is not a time span directive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::TimeUnit;
+    /// use fundu_systemd::Directive;
+    ///
+    /// assert_eq!(
+    ///     Directive::from_name("RestartSec").map(|d| d.default_unit),
+    ///     Some(TimeUnit::Second)
+    /// );
+    /// assert_eq!(
+    ///     Directive::from_name("CPUQuotaPeriodUSec").map(|d| d.default_unit),
+    ///     Some(TimeUnit::MicroSecond)
+    /// );
+    /// assert_eq!(Directive::from_name("ExecStart"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        DIRECTIVES
+            .binary_search_by(|(directive, _)| (*directive).cmp(name))
+            .map(|index| DIRECTIVES[index].1)
+            .ok()
+            .or_else(|| {
+                if name.ends_with("NSec") {
+                    Some(NANOS)
+                } else if name.ends_with("USec") {
+                    Some(MICROS)
+                } else if name.ends_with("Sec") {
+                    Some(SECONDS)
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// Parse the `value` of this directive into a [`Duration`]
+    ///
+    /// The `value` is parsed with the [`TimeSpanParser`] and the rules of this directive.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the `value` is not a valid time span
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{Duration, ParseError};
+    /// use fundu_systemd::{Directive, SYSTEMD_MAX_NANOS_DURATION};
+    ///
+    /// let directive = Directive::from_name("TimerSlackNSec").unwrap();
+    /// assert_eq!(directive.parse("50"), Ok(Duration::positive(0, 50)));
+    /// assert_eq!(directive.parse("2us 5ns"), Ok(Duration::positive(0, 2005)));
+    /// assert_eq!(directive.parse("infinity"), Ok(SYSTEMD_MAX_NANOS_DURATION));
+    ///
+    /// let directive = Directive::from_name("RestartSec").unwrap();
+    /// assert_eq!(
+    ///     directive.parse("5ns"),
+    ///     Err(ParseError::InvalidInput("ns".to_owned()))
+    /// );
+    /// ```
+    pub fn parse(&self, value: &str) -> Result<Duration, ParseError> {
+        let parser = TimeSpanParser::with_default_unit(self.default_unit);
+        let duration = if self.nanos {
+            parser.parse_nanos_with_max(value, self.max)
+        } else {
+            parser.parse_with_max(value, self.max)
+        }?;
+        match self.infinity {
+            Infinity::ZeroIsInfinity if duration.is_zero() => Ok(self.max),
+            _ => Ok(duration),
+        }
+    }
+}
+
+/// Parse the `value` of the systemd directive with the given `name` into a [`Duration`]
+///
+/// The default unit, the maximum, the nano second time units and the handling of `infinity` are
+/// chosen per directive (see [`Directive::from_name`]).
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the `name` is not a time span directive or the `value` is not a
+/// valid time span
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu::{Duration, ParseError};
+/// use fundu_systemd::{parse_directive, SYSTEMD_MAX_MICRO_DURATION};
+///
+/// assert_eq!(
+///     parse_directive("RestartSec", "100ms"),
+///     Ok(Duration::positive(0, 100_000_000))
+/// );
+/// assert_eq!(
+///     parse_directive("RuntimeMaxSec", "infinity"),
+///     Ok(SYSTEMD_MAX_MICRO_DURATION)
+/// );
+/// assert_eq!(
+///     parse_directive("TimeoutStopSec", "0"),
+///     Ok(SYSTEMD_MAX_MICRO_DURATION)
+/// );
+/// assert_eq!(
+///     parse_directive("CPUQuotaPeriodUSec", "10000"),
+///     Ok(Duration::positive(0, 10_000_000))
+/// );
+/// assert_eq!(
+///     parse_directive("ExecStart", "/bin/true"),
+///     Err(ParseError::InvalidInput(
+///         "Not a time span directive: 'ExecStart'".to_owned()
+///     ))
+/// );
+/// ```
+pub fn parse_directive(name: &str, value: &str) -> Result<Duration, ParseError> {
+    Directive::from_name(name)
+        .ok_or_else(|| ParseError::InvalidInput(format!("Not a time span directive: '{name}'")))?
+        .parse(value)
+}
+
+/// A time span directive found in a unit file with the parsed value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnitFileEntry<'a> {
+    /// The section like `Service` without the brackets or an empty string if the directive is not
+    /// in a section
+    pub section: &'a str,
+    /// The name of the directive like `RestartSec`
+    pub name: &'a str,
+    /// The value with continuation lines joined
+    pub value: Cow<'a, str>,
+    /// The number of the line where the directive starts counting from `1`
+    pub line: usize,
+    /// The [`Duration`] or the [`ParseError`] if the value is not valid for this directive
+    pub duration: Result<Duration, ParseError>,
+}
+
+/// Extract and validate all time span directives of the `content` of a unit file
+///
+/// The `content` is read like systemd reads unit files: Lines starting with `#` or `;` are
+/// comments, sections start with a line like `[Service]` and the directives are assignments like
+/// `RestartSec=5s` with whitespace around the name and the value being ignored. A line ending with
+/// a backslash is continued on the next line and the backslash is replaced by a space. Every
+/// directive recognized by [`Directive::from_name`] is parsed with its rules. Empty assignments
+/// like `RestartSec=` reset a directive to its default in systemd and are skipped.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu::{Duration, ParseError};
+/// use fundu_systemd::parse_unit_file;
+///
+/// let unit = "\
+/// [Unit]
+/// Description=Example
+///
+/// [Service]
+/// ExecStart=/usr/bin/example
+/// RestartSec=5s
+/// TimeoutStopSec=1min \\
+///     30s
+/// WatchdogSec=5 apples
+/// ";
+/// let entries = parse_unit_file(unit);
+/// assert_eq!(entries.len(), 3);
+///
+/// assert_eq!(entries[0].section, "Service");
+/// assert_eq!(entries[0].name, "RestartSec");
+/// assert_eq!(entries[0].line, 6);
+/// assert_eq!(entries[0].duration, Ok(Duration::positive(5, 0)));
+///
+/// assert_eq!(entries[1].value, "1min  30s");
+/// assert_eq!(entries[1].duration, Ok(Duration::positive(90, 0)));
+///
+/// assert_eq!(
+///     entries[2].duration,
+///     Err(ParseError::InvalidInput("apples".to_owned()))
+/// );
+/// ```
+pub fn parse_unit_file(content: &str) -> Vec<UnitFileEntry<'_>> {
+    let mut entries = Vec::new();
+    let mut section = "";
+    let mut lines = content.lines().enumerate();
+    while let Some((index, line)) = lines.next() {
+        let line = trim_whitespace(line);
+        if line.is_empty() || line.starts_with(['#', ';']) {
+            continue;
+        }
+        if let Some(name) = line
+            .strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'))
+        {
+            section = name;
+            continue;
+        }
+        let (name, value) = match line.split_once('=') {
+            Some(assignment) => assignment,
+            None => continue,
+        };
+
+        let mut value = Cow::Borrowed(trim_whitespace(value));
+        let mut continued = value.ends_with('\\');
+        while continued {
+            let value = value.to_mut();
+            value.pop();
+            value.push(' ');
+            match lines.next() {
+                Some((_, line)) if trim_whitespace(line).starts_with(['#', ';']) => {}
+                Some((_, line)) => {
+                    let line = trim_whitespace(line);
+                    value.push_str(line);
+                    continued = line.ends_with('\\');
+                }
+                None => continued = false,
+            }
+        }
+
+        let value = match value {
+            Cow::Borrowed(value) => Cow::Borrowed(value),
+            Cow::Owned(value) => Cow::Owned(trim_whitespace(&value).to_owned()),
+        };
+        let name = trim_whitespace(name);
+        if value.is_empty() {
+            continue;
+        }
+        if let Some(directive) = Directive::from_name(name) {
+            let duration = directive.parse(&value);
+            entries.push(UnitFileEntry {
+                section,
+                name,
+                value,
+                line: index + 1,
+                duration,
+            });
+        }
+    }
+    entries
+}
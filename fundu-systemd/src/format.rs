@@ -0,0 +1,397 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::fmt::Write;
+
+use fundu::Duration;
+#[cfg(feature = "datetime")]
+use fundu_gnu::{DateTime, JulianDay, TimeZone};
+
+#[cfg(feature = "datetime")]
+use crate::calendar::{utc_to_local, WEEKDAYS};
+
+const USEC_PER_MSEC: u64 = 1000;
+const USEC_PER_SEC: u64 = 1_000_000;
+const USEC_PER_MINUTE: u64 = 60 * USEC_PER_SEC;
+const USEC_PER_HOUR: u64 = 60 * USEC_PER_MINUTE;
+const USEC_PER_DAY: u64 = 24 * USEC_PER_HOUR;
+const USEC_PER_WEEK: u64 = 7 * USEC_PER_DAY;
+/// A month is `30.44` days like in systemd
+const USEC_PER_MONTH: u64 = 2_629_800 * USEC_PER_SEC;
+/// A year is `365.25` days like in systemd
+const USEC_PER_YEAR: u64 = 31_557_600 * USEC_PER_SEC;
+
+#[cfg(feature = "datetime")]
+/// The latest timestamp systemd formats (`9999-12-30 23:59:59 UTC`) in micro seconds
+const USEC_TIMESTAMP_FORMATTABLE_MAX: u64 = 253_402_214_399_000_000;
+#[cfg(feature = "datetime")]
+/// The placeholder systemd writes for timestamps after [`USEC_TIMESTAMP_FORMATTABLE_MAX`]
+const TIMESTAMP_NOT_FORMATTABLE: &str = "--- XXXX-XX-XX XX:XX:XX";
+
+/// The time units of `format_timespan` in systemd from the largest to the smallest
+const TIME_SPAN_UNITS: [(&str, u64); 9] = [
+    ("y", USEC_PER_YEAR),
+    ("month", USEC_PER_MONTH),
+    ("w", USEC_PER_WEEK),
+    ("d", USEC_PER_DAY),
+    ("h", USEC_PER_HOUR),
+    ("min", USEC_PER_MINUTE),
+    ("s", USEC_PER_SEC),
+    ("ms", USEC_PER_MSEC),
+    ("us", 1),
+];
+
+#[cfg(feature = "datetime")]
+/// The styles in which systemd formats timestamps
+///
+/// These are the styles of the `--timestamp=` option of `systemctl` and `journalctl`. The examples
+/// show the timestamp `2012-11-23 10:12:13.5 UTC` in the time zone `Europe/Berlin`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum TimestampStyle {
+    /// The local time with the day of the week and the time zone: `Fri 2012-11-23 11:12:13 CET`
+    #[default]
+    Pretty,
+    /// Like [`TimestampStyle::Pretty`] with micro seconds: `Fri 2012-11-23 11:12:13.500000 CET`
+    Us,
+    /// Like [`TimestampStyle::Pretty`] but in UTC: `Fri 2012-11-23 10:12:13 UTC`
+    Utc,
+    /// Like [`TimestampStyle::Utc`] with micro seconds: `Fri 2012-11-23 10:12:13.500000 UTC`
+    UsUtc,
+    /// The seconds since the unix epoch: `@1353665533`
+    Unix,
+}
+
+/// Return the micro seconds of the `duration` clamped to `0` and `u64::MAX`
+fn to_usec(duration: &Duration) -> u64 {
+    u64::try_from(duration.as_micros().max(0)).unwrap_or(u64::MAX)
+}
+
+#[cfg(feature = "datetime")]
+/// Return the micro seconds since the unix epoch like systemd's `timestamp_is_set` or `None`
+///
+/// Like in systemd, the unix epoch itself and timestamps before the unix epoch are not set.
+fn timestamp_to_usec(date_time: DateTime) -> Option<u64> {
+    date_time
+        .duration_since(DateTime::UNIX_EPOCH)
+        .map(|duration| to_usec(&duration))
+        .filter(|usec| *usec > 0 && *usec < u64::MAX)
+}
+
+/// Format the `duration` like `format_timespan` of systemd does
+///
+/// The `duration` is split into years, months, weeks, days, hours, minutes, seconds, milli
+/// seconds and micro seconds as they are printed for example by `systemctl show` or
+/// `systemd-analyze timespan`. The result can be parsed again with the [`crate::TimeSpanParser`].
+///
+/// The `accuracy` limits the precision of the output. Once the remainder of the `duration` is
+/// smaller than the `accuracy`, the remainder is dropped. Durations smaller than a minute may be
+/// written with a fraction like `10.500s` whose amount of digits also depends on the `accuracy`.
+/// An `accuracy` of [`Duration::ZERO`] is the same as an accuracy of a micro second.
+///
+/// Like in systemd, the `duration` has a precision of micro seconds. Durations smaller than a
+/// micro second (including negative durations) are written as `0` and durations equal to or
+/// larger than the [`crate::SYSTEMD_MAX_MICRO_DURATION`] as `infinity`.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu::Duration;
+/// use fundu_systemd::format_timespan;
+///
+/// let duration = Duration::positive(5410, 500_000_000);
+/// assert_eq!(
+///     format_timespan(duration, Duration::ZERO),
+///     "1h 30min 10.500000s"
+/// );
+/// assert_eq!(
+///     format_timespan(duration, Duration::positive(0, 1_000_000)),
+///     "1h 30min 10.500s"
+/// );
+/// assert_eq!(
+///     format_timespan(duration, Duration::positive(60, 0)),
+///     "1h 30min"
+/// );
+/// assert_eq!(
+///     format_timespan(Duration::positive(44_706_600, 0), Duration::ZERO),
+///     "1y 5month"
+/// );
+/// assert_eq!(format_timespan(Duration::ZERO, Duration::ZERO), "0");
+/// assert_eq!(format_timespan(Duration::MAX, Duration::ZERO), "infinity");
+/// ```
+pub fn format_timespan(duration: Duration, accuracy: Duration) -> String {
+    let mut usec = to_usec(&duration);
+    if usec == u64::MAX {
+        return "infinity".to_owned();
+    }
+    if usec == 0 {
+        return "0".to_owned();
+    }
+
+    let accuracy = to_usec(&accuracy);
+    let mut buffer = String::new();
+    for (suffix, unit) in TIME_SPAN_UNITS {
+        if usec == 0 || (usec < accuracy && !buffer.is_empty()) {
+            break;
+        }
+        if usec < unit {
+            continue;
+        }
+
+        if !buffer.is_empty() {
+            buffer.push(' ');
+        }
+        let (whole, mut fraction) = (usec / unit, usec % unit);
+        // Durations smaller than a minute are written with a fraction if the accuracy allows it
+        if usec < USEC_PER_MINUTE && fraction > 0 {
+            let mut digits = 0_i32;
+            let mut power = unit;
+            while power > 1 {
+                digits += 1_i32;
+                power /= 10;
+            }
+            let mut power = accuracy;
+            while power > 1 {
+                fraction /= 10;
+                digits -= 1_i32;
+                power /= 10;
+            }
+            if digits > 0_i32 {
+                #[allow(clippy::cast_sign_loss)]
+                let width = digits as usize;
+                // The unwrap is safe because writing into a `String` doesn't fail
+                write!(buffer, "{whole}.{fraction:0width$}{suffix}").unwrap();
+                break;
+            }
+        }
+        // The unwrap is safe because writing into a `String` doesn't fail
+        write!(buffer, "{whole}{suffix}").unwrap();
+        usec %= unit;
+    }
+    buffer
+}
+
+#[cfg(feature = "datetime")]
+/// Format the `date_time` like `format_timestamp` of systemd in the [`TimestampStyle::Pretty`]
+///
+/// This is the style systemd uses for example in `systemctl show` or `systemctl status` like in
+/// `Fri 2012-11-23 11:12:13 CET`. See [`format_timestamp_style`] for the details.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_systemd::{format_timestamp, DateTime, TimeZone};
+///
+/// let zone = TimeZone::from_tz_string("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+/// let date_time = DateTime::from_gregorian_date_time(2012, 11, 23, 10, 12, 13, 500_000_000);
+/// assert_eq!(
+///     format_timestamp(date_time, Some(&zone)),
+///     Some("Fri 2012-11-23 11:12:13 CET".to_owned())
+/// );
+/// assert_eq!(format_timestamp(DateTime::UNIX_EPOCH, Some(&zone)), None);
+/// ```
+#[inline]
+pub fn format_timestamp(date_time: DateTime, zone: Option<&TimeZone>) -> Option<String> {
+    format_timestamp_style(date_time, zone, TimestampStyle::Pretty)
+}
+
+#[cfg(feature = "datetime")]
+/// Format the UTC `date_time` like `format_timestamp_style` of systemd in the given `style`
+///
+/// The local time is the time in the `zone`. If the `zone` is `None`, the local time zone of the
+/// system is used (see [`TimeZone::local`]) or UTC if it can't be determined. The time zone is
+/// written as abbreviation like `CET` (see [`TimeZone::abbreviation_at`]) and omitted if the zone
+/// has no abbreviation. Like in systemd, the micro seconds are truncated and the output can be
+/// parsed again with the [`crate::TimestampParser`].
+///
+/// Returns `None` if the `date_time` is not after the unix epoch. In this case systemd usually
+/// prints `n/a`. Like in systemd, timestamps after `9999-12-30 23:59:59 UTC` are written as `---
+/// XXXX-XX-XX XX:XX:XX` in all styles but [`TimestampStyle::Unix`].
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_systemd::{format_timestamp_style, DateTime, TimeZone, TimestampStyle};
+///
+/// let zone = TimeZone::from_tz_string("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+/// let date_time = DateTime::from_gregorian_date_time(2012, 11, 23, 10, 12, 13, 500_000_000);
+/// assert_eq!(
+///     format_timestamp_style(date_time, Some(&zone), TimestampStyle::Us),
+///     Some("Fri 2012-11-23 11:12:13.500000 CET".to_owned())
+/// );
+/// assert_eq!(
+///     format_timestamp_style(date_time, Some(&zone), TimestampStyle::Utc),
+///     Some("Fri 2012-11-23 10:12:13 UTC".to_owned())
+/// );
+/// assert_eq!(
+///     format_timestamp_style(date_time, None, TimestampStyle::Unix),
+///     Some("@1353665533".to_owned())
+/// );
+/// ```
+pub fn format_timestamp_style(
+    date_time: DateTime,
+    zone: Option<&TimeZone>,
+    style: TimestampStyle,
+) -> Option<String> {
+    let usec = timestamp_to_usec(date_time)?;
+    if style == TimestampStyle::Unix {
+        return Some(format!("@{}", usec / USEC_PER_SEC));
+    }
+    if usec > USEC_TIMESTAMP_FORMATTABLE_MAX {
+        return Some(TIMESTAMP_NOT_FORMATTABLE.to_owned());
+    }
+
+    let utc =
+        DateTime::UNIX_EPOCH.checked_add_duration(&Duration::positive(usec / USEC_PER_SEC, 0))?;
+    let local_zone;
+    let zone = match (style, zone) {
+        (TimestampStyle::Utc | TimestampStyle::UsUtc, _) => None,
+        (_, Some(zone)) => Some(zone),
+        (_, None) => {
+            local_zone = TimeZone::local().unwrap_or_default();
+            Some(&local_zone)
+        }
+    };
+    let local = match zone {
+        Some(zone) => utc_to_local(zone, utc)?,
+        None => utc,
+    };
+
+    let (year, month, day, hour, minute, second, _) = local.to_gregorian_date_time()?;
+    let weekday = JulianDay::try_from_gregorian(year, month, day)?.as_days();
+    // The julian day `0` is a monday like the first of the `WEEKDAYS`
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let weekday = WEEKDAYS[weekday.rem_euclid(7) as usize].1;
+
+    let mut buffer =
+        format!("{weekday} {year:04}-{month:02}-{day:02} {hour:02}:{minute:02}:{second:02}");
+    if matches!(style, TimestampStyle::Us | TimestampStyle::UsUtc) {
+        // The unwrap is safe because writing into a `String` doesn't fail
+        write!(buffer, ".{:06}", usec % USEC_PER_SEC).unwrap();
+    }
+    match zone {
+        None => buffer.push_str(" UTC"),
+        Some(zone) => {
+            if let Some(abbreviation) = zone.abbreviation_at(&utc) {
+                buffer.push(' ');
+                buffer.push_str(abbreviation);
+            }
+        }
+    }
+    Some(buffer)
+}
+
+#[cfg(feature = "datetime")]
+/// Format the `date_time` relative to `now` like `format_timestamp_relative` of systemd
+///
+/// The distance to `now` is written with at most two time units like `3min 2s ago`, `1 day 2h
+/// left` or `2 years 3 months ago` as it's printed for example by `systemctl status` or
+/// `systemctl list-timers`. If `now` is `None`, the current time of the system is used (see
+/// [`DateTime::now_utc`]).
+///
+/// Returns `None` if the `date_time` is not after the unix epoch. In this case systemd usually
+/// prints `n/a`.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_systemd::{format_timestamp_relative, DateTime};
+///
+/// let now = DateTime::from_gregorian_date_time(2023, 10, 16, 12, 0, 0, 0);
+/// assert_eq!(
+///     format_timestamp_relative(
+///         DateTime::from_gregorian_date_time(2023, 10, 16, 11, 56, 58, 0),
+///         Some(now)
+///     ),
+///     Some("3min 2s ago".to_owned())
+/// );
+/// assert_eq!(
+///     format_timestamp_relative(
+///         DateTime::from_gregorian_date_time(2023, 10, 17, 14, 0, 0, 0),
+///         Some(now)
+///     ),
+///     Some("1 day 2h left".to_owned())
+/// );
+/// assert_eq!(format_timestamp_relative(now, Some(now)), Some("now".to_owned()));
+/// ```
+pub fn format_timestamp_relative(date_time: DateTime, now: Option<DateTime>) -> Option<String> {
+    let usec = timestamp_to_usec(date_time)?;
+    let now = now
+        .unwrap_or_else(DateTime::now_utc)
+        .duration_since(DateTime::UNIX_EPOCH)
+        .map_or(0, |duration| to_usec(&duration));
+    let (delta, direction) = if now > usec {
+        (now - usec, "ago")
+    } else {
+        (usec - now, "left")
+    };
+
+    let plural = |count: u64, singular: &'static str, plural: &'static str| {
+        if count == 1 {
+            singular
+        } else {
+            plural
+        }
+    };
+    let formatted = if delta >= USEC_PER_YEAR {
+        let (years, months) = (
+            delta / USEC_PER_YEAR,
+            delta % USEC_PER_YEAR / USEC_PER_MONTH,
+        );
+        format!(
+            "{years} {} {months} {} {direction}",
+            plural(years, "year", "years"),
+            plural(months, "month", "months")
+        )
+    } else if delta >= USEC_PER_MONTH {
+        let (months, days) = (
+            delta / USEC_PER_MONTH,
+            delta % USEC_PER_MONTH / USEC_PER_DAY,
+        );
+        format!(
+            "{months} {} {days} {} {direction}",
+            plural(months, "month", "months"),
+            plural(days, "day", "days")
+        )
+    } else if delta >= USEC_PER_WEEK {
+        let (weeks, days) = (delta / USEC_PER_WEEK, delta % USEC_PER_WEEK / USEC_PER_DAY);
+        format!(
+            "{weeks} {} {days} {} {direction}",
+            plural(weeks, "week", "weeks"),
+            plural(days, "day", "days")
+        )
+    } else if delta >= 2 * USEC_PER_DAY {
+        format!("{} days {direction}", delta / USEC_PER_DAY)
+    } else if delta >= 25 * USEC_PER_HOUR {
+        format!(
+            "1 day {}h {direction}",
+            (delta - USEC_PER_DAY) / USEC_PER_HOUR
+        )
+    } else if delta >= 6 * USEC_PER_HOUR {
+        format!("{}h {direction}", delta / USEC_PER_HOUR)
+    } else if delta >= USEC_PER_HOUR {
+        format!(
+            "{}h {}min {direction}",
+            delta / USEC_PER_HOUR,
+            delta % USEC_PER_HOUR / USEC_PER_MINUTE
+        )
+    } else if delta >= 5 * USEC_PER_MINUTE {
+        format!("{}min {direction}", delta / USEC_PER_MINUTE)
+    } else if delta >= USEC_PER_MINUTE {
+        format!(
+            "{}min {}s {direction}",
+            delta / USEC_PER_MINUTE,
+            delta % USEC_PER_MINUTE / USEC_PER_SEC
+        )
+    } else if delta >= USEC_PER_SEC {
+        format!("{}s {direction}", delta / USEC_PER_SEC)
+    } else if delta >= USEC_PER_MSEC {
+        format!("{}ms {direction}", delta / USEC_PER_MSEC)
+    } else if delta > 0 {
+        format!("{delta}us {direction}")
+    } else {
+        "now".to_owned()
+    };
+    Some(formatted)
+}
@@ -177,6 +177,121 @@
 //!
 //! For further details see [`parse`], [`parse_nanos`] or the documentation of [`TimeSpanParser`]
 //!
+//! # Calendar events
+//!
+//! Besides time spans, `fundu-systemd` parses the calendar events of systemd timer units (like
+//! `OnCalendar=Mon..Fri *-*-* 09:00:00`) into a [`CalendarSpec`]. Its normalized form matches the
+//! output of `systemd-analyze calendar` and [`CalendarSpec::next_elapse`] iterates over the times
+//! at which the calendar event elapses. The calendar events, the timestamps and the formatting of
+//! timestamps are built on the [`DateTime`] of `fundu-gnu` and need the `datetime` feature.
+//!
+//! ```rust
+//! # #[cfg(feature = "datetime")]
+//! # {
+//! use fundu_systemd::{CalendarSpec, DateTime};
+//!
+//! let spec = CalendarSpec::parse("Sat,Sun 10:00 UTC").unwrap();
+//! assert_eq!(spec.to_string(), "Sat,Sun *-*-* 10:00:00 UTC");
+//!
+//! let mut elapses = spec.next_elapse(DateTime::from_gregorian_date_time(
+//!     2023, 10, 16, 12, 0, 0, 0,
+//! ));
+//! assert_eq!(
+//!     elapses.next(),
+//!     Some(DateTime::from_gregorian_date_time(
+//!         2023, 10, 21, 10, 0, 0, 0
+//!     ))
+//! );
+//! # }
+//! ```
+//!
+//! # Timestamps
+//!
+//! The [`TimestampParser`] parses the timestamps systemd accepts for example in `journalctl
+//! --since` or `systemd-analyze timestamp` like `2012-11-23 11:12:13`, `yesterday`, `+3h30min`,
+//! `5min ago` or `@1395716396` into a [`DateTime`].
+//!
+//! ```rust
+//! # #[cfg(feature = "datetime")]
+//! # {
+//! use fundu_systemd::{DateTime, FixedClock, TimestampParser};
+//!
+//! let clock = FixedClock::new(DateTime::from_gregorian_date_time(
+//!     2023, 10, 16, 12, 0, 0, 0,
+//! ));
+//! let parser = TimestampParser::with_clock(&clock);
+//! assert_eq!(
+//!     parser.parse("Fri 2012-11-23 11:12:13 UTC"),
+//!     Ok(DateTime::from_gregorian_date_time(
+//!         2012, 11, 23, 11, 12, 13, 0
+//!     ))
+//! );
+//! assert_eq!(
+//!     parser.parse("5min ago"),
+//!     Ok(DateTime::from_gregorian_date_time(
+//!         2023, 10, 16, 11, 55, 0, 0
+//!     ))
+//! );
+//! # }
+//! ```
+//!
+//! # Formatting
+//!
+//! [`format_timespan`] writes a [`Duration`] like systemd does for example in `systemctl show`
+//! with a configurable accuracy. The timestamps are written with [`format_timestamp`],
+//! [`format_timestamp_style`] or relative to now with [`format_timestamp_relative`]. All outputs
+//! are byte-identical to the ones of systemd.
+//!
+//! ```rust
+//! use fundu::Duration;
+//! use fundu_systemd::format_timespan;
+//!
+//! assert_eq!(
+//!     format_timespan(
+//!         Duration::positive(5410, 500_000_000),
+//!         Duration::positive(0, 1_000_000)
+//!     ),
+//!     "1h 30min 10.500s"
+//! );
+//!
+//! # #[cfg(feature = "datetime")]
+//! # {
+//! use fundu_systemd::{format_timestamp_relative, DateTime};
+//!
+//! let now = DateTime::from_gregorian_date_time(2023, 10, 16, 12, 0, 0, 0);
+//! let date_time = DateTime::from_gregorian_date_time(2023, 10, 16, 11, 55, 0, 0);
+//! assert_eq!(
+//!     format_timestamp_relative(date_time, Some(now)),
+//!     Some("5min ago".to_owned())
+//! );
+//! # }
+//! ```
+//!
+//! # Unit file directives
+//!
+//! The directives of systemd interpret their values differently, for example numbers without a
+//! time unit are seconds in `RestartSec=` but micro seconds in the `*USec=` properties and
+//! `TimeoutSec=0` disables the timeout like `infinity`. [`parse_directive`] picks the right rules
+//! by the name of the directive and [`parse_unit_file`] extracts and validates all time span
+//! directives of a unit file.
+//!
+//! ```rust
+//! use fundu::Duration;
+//! use fundu_systemd::{parse_directive, parse_unit_file, SYSTEMD_MAX_MICRO_DURATION};
+//!
+//! assert_eq!(
+//!     parse_directive("RestartSec", "1.5"),
+//!     Ok(Duration::positive(1, 500_000_000))
+//! );
+//! assert_eq!(
+//!     parse_directive("TimeoutSec", "0"),
+//!     Ok(SYSTEMD_MAX_MICRO_DURATION)
+//! );
+//!
+//! let entries = parse_unit_file("[Service]\nRestartSec=100ms\n");
+//! assert_eq!(entries[0].duration, Ok(Duration::positive(0, 100_000_000)));
+//! ```
+//!
 //! [`chrono::Duration`]: https://docs.rs/chrono/latest/chrono/struct.Duration.html
 //! [`time::Duration`]: https://docs.rs/time/latest/time/struct.Duration.html
 
@@ -204,49 +319,53 @@
 #![allow(clippy::enum_glob_use)]
 #![allow(clippy::module_name_repetitions)]
 
+mod builder;
+#[cfg(feature = "datetime")]
+mod calendar;
+mod directive;
+mod format;
+#[cfg(feature = "macros")]
+mod macros;
+#[cfg(feature = "datetime")]
+mod timestamp;
+
+pub use builder::TimeSpanParserBuilder;
+#[cfg(feature = "datetime")]
+pub use calendar::{CalendarSpec, NextElapse};
+pub use directive::{
+    parse_directive, parse_unit_file, Directive, Infinity, UnitFileEntry, DIRECTIVES,
+};
+pub use format::format_timespan;
+#[cfg(feature = "datetime")]
+pub use format::{
+    format_timestamp, format_timestamp_relative, format_timestamp_style, TimestampStyle,
+};
 use fundu::TimeUnit::*;
-use fundu::{
-    Config, ConfigBuilder, Delimiter, Duration, Multiplier, ParseError, Parser, TimeUnit,
-    TimeUnitsLike,
+pub use fundu::{
+    BufReadParser, Match, Matches, ParseDuration, ParseDurationPrefix, Scanner, StreamItem,
+    StreamIter, StreamParser,
 };
+use fundu::{Delimiter, Duration, Multiplier, ParseError, Parser, TimeUnit, TimeUnitsLike};
+use fundu_core::presets::{systemd_time_unit, SYSTEMD_CONFIG, SYSTEMD_DELIMITER};
+pub use fundu_core::presets::{SYSTEMD_MAX_MICRO_DURATION, SYSTEMD_MAX_NANOS_DURATION};
+#[cfg(feature = "datetime")]
+pub use fundu_gnu::{Clock, DateTime, FixedClock, TimeZone};
+#[cfg(feature = "datetime")]
+pub use timestamp::TimestampParser;
 
-// whitespace definition of: b' ', b'\x09', b'\x0A', b'\x0B', b'\x0C', b'\x0D'
-const DELIMITER: Delimiter = |byte| byte == b' ' || byte.wrapping_sub(9) < 5;
-
-const CONFIG: Config = ConfigBuilder::new()
-    .allow_time_unit_delimiter()
-    .disable_exponent()
-    .disable_infinity()
-    .number_is_optional()
-    .parse_multiple(None)
-    .inner_delimiter(DELIMITER)
-    .outer_delimiter(DELIMITER)
-    .build();
+/// Used by the macros of this crate. Not public api.
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub mod __private {
+    pub use fundu::Duration;
+    pub use fundu_macros::{std_timespan, timespan};
+}
 
 const TIME_UNITS_WITH_NANOS: TimeUnitsWithNanos = TimeUnitsWithNanos {};
 const TIME_UNITS: TimeUnits = TimeUnits {};
 
-const NANO_SECOND: (TimeUnit, Multiplier) = (NanoSecond, Multiplier(1, 0));
-const MICRO_SECOND: (TimeUnit, Multiplier) = (MicroSecond, Multiplier(1, 0));
-const MILLI_SECOND: (TimeUnit, Multiplier) = (MilliSecond, Multiplier(1, 0));
-const SECOND: (TimeUnit, Multiplier) = (Second, Multiplier(1, 0));
-const MINUTE: (TimeUnit, Multiplier) = (Minute, Multiplier(1, 0));
-const HOUR: (TimeUnit, Multiplier) = (Hour, Multiplier(1, 0));
-const DAY: (TimeUnit, Multiplier) = (Day, Multiplier(1, 0));
-const WEEK: (TimeUnit, Multiplier) = (Week, Multiplier(1, 0));
-const MONTH: (TimeUnit, Multiplier) = (Month, Multiplier(1, 0));
-const YEAR: (TimeUnit, Multiplier) = (Year, Multiplier(1, 0));
-
 const PARSER: TimeSpanParser<'static> = TimeSpanParser::new();
 
-/// The maximum duration used when parsing with micro seconds precision
-pub const SYSTEMD_MAX_MICRO_DURATION: Duration =
-    Duration::positive(u64::MAX / 1_000_000, (u64::MAX % 1_000_000) as u32 * 1000);
-
-/// The maximum duration used when parsing with nano seconds precision
-pub const SYSTEMD_MAX_NANOS_DURATION: Duration =
-    Duration::positive(u64::MAX / 1_000_000_000, (u64::MAX % 1_000_000_000) as u32);
-
 /// The main systemd time span parser
 ///
 /// Note this parser can be created as const at compile time.
@@ -313,9 +432,14 @@ pub const SYSTEMD_MAX_NANOS_DURATION: Duration =
 ///
 /// assert_eq!(parser.parse("100"), Ok(Duration::positive(0, 100_000)));
 /// ```
+///
+/// Deviations from the systemd syntax like negative time spans or additional time unit aliases can
+/// be configured with the [`TimeSpanParserBuilder`].
 #[derive(Debug, Eq, PartialEq)]
 pub struct TimeSpanParser<'a> {
     raw: Parser<'a>,
+    aliases: &'a [(&'a str, TimeUnit)],
+    max: Option<Duration>,
 }
 
 impl<'a> TimeSpanParser<'a> {
@@ -337,7 +461,9 @@ impl<'a> TimeSpanParser<'a> {
     /// ```
     pub const fn new() -> Self {
         Self {
-            raw: Parser::with_config(CONFIG),
+            raw: Parser::with_config(SYSTEMD_CONFIG),
+            aliases: &[],
+            max: None,
         }
     }
 
@@ -357,10 +483,12 @@ impl<'a> TimeSpanParser<'a> {
     /// );
     /// ```
     pub const fn with_default_unit(time_unit: TimeUnit) -> Self {
-        let mut config = CONFIG;
+        let mut config = SYSTEMD_CONFIG;
         config.default_unit = time_unit;
         Self {
             raw: Parser::with_config(config),
+            aliases: &[],
+            max: None,
         }
     }
 
@@ -372,7 +500,8 @@ impl<'a> TimeSpanParser<'a> {
     ///
     /// This method does not include the time units for nano seconds unlike the
     /// [`TimeSpanParser::parse_nanos`] method. The parser saturates at the maximum [`Duration`] of
-    /// `u64::MAX` micro seconds. If you need a different maximum use the
+    /// `u64::MAX` micro seconds unless configured otherwise with
+    /// [`TimeSpanParserBuilder::max`]. If you need a different maximum use the
     /// [`TimeSpanParser::parse_with_max`] method.
     ///
     /// # Errors
@@ -409,7 +538,80 @@ impl<'a> TimeSpanParser<'a> {
     /// assert_eq!(parser.parse("infinity"), Ok(SYSTEMD_MAX_MICRO_DURATION));
     /// ```
     pub fn parse(&self, source: &str) -> Result<Duration, ParseError> {
-        self.parse_with_max(source, SYSTEMD_MAX_MICRO_DURATION)
+        self.parse_with_max(source, self.max.unwrap_or(SYSTEMD_MAX_MICRO_DURATION))
+    }
+
+    /// Parse the longest valid time span at the start of `source` and return the unconsumed rest
+    ///
+    /// Like [`TimeSpanParser::parse`], leading whitespace is ignored, the nano second time units
+    /// are not included and the time span saturates at the maximum [`Duration`]. The time unit has
+    /// to match the letters after the number as a whole, so `5mthen` is parsed as `5` with the
+    /// remainder `mthen`. See [`fundu::Parser::parse_prefix`] for the details.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if there's no valid time span at the start of the `source`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::Duration;
+    /// use fundu_systemd::{TimeSpanParser, SYSTEMD_MAX_MICRO_DURATION};
+    ///
+    /// let parser = TimeSpanParser::new();
+    /// assert_eq!(
+    ///     parser.parse_prefix("1min 30s; restart"),
+    ///     Ok((Duration::positive(90, 0), "; restart"))
+    /// );
+    /// assert_eq!(
+    ///     parser.parse_prefix("  infinity, never"),
+    ///     Ok((SYSTEMD_MAX_MICRO_DURATION, ", never"))
+    /// );
+    /// assert!(parser.parse_prefix("restart 1min").is_err());
+    /// ```
+    pub fn parse_prefix<'s>(&self, source: &'s str) -> Result<(Duration, &'s str), ParseError> {
+        let max = self.max.unwrap_or(SYSTEMD_MAX_MICRO_DURATION);
+        let trimmed = source.trim_start_matches(|c: char| {
+            c.is_ascii() && (self.raw.config.outer_delimiter)(c as u8)
+        });
+        if let Some(remainder) = trimmed.strip_prefix("infinity") {
+            if !remainder.starts_with(|c: char| c.is_alphabetic()) {
+                return Ok((max, remainder));
+            }
+        }
+        self.raw
+            .parse_prefix(
+                trimmed,
+                &WithAliases::new(&TIME_UNITS, self.aliases),
+                None,
+                None,
+            )
+            .map(|(duration, remainder)| (duration.min(max).max(-max), remainder))
+    }
+
+    /// Return an iterator over all time spans embedded in the `text`
+    ///
+    /// The time spans are parsed with [`TimeSpanParser::parse`]. Numbers without a time unit are
+    /// not matched. See [`Scanner`] for the details and more settings.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::Duration;
+    /// use fundu_systemd::{TimeSpanParser, SYSTEMD_MAX_MICRO_DURATION};
+    ///
+    /// let parser = TimeSpanParser::new();
+    /// let text = "Scheduled restart in 1min 30s, retry 3 of 5, limit infinity";
+    /// let matches: Vec<_> = parser.find_iter(text).collect();
+    ///
+    /// assert_eq!(matches.len(), 2);
+    /// assert_eq!(matches[0].source, "1min 30s");
+    /// assert_eq!(matches[0].duration, Duration::positive(90, 0));
+    /// assert_eq!(matches[1].source, "infinity");
+    /// assert_eq!(matches[1].duration, SYSTEMD_MAX_MICRO_DURATION);
+    /// ```
+    pub fn find_iter<'t>(&self, text: &'t str) -> Matches<'t, &Self> {
+        Scanner::new(self).into_find_iter(text)
     }
 
     /// Parse the `source` string into a [`Duration`] saturating at the given `max` [`Duration`]
@@ -447,13 +649,18 @@ impl<'a> TimeSpanParser<'a> {
     /// ```
     pub fn parse_with_max(&self, source: &str, max: Duration) -> Result<Duration, ParseError> {
         assert!(max.is_positive());
-        let trimmed = trim_whitespace(source);
+        let trimmed = trim_delimiter(source, self.raw.config.outer_delimiter);
         match Self::parse_infinity(trimmed, max) {
             Some(duration) => Ok(duration),
             None => self
                 .raw
-                .parse(trimmed, &TIME_UNITS, None, None)
-                .map(|duration| duration.min(max)),
+                .parse(
+                    trimmed,
+                    &WithAliases::new(&TIME_UNITS, self.aliases),
+                    None,
+                    None,
+                )
+                .map(|duration| duration.min(max).max(-max)),
         }
     }
 
@@ -461,7 +668,8 @@ impl<'a> TimeSpanParser<'a> {
     ///
     /// This method does include the time units for nano seconds unlike the
     /// [`TimeSpanParser::parse`] method. The parser saturates at the maximum [`Duration`] of
-    /// `u64::MAX` nano seconds. If you need a different maximum use the
+    /// `u64::MAX` nano seconds unless configured otherwise with
+    /// [`TimeSpanParserBuilder::max`]. If you need a different maximum use the
     /// [`TimeSpanParser::parse_nanos_with_max`] method.
     ///
     /// # Errors
@@ -501,7 +709,7 @@ impl<'a> TimeSpanParser<'a> {
     /// );
     /// ```
     pub fn parse_nanos(&self, source: &str) -> Result<Duration, ParseError> {
-        self.parse_nanos_with_max(source, SYSTEMD_MAX_NANOS_DURATION)
+        self.parse_nanos_with_max(source, self.max.unwrap_or(SYSTEMD_MAX_NANOS_DURATION))
     }
 
     /// Parse the `source` string into a [`Duration`] saturating at the given `max` [`Duration`]
@@ -543,13 +751,18 @@ impl<'a> TimeSpanParser<'a> {
         max: Duration,
     ) -> Result<Duration, ParseError> {
         assert!(max.is_positive());
-        let trimmed = trim_whitespace(source);
+        let trimmed = trim_delimiter(source, self.raw.config.outer_delimiter);
         match Self::parse_infinity(trimmed, max) {
             Some(duration) => Ok(duration),
             None => self
                 .raw
-                .parse(trimmed, &TIME_UNITS_WITH_NANOS, None, None)
-                .map(|duration| duration.min(max)),
+                .parse(
+                    trimmed,
+                    &WithAliases::new(&TIME_UNITS_WITH_NANOS, self.aliases),
+                    None,
+                    None,
+                )
+                .map(|duration| duration.min(max).max(-max)),
         }
     }
 
@@ -589,6 +802,21 @@ impl<'a> Default for TimeSpanParser<'a> {
     }
 }
 
+impl ParseDuration for TimeSpanParser<'_> {
+    fn parse_duration(&self, source: &str) -> Result<Duration, ParseError> {
+        self.parse(source)
+    }
+}
+
+impl ParseDurationPrefix for TimeSpanParser<'_> {
+    fn parse_duration_prefix<'s>(
+        &self,
+        source: &'s str,
+    ) -> Result<(Duration, &'s str), ParseError> {
+        self.parse_prefix(source)
+    }
+}
+
 /// This struct is used internally to hold the time units without nano second time units
 pub struct TimeUnits {}
 
@@ -600,20 +828,9 @@ impl TimeUnitsLike for TimeUnits {
 
     #[inline]
     fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
-        match identifier {
-            // These are two different letters: the greek small letter mu U+03BC and the micro sign
-            // U+00B5
-            "us" | "\u{03bc}s" | "\u{00b5}s" | "usec" => Some(MICRO_SECOND),
-            "ms" | "msec" => Some(MILLI_SECOND),
-            "s" | "sec" | "second" | "seconds" => Some(SECOND),
-            "m" | "min" | "minute" | "minutes" => Some(MINUTE),
-            "h" | "hr" | "hour" | "hours" => Some(HOUR),
-            "d" | "day" | "days" => Some(DAY),
-            "w" | "week" | "weeks" => Some(WEEK),
-            "M" | "month" | "months" => Some(MONTH),
-            "y" | "year" | "years" => Some(YEAR),
-            _ => None,
-        }
+        systemd_time_unit(identifier)
+            .filter(|time_unit| *time_unit != NanoSecond)
+            .map(|time_unit| (time_unit, Multiplier(1, 0)))
     }
 }
 
@@ -628,24 +845,42 @@ impl TimeUnitsLike for TimeUnitsWithNanos {
 
     #[inline]
     fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
-        match identifier {
-            "ns" | "nsec" => Some(NANO_SECOND),
-            // These are two different letters: the greek small letter mu U+03BC and the micro sign
-            // U+00B5
-            "us" | "\u{03bc}s" | "\u{00b5}s" | "usec" => Some(MICRO_SECOND),
-            "ms" | "msec" => Some(MILLI_SECOND),
-            "s" | "sec" | "second" | "seconds" => Some(SECOND),
-            "m" | "min" | "minute" | "minutes" => Some(MINUTE),
-            "h" | "hr" | "hour" | "hours" => Some(HOUR),
-            "d" | "day" | "days" => Some(DAY),
-            "w" | "week" | "weeks" => Some(WEEK),
-            "M" | "month" | "months" => Some(MONTH),
-            "y" | "year" | "years" => Some(YEAR),
-            _ => None,
+        systemd_time_unit(identifier).map(|time_unit| (time_unit, Multiplier(1, 0)))
+    }
+}
+
+/// The time units of systemd extended by the aliases of the [`TimeSpanParserBuilder`]
+struct WithAliases<'a, T> {
+    time_units: &'a T,
+    aliases: &'a [(&'a str, TimeUnit)],
+}
+
+impl<'a, T> WithAliases<'a, T> {
+    const fn new(time_units: &'a T, aliases: &'a [(&'a str, TimeUnit)]) -> Self {
+        Self {
+            time_units,
+            aliases,
         }
     }
 }
 
+impl<T: TimeUnitsLike> TimeUnitsLike for WithAliases<'_, T> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
+        self.time_units.get(identifier).or_else(|| {
+            self.aliases
+                .iter()
+                .find(|(alias, _)| *alias == identifier)
+                .map(|(_, time_unit)| (*time_unit, Multiplier(1, 0)))
+        })
+    }
+}
+
 /// Parse the `source` string into a [`Duration`]
 ///
 /// This method does not include the time units for nano seconds unlike the
@@ -781,17 +1016,29 @@ pub fn parse_nanos(
 
 // This is a faster alternative to str::trim_matches. We're exploiting that we're using the posix
 // definition of whitespace which only contains ascii characters as whitespace
-fn trim_whitespace(source: &str) -> &str {
+/// Return true if the character is a posix whitespace
+#[cfg(feature = "datetime")]
+pub(crate) const fn is_whitespace(c: char) -> bool {
+    c == ' ' || (c as u32).wrapping_sub(9) < 5
+}
+
+/// Trim the posix whitespace from both ends of the `source`
+pub(crate) fn trim_whitespace(source: &str) -> &str {
+    trim_delimiter(source, SYSTEMD_DELIMITER)
+}
+
+/// Trim the ascii characters matching the `delimiter` from both ends of the `source`
+fn trim_delimiter(source: &str, delimiter: Delimiter) -> &str {
     let mut bytes = source.as_bytes();
     while let Some((byte, remainder)) = bytes.split_first() {
-        if byte == &b' ' || byte.wrapping_sub(9) < 5 {
+        if byte.is_ascii() && delimiter(*byte) {
             bytes = remainder;
         } else {
             break;
         }
     }
     while let Some((byte, remainder)) = bytes.split_last() {
-        if byte == &b' ' || byte.wrapping_sub(9) < 5 {
+        if byte.is_ascii() && delimiter(*byte) {
             bytes = remainder;
         } else {
             break;
@@ -810,7 +1057,7 @@ mod tests {
     #[test]
     fn test_parser_new() {
         let parser = TimeSpanParser::new();
-        assert_eq!(parser.raw.config, CONFIG);
+        assert_eq!(parser.raw.config, SYSTEMD_CONFIG);
     }
 
     #[rstest]
@@ -818,7 +1065,7 @@ mod tests {
     #[case::second(TimeUnit::Second)]
     fn test_parser_with_default_unit(#[case] time_unit: TimeUnit) {
         let parser = TimeSpanParser::with_default_unit(time_unit);
-        let mut config = CONFIG;
+        let mut config = SYSTEMD_CONFIG;
         config.default_unit = time_unit;
         assert_eq!(parser.raw.config, config);
     }
@@ -827,7 +1074,7 @@ mod tests {
     #[case::not_second(TimeUnit::Week)]
     #[case::second(TimeUnit::Second)]
     fn test_parser_set_default_unit(#[case] time_unit: TimeUnit) {
-        let mut config = CONFIG;
+        let mut config = SYSTEMD_CONFIG;
         config.default_unit = time_unit;
 
         let mut parser = TimeSpanParser::new();
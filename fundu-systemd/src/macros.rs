@@ -0,0 +1,63 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+/// Parse a string literal into a [`Duration`](fundu::Duration) at compile time
+///
+/// The literal is parsed like with [`TimeSpanParser::new`](crate::TimeSpanParser::new) and the
+/// macro expands to a constant expression, so it can be used to initialize a `const` without an
+/// `unwrap()` at runtime. An invalid time span results in a compile error pointing to the literal.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu::Duration;
+/// use fundu_systemd::{timespan, SYSTEMD_MAX_MICRO_DURATION};
+///
+/// const RESTART_SEC: Duration = timespan!("5min 30s");
+/// assert_eq!(RESTART_SEC, Duration::positive(330, 0));
+/// assert_eq!(timespan!("100"), Duration::positive(100, 0));
+/// assert_eq!(timespan!("infinity"), SYSTEMD_MAX_MICRO_DURATION);
+/// ```
+///
+/// Invalid time spans don't compile
+///
+/// ```rust,compile_fail
+/// // error: Invalid duration: Invalid input: nsec
+/// const TIMEOUT: fundu::Duration = fundu_systemd::timespan!("1nsec");
+/// ```
+#[macro_export]
+macro_rules! timespan {
+    ($source:literal) => {
+        $crate::__private::timespan!($crate, $source)
+    };
+}
+
+/// Parse a string literal into a [`std::time::Duration`] at compile time
+///
+/// Like [`timespan!`](crate::timespan!) but the macro expands to a [`std::time::Duration`].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use fundu_systemd::std_timespan;
+///
+/// const WATCHDOG_SEC: Duration = std_timespan!("1min 500ms");
+/// assert_eq!(WATCHDOG_SEC, Duration::from_millis(60_500));
+/// ```
+///
+/// Invalid time spans don't compile
+///
+/// ```rust,compile_fail
+/// // error: Invalid duration: Syntax error: No exponent allowed at column 1
+/// const TIMEOUT: std::time::Duration = fundu_systemd::std_timespan!("1e3");
+/// ```
+#[macro_export]
+macro_rules! std_timespan {
+    ($source:literal) => {
+        $crate::__private::std_timespan!($crate, $source)
+    };
+}
@@ -0,0 +1,745 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::fmt::{self, Debug};
+
+use fundu::{Duration, ParseError};
+use fundu_gnu::{Clock, DateTime, JulianDay, TimeZone};
+
+use crate::calendar::{local_to_utc, utc_to_local, WEEKDAYS};
+use crate::{is_whitespace, TimeSpanParser, SYSTEMD_MAX_MICRO_DURATION};
+
+const USEC_PER_SEC: i128 = 1_000_000;
+const NANOS_PER_USEC: u32 = 1000;
+
+/// The latest timestamp systemd accepts (`9999-12-30 23:59:59 UTC`) in micro seconds
+const USEC_TIMESTAMP_MAX: i128 = 253_402_214_399 * USEC_PER_SEC;
+
+const MONTHS: [(&str, &str); 12] = [
+    ("January", "Jan"),
+    ("February", "Feb"),
+    ("March", "Mar"),
+    ("April", "Apr"),
+    ("May", "May"),
+    ("June", "Jun"),
+    ("July", "Jul"),
+    ("August", "Aug"),
+    ("September", "Sep"),
+    ("October", "Oct"),
+    ("November", "Nov"),
+    ("December", "Dec"),
+];
+
+/// The `strptime` formats in the order systemd tries them and if the seconds may have a fraction
+const FORMATS: [(&str, bool); 9] = [
+    ("%y-%m-%d %H:%M:%S", true),
+    ("%b %d %H:%M:%S", true),
+    ("%Y-%m-%d %H:%M:%S", true),
+    ("%y-%m-%d %H:%M", false),
+    ("%Y-%m-%d %H:%M", false),
+    ("%y-%m-%d", false),
+    ("%Y-%m-%d", false),
+    ("%H:%M:%S", true),
+    ("%H:%M", false),
+];
+
+/// The index of the first format in [`FORMATS`] which consists only of the time
+const TIME_FORMATS: usize = 7;
+
+/// The words for a day relative to today and their offset in days
+const DAYS: [(&str, i64); 3] = [("today", 0), ("yesterday", -1), ("tomorrow", 1)];
+
+/// The broken-down local date and time like the `struct tm` of the C library
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Tm {
+    year: i64,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    usec: u32,
+}
+
+impl Tm {
+    fn from_date_time(date_time: DateTime) -> Option<Self> {
+        let (year, month, day, hour, minute, second, _) = date_time.to_gregorian_date_time()?;
+        Some(Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            usec: 0,
+        })
+    }
+
+    /// Return the date and time of `now` in the local time `zone`
+    fn from_local(zone: &TimeZone, now: DateTime) -> Result<Self, ParseError> {
+        utc_to_local(zone, now)
+            .and_then(Self::from_date_time)
+            .ok_or(ParseError::Overflow)
+    }
+
+    fn reset_time(&mut self) {
+        self.hour = 0;
+        self.minute = 0;
+        self.second = 0;
+    }
+
+    /// The day of the week counted like `tm_wday` from `0` (sunday) to `6` (saturday)
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn weekday(&self) -> Option<u8> {
+        let days = JulianDay::try_from_gregorian(self.year, self.month, 1)?.as_days();
+        // The julian day `0` is a monday
+        Some((days + i64::from(self.day)).rem_euclid(7) as u8)
+    }
+
+    /// Return the local `DateTime` moved by `days`
+    ///
+    /// Like `mktime`, days and seconds which are out of range (like `2023-02-30` or `23:59:60`)
+    /// carry over into the next month or minute.
+    fn to_date_time(self, days: i64) -> Option<DateTime> {
+        let seconds = u64::from(self.hour) * 3600 + u64::from(self.minute) * 60;
+        DateTime::try_from_gregorian_date_time(self.year, self.month, 1, 0, 0, 0, 0)?
+            .checked_add_gregorian(0, 0, i64::from(self.day) - 1 + days)?
+            .checked_add_duration(&Duration::positive(
+                seconds + u64::from(self.second),
+                self.usec * NANOS_PER_USEC,
+            ))
+    }
+}
+
+/// A parser for the systemd timestamp syntax like `2012-11-23 11:12:13`, `yesterday` or `+3h`
+///
+/// The format follows the systemd
+/// [documentation](https://www.freedesktop.org/software/systemd/man/systemd.time.html#Parsing%20Timestamps)
+/// and `parse_timestamp` of systemd which is used for example by `systemd-analyze timestamp` or
+/// `journalctl --since`:
+///
+/// * An absolute date and time in the formats `YYYY-MM-DD HH:MM:SS`, `YY-MM-DD HH:MM:SS` or `Mon DD
+///   HH:MM:SS` like `Nov 23 11:12:13`. The seconds may have a fraction with a precision of micro
+///   seconds like in `11:12:13.5`. If the seconds are missing, they are `00`. If the time is
+///   missing, it is `00:00:00`. If the date is missing, the date of today is used.
+/// * An optional day of the week in front of the date like `Fri 2012-11-23`. The day of the week
+///   must match the date.
+/// * The words `now`, `today`, `yesterday` and `tomorrow`. Unlike systemd, the day words may be
+///   followed by a time like in `tomorrow 12:00`.
+/// * A time span relative to `now` with a `+` or `-` sign (`+3h30min`, `-5s`) or followed by `ago`
+///   or `left` (`5min ago`). The time span is parsed with the [`TimeSpanParser`].
+/// * The seconds since the unix epoch after an `@` like in `@1395716396`. This can be any time
+///   span, too.
+/// * Absolute dates and times and the day words may end with ` UTC` or the name of a time zone like
+///   ` Europe/Berlin` (see [`TimeZone::from_name`]). Otherwise, the local time zone is used (see
+///   [`TimestampParser::set_local_zone`]).
+///
+/// Like in systemd, whitespace around the timestamp is not allowed and the timestamp must lie
+/// between the unix epoch and `9999-12-30 23:59:59 UTC`. The parsed [`DateTime`] is in UTC and
+/// has a precision of micro seconds.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu_systemd::{DateTime, FixedClock, TimeZone, TimestampParser};
+///
+/// let clock = FixedClock::new(DateTime::from_gregorian_date_time(
+///     2023, 10, 16, 12, 0, 0, 0,
+/// ));
+/// let mut parser = TimestampParser::with_clock(&clock);
+/// parser.set_local_zone(TimeZone::UTC);
+///
+/// assert_eq!(
+///     parser.parse("2012-11-23 11:12:13"),
+///     Ok(DateTime::from_gregorian_date_time(
+///         2012, 11, 23, 11, 12, 13, 0
+///     ))
+/// );
+/// assert_eq!(
+///     parser.parse("yesterday"),
+///     Ok(DateTime::from_gregorian_date_time(2023, 10, 15, 0, 0, 0, 0))
+/// );
+/// assert_eq!(
+///     parser.parse("+3h30min"),
+///     Ok(DateTime::from_gregorian_date_time(
+///         2023, 10, 16, 15, 30, 0, 0
+///     ))
+/// );
+/// assert_eq!(
+///     parser.parse("5min ago"),
+///     Ok(DateTime::from_gregorian_date_time(
+///         2023, 10, 16, 11, 55, 0, 0
+///     ))
+/// );
+/// assert_eq!(
+///     parser.parse("@1395716396"),
+///     Ok(DateTime::from_gregorian_date_time(
+///         2014, 3, 25, 2, 59, 56, 0
+///     ))
+/// );
+/// ```
+pub struct TimestampParser<'a> {
+    clock: Option<&'a (dyn Clock + Sync)>,
+    local_zone: Option<TimeZone>,
+}
+
+impl<'a> TimestampParser<'a> {
+    /// Create a new `TimestampParser`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_systemd::{DateTime, TimestampParser};
+    ///
+    /// let parser = TimestampParser::new();
+    /// assert_eq!(
+    ///     parser.parse("1970-01-01 00:00:00 UTC"),
+    ///     Ok(DateTime::UNIX_EPOCH)
+    /// );
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            clock: None,
+            local_zone: None,
+        }
+    }
+
+    /// Create a new `TimestampParser` using the [`Clock`] to get the date and time of `now`
+    ///
+    /// The default clock is the [`fundu_gnu::SystemClock`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_systemd::{DateTime, FixedClock, TimestampParser};
+    ///
+    /// let clock = FixedClock::new(DateTime::from_gregorian_date_time(
+    ///     2023, 10, 16, 12, 0, 0, 0,
+    /// ));
+    /// let parser = TimestampParser::with_clock(&clock);
+    /// assert_eq!(
+    ///     parser.parse("-1d"),
+    ///     Ok(DateTime::from_gregorian_date_time(
+    ///         2023, 10, 15, 12, 0, 0, 0
+    ///     ))
+    /// );
+    /// ```
+    pub const fn with_clock(clock: &'a (dyn Clock + Sync)) -> Self {
+        Self {
+            clock: Some(clock),
+            local_zone: None,
+        }
+    }
+
+    /// Set the [`Clock`] to get the date and time of `now`
+    ///
+    /// See also [`TimestampParser::with_clock`].
+    pub fn set_clock(&mut self, clock: &'a (dyn Clock + Sync)) {
+        self.clock = Some(clock);
+    }
+
+    /// Set the [`TimeZone`] of timestamps without a time zone
+    ///
+    /// The default is the local time zone of the system (see [`TimeZone::local`]) or UTC if it
+    /// can't be determined.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_systemd::{DateTime, TimeZone, TimestampParser};
+    ///
+    /// let mut parser = TimestampParser::new();
+    /// parser.set_local_zone(TimeZone::from_tz_string("EST5").unwrap());
+    /// assert_eq!(
+    ///     parser.parse("2012-11-23 11:12:13"),
+    ///     Ok(DateTime::from_gregorian_date_time(
+    ///         2012, 11, 23, 16, 12, 13, 0
+    ///     ))
+    /// );
+    /// ```
+    pub fn set_local_zone(&mut self, zone: TimeZone) {
+        self.local_zone = Some(zone);
+    }
+
+    /// Parse the `source` string into a [`DateTime`] relative to the date and time of `now`
+    ///
+    /// See [`TimestampParser::parse_with_date`] and the documentation of the [`TimestampParser`]
+    /// for a description of the format.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the `source` is not a valid timestamp or the timestamp is out of
+    /// range
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu_systemd::{DateTime, TimestampParser};
+    ///
+    /// let parser = TimestampParser::new();
+    /// assert_eq!(
+    ///     parser.parse("2012-11-23 11:12:13.5 UTC"),
+    ///     Ok(DateTime::from_gregorian_date_time(
+    ///         2012,
+    ///         11,
+    ///         23,
+    ///         11,
+    ///         12,
+    ///         13,
+    ///         500_000_000
+    ///     ))
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse(&self, source: &str) -> Result<DateTime, ParseError> {
+        self.parse_with_date(source, None)
+    }
+
+    /// Parse the `source` string into a [`DateTime`] relative to the optionally given `date`
+    ///
+    /// If the `date` is `None`, then the time of `now` of the [`Clock`] is assumed (see
+    /// [`TimestampParser::with_clock`]). See the documentation of the [`TimestampParser`] for a
+    /// description of the format.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the `source` is not a valid timestamp or the timestamp is out of
+    /// range
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::ParseError;
+    /// use fundu_systemd::{DateTime, TimestampParser};
+    ///
+    /// let parser = TimestampParser::new();
+    /// let date = DateTime::from_gregorian_date_time(2023, 10, 16, 12, 0, 0, 0);
+    /// assert_eq!(
+    ///     parser.parse_with_date("11:12 UTC", Some(date)),
+    ///     Ok(DateTime::from_gregorian_date_time(
+    ///         2023, 10, 16, 11, 12, 0, 0
+    ///     ))
+    /// );
+    /// assert_eq!(
+    ///     parser.parse_with_date("tomorrow 12:00 UTC", Some(date)),
+    ///     Ok(DateTime::from_gregorian_date_time(
+    ///         2023, 10, 17, 12, 0, 0, 0
+    ///     ))
+    /// );
+    /// assert_eq!(
+    ///     parser.parse_with_date("2 weeks left", Some(date)),
+    ///     Ok(DateTime::from_gregorian_date_time(
+    ///         2023, 10, 30, 12, 0, 0, 0
+    ///     ))
+    /// );
+    /// assert_eq!(
+    ///     parser.parse_with_date("Sat 2023-10-16 UTC", Some(date)),
+    ///     Err(ParseError::Syntax(
+    ///         0,
+    ///         "The day of the week doesn't match the date".to_string()
+    ///     ))
+    /// );
+    /// ```
+    pub fn parse_with_date(
+        &self,
+        source: &str,
+        date: Option<DateTime>,
+    ) -> Result<DateTime, ParseError> {
+        if source.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let now = date.unwrap_or_else(|| self.clock.map_or_else(DateTime::now_utc, Clock::now));
+        let usec = if let Some(span) = source.strip_prefix('@') {
+            // Like systemd, the seconds since the unix epoch are not restricted to the maximum
+            return from_usec(parse_span(span, 1)?);
+        } else if source == "now" {
+            to_usec(now)?
+        } else if let Some(span) = source.strip_prefix('+') {
+            to_usec(now)? + parse_span(span, 1)?
+        } else if let Some(span) = source.strip_prefix('-') {
+            to_usec(now)? - parse_span(span, 1)?
+        } else if let Some(span) = source.strip_suffix(" ago") {
+            to_usec(now)? - parse_span(span, 0)?
+        } else if let Some(span) = source.strip_suffix(" left") {
+            to_usec(now)? + parse_span(span, 0)?
+        } else {
+            let (source, zone) = self.split_zone(source);
+            let local = parse_date_time(source, Tm::from_local(&zone, now)?)?;
+            let utc = mktime(&zone, local).ok_or(ParseError::Overflow)?;
+            to_usec(utc)?
+        };
+
+        if (0..=USEC_TIMESTAMP_MAX).contains(&usec) {
+            from_usec(usec)
+        } else {
+            Err(out_of_range())
+        }
+    }
+
+    /// Split the `source` into the timestamp and the time zone
+    fn split_zone<'b>(&self, source: &'b str) -> (&'b str, TimeZone) {
+        let index = source.len().saturating_sub(4);
+        if source.is_char_boundary(index) && source[index..].eq_ignore_ascii_case(" UTC") {
+            return (&source[..index], TimeZone::UTC);
+        }
+
+        if let Some((timestamp, name)) = source.rsplit_once(' ') {
+            if name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                if let Ok(zone) = TimeZone::from_name(name) {
+                    return (timestamp, zone);
+                }
+            }
+        }
+
+        let zone = self
+            .local_zone
+            .clone()
+            .unwrap_or_else(|| TimeZone::local().unwrap_or_default());
+        (source, zone)
+    }
+}
+
+impl Default for TimestampParser<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for TimestampParser<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TimestampParser")
+            .field("clock", &self.clock.map_or("SystemClock", |_| "Custom"))
+            .field("local_zone", &self.local_zone)
+            .finish()
+    }
+}
+
+fn out_of_range() -> ParseError {
+    ParseError::InvalidInput("Timestamp out of range".to_owned())
+}
+
+fn shift_error_position(error: ParseError, offset: usize) -> ParseError {
+    match error {
+        ParseError::Syntax(position, message) => ParseError::Syntax(offset + position, message),
+        ParseError::TimeUnit(position, message) => ParseError::TimeUnit(offset + position, message),
+        error => error,
+    }
+}
+
+/// Parse the time span in `source` into micro seconds
+///
+/// The `offset` is the position of the time span in the timestamp.
+fn parse_span(source: &str, offset: usize) -> Result<i128, ParseError> {
+    let duration = TimeSpanParser::new()
+        .parse(source)
+        .map_err(|error| shift_error_position(error, offset))?;
+    // The time span saturates at the maximum which is `infinity` in systemd
+    if duration >= SYSTEMD_MAX_MICRO_DURATION {
+        Err(out_of_range())
+    } else {
+        Ok(duration.as_micros())
+    }
+}
+
+/// Return the micro seconds since the unix epoch truncating the nano seconds
+fn to_usec(date_time: DateTime) -> Result<i128, ParseError> {
+    date_time
+        .duration_since(DateTime::UNIX_EPOCH)
+        .map(|duration| duration.as_micros())
+        .ok_or(ParseError::Overflow)
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn from_usec(usec: i128) -> Result<DateTime, ParseError> {
+    let seconds = u64::try_from(usec / USEC_PER_SEC).map_err(|_| out_of_range())?;
+    let nanos = (usec % USEC_PER_SEC) as u32 * NANOS_PER_USEC;
+    DateTime::UNIX_EPOCH
+        .checked_add_duration(&Duration::positive(seconds, nanos))
+        .ok_or_else(out_of_range)
+}
+
+/// Convert the `local` date and time into UTC like `mktime` of the C library
+///
+/// Starting with an offset of zero, the offset is adjusted until it matches the offset of the
+/// `zone`. This resolves ambiguous local times like glibc does. Local times within a gap, when the
+/// clock is turned forward, move forward by the length of the gap.
+fn mktime(zone: &TimeZone, local: DateTime) -> Option<DateTime> {
+    let mut utc = local;
+    for _ in 0..4_u8 {
+        let guess = utc_to_local(zone, utc)?;
+        if guess == local {
+            return Some(utc);
+        }
+        utc = utc.checked_add_duration(&local.duration_since(guess)?)?;
+    }
+    local_to_utc(zone, local)
+}
+
+/// Parse a date and time like `2012-11-23 11:12:13`, `Fri 11:12` or `yesterday`
+///
+/// Unset parts of the date and time are taken from `now` in local time. Returns the local
+/// [`DateTime`].
+fn parse_date_time(source: &str, mut now: Tm) -> Result<DateTime, ParseError> {
+    for (word, days) in DAYS {
+        if source == word {
+            now.reset_time();
+            return now.to_date_time(days).ok_or(ParseError::Overflow);
+        }
+        if let Some(time) = source
+            .strip_prefix(word)
+            .filter(|time| time.starts_with(' '))
+        {
+            now.reset_time();
+            let tm = parse_formats(time, &FORMATS[TIME_FORMATS..], now)
+                .ok_or_else(|| ParseError::Syntax(word.len(), "Invalid time".to_owned()))?;
+            return tm.to_date_time(days).ok_or(ParseError::Overflow);
+        }
+    }
+
+    let (position, weekday) = parse_weekday(source);
+    let tm = parse_formats(&source[position..], &FORMATS, now)
+        .ok_or_else(|| ParseError::Syntax(position, "Invalid timestamp".to_owned()))?;
+    if weekday.map_or(false, |weekday| tm.weekday() != Some(weekday)) {
+        return Err(ParseError::Syntax(
+            0,
+            "The day of the week doesn't match the date".to_owned(),
+        ));
+    }
+    tm.to_date_time(0).ok_or(ParseError::Overflow)
+}
+
+/// Parse an optional day of the week followed by a space
+///
+/// Returns the position after the space and the day of the week counted from `0` (sunday) to `6`
+/// (saturday).
+#[allow(clippy::cast_possible_truncation)]
+fn parse_weekday(source: &str) -> (usize, Option<u8>) {
+    let bytes = source.as_bytes();
+    for (index, names) in WEEKDAYS.iter().enumerate() {
+        for name in [names.0, names.1] {
+            if bytes.len() > name.len()
+                && bytes[..name.len()].eq_ignore_ascii_case(name.as_bytes())
+                && bytes[name.len()] == b' '
+            {
+                return (name.len() + 1, Some((index as u8 + 1) % 7));
+            }
+        }
+    }
+    (0, None)
+}
+
+/// Try the `formats` in order and return the first which matches the whole `source`
+fn parse_formats(source: &str, formats: &[(&str, bool)], now: Tm) -> Option<Tm> {
+    formats.iter().find_map(|(format, fraction)| {
+        let mut tm = now;
+        let mut position = strptime(format, source.as_bytes(), &mut tm)?;
+        if *fraction && source.as_bytes().get(position) == Some(&b'.') {
+            position += 1;
+            tm.usec = parse_fraction(source.as_bytes(), &mut position)?;
+        }
+        if position < source.len() {
+            return None;
+        }
+
+        if !format.contains("%H") {
+            tm.reset_time();
+        } else if !format.contains("%S") {
+            tm.second = 0;
+        } else {
+            // The time is complete
+        }
+        Some(tm)
+    })
+}
+
+/// Match the `source` against the `format` like `strptime` of the C library
+///
+/// Only the conversions used in the [`FORMATS`] are supported. Returns the position after the
+/// match.
+#[allow(clippy::cast_possible_truncation)]
+fn strptime(format: &str, source: &[u8], tm: &mut Tm) -> Option<usize> {
+    let mut position = 0;
+    let mut format = format.bytes();
+    while let Some(byte) = format.next() {
+        match byte {
+            b'%' => match format.next()? {
+                b'y' => {
+                    let year = i64::from(parse_number(source, &mut position, 0, 99, 2)?);
+                    tm.year = if year >= 69 { 1900 + year } else { 2000 + year };
+                }
+                b'Y' => tm.year = i64::from(parse_number(source, &mut position, 0, 9999, 4)?),
+                b'm' => tm.month = parse_number(source, &mut position, 1, 12, 2)? as u8,
+                b'd' => tm.day = parse_number(source, &mut position, 1, 31, 2)? as u8,
+                b'H' => tm.hour = parse_number(source, &mut position, 0, 23, 2)? as u8,
+                b'M' => tm.minute = parse_number(source, &mut position, 0, 59, 2)? as u8,
+                // Like in the C library, leap seconds are allowed
+                b'S' => tm.second = parse_number(source, &mut position, 0, 61, 2)? as u8,
+                b'b' => tm.month = parse_month(source, &mut position)?,
+                _ => unreachable!(), // cov:excl-line
+            },
+            b' ' => {
+                while source
+                    .get(position)
+                    .map_or(false, |byte| is_whitespace(char::from(*byte)))
+                {
+                    position += 1;
+                }
+            }
+            byte if source.get(position) == Some(&byte) => position += 1,
+            _ => return None,
+        }
+    }
+    Some(position)
+}
+
+/// Parse a number with at most `digits` digits between `min` and `max` like `strptime`
+///
+/// Leading whitespace is skipped and no more digits are consumed once the number would exceed
+/// `max`.
+fn parse_number(
+    source: &[u8],
+    position: &mut usize,
+    min: u32,
+    max: u32,
+    digits: usize,
+) -> Option<u32> {
+    while source
+        .get(*position)
+        .map_or(false, |byte| is_whitespace(char::from(*byte)))
+    {
+        *position += 1;
+    }
+
+    let mut number = 0;
+    for count in 0..digits {
+        match source.get(*position) {
+            Some(byte) if byte.is_ascii_digit() && (count == 0 || number * 10 <= max) => {
+                number = number * 10 + u32::from(byte - b'0');
+                *position += 1;
+            }
+            _ if count == 0 => return None,
+            _ => break,
+        }
+    }
+    (min..=max).contains(&number).then_some(number)
+}
+
+/// Parse the full or abbreviated english name of a month ignoring the case
+#[allow(clippy::cast_possible_truncation)]
+fn parse_month(source: &[u8], position: &mut usize) -> Option<u8> {
+    let remainder = &source[*position..];
+    MONTHS.iter().enumerate().find_map(|(index, names)| {
+        [names.0, names.1].into_iter().find_map(|name| {
+            (remainder.len() >= name.len()
+                && remainder[..name.len()].eq_ignore_ascii_case(name.as_bytes()))
+            .then(|| {
+                *position += name.len();
+                index as u8 + 1
+            })
+        })
+    })
+}
+
+/// Parse the fraction of a second into micro seconds like systemd
+///
+/// Any amount of digits is allowed but at least one is required. The micro seconds are rounded
+/// with the seventh digit, so the result may be a full second.
+fn parse_fraction(source: &[u8], position: &mut usize) -> Option<u32> {
+    let start = *position;
+    let mut usec = 0;
+    for _ in 0..6_u8 {
+        usec *= 10;
+        if let Some(byte) = source.get(*position).filter(|byte| byte.is_ascii_digit()) {
+            usec += u32::from(byte - b'0');
+            *position += 1;
+        }
+    }
+    if *position == start {
+        return None;
+    }
+    if source
+        .get(*position)
+        .map_or(false, |byte| (b'5'..=b'9').contains(byte))
+    {
+        usec += 1;
+    }
+    while source.get(*position).map_or(false, u8::is_ascii_digit) {
+        *position += 1;
+    }
+    Some(usec)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::one_digit("5", 31, 2, Some(5), 1)]
+    #[case::two_digits("12", 31, 2, Some(12), 2)]
+    #[case::max_digits("1234", 9999, 2, Some(12), 2)]
+    #[case::leading_whitespace(" \t7", 31, 2, Some(7), 3)]
+    #[case::stop_before_exceeding_max("75", 61, 2, Some(7), 1)]
+    #[case::leading_zero("09", 31, 2, Some(9), 2)]
+    #[case::below_min("0", 31, 2, None, 1)]
+    #[case::above_max("32", 31, 2, None, 2)]
+    #[case::no_digit("a", 31, 2, None, 0)]
+    #[case::sign("+1", 31, 2, None, 0)]
+    fn test_parse_number(
+        #[case] source: &str,
+        #[case] max: u32,
+        #[case] digits: usize,
+        #[case] expected: Option<u32>,
+        #[case] expected_position: usize,
+    ) {
+        let mut position = 0;
+        assert_eq!(
+            parse_number(source.as_bytes(), &mut position, 1, max, digits),
+            expected
+        );
+        assert_eq!(position, expected_position);
+    }
+
+    #[rstest]
+    #[case::one_digit("5", Some(500_000))]
+    #[case::six_digits("123456", Some(123_456))]
+    #[case::round_down("1234564", Some(123_456))]
+    #[case::round_up("1234565", Some(123_457))]
+    #[case::round_up_to_full_second("9999995", Some(1_000_000))]
+    #[case::many_digits("1234564999", Some(123_456))]
+    #[case::no_digits("", None)]
+    fn test_parse_fraction(#[case] source: &str, #[case] expected: Option<u32>) {
+        let mut position = 0;
+        assert_eq!(parse_fraction(source.as_bytes(), &mut position), expected);
+        if expected.is_some() {
+            assert_eq!(position, source.len());
+        }
+    }
+
+    #[rstest]
+    #[case::thursday(1970, 1, 1, 4)]
+    #[case::sunday(2023, 10, 15, 0)]
+    #[case::saturday(2023, 10, 21, 6)]
+    #[case::day_out_of_range(2023, 2, 31, 5)]
+    fn test_tm_weekday(
+        #[case] year: i64,
+        #[case] month: u8,
+        #[case] day: u8,
+        #[case] expected: u8,
+    ) {
+        let tm = Tm {
+            year,
+            month,
+            day,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            usec: 0,
+        };
+        assert_eq!(tm.weekday(), Some(expected));
+    }
+}
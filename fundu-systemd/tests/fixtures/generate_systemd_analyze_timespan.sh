@@ -0,0 +1,30 @@
+#!/bin/sh
+# Generate the reference outputs of `systemd-analyze timespan` in `systemd_analyze_timespan.txt`
+#
+# Requires systemd-analyze. Run this script from within the fixtures directory after upgrading
+# systemd and review the diff of `systemd_analyze_timespan.txt`.
+
+set -e
+
+OUTPUT=systemd_analyze_timespan.txt
+VERSION=$(systemd-analyze --version | head -n 1)
+
+{
+    echo "# Reference outputs of \`systemd-analyze timespan\` ($VERSION)"
+    echo "#"
+    echo "# Generated with generate_systemd_analyze_timespan.sh. Every line consists of the input and"
+    echo "# the micro seconds (\`μs:\`) printed by systemd-analyze or \`error\` separated by a tab. A tab"
+    echo "# within the input is written as \`\\\\t\` and a newline as \`\\\\n\`."
+    while IFS= read -r input; do
+        case "$input" in
+        '#'*) continue ;;
+        esac
+        source=$(printf '%b' "$input")
+        if result=$(systemd-analyze timespan -- "$source" 2> /dev/null); then
+            usec=$(printf '%s\n' "$result" | sed -n 's/^ *μs: //p')
+            printf '%s\t%s\n' "$input" "$usec"
+        else
+            printf '%s\terror\n' "$input"
+        fi
+    done < systemd_analyze_timespan.inputs
+} > "$OUTPUT"
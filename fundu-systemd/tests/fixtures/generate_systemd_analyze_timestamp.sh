@@ -0,0 +1,52 @@
+#!/bin/sh
+# Generate the reference outputs of `systemd-analyze timestamp` in `systemd_analyze_timestamp.txt`
+#
+# Requires systemd-analyze, the time zone database and a c compiler. The clock of systemd-analyze
+# is fixed at @1697459696.789012 (Mon 2023-10-16 12:34:56.789012 UTC) with a small `LD_PRELOAD`
+# library overriding `clock_gettime`. Run this script from within the fixtures directory after
+# upgrading systemd and review the diff of `systemd_analyze_timestamp.txt`.
+
+set -e
+
+NOW_NSEC=1697459696789012000
+OUTPUT=systemd_analyze_timestamp.txt
+TMP_DIR=$(mktemp -d)
+trap 'rm -rf "$TMP_DIR"' EXIT
+
+cat > "$TMP_DIR/fakeclock.c" << 'CODE'
+#include <stdlib.h>
+#include <time.h>
+int clock_gettime(clockid_t clk, struct timespec *ts) {
+    (void) clk;
+    unsigned long long nsec = strtoull(getenv("FAKE_NOW_NSEC"), NULL, 10);
+    ts->tv_sec = nsec / 1000000000ULL;
+    ts->tv_nsec = nsec % 1000000000ULL;
+    return 0;
+}
+CODE
+cc -shared -fPIC -o "$TMP_DIR/fakeclock.so" "$TMP_DIR/fakeclock.c"
+
+VERSION=$(systemd-analyze --version | head -n 1)
+
+{
+    echo "# Reference outputs of \`systemd-analyze timestamp\` ($VERSION)"
+    echo "#"
+    echo "# Generated with generate_systemd_analyze_timestamp.sh. The clock was fixed at"
+    echo "# @1697459696.789012 (Mon 2023-10-16 12:34:56.789012 UTC) and the local time zone was set with"
+    echo "# the \`TZ\` environment variable. Every line consists of the time zone, the timestamp and the"
+    echo "# \`UNIX seconds\` printed by systemd-analyze or \`error\` separated by a tab. A tab within the"
+    echo "# timestamp is written as \`\\\\t\`."
+    while IFS="$(printf '\t')" read -r zone input; do
+        case "$zone" in
+        '' | '#'*) continue ;;
+        esac
+        source=$(printf '%b' "$input")
+        if result=$(FAKE_NOW_NSEC=$NOW_NSEC LD_PRELOAD="$TMP_DIR/fakeclock.so" TZ="$zone" \
+            systemd-analyze timestamp -- "$source" 2> /dev/null); then
+            seconds=$(printf '%s\n' "$result" | sed -n 's/^ *UNIX seconds: //p')
+            printf '%s\t%s\t%s\n' "$zone" "$input" "$seconds"
+        else
+            printf '%s\t%s\terror\n' "$zone" "$input"
+        fi
+    done < systemd_analyze_timestamp.inputs
+} > "$OUTPUT"
@@ -0,0 +1,141 @@
+#!/bin/sh
+# Generate the reference outputs of `format_timespan`, `format_timestamp_style` and
+# `format_timestamp_relative` of systemd in `systemd_format_timespan.txt`,
+# `systemd_format_timestamp.txt` and `systemd_format_timestamp_relative.txt`
+#
+# Requires the `libsystemd-shared-<version>.so` of systemd, the time zone database and a c
+# compiler. The library is searched in the usual directories or taken from the `SYSTEMD_SHARED`
+# environment variable. The functions are called with a small c program linked against the library.
+# The clock is fixed at @1697459696.789012 (Mon 2023-10-16 12:34:56.789012 UTC) with a small
+# `LD_PRELOAD` library overriding `clock_gettime`. Run this script from within the fixtures
+# directory after upgrading systemd and review the diff of the outputs.
+
+set -e
+
+NOW_NSEC=1697459696789012000
+ACCURACIES="0 1 2 9 10 11 100 1000 1001 10000 100000 1000000 5000000 60000000 3600000000
+86400000000 18446744073709551615"
+ZONES="UTC Europe/Berlin America/New_York Asia/Kolkata Australia/Lord_Howe Pacific/Chatham
+Asia/Kathmandu America/Sao_Paulo"
+STYLES="pretty us utc us+utc unix"
+TMP_DIR=$(mktemp -d)
+trap 'rm -rf "$TMP_DIR"' EXIT
+
+if [ -z "$SYSTEMD_SHARED" ]; then
+    for path in /usr/lib/systemd/libsystemd-shared-*.so \
+        /usr/lib/*/systemd/libsystemd-shared-*.so \
+        /lib/systemd/libsystemd-shared-*.so; do
+        if [ -f "$path" ]; then
+            SYSTEMD_SHARED=$path
+            break
+        fi
+    done
+fi
+if [ ! -f "$SYSTEMD_SHARED" ]; then
+    echo "libsystemd-shared not found. Please set SYSTEMD_SHARED to its path." >&2
+    exit 1
+fi
+LIBRARY=$(basename "$SYSTEMD_SHARED")
+VERSION=$(echo "$LIBRARY" | sed 's/^libsystemd-shared-\(.*\)\.so$/\1/')
+
+cat > "$TMP_DIR/fakeclock.c" << 'CODE'
+#include <stdlib.h>
+#include <time.h>
+int clock_gettime(clockid_t clk, struct timespec *ts) {
+    (void) clk;
+    unsigned long long nsec = strtoull(getenv("FAKE_NOW_NSEC"), NULL, 10);
+    ts->tv_sec = nsec / 1000000000ULL;
+    ts->tv_nsec = nsec % 1000000000ULL;
+    return 0;
+}
+CODE
+cc -shared -fPIC -o "$TMP_DIR/fakeclock.so" "$TMP_DIR/fakeclock.c"
+
+# Print the output of the function given as first argument or `n/a` if systemd returns NULL
+cat > "$TMP_DIR/format.c" << 'CODE'
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+typedef uint64_t usec_t;
+char *format_timespan(char *buf, size_t l, usec_t t, usec_t accuracy);
+char *format_timestamp_style(char *buf, size_t l, usec_t t, int style);
+char *format_timestamp_relative(char *buf, size_t l, usec_t t);
+static const char *const STYLES[] = {"pretty", "us", "utc", "us+utc", "unix"};
+int main(int argc, char **argv) {
+    char buf[256];
+    const char *result = NULL;
+    usec_t usec = strtoull(argv[2], NULL, 10);
+    if (strcmp(argv[1], "timespan") == 0 && argc == 4) {
+        result = format_timespan(buf, sizeof(buf), usec, strtoull(argv[3], NULL, 10));
+    } else if (strcmp(argv[1], "timestamp") == 0 && argc == 4) {
+        for (int style = 0; style < 5; style++) {
+            if (strcmp(argv[3], STYLES[style]) == 0) {
+                result = format_timestamp_style(buf, sizeof(buf), usec, style);
+            }
+        }
+    } else if (strcmp(argv[1], "relative") == 0 && argc == 3) {
+        result = format_timestamp_relative(buf, sizeof(buf), usec);
+    } else {
+        return 1;
+    }
+    puts(result ? result : "n/a");
+    return 0;
+}
+CODE
+cc -o "$TMP_DIR/format" "$TMP_DIR/format.c" "$SYSTEMD_SHARED" \
+    -Wl,-rpath,"$(dirname "$SYSTEMD_SHARED")"
+
+format() {
+    FAKE_NOW_NSEC=$NOW_NSEC LD_PRELOAD="$TMP_DIR/fakeclock.so" "$TMP_DIR/format" "$@"
+}
+
+inputs() {
+    grep -v '^#' "$1"
+}
+
+{
+    echo "# Reference outputs of \`format_timespan\` of systemd $VERSION"
+    echo "#"
+    echo "# Generated with generate_systemd_format.sh. The outputs were created with the"
+    echo "# \`format_timespan\` function of \`$LIBRARY\` which is also used by"
+    echo "# \`systemd-analyze timespan\` (with an accuracy of \`0\`) and \`systemctl show\`. Every line"
+    echo "# consists of the micro seconds, the accuracy in micro seconds and the output of systemd"
+    echo "# separated by a tab."
+    for usec in $(inputs systemd_format_timespan.inputs); do
+        for accuracy in $ACCURACIES; do
+            printf '%s\t%s\t%s\n' "$usec" "$accuracy" "$(format timespan "$usec" "$accuracy")"
+        done
+    done
+} > systemd_format_timespan.txt
+
+{
+    echo "# Reference outputs of \`format_timestamp_style\` of systemd $VERSION"
+    echo "#"
+    echo "# Generated with generate_systemd_format.sh. The outputs were created with the"
+    echo "# \`format_timestamp_style\` function of \`$LIBRARY\` and the local time zone set with"
+    echo "# the \`TZ\` environment variable. Every line consists of the time zone, the micro seconds since"
+    echo "# the unix epoch, the style (like the \`--timestamp=\` option of systemctl) and the output of"
+    echo "# systemd separated by a tab. Timestamps which systemd doesn't format are \`n/a\`."
+    for zone in $ZONES; do
+        for usec in $(inputs systemd_format_timestamp.inputs); do
+            for style in $STYLES; do
+                printf '%s\t%s\t%s\t%s\n' "$zone" "$usec" "$style" \
+                    "$(TZ=$zone format timestamp "$usec" "$style")"
+            done
+        done
+    done
+} > systemd_format_timestamp.txt
+
+{
+    echo "# Reference outputs of \`format_timestamp_relative\` of systemd $VERSION"
+    echo "#"
+    echo "# Generated with generate_systemd_format.sh. The outputs were created with the"
+    echo "# \`format_timestamp_relative\` function of \`$LIBRARY\` with the clock fixed at"
+    echo "# @1697459696.789012 (Mon 2023-10-16 12:34:56.789012 UTC). Every line consists of the micro"
+    echo "# seconds since the unix epoch and the output of systemd separated by a tab. Timestamps which"
+    echo "# systemd doesn't format are \`n/a\`."
+    for usec in $(inputs systemd_format_timestamp_relative.inputs); do
+        printf '%s\t%s\n' "$usec" "$(format relative "$usec")"
+    done
+} > systemd_format_timestamp_relative.txt
@@ -0,0 +1,361 @@
+// spell-checker: ignore semiannually annually Tuesday Wednesday
+
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+#![cfg(feature = "datetime")]
+
+use fundu::ParseError;
+use fundu_systemd::{CalendarSpec, DateTime, TimeZone};
+use rstest::rstest;
+
+const CET: &str = "CET-1CEST,M3.5.0,M10.5.0/3";
+
+fn date_time(year: i64, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> DateTime {
+    DateTime::from_gregorian_date_time(year, month, day, hour, minute, second, 0)
+}
+
+#[rstest]
+#[case::daily("daily", "*-*-* 00:00:00")]
+#[case::minutely("minutely", "*-*-* *:*:00")]
+#[case::hourly("hourly", "*-*-* *:00:00")]
+#[case::weekly("weekly", "Mon *-*-* 00:00:00")]
+#[case::monthly("monthly", "*-*-01 00:00:00")]
+#[case::yearly("yearly", "*-01-01 00:00:00")]
+#[case::annually("annually", "*-01-01 00:00:00")]
+#[case::quarterly("quarterly", "*-01,04,07,10-01 00:00:00")]
+#[case::semiannually("semiannually", "*-01,07-01 00:00:00")]
+#[case::shorthand_with_utc("daily UTC", "*-*-* 00:00:00 UTC")]
+#[case::workdays("Mon..Fri *-*-* 09:00:00", "Mon..Fri *-*-* 09:00:00")]
+#[case::first_of_month("*-*-01 00:00:00", "*-*-01 00:00:00")]
+#[case::weekend("Sat,Sun 10:00", "Sat,Sun *-*-* 10:00:00")]
+#[case::quarter_hours("*:0/15", "*-*-* *:00/15:00")]
+#[case::weekday_ranges("Wed..Sat,Tue 12-10-15 1:2:3", "Tue..Sat 2012-10-15 01:02:03")]
+#[case::weekday_list("Mon,Sun 12-*-* 2,1:23", "Mon,Sun 2012-*-* 01,02:23:00")]
+#[case::only_day("Wed *-1", "Wed *-*-01 00:00:00")]
+#[case::duplicate_weekdays("Wed..Wed,Wed *-1", "Wed *-*-01 00:00:00")]
+#[case::trailing_comma("Wed, 17:48", "Wed *-*-* 17:48:00")]
+#[case::weekdays_not_in_range("Mon,Wed,Fri", "Mon,Wed,Fri *-*-* 00:00:00")]
+#[case::two_weekdays_range("Mon..Tue", "Mon,Tue *-*-* 00:00:00")]
+#[case::weekdays_dash_range("Mon-Wed", "Mon..Wed *-*-* 00:00:00")]
+#[case::long_weekday_names("monday *-12-* 17:00", "Mon *-12-* 17:00:00")]
+#[case::case_insensitive_weekday("tUESday 00:00", "Tue *-*-* 00:00:00")]
+#[case::sorted_lists("Mon,Fri *-*-3,1,2 *:30:45", "Mon,Fri *-*-01,02,03 *:30:45")]
+#[case::sorted_and_dedup_time("12,14,13,12:20,10,30", "*-*-* 12,13,14:10,20,30:00")]
+#[case::range_and_list("12..14:10,20,30", "*-*-* 12..14:10,20,30:00")]
+#[case::repetition_in_month("mon,fri *-1/2-1,3 *:30:45", "Mon,Fri *-01/2-01,03 *:30:45")]
+#[case::month_day_time("03-05 08:05:40", "*-03-05 08:05:40")]
+#[case::only_time("08:05:40", "*-*-* 08:05:40")]
+#[case::only_hours_minutes("05:40", "*-*-* 05:40:00")]
+#[case::weekdays_month_day("Sat,Sun 12-05 08:05:40", "Sat,Sun *-12-05 08:05:40")]
+#[case::weekdays_time("Sat,Sun 08:05:40", "Sat,Sun *-*-* 08:05:40")]
+#[case::full_date("2003-03-05 05:40", "2003-03-05 05:40:00")]
+#[case::fraction_and_repetition("05:40:23.4200004/3.1700005", "*-*-* 05:40:23.420000/3.170001")]
+#[case::month_range("2003-02..04-05", "2003-02..04-05 00:00:00")]
+#[case::utc("2003-03-05 05:40 UTC", "2003-03-05 05:40:00 UTC")]
+#[case::utc_case_insensitive("2015-10-25 01:00:00 uTc", "2015-10-25 01:00:00 UTC")]
+#[case::only_date("2003-03-05", "2003-03-05 00:00:00")]
+#[case::only_month_day("03-05", "*-03-05 00:00:00")]
+#[case::minute_repetition("*:2/3", "*-*-* *:02/3:00")]
+#[case::fraction_rounded("2016-03-27 03:17:00.4200005", "2016-03-27 03:17:00.420001")]
+#[case::fraction_rounded_to_full_second("12:00:00.9999995", "*-*-* 12:00:01")]
+#[case::fraction_repetition("2016-03-27 03:17:00/0.42", "2016-03-27 03:17:00/0.420000")]
+#[case::hour_range_and_value("9..11,13:00,30", "*-*-* 09..11,13:00,30:00")]
+#[case::ranges_everywhere("1..3-1..3 1..3:1..3", "*-01..03-01..03 01..03:01..03:00")]
+#[case::fraction_range("00:00:1.125..3.125", "*-*-* 00:00:01.125000..03.125000")]
+#[case::any_seconds("*:*:*", "*-*-* *:*:*")]
+#[case::end_of_month("*-*~1 Utc", "*-*~01 00:00:00 UTC")]
+#[case::end_of_month_list("*-*~05,3", "*-*~03,05 00:00:00")]
+#[case::end_of_month_any_day("*-*~* 00:00:00", "*-*-* 00:00:00")]
+#[case::end_of_month_february("*-02~03", "*-02~03 00:00:00")]
+#[case::range_with_repetition("*-*-1..20/5", "*-*-01..16/5 00:00:00")]
+#[case::range_with_repetition_hitting_stop("*-*-1..31/10", "*-*-01..31/10 00:00:00")]
+#[case::range_of_seconds_with_repetition("*:*:1.5..10/2.5", "*-*-* *:*:01.500000..09/2.500000")]
+#[case::range_of_hours_with_repetition("0..23/5:00", "*-*-* 00..20/5:00:00")]
+#[case::range_of_years_with_repetition("2023..2030/3-1-1", "2023..2029/3-01-01 00:00:00")]
+#[case::range_with_single_value("*-*-5..5", "*-*-05 00:00:00")]
+#[case::range_repeating_once("*-*-1..3/5", "*-*-01 00:00:00")]
+#[case::end_of_month_range_with_repetition("*-*~1..3/2", "*-*~01..03/2 00:00:00")]
+#[case::end_of_month_max("*-*~28", "*-*~28 00:00:00")]
+#[case::multiple_spaces("Mon   2023-10-16   12:00", "Mon 2023-10-16 12:00:00")]
+#[case::timestamp("@1493187147 UTC", "2017-04-26 06:12:27 UTC")]
+#[case::timestamp_zero("@0", "1970-01-01 00:00:00 UTC")]
+#[case::timestamp_with_whitespace("@ 5", "1970-01-01 00:00:05 UTC")]
+#[case::timestamp_with_plus("@+5", "1970-01-01 00:00:05 UTC")]
+#[case::timestamp_ignores_time_zone("@5 Europe/Berlin", "1970-01-01 00:00:05 UTC")]
+#[case::trailing_space_after_date("*-*~05,3 ", "*-*~03,05 00:00:00")]
+fn test_calendar_spec_normalized_form(#[case] source: &str, #[case] expected: &str) {
+    let spec = CalendarSpec::parse(source).unwrap();
+    assert_eq!(spec.to_string(), expected);
+    assert_eq!(source.parse::<CalendarSpec>(), Ok(spec.clone()));
+
+    // The normalized form is stable
+    assert_eq!(CalendarSpec::parse(expected), Ok(spec));
+}
+
+#[test]
+fn test_calendar_spec_with_time_zone_name() {
+    if TimeZone::from_name("Europe/Berlin").is_err() {
+        return;
+    }
+    let spec = CalendarSpec::parse("2023-10-* 12:00 Europe/Berlin").unwrap();
+    assert_eq!(spec.to_string(), "2023-10-* 12:00:00 Europe/Berlin");
+
+    // 2023-10-29 is the end of the daylight saving time
+    let elapses: Vec<DateTime> = spec.next_elapse(date_time(2023, 10, 28, 0, 0, 0)).collect();
+    assert_eq!(
+        elapses,
+        vec![
+            date_time(2023, 10, 28, 10, 0, 0),
+            date_time(2023, 10, 29, 11, 0, 0),
+            date_time(2023, 10, 30, 11, 0, 0),
+            date_time(2023, 10, 31, 11, 0, 0),
+        ]
+    );
+}
+
+#[rstest]
+#[case::empty("", ParseError::Empty)]
+#[case::whitespace("  \t ", ParseError::Syntax(0, "Expected a number".to_string()))]
+#[case::leading_whitespace(" daily", ParseError::Syntax(0, "Expected a number".to_string()))]
+#[case::trailing_whitespace("daily ", ParseError::Syntax(0, "Expected a number".to_string()))]
+#[case::trailing_whitespace_after_time("12:00 ", ParseError::Syntax(5, "Invalid character".to_string()))]
+#[case::two_spaces_before_time_zone("12:00  UTC", ParseError::Syntax(5, "Invalid character".to_string()))]
+#[case::word("test", ParseError::Syntax(0, "Expected a number".to_string()))]
+#[case::only_utc(" utc", ParseError::Syntax(0, "Expected a number".to_string()))]
+#[case::only_number("7", ParseError::Syntax(1, "Invalid character".to_string()))]
+#[case::too_many_colons("1:2:3:4", ParseError::Syntax(5, "Invalid character".to_string()))]
+#[case::large_hour("121212:1:2", ParseError::Syntax(0, "Invalid hour".to_string()))]
+#[case::date_with_fraction("2000-03-05.23 00:00:00", ParseError::Syntax(10, "Invalid character".to_string()))]
+#[case::minute_with_fraction("2000-03-05 00:00.1:00", ParseError::Syntax(16, "Invalid character".to_string()))]
+#[case::zero_repetition("00:00:00/0.00000001", ParseError::Syntax(9, "Repetition must not be zero".to_string()))]
+#[case::fraction_range_too_short("00:00:00.0..00.9", ParseError::Syntax(6, "Invalid second".to_string()))]
+#[case::end_of_month_in_year("2016~11-22", ParseError::Syntax(7, "Invalid character".to_string()))]
+#[case::end_of_month_repetition_too_large("*-*~5/5", ParseError::Syntax(4, "Invalid day".to_string()))]
+#[case::open_weekday_range("Monday.. 12:00", ParseError::Syntax(8, "Invalid range of days of the week".to_string()))]
+#[case::open_weekday_range_at_end("Monday..", ParseError::Syntax(8, "Invalid range of days of the week".to_string()))]
+#[case::reversed_weekday_range("Fri..Mon", ParseError::Syntax(5, "Invalid range of days of the week".to_string()))]
+#[case::double_weekday_range("Mon..Wed..Fri", ParseError::Syntax(8, "Invalid character".to_string()))]
+#[case::invalid_weekday("Mond", ParseError::Syntax(0, "Invalid day of the week".to_string()))]
+#[case::invalid_weekday_after_comma("Mon,Foo", ParseError::Syntax(4, "Invalid day of the week".to_string()))]
+#[case::sign("00:+00/-5", ParseError::Syntax(3, "Expected a number".to_string()))]
+#[case::spaces_in_date("2016- 11- 24 12: 30: 00", ParseError::Syntax(5, "Expected a number".to_string()))]
+#[case::repetition_after_star("*/2", ParseError::Syntax(1, "Invalid character".to_string()))]
+#[case::month_out_of_range("*-13-01", ParseError::Syntax(2, "Invalid month".to_string()))]
+#[case::month_zero("*-0-01", ParseError::Syntax(2, "Invalid month".to_string()))]
+#[case::day_out_of_range("*-*-32", ParseError::Syntax(4, "Invalid day".to_string()))]
+#[case::year_out_of_range("2200-01-01", ParseError::Syntax(0, "Invalid year".to_string()))]
+#[case::minute_repetition_too_large("00:00/60", ParseError::Syntax(3, "Invalid minute".to_string()))]
+#[case::repetition_too_large_for_start("20/4:00", ParseError::Syntax(0, "Invalid hour".to_string()))]
+#[case::seconds_too_large("00:00:2300", ParseError::Syntax(6, "Invalid second".to_string()))]
+#[case::seconds_overflow("00:00:18446744073709551615", ParseError::Syntax(6, "Invalid second".to_string()))]
+#[case::reversed_range("12..1/2-*", ParseError::Syntax(0, "Invalid month".to_string()))]
+#[case::timestamp_out_of_range("@88588582097858858", ParseError::Syntax(0, "Timestamp out of range".to_string()))]
+#[case::timestamp_invalid("@1x", ParseError::Syntax(2, "Invalid character".to_string()))]
+#[case::timestamp_time_span("@1d", ParseError::Syntax(2, "Invalid character".to_string()))]
+#[case::timestamp_fraction("@1.5", ParseError::Syntax(2, "Invalid character".to_string()))]
+#[case::timestamp_negative("@-1", ParseError::Syntax(1, "Expected a number".to_string()))]
+#[case::end_of_month_too_large("*-*~29", ParseError::Syntax(4, "Invalid day".to_string()))]
+#[case::end_of_month_range_too_large("*-*~1..29", ParseError::Syntax(4, "Invalid day".to_string()))]
+#[case::end_of_month_repetition_to_start("*-*~1/1", ParseError::Syntax(4, "Invalid day".to_string()))]
+fn test_calendar_spec_parse_when_invalid(#[case] source: &str, #[case] expected: ParseError) {
+    assert_eq!(CalendarSpec::parse(source), Err(expected));
+}
+
+#[rstest]
+#[case::workdays_from_saturday(
+    "Mon..Fri *-*-* 09:00:00",
+    date_time(2023, 10, 14, 0, 0, 0),
+    vec![
+        date_time(2023, 10, 16, 9, 0, 0),
+        date_time(2023, 10, 17, 9, 0, 0),
+        date_time(2023, 10, 18, 9, 0, 0),
+        date_time(2023, 10, 19, 9, 0, 0),
+        date_time(2023, 10, 20, 9, 0, 0),
+        date_time(2023, 10, 23, 9, 0, 0),
+    ]
+)]
+#[case::weekend(
+    "Sat,Sun 10:00",
+    date_time(2023, 10, 16, 12, 0, 0),
+    vec![
+        date_time(2023, 10, 21, 10, 0, 0),
+        date_time(2023, 10, 22, 10, 0, 0),
+        date_time(2023, 10, 28, 10, 0, 0),
+    ]
+)]
+#[case::quarter_hours(
+    "*:0/15",
+    date_time(2023, 10, 16, 23, 31, 0),
+    vec![
+        date_time(2023, 10, 16, 23, 45, 0),
+        date_time(2023, 10, 17, 0, 0, 0),
+        date_time(2023, 10, 17, 0, 15, 0),
+    ]
+)]
+#[case::exactly_at_elapse_is_excluded(
+    "daily",
+    date_time(2023, 10, 16, 0, 0, 0),
+    vec![date_time(2023, 10, 17, 0, 0, 0), date_time(2023, 10, 18, 0, 0, 0)]
+)]
+#[case::weekly_from_monday(
+    "weekly",
+    date_time(2023, 10, 16, 0, 0, 0),
+    vec![date_time(2023, 10, 23, 0, 0, 0), date_time(2023, 10, 30, 0, 0, 0)]
+)]
+#[case::quarterly(
+    "quarterly",
+    date_time(2023, 10, 16, 0, 0, 0),
+    vec![date_time(2024, 1, 1, 0, 0, 0), date_time(2024, 4, 1, 0, 0, 0)]
+)]
+#[case::leap_day(
+    "*-02-29 12:00",
+    date_time(2023, 1, 1, 0, 0, 0),
+    vec![date_time(2024, 2, 29, 12, 0, 0), date_time(2028, 2, 29, 12, 0, 0)]
+)]
+#[case::thirty_first(
+    "*-*-31",
+    date_time(2023, 1, 31, 0, 0, 0),
+    vec![date_time(2023, 3, 31, 0, 0, 0), date_time(2023, 5, 31, 0, 0, 0)]
+)]
+#[case::last_day_of_month(
+    "*-*~01",
+    date_time(2024, 1, 31, 0, 0, 0),
+    vec![date_time(2024, 2, 29, 0, 0, 0), date_time(2024, 3, 31, 0, 0, 0)]
+)]
+#[case::last_days_of_february(
+    "*-02~01..03 12:00",
+    date_time(2023, 1, 1, 0, 0, 0),
+    vec![
+        date_time(2023, 2, 26, 12, 0, 0),
+        date_time(2023, 2, 27, 12, 0, 0),
+        date_time(2023, 2, 28, 12, 0, 0),
+        date_time(2024, 2, 27, 12, 0, 0),
+    ]
+)]
+#[case::fractional_seconds(
+    "12:00:0/0.5",
+    date_time(2023, 10, 16, 12, 0, 58),
+    vec![
+        DateTime::from_gregorian_date_time(2023, 10, 16, 12, 0, 58, 500_000_000),
+        date_time(2023, 10, 16, 12, 0, 59),
+        DateTime::from_gregorian_date_time(2023, 10, 16, 12, 0, 59, 500_000_000),
+        date_time(2023, 10, 17, 12, 0, 0),
+    ]
+)]
+#[case::year_list_ends(
+    "2023,2025-10-16 12:00",
+    date_time(2023, 1, 1, 0, 0, 0),
+    vec![date_time(2023, 10, 16, 12, 0, 0), date_time(2025, 10, 16, 12, 0, 0)]
+)]
+#[case::timestamp(
+    "@1697457600",
+    date_time(2023, 1, 1, 0, 0, 0),
+    vec![date_time(2023, 10, 16, 12, 0, 0)]
+)]
+fn test_calendar_spec_next_elapse(
+    #[case] source: &str,
+    #[case] after: DateTime,
+    #[case] expected: Vec<DateTime>,
+) {
+    let spec = CalendarSpec::parse(source).unwrap();
+    let elapses: Vec<DateTime> = spec
+        .next_elapse_with_local_zone(after, TimeZone::UTC)
+        .take(expected.len())
+        .collect();
+    assert_eq!(elapses, expected);
+}
+
+#[rstest]
+#[case::year_list("2023,2025-10-16 12:00", date_time(2025, 10, 16, 12, 0, 0))]
+#[case::weekday_never_matches("Tue 2023-10-16", date_time(2023, 1, 1, 0, 0, 0))]
+#[case::timestamp("@1697457600", date_time(2023, 10, 16, 12, 0, 0))]
+#[case::february_thirtieth("*-02-30", date_time(2023, 1, 1, 0, 0, 0))]
+#[case::max_year("2199-12-31 23:59:59", date_time(2199, 12, 31, 23, 59, 59))]
+fn test_calendar_spec_next_elapse_when_no_more_elapses(
+    #[case] source: &str,
+    #[case] after: DateTime,
+) {
+    let spec = CalendarSpec::parse(source).unwrap();
+    let mut elapses = spec.next_elapse_with_local_zone(after, TimeZone::UTC);
+    assert_eq!(elapses.next(), None);
+    assert_eq!(elapses.next(), None);
+}
+
+#[test]
+fn test_calendar_spec_next_elapse_when_after_has_nanos() {
+    let spec = CalendarSpec::parse("12:00:00.000001").unwrap();
+    let after = DateTime::from_gregorian_date_time(2023, 10, 16, 12, 0, 0, 500);
+    assert_eq!(
+        spec.next_elapse_with_local_zone(after, TimeZone::UTC)
+            .next(),
+        Some(DateTime::from_gregorian_date_time(
+            2023, 10, 16, 12, 0, 0, 1000
+        ))
+    );
+}
+
+#[rstest]
+#[case::daily_in_winter(
+    "daily",
+    date_time(2023, 1, 16, 12, 0, 0),
+    vec![date_time(2023, 1, 16, 23, 0, 0), date_time(2023, 1, 17, 23, 0, 0)]
+)]
+#[case::daily_over_start_of_dst(
+    "daily",
+    date_time(2023, 3, 25, 12, 0, 0),
+    vec![date_time(2023, 3, 25, 23, 0, 0), date_time(2023, 3, 26, 22, 0, 0)]
+)]
+#[case::non_existent_time_is_skipped(
+    "*-*-* 02:30",
+    date_time(2023, 3, 25, 12, 0, 0),
+    vec![date_time(2023, 3, 27, 0, 30, 0), date_time(2023, 3, 28, 0, 30, 0)]
+)]
+#[case::hourly_over_start_of_dst(
+    "*:30",
+    date_time(2023, 3, 26, 0, 0, 0),
+    vec![
+        date_time(2023, 3, 26, 0, 30, 0),
+        date_time(2023, 3, 26, 1, 30, 0),
+        date_time(2023, 3, 26, 2, 30, 0),
+    ]
+)]
+#[case::ambiguous_time_elapses_once(
+    "*-*-* 02:30",
+    date_time(2023, 10, 28, 12, 0, 0),
+    vec![date_time(2023, 10, 29, 0, 30, 0), date_time(2023, 10, 30, 1, 30, 0)]
+)]
+#[case::repeated_hour_elapses_once(
+    "*:30",
+    date_time(2023, 10, 28, 23, 45, 0),
+    vec![
+        date_time(2023, 10, 29, 0, 30, 0),
+        date_time(2023, 10, 29, 2, 30, 0),
+        date_time(2023, 10, 29, 3, 30, 0),
+    ]
+)]
+#[case::within_repeated_hour(
+    "*:30",
+    date_time(2023, 10, 29, 1, 10, 0),
+    vec![date_time(2023, 10, 29, 1, 30, 0), date_time(2023, 10, 29, 2, 30, 0)]
+)]
+#[case::utc_ignores_local_zone(
+    "daily UTC",
+    date_time(2023, 10, 16, 12, 0, 0),
+    vec![date_time(2023, 10, 17, 0, 0, 0), date_time(2023, 10, 18, 0, 0, 0)]
+)]
+fn test_calendar_spec_next_elapse_with_local_zone(
+    #[case] source: &str,
+    #[case] after: DateTime,
+    #[case] expected: Vec<DateTime>,
+) {
+    let zone = TimeZone::from_tz_string(CET).unwrap();
+    let spec = CalendarSpec::parse(source).unwrap();
+    let elapses: Vec<DateTime> = spec
+        .next_elapse_with_local_zone(after, zone)
+        .take(expected.len())
+        .collect();
+    assert_eq!(elapses, expected);
+}
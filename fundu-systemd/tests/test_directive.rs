@@ -0,0 +1,156 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::borrow::Cow;
+
+use fundu::{Duration, ParseError, TimeUnit};
+use fundu_systemd::{
+    parse_directive, parse_unit_file, Directive, Infinity, DIRECTIVES, SYSTEMD_MAX_MICRO_DURATION,
+    SYSTEMD_MAX_NANOS_DURATION,
+};
+use rstest::rstest;
+
+#[test]
+fn test_directives_are_sorted() {
+    assert!(DIRECTIVES.windows(2).all(|w| w[0].0 < w[1].0));
+}
+
+#[rstest]
+#[case::restart_sec("RestartSec", TimeUnit::Second, false, Infinity::Allowed)]
+#[case::timeout_sec("TimeoutSec", TimeUnit::Second, false, Infinity::ZeroIsInfinity)]
+#[case::timer_slack_nsec("TimerSlackNSec", TimeUnit::NanoSecond, true, Infinity::Allowed)]
+#[case::unknown_usec("CPUQuotaPeriodUSec", TimeUnit::MicroSecond, false, Infinity::Allowed)]
+#[case::unknown_nsec("SomeNSec", TimeUnit::NanoSecond, true, Infinity::Allowed)]
+#[case::unknown_sec("SomeSec", TimeUnit::Second, false, Infinity::Allowed)]
+fn test_directive_from_name(
+    #[case] name: &str,
+    #[case] default_unit: TimeUnit,
+    #[case] nanos: bool,
+    #[case] infinity: Infinity,
+) {
+    let directive = Directive::from_name(name).unwrap();
+    assert_eq!(directive.default_unit, default_unit);
+    assert_eq!(directive.nanos, nanos);
+    assert_eq!(directive.infinity, infinity);
+}
+
+#[rstest]
+#[case::empty("")]
+#[case::exec_start("ExecStart")]
+#[case::lowercase("restartsec")]
+#[case::seconds("Seconds")]
+fn test_directive_from_name_when_no_time_span_then_none(#[case] name: &str) {
+    assert_eq!(Directive::from_name(name), None);
+}
+
+#[rstest]
+#[case::restart_sec_default_unit("RestartSec", "5", Duration::positive(5, 0))]
+#[case::restart_sec_fraction("RestartSec", "1.5", Duration::positive(1, 500_000_000))]
+#[case::restart_sec_zero("RestartSec", "0", Duration::ZERO)]
+#[case::restart_sec_infinity("RestartSec", "infinity", SYSTEMD_MAX_MICRO_DURATION)]
+#[case::accuracy_sec("AccuracySec", "1us", Duration::positive(0, 1000))]
+#[case::runtime_max_sec_infinity("RuntimeMaxSec", "infinity", SYSTEMD_MAX_MICRO_DURATION)]
+#[case::timeout_sec_zero("TimeoutSec", "0", SYSTEMD_MAX_MICRO_DURATION)]
+#[case::timeout_stop_sec_zero_units("TimeoutStopSec", "0s 0ms", SYSTEMD_MAX_MICRO_DURATION)]
+#[case::timeout_start_sec("TimeoutStartSec", "1min 30s", Duration::positive(90, 0))]
+#[case::usec_default_unit("CPUQuotaPeriodUSec", "10000", Duration::positive(0, 10_000_000))]
+#[case::timer_slack_nsec("TimerSlackNSec", "50", Duration::positive(0, 50))]
+#[case::timer_slack_nsec_units("TimerSlackNSec", "1us 5ns", Duration::positive(0, 1005))]
+#[case::timer_slack_nsec_infinity("TimerSlackNSec", "infinity", SYSTEMD_MAX_NANOS_DURATION)]
+#[case::saturates_at_max("RestartSec", "1000000000000000y", SYSTEMD_MAX_MICRO_DURATION)]
+fn test_parse_directive(#[case] name: &str, #[case] value: &str, #[case] expected: Duration) {
+    assert_eq!(parse_directive(name, value), Ok(expected));
+}
+
+#[rstest]
+#[case::unknown_directive(
+    "ExecStart",
+    "5s",
+    ParseError::InvalidInput("Not a time span directive: 'ExecStart'".to_owned())
+)]
+#[case::nanos_when_not_allowed("RestartSec", "5ns", ParseError::InvalidInput("ns".to_owned()))]
+#[case::invalid_unit("WatchdogSec", "5 apples", ParseError::InvalidInput("apples".to_owned()))]
+#[case::empty("RestartSec", "", ParseError::Empty)]
+fn test_parse_directive_when_error(
+    #[case] name: &str,
+    #[case] value: &str,
+    #[case] expected: ParseError,
+) {
+    assert_eq!(parse_directive(name, value), Err(expected));
+}
+
+#[test]
+fn test_parse_unit_file() {
+    let unit = "\
+# A comment
+; Another comment
+RuntimeMaxSec=1h
+
+[Unit]
+Description=Example with RestartSec=5s
+StartLimitIntervalSec = 10s
+
+[Service]
+ExecStart=/usr/bin/example
+RestartSec=
+TimeoutStopSec=1min \\
+# a comment inside the continuation
+    30s
+  WatchdogSec=5 apples
+TimerSlackNSec=100
+
+[Timer]
+OnCalendar=daily
+RandomizedDelaySec=1h\\
+";
+    let entries = parse_unit_file(unit);
+    let actual = entries
+        .iter()
+        .map(|entry| (entry.section, entry.name, entry.value.as_ref(), entry.line))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        actual,
+        vec![
+            ("", "RuntimeMaxSec", "1h", 3),
+            ("Unit", "StartLimitIntervalSec", "10s", 7),
+            ("Service", "TimeoutStopSec", "1min  30s", 12),
+            ("Service", "WatchdogSec", "5 apples", 15),
+            ("Service", "TimerSlackNSec", "100", 16),
+            ("Timer", "RandomizedDelaySec", "1h", 20),
+        ]
+    );
+
+    let durations = entries
+        .into_iter()
+        .map(|entry| entry.duration)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        durations,
+        vec![
+            Ok(Duration::positive(3600, 0)),
+            Ok(Duration::positive(10, 0)),
+            Ok(Duration::positive(90, 0)),
+            Err(ParseError::InvalidInput("apples".to_owned())),
+            Ok(Duration::positive(0, 100)),
+            Ok(Duration::positive(3600, 0)),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_unit_file_borrows_value_without_continuation() {
+    let entries = parse_unit_file("[Service]\nRestartSec= 5s \n");
+    assert_eq!(entries.len(), 1);
+    assert!(matches!(entries[0].value, Cow::Borrowed("5s")));
+}
+
+#[rstest]
+#[case::empty("")]
+#[case::only_comments("# RestartSec=5s\n; TimeoutSec=5s")]
+#[case::no_time_spans("[Service]\nExecStart=/bin/true\nType=oneshot")]
+#[case::no_assignment("[Service]\nRestartSec")]
+fn test_parse_unit_file_when_no_time_spans_then_empty(#[case] unit: &str) {
+    assert!(parse_unit_file(unit).is_empty());
+}
@@ -0,0 +1,212 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use fundu::Duration;
+use fundu_systemd::{format_timespan, TimeSpanParser};
+#[cfg(feature = "datetime")]
+use fundu_systemd::{
+    format_timestamp, format_timestamp_relative, format_timestamp_style, DateTime, TimeZone,
+    TimestampParser, TimestampStyle,
+};
+use rstest::rstest;
+
+/// The reference outputs of `format_timespan` of systemd
+const TIMESPAN_FIXTURE: &str = include_str!("fixtures/systemd_format_timespan.txt");
+#[cfg(feature = "datetime")]
+/// The reference outputs of `format_timestamp_style` of systemd
+const TIMESTAMP_FIXTURE: &str = include_str!("fixtures/systemd_format_timestamp.txt");
+#[cfg(feature = "datetime")]
+/// The reference outputs of `format_timestamp_relative` of systemd
+const RELATIVE_FIXTURE: &str = include_str!("fixtures/systemd_format_timestamp_relative.txt");
+
+#[cfg(feature = "datetime")]
+/// The time of `now` of the relative fixtures
+const NOW: DateTime = DateTime::from_gregorian_date_time(2023, 10, 16, 12, 34, 56, 789_012_000);
+
+fn from_usec(usec: u64) -> Duration {
+    Duration::positive(usec / 1_000_000, (usec % 1_000_000) as u32 * 1000)
+}
+
+#[cfg(feature = "datetime")]
+fn timestamp_from_usec(usec: u64) -> DateTime {
+    DateTime::UNIX_EPOCH
+        .checked_add_duration(&from_usec(usec))
+        .unwrap()
+}
+
+fn fixture_lines(fixture: &str) -> impl Iterator<Item = Vec<&str>> {
+    fixture
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .map(|line| line.split('\t').collect())
+}
+
+#[test]
+fn test_format_timespan_matches_systemd() {
+    let mut mismatches = vec![];
+    for fields in fixture_lines(TIMESPAN_FIXTURE) {
+        let (usec, accuracy, expected) = (fields[0], fields[1], fields[2]);
+        let actual = format_timespan(
+            from_usec(usec.parse().unwrap()),
+            from_usec(accuracy.parse().unwrap()),
+        );
+        if actual != expected {
+            mismatches.push(format!(
+                "{usec} (accuracy {accuracy}): expected '{expected}', got '{actual}'"
+            ));
+        }
+    }
+    assert!(mismatches.is_empty(), "{mismatches:#?}");
+}
+
+#[cfg(feature = "datetime")]
+#[test]
+fn test_format_timestamp_style_matches_systemd() {
+    let mut mismatches = vec![];
+    for fields in fixture_lines(TIMESTAMP_FIXTURE) {
+        let (name, usec, style, expected) = (fields[0], fields[1], fields[2], fields[3]);
+        let zone = match TimeZone::from_name(name) {
+            Ok(zone) => zone,
+            // The time zone database of the system doesn't have this time zone
+            Err(_) => continue,
+        };
+        let style = match style {
+            "pretty" => TimestampStyle::Pretty,
+            "us" => TimestampStyle::Us,
+            "utc" => TimestampStyle::Utc,
+            "us+utc" => TimestampStyle::UsUtc,
+            "unix" => TimestampStyle::Unix,
+            style => panic!("Unknown style: {style}"),
+        };
+
+        let actual = format_timestamp_style(
+            timestamp_from_usec(usec.parse().unwrap()),
+            Some(&zone),
+            style,
+        );
+        if actual.as_deref().unwrap_or("n/a") != expected {
+            mismatches.push(format!(
+                "{name} {usec} {style:?}: expected '{expected}', got {actual:?}"
+            ));
+        }
+    }
+    assert!(mismatches.is_empty(), "{mismatches:#?}");
+}
+
+#[cfg(feature = "datetime")]
+#[test]
+fn test_format_timestamp_relative_matches_systemd() {
+    let mut mismatches = vec![];
+    for fields in fixture_lines(RELATIVE_FIXTURE) {
+        let (usec, expected) = (fields[0], fields[1]);
+        let actual =
+            format_timestamp_relative(timestamp_from_usec(usec.parse().unwrap()), Some(NOW));
+        if actual.as_deref().unwrap_or("n/a") != expected {
+            mismatches.push(format!("{usec}: expected '{expected}', got {actual:?}"));
+        }
+    }
+    assert!(mismatches.is_empty(), "{mismatches:#?}");
+}
+
+#[rstest]
+#[case::negative(Duration::negative(1, 0), Duration::ZERO, "0")]
+#[case::nanos_only(Duration::positive(0, 999), Duration::ZERO, "0")]
+#[case::nanos_are_truncated(Duration::positive(1, 1999), Duration::ZERO, "1.000001s")]
+#[case::max_micro_duration(fundu_systemd::SYSTEMD_MAX_MICRO_DURATION, Duration::ZERO, "infinity")]
+#[case::negative_accuracy(
+    Duration::positive(1, 500_000_000),
+    Duration::negative(1, 0),
+    "1.500000s"
+)]
+#[case::accuracy_larger_than_duration(Duration::positive(90, 0), Duration::MAX, "1min")]
+fn test_format_timespan(
+    #[case] duration: Duration,
+    #[case] accuracy: Duration,
+    #[case] expected: &str,
+) {
+    assert_eq!(format_timespan(duration, accuracy), expected);
+}
+
+#[rstest]
+#[case::seconds(Duration::positive(10, 500_000_000), Duration::ZERO)]
+#[case::minutes(
+    Duration::positive(5410, 500_000_000),
+    Duration::positive(0, 1_000_000)
+)]
+#[case::all_units(Duration::positive(34_279_261, 1_001_000), Duration::ZERO)]
+fn test_format_timespan_can_be_parsed_again(
+    #[case] duration: Duration,
+    #[case] accuracy: Duration,
+) {
+    assert_eq!(
+        TimeSpanParser::new().parse(&format_timespan(duration, accuracy)),
+        Ok(duration)
+    );
+}
+
+#[cfg(feature = "datetime")]
+#[rstest]
+#[case::pretty(TimestampStyle::Pretty, "Fri 2012-11-23 11:12:13 CET")]
+#[case::us(TimestampStyle::Us, "Fri 2012-11-23 11:12:13.500000 CET")]
+#[case::utc(TimestampStyle::Utc, "Fri 2012-11-23 10:12:13 UTC")]
+#[case::us_utc(TimestampStyle::UsUtc, "Fri 2012-11-23 10:12:13.500000 UTC")]
+#[case::unix(TimestampStyle::Unix, "@1353665533")]
+fn test_format_timestamp_style_with_tz_string(
+    #[case] style: TimestampStyle,
+    #[case] expected: &str,
+) {
+    let zone = TimeZone::from_tz_string("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+    let date_time = DateTime::from_gregorian_date_time(2012, 11, 23, 10, 12, 13, 500_000_999);
+    assert_eq!(
+        format_timestamp_style(date_time, Some(&zone), style),
+        Some(expected.to_owned())
+    );
+}
+
+#[cfg(feature = "datetime")]
+#[rstest]
+#[case::utc(TimeZone::UTC, "Mon 2023-10-16 12:34:56 UTC")]
+#[case::fixed_offset_without_abbreviation(
+    TimeZone::fixed(fundu_gnu::UtcOffset::from_hms(-5, -30, 0).unwrap()),
+    "Mon 2023-10-16 07:04:56"
+)]
+fn test_format_timestamp_with_zone(#[case] zone: TimeZone, #[case] expected: &str) {
+    assert_eq!(
+        format_timestamp(NOW, Some(&zone)),
+        Some(expected.to_owned())
+    );
+}
+
+#[cfg(feature = "datetime")]
+#[rstest]
+#[case::pretty(TimestampStyle::Pretty)]
+#[case::us(TimestampStyle::Us)]
+#[case::utc(TimestampStyle::Utc)]
+#[case::us_utc(TimestampStyle::UsUtc)]
+fn test_format_timestamp_style_can_be_parsed_again(#[case] style: TimestampStyle) {
+    let zone = TimeZone::from_tz_string("EST5EDT,M3.2.0,M11.1.0").unwrap();
+    let mut parser = TimestampParser::new();
+    parser.set_local_zone(zone.clone());
+
+    let formatted = format_timestamp_style(NOW, Some(&zone), style).unwrap();
+    let expected = if matches!(style, TimestampStyle::Us | TimestampStyle::UsUtc) {
+        NOW
+    } else {
+        DateTime::from_gregorian_date_time(2023, 10, 16, 12, 34, 56, 0)
+    };
+    // The abbreviation `EDT` is not a time zone name, so it's parsed without it
+    let source = formatted.strip_suffix(" EDT").unwrap_or(&formatted);
+    assert_eq!(parser.parse(source), Ok(expected));
+}
+
+#[cfg(feature = "datetime")]
+#[rstest]
+#[case::unix_epoch(DateTime::UNIX_EPOCH)]
+#[case::before_unix_epoch(DateTime::from_gregorian_date_time(1969, 12, 31, 23, 59, 59, 0))]
+#[case::nanos_after_unix_epoch(DateTime::from_gregorian_date_time(1970, 1, 1, 0, 0, 0, 999))]
+fn test_format_timestamp_when_not_set_then_none(#[case] date_time: DateTime) {
+    assert_eq!(format_timestamp(date_time, Some(&TimeZone::UTC)), None);
+    assert_eq!(format_timestamp_relative(date_time, Some(NOW)), None);
+}
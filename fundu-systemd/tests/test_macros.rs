@@ -0,0 +1,47 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+#![cfg(feature = "macros")]
+
+use fundu::Duration;
+use fundu_systemd::{std_timespan, timespan, TimeSpanParser};
+use rstest::rstest;
+
+#[rstest]
+#[case::number(timespan!("1"), "1")]
+#[case::only_time_unit(timespan!("min"), "min")]
+#[case::micro_second(timespan!("1us"), "1us")]
+#[case::micro_sign(timespan!("1\u{00b5}s"), "1\u{00b5}s")]
+#[case::greek_mu(timespan!("1\u{03bc}s"), "1\u{03bc}s")]
+#[case::milli_second(timespan!("1msec"), "1msec")]
+#[case::second(timespan!("1 seconds"), "1 seconds")]
+#[case::minute(timespan!("1minutes"), "1minutes")]
+#[case::hour(timespan!("1hr"), "1hr")]
+#[case::day(timespan!("1days"), "1days")]
+#[case::week(timespan!("1weeks"), "1weeks")]
+#[case::month(timespan!("1M"), "1M")]
+#[case::year(timespan!("1years"), "1years")]
+#[case::multiple(timespan!("1y 12month 1.5d"), "1y 12month 1.5d")]
+#[case::without_delimiter(timespan!("55s500ms"), "55s500ms")]
+#[case::surrounding_whitespace(timespan!(" \t1s\n "), " \t1s\n ")]
+#[case::infinity(timespan!("infinity"), "infinity")]
+#[case::infinity_with_whitespace(timespan!(" infinity "), " infinity ")]
+#[case::saturating(timespan!("100000000000000000000000000000years"), "100000000000000000000000000000years")]
+fn test_timespan_macro_is_equal_to_time_span_parser(
+    #[case] duration: Duration,
+    #[case] source: &str,
+) {
+    assert_eq!(TimeSpanParser::new().parse(source), Ok(duration));
+}
+
+#[test]
+fn test_std_timespan_macro_is_equal_to_time_span_parser() {
+    assert_eq!(
+        TimeSpanParser::new()
+            .parse("1min 500ms")
+            .map(|duration| duration.try_into().unwrap()),
+        Ok(std_timespan!("1min 500ms"))
+    );
+}
@@ -0,0 +1,11 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+#![cfg(feature = "macros")]
+
+#[test]
+fn test_timespan_macros_when_invalid_then_compile_error() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
@@ -7,7 +7,8 @@
 
 use fundu::{Duration, ParseError, TimeUnit};
 use fundu_systemd::{
-    parse, parse_nanos, TimeSpanParser, SYSTEMD_MAX_MICRO_DURATION, SYSTEMD_MAX_NANOS_DURATION,
+    parse, parse_nanos, BufReadParser, StreamParser, TimeSpanParser, TimeSpanParserBuilder,
+    SYSTEMD_MAX_MICRO_DURATION, SYSTEMD_MAX_NANOS_DURATION,
 };
 use rstest::rstest;
 
@@ -215,3 +216,169 @@ fn test_parse_with_invalid_max_then_panic(#[case] max: Duration) {
 fn test_parse_nanos_with_invalid_max_then_panic(#[case] max: Duration) {
     _ = parse_nanos("123", None, Some(max));
 }
+
+#[rstest]
+#[case::negative("-1", ParseError::NegativeNumber)]
+#[case::exponent("234e10", ParseError::Syntax(3, "No exponent allowed".to_string()))]
+#[case::alias("1sek", ParseError::InvalidInput("sek".to_string()))]
+#[case::underscore("1s_2s", ParseError::InvalidInput("s_2s".to_string()))]
+fn test_parser_builder_default_is_systemd_compatible(
+    #[case] input: &str,
+    #[case] expected: ParseError,
+) {
+    let parser = TimeSpanParserBuilder::new().build();
+    assert_eq!(parser, TimeSpanParser::new());
+    assert_eq!(parser.parse(input), Err(expected));
+}
+
+#[rstest]
+#[case::negative("-1min", Duration::negative(60, 0))]
+#[case::negative_sum("1min_-90s", Duration::negative(30, 0))]
+#[case::negative_saturates("-1000000000000000y", -SYSTEMD_MAX_MICRO_DURATION)]
+#[case::exponent("1e3ms", Duration::positive(1, 0))]
+#[case::negative_exponent("25E-1s", Duration::positive(2, 500_000_000))]
+#[case::alias("1sek", Duration::positive(1, 0))]
+#[case::alias_and_systemd_units("1tag_1d", Duration::positive(2 * 86_400, 0))]
+#[case::delimiter("_1s_2s_", Duration::positive(3, 0))]
+#[case::delimiter_between_number_and_unit("1_s", Duration::positive(1, 0))]
+#[case::infinity("_infinity_", SYSTEMD_MAX_MICRO_DURATION)]
+fn test_parser_builder_with_deviations(#[case] input: &str, #[case] expected: Duration) {
+    const PARSER: TimeSpanParser = TimeSpanParserBuilder::new()
+        .allow_negative()
+        .allow_exponent()
+        .aliases(&[("sek", TimeUnit::Second), ("tag", TimeUnit::Day)])
+        .delimiter(|byte| byte == b'_')
+        .build();
+    assert_eq!(PARSER.parse(input), Ok(expected));
+}
+
+#[rstest]
+#[case::saturates("2h", Duration::positive(3600, 0))]
+#[case::infinity("infinity", Duration::positive(3600, 0))]
+#[case::below_max("1min", Duration::positive(60, 0))]
+fn test_parser_builder_with_max(#[case] input: &str, #[case] expected: Duration) {
+    let parser = TimeSpanParserBuilder::new()
+        .max(Duration::positive(3600, 0))
+        .build();
+    assert_eq!(parser.parse(input), Ok(expected));
+    assert_eq!(parser.parse_nanos(input), Ok(expected));
+    assert_eq!(
+        parser.parse_with_max(input, SYSTEMD_MAX_MICRO_DURATION),
+        TimeSpanParser::new().parse(input)
+    );
+}
+
+/// The reference outputs of `systemd-analyze timespan` (see
+/// `fixtures/generate_systemd_analyze_timespan.sh`)
+const SYSTEMD_ANALYZE_FIXTURE: &str = include_str!("fixtures/systemd_analyze_timespan.txt");
+
+/// The known differences to `systemd-analyze timespan`
+const SYSTEMD_ANALYZE_DIFFERENCES: &str =
+    include_str!("fixtures/systemd_analyze_timespan_differences.txt");
+
+#[test]
+fn test_parse_matches_systemd_analyze_timespan() {
+    let known_differences = SYSTEMD_ANALYZE_DIFFERENCES
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.split('\t').next())
+        .collect::<Vec<&str>>();
+    let parser = TimeSpanParser::new();
+    let mut mismatches = vec![];
+    for line in SYSTEMD_ANALYZE_FIXTURE
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+    {
+        let fields: Vec<&str> = line.split('\t').collect();
+        let (source, expected) = (
+            fields[0].replace("\\t", "\t").replace("\\n", "\n"),
+            fields[1],
+        );
+        let actual = parser.parse(&source).map(|duration| {
+            let duration: std::time::Duration = duration.try_into().unwrap();
+            duration.as_micros()
+        });
+        let is_match = match expected {
+            "error" => actual.is_err(),
+            expected => actual == Ok(expected.parse().unwrap()),
+        };
+        let is_known = known_differences.contains(&fields[0]);
+        if is_match == is_known {
+            mismatches.push(format!(
+                "'{source}': expected {expected}, got {actual:?} (known difference: {is_known})"
+            ));
+        }
+    }
+    assert!(mismatches.is_empty(), "{mismatches:#?}");
+}
+
+#[test]
+fn test_time_span_parser_with_buf_read_parser_and_line_separator() {
+    let input = "1min 30s\n2h\ninfinity\n";
+    let reader = std::io::BufReader::with_capacity(3, input.as_bytes());
+    let stream = StreamParser::with_separator(TimeSpanParser::new(), |byte| byte == b'\n');
+
+    let durations = BufReadParser::with_stream(reader, stream)
+        .map(|item| item.unwrap().duration)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        durations,
+        vec![
+            Duration::positive(90, 0),
+            Duration::positive(7200, 0),
+            SYSTEMD_MAX_MICRO_DURATION
+        ]
+    );
+}
+
+#[rstest]
+#[case::log_line(
+    "Service hold-off time over, scheduling restart in 100ms.",
+    vec![("100ms", Duration::positive(0, 100_000_000))]
+)]
+#[case::multiple_units("Timeout after 1min 30s (2 attempts)", vec![("1min 30s", Duration::positive(90, 0))])]
+#[case::number_without_unit("retry 3 of 5", vec![])]
+#[case::infinity("RuntimeMaxSec=infinity", vec![("infinity", SYSTEMD_MAX_MICRO_DURATION)])]
+#[case::several("1h, 2 min and 5us", vec![
+    ("1h", Duration::positive(3600, 0)),
+    ("2 min", Duration::positive(120, 0)),
+    ("5us", Duration::positive(0, 5000))
+])]
+fn test_time_span_parser_find_iter(#[case] text: &str, #[case] expected: Vec<(&str, Duration)>) {
+    let actual = TimeSpanParser::new()
+        .find_iter(text)
+        .map(|m| (m.source, m.duration))
+        .collect::<Vec<_>>();
+    assert_eq!(actual, expected);
+}
+
+#[rstest]
+#[case::only_time_span("1min 30s", Duration::positive(90, 0), "")]
+#[case::remainder("1min 30s; restart", Duration::positive(90, 0), "; restart")]
+#[case::leading_whitespace(" \t2h later", Duration::positive(7200, 0), " later")]
+#[case::time_unit_delimiter("5 sec, then", Duration::positive(5, 0), ", then")]
+#[case::number_without_time_unit("5 apples", Duration::positive(5, 0), " apples")]
+#[case::infinity("infinity, never", SYSTEMD_MAX_MICRO_DURATION, ", never")]
+#[case::saturating("1000000000000000y and more", SYSTEMD_MAX_MICRO_DURATION, " and more")]
+#[case::no_nanos("5ns", Duration::positive(5, 0), "ns")]
+fn test_time_span_parser_parse_prefix(
+    #[case] source: &str,
+    #[case] expected: Duration,
+    #[case] remainder: &str,
+) {
+    assert_eq!(
+        TimeSpanParser::new().parse_prefix(source),
+        Ok((expected, remainder))
+    );
+}
+
+#[rstest]
+#[case::empty("", ParseError::Empty)]
+#[case::word("restart 1min", ParseError::InvalidInput("restart 1min".to_owned()))]
+#[case::infinity_inside_word("infinityx", ParseError::InvalidInput("infinityx".to_owned()))]
+fn test_time_span_parser_parse_prefix_when_error(
+    #[case] source: &str,
+    #[case] expected: ParseError,
+) {
+    assert_eq!(TimeSpanParser::new().parse_prefix(source), Err(expected));
+}
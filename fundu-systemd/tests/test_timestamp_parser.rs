@@ -0,0 +1,167 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+#![cfg(feature = "datetime")]
+
+use fundu::ParseError;
+use fundu_systemd::{DateTime, FixedClock, TimeZone, TimestampParser};
+use rstest::rstest;
+
+/// The reference outputs of `systemd-analyze timestamp`
+const FIXTURE: &str = include_str!("fixtures/systemd_analyze_timestamp.txt");
+
+/// The time of `now` of the fixtures
+const NOW: DateTime = DateTime::from_gregorian_date_time(2023, 10, 16, 12, 34, 56, 789_012_000);
+
+const CLOCK: FixedClock = FixedClock::new(NOW);
+
+fn date_time(year: i64, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> DateTime {
+    DateTime::from_gregorian_date_time(year, month, day, hour, minute, second, 0)
+}
+
+/// Parse the `UNIX seconds` of systemd-analyze like `@1353669133.500000`
+fn parse_unix_seconds(source: &str) -> DateTime {
+    let source = source.strip_prefix('@').unwrap();
+    let (seconds, micros) = source.split_once('.').unwrap_or((source, "0"));
+    DateTime::UNIX_EPOCH
+        .checked_add_duration(&fundu::Duration::positive(
+            seconds.parse().unwrap(),
+            micros.parse::<u32>().unwrap() * 1000,
+        ))
+        .unwrap()
+}
+
+fn utc_parser() -> TimestampParser<'static> {
+    let mut parser = TimestampParser::with_clock(&CLOCK);
+    parser.set_local_zone(TimeZone::UTC);
+    parser
+}
+
+#[test]
+fn test_timestamp_parser_matches_systemd_analyze() {
+    let mut mismatches = vec![];
+    for line in FIXTURE.lines().filter(|line| !line.starts_with('#')) {
+        let fields: Vec<&str> = line.split('\t').collect();
+        let (name, source, expected) = (fields[0], fields[1].replace("\\t", "\t"), fields[2]);
+        let zone = match TimeZone::from_name(name) {
+            Ok(zone) => zone,
+            // The time zone database of the system doesn't have this time zone
+            Err(_) => continue,
+        };
+
+        let mut parser = TimestampParser::with_clock(&CLOCK);
+        parser.set_local_zone(zone);
+        let actual = parser.parse(&source);
+        let is_match = match expected {
+            "error" => actual.is_err(),
+            expected => actual == Ok(parse_unix_seconds(expected)),
+        };
+        if !is_match {
+            mismatches.push(format!(
+                "{name} '{source}': expected {expected}, got {actual:?}"
+            ));
+        }
+    }
+    assert!(mismatches.is_empty(), "{mismatches:#?}");
+}
+
+#[rstest]
+#[case::tomorrow_with_time("tomorrow 12:00", date_time(2023, 10, 17, 12, 0, 0))]
+#[case::today_with_time("today 11:12:13", date_time(2023, 10, 16, 11, 12, 13))]
+#[case::yesterday_with_time_and_zone("yesterday 23:59:59 UTC", date_time(2023, 10, 15, 23, 59, 59))]
+#[case::today_with_fraction(
+    "today 1:02:03.25",
+    DateTime::from_gregorian_date_time(2023, 10, 16, 1, 2, 3, 250_000_000)
+)]
+#[case::span_with_nanos_is_truncated(
+    "@1.0000019",
+    DateTime::from_gregorian_date_time(1970, 1, 1, 0, 0, 1, 1000)
+)]
+#[case::span_with_time_units("@1d 2h", date_time(1970, 1, 2, 2, 0, 0))]
+fn test_timestamp_parser_parse(#[case] source: &str, #[case] expected: DateTime) {
+    assert_eq!(utc_parser().parse(source), Ok(expected));
+}
+
+#[rstest]
+#[case::empty("", ParseError::Empty)]
+#[case::invalid_date("2012-13-01", ParseError::Syntax(0, "Invalid timestamp".to_owned()))]
+#[case::invalid_date_after_weekday(
+    "Mon 2012-13-01",
+    ParseError::Syntax(4, "Invalid timestamp".to_owned())
+)]
+#[case::weekday_does_not_match(
+    "Tue 2023-10-16",
+    ParseError::Syntax(0, "The day of the week doesn't match the date".to_owned())
+)]
+#[case::invalid_time_after_day("tomorrow 12", ParseError::Syntax(8, "Invalid time".to_owned()))]
+#[case::date_after_day(
+    "tomorrow 2023-10-16",
+    ParseError::Syntax(8, "Invalid time".to_owned())
+)]
+#[case::invalid_span("+5 apples", ParseError::InvalidInput("apples".to_owned()))]
+#[case::invalid_span_ago(
+    "5 apples ago",
+    ParseError::InvalidInput("apples".to_owned())
+)]
+#[case::unknown_time_zone(
+    "2023-10-16 12:00 Europe/Nowhere",
+    ParseError::Syntax(0, "Invalid timestamp".to_owned())
+)]
+#[case::infinity("@infinity", ParseError::InvalidInput("Timestamp out of range".to_owned()))]
+#[case::before_unix_epoch(
+    "1969-12-31 23:59:59",
+    ParseError::InvalidInput("Timestamp out of range".to_owned())
+)]
+#[case::after_maximum("+8000y", ParseError::InvalidInput("Timestamp out of range".to_owned()))]
+fn test_timestamp_parser_parse_error(#[case] source: &str, #[case] expected: ParseError) {
+    assert_eq!(utc_parser().parse(source), Err(expected));
+}
+
+#[rstest]
+#[case::absolute("2012-11-23 11:12:13", date_time(2012, 11, 23, 11, 12, 13))]
+#[case::relative("+1h", date_time(2023, 10, 17, 1, 0, 0))]
+#[case::now("now", date_time(2023, 10, 17, 0, 0, 0))]
+#[case::today("today", date_time(2023, 10, 17, 0, 0, 0))]
+fn test_timestamp_parser_parse_with_date(#[case] source: &str, #[case] expected: DateTime) {
+    assert_eq!(
+        utc_parser().parse_with_date(source, Some(date_time(2023, 10, 17, 0, 0, 0))),
+        Ok(expected)
+    );
+}
+
+#[rstest]
+#[case::name_overrides_local_zone(
+    "2023-10-16 12:00 Europe/Berlin",
+    date_time(2023, 10, 16, 10, 0, 0)
+)]
+#[case::winter_time("2023-11-16 12:00 Europe/Berlin", date_time(2023, 11, 16, 11, 0, 0))]
+#[case::today("today Europe/Berlin", date_time(2023, 10, 15, 22, 0, 0))]
+#[case::time_only("11:00 America/New_York", date_time(2023, 10, 16, 15, 0, 0))]
+fn test_timestamp_parser_with_time_zone_name(#[case] source: &str, #[case] expected: DateTime) {
+    if TimeZone::from_name("Europe/Berlin").is_err()
+        || TimeZone::from_name("America/New_York").is_err()
+    {
+        return;
+    }
+    assert_eq!(utc_parser().parse(source), Ok(expected));
+}
+
+#[test]
+fn test_timestamp_parser_set_clock() {
+    let clock = FixedClock::new(date_time(2000, 1, 1, 0, 0, 0));
+    let mut parser = utc_parser();
+    parser.set_clock(&clock);
+    assert_eq!(parser.parse("now"), Ok(date_time(2000, 1, 1, 0, 0, 0)));
+}
+
+#[test]
+fn test_timestamp_parser_default() {
+    let parser = TimestampParser::default();
+    assert_eq!(
+        format!("{parser:?}"),
+        "TimestampParser { clock: \"SystemClock\", local_zone: None }"
+    );
+    assert_eq!(parser.parse("@0"), Ok(DateTime::UNIX_EPOCH));
+}
@@ -6,7 +6,11 @@
 use std::time::Duration;
 
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
-use fundu::{CustomDurationParser, DEFAULT_ALL_TIME_UNITS, SYSTEMD_TIME_UNITS};
+use fundu::TimeUnit::*;
+use fundu::{
+    CustomDurationParser, CustomTimeUnit, Multiplier, Numeral, DEFAULT_ALL_TIME_UNITS,
+    SYSTEMD_TIME_UNITS,
+};
 
 fn criterion_config() -> Criterion {
     Criterion::default()
@@ -56,6 +60,70 @@ fn benchmark_parsing(criterion: &mut Criterion) {
     group.finish();
 }
 
+/// Return `count` identifiers `{prefix}0`, `{prefix}1`, ...
+fn make_identifiers(prefix: &str, count: usize) -> Vec<String> {
+    (0..count).map(|i| format!("{prefix}{i}")).collect()
+}
+
+fn benchmark_lookup(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("custom duration parser lookup");
+    for count in [10, 100, 1000] {
+        let units = make_identifiers("unit", count);
+        let numerals = make_identifiers("num", count);
+        let multipliers: Vec<Multiplier> = (1..=count)
+            .map(|i| Multiplier(i64::try_from(i).unwrap(), 0))
+            .collect();
+        let unit_ids: Vec<&str> = units.iter().map(String::as_str).collect();
+        let parser = CustomDurationParser::builder()
+            .time_units(
+                &unit_ids
+                    .iter()
+                    .zip(&multipliers)
+                    .map(|(id, multiplier)| {
+                        CustomTimeUnit::new(Second, std::slice::from_ref(id), Some(*multiplier))
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            .build();
+        let last_unit = format!("1unit{}", count - 1);
+        group.bench_with_input(
+            BenchmarkId::new("time units first", count),
+            "1unit0",
+            |b, input| b.iter(|| black_box(&parser).parse(input).unwrap()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("time units last", count),
+            &last_unit,
+            |b, input| b.iter(|| black_box(&parser).parse(input).unwrap()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("time units invalid", count),
+            "1unit",
+            |b, input| b.iter(|| black_box(&parser).parse(input).unwrap_err()),
+        );
+
+        let numeral_ids: Vec<&str> = numerals.iter().map(String::as_str).collect();
+        let parser = CustomDurationParser::builder()
+            .time_unit(CustomTimeUnit::with_default(Second, &["s"]))
+            .numerals(
+                &numeral_ids
+                    .iter()
+                    .zip(&multipliers)
+                    .map(|(id, multiplier)| Numeral::new(std::slice::from_ref(id), *multiplier))
+                    .collect::<Vec<_>>(),
+            )
+            .allow_time_unit_delimiter()
+            .build();
+        let last_numeral = format!("num{} s", count - 1);
+        group.bench_with_input(
+            BenchmarkId::new("numerals last", count),
+            &last_numeral,
+            |b, input| b.iter(|| black_box(&parser).parse(input).unwrap()),
+        );
+    }
+    group.finish();
+}
+
 criterion_group!(
     name = initialization;
     config = criterion_config();
@@ -67,4 +135,10 @@ criterion_group!(
     targets = benchmark_parsing
 );
 
-criterion_main!(initialization, parsing);
+criterion_group!(
+    name = lookup;
+    config = criterion_config();
+    targets = benchmark_lookup
+);
+
+criterion_main!(initialization, parsing, lookup);
@@ -5,7 +5,7 @@
 
 use std::time::Duration;
 
-use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
 use fundu::DurationParser;
 use fundu::TimeUnit::*;
 
@@ -118,6 +118,84 @@ fn benchmark_parsing_with_time_units(criterion: &mut Criterion) {
     group.finish();
 }
 
+fn benchmark_parsing_many(criterion: &mut Criterion) {
+    const SIZE: usize = 10_000;
+    let columns = [
+        (
+            "numbers",
+            (0..SIZE).map(|i| i.to_string()).collect::<Vec<_>>(),
+        ),
+        (
+            "time units",
+            (0..SIZE).map(|i| format!("{i}ms")).collect::<Vec<_>>(),
+        ),
+        (
+            "fractions",
+            (0..SIZE).map(|i| format!("{i}.5s")).collect::<Vec<_>>(),
+        ),
+    ];
+    let parser = DurationParser::with_all_time_units();
+    let mut group = criterion.benchmark_group("parsing speed many");
+    group.throughput(Throughput::Elements(SIZE as u64));
+    for (parameter, column) in &columns {
+        group.bench_with_input(
+            BenchmarkId::new("per item loop", parameter),
+            column,
+            |b, column| {
+                b.iter(|| {
+                    black_box(&parser);
+                    column
+                        .iter()
+                        .map(|source| parser.parse(source))
+                        .collect::<Vec<_>>()
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("parse_many", parameter),
+            column,
+            |b, column| b.iter(|| black_box(&parser).parse_many(column)),
+        );
+        let mut results = Vec::with_capacity(SIZE);
+        group.bench_with_input(
+            BenchmarkId::new("parse_many_into", parameter),
+            column,
+            |b, column| {
+                b.iter(|| {
+                    results.clear();
+                    black_box(&parser).parse_many_into(column, &mut results);
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn benchmark_parsing_errors(criterion: &mut Criterion) {
+    let inputs = [
+        ("invalid time unit", "1.5x"),
+        ("invalid time unit multiple", "1s 2m 3x"),
+        ("syntax", "1e"),
+        ("empty", ""),
+    ];
+    let parser = DurationParser::builder()
+        .all_time_units()
+        .parse_multiple(None)
+        .build();
+    let mut group = criterion.benchmark_group("parsing errors");
+    for (parameter, input) in inputs {
+        group.bench_with_input(BenchmarkId::new("parse", parameter), input, |b, input| {
+            b.iter(|| black_box(&parser).parse(input))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("parse_borrowed", parameter),
+            input,
+            |b, input| b.iter(|| black_box(&parser).parse_borrowed(input)),
+        );
+    }
+    group.finish();
+}
+
 fn reference_benchmark(criterion: &mut Criterion) {
     let inputs = get_parsing_speed_inputs();
     let mut group = criterion.benchmark_group("reference speed");
@@ -151,6 +229,16 @@ criterion_group!(
     config = criterion_config();
     targets = benchmark_parsing_with_time_units
 );
+criterion_group!(
+    name = parsing_many;
+    config = criterion_config();
+    targets = benchmark_parsing_many
+);
+criterion_group!(
+    name = parsing_errors;
+    config = criterion_config();
+    targets = benchmark_parsing_errors
+);
 criterion_group!(
     name = reference;
     config = criterion_config();
@@ -161,5 +249,7 @@ criterion_main!(
     parsing,
     parsing_infinity,
     reference,
-    parsing_time_units
+    parsing_time_units,
+    parsing_many,
+    parsing_errors
 );
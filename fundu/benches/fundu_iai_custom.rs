@@ -3,7 +3,11 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
-use fundu::{CustomDurationParser, DEFAULT_ALL_TIME_UNITS, SYSTEMD_TIME_UNITS};
+use fundu::TimeUnit::*;
+use fundu::{
+    CustomDurationParser, CustomTimeUnit, Duration, Multiplier, DEFAULT_ALL_TIME_UNITS,
+    SYSTEMD_TIME_UNITS,
+};
 use iai_callgrind::{black_box, main};
 
 #[inline(never)]
@@ -16,10 +20,62 @@ fn initialization_with_systemd_time_units<'a>() -> CustomDurationParser<'a> {
     CustomDurationParser::with_time_units(black_box(&SYSTEMD_TIME_UNITS))
 }
 
+/// Return `count` identifiers `unit0`, `unit1`, ... which live as long as the benchmark
+fn make_identifiers(count: usize) -> &'static [&'static str] {
+    (0..count)
+        .map(|i| &*Box::leak(format!("unit{i}").into_boxed_str()))
+        .collect::<Vec<_>>()
+        .leak()
+}
+
+/// Setup a parser with `count` time units each with a single identifier and different multiplier
+#[inline(never)]
+#[export_name = "__iai_setup::setup_parser_with_many_time_units"]
+fn setup_parser_with_many_time_units(count: usize) -> CustomDurationParser<'static> {
+    let time_units: Vec<CustomTimeUnit> = make_identifiers(count)
+        .iter()
+        .enumerate()
+        .map(|(i, id)| {
+            let multiplier = Multiplier(i64::try_from(i).unwrap() + 1, 0);
+            CustomTimeUnit::new(Second, std::slice::from_ref(id), Some(multiplier))
+        })
+        .collect();
+    CustomDurationParser::with_time_units(&time_units)
+}
+
+#[inline(never)]
+fn parsing_first_of_ten_time_units() -> Duration {
+    let parser = setup_parser_with_many_time_units(black_box(10));
+    black_box(parser).parse(black_box("1unit0")).unwrap()
+}
+
+#[inline(never)]
+fn parsing_last_of_ten_time_units() -> Duration {
+    let parser = setup_parser_with_many_time_units(black_box(10));
+    black_box(parser).parse(black_box("1unit9")).unwrap()
+}
+
+#[inline(never)]
+fn parsing_first_of_thousand_time_units() -> Duration {
+    let parser = setup_parser_with_many_time_units(black_box(1000));
+    black_box(parser).parse(black_box("1unit0")).unwrap()
+}
+
+#[inline(never)]
+fn parsing_last_of_thousand_time_units() -> Duration {
+    let parser = setup_parser_with_many_time_units(black_box(1000));
+    black_box(parser).parse(black_box("1unit999")).unwrap()
+}
+
 main!(
     callgrind_args =
-        "toggle-collect=iai_callgrind::black_box";
+        "toggle-collect=iai_callgrind::black_box",
+        "toggle-collect=__iai_setup::setup_parser_with_many_time_units";
     functions =
         initialization_with_default_time_units,
-        initialization_with_systemd_time_units
+        initialization_with_systemd_time_units,
+        parsing_first_of_ten_time_units,
+        parsing_last_of_ten_time_units,
+        parsing_first_of_thousand_time_units,
+        parsing_last_of_thousand_time_units
 );
@@ -0,0 +1,72 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::collections::HashMap;
+use std::hash::{BuildHasherDefault, Hasher};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// A map from the identifiers of time units, keywords or numerals to their lookup data
+///
+/// The map is built when the identifiers are added to the [`crate::CustomDurationParser`], so the
+/// lookup during parsing takes constant time independently of the number of identifiers.
+pub(crate) type IdentifierMap<'a, V> = HashMap<&'a str, V, BuildHasherDefault<FnvHasher>>;
+
+/// A [`Hasher`] implementing the 64-bit `FNV-1a` hash function
+///
+/// Identifiers are usually short, and for short keys `FNV-1a` is much faster than the default
+/// `SipHash` of the standard library. Only the identifiers defined by the user of the parser are
+/// inserted into the map, so the protection of `SipHash` against hash flooding is not needed.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+}
+
+impl Hasher for FnvHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(FNV_PRIME);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::empty(b"", 0xcbf2_9ce4_8422_2325)]
+    #[case::a(b"a", 0xaf63_dc4c_8601_ec8c)]
+    #[case::foobar(b"foobar", 0x8594_4171_f739_67e8)]
+    fn test_fnv_hasher_write(#[case] bytes: &[u8], #[case] expected: u64) {
+        let mut hasher = FnvHasher::default();
+        hasher.write(bytes);
+        assert_eq!(hasher.finish(), expected);
+    }
+
+    #[test]
+    fn test_identifier_map() {
+        let mut map: IdentifierMap<'_, usize> = IdentifierMap::default();
+        map.insert("s", 0);
+        map.insert("sec", 1);
+
+        assert_eq!(map.get("s"), Some(&0));
+        assert_eq!(map.get("sec"), Some(&1));
+        assert_eq!(map.get("secs"), None);
+    }
+}
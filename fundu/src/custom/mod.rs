@@ -6,7 +6,10 @@
 use fundu_core::config::NumbersLike;
 use fundu_core::time::Multiplier;
 
+use self::lookup::IdentifierMap;
+
 pub(crate) mod builder;
+pub(crate) mod lookup;
 pub(crate) mod parser;
 pub(crate) mod time_units;
 
@@ -66,15 +69,34 @@ impl<'a> Numeral<'a> {
 #[derive(Debug, PartialEq, Eq)]
 pub(crate) struct Numerals<'a> {
     data: Vec<Numeral<'a>>,
+    index: IdentifierMap<'a, Multiplier>,
 }
 
 impl<'a> Numerals<'a> {
     pub(crate) fn new() -> Self {
-        Self { data: vec![] }
+        Self {
+            data: vec![],
+            index: IdentifierMap::default(),
+        }
     }
 
     pub(crate) fn with_numerals(numerals: Vec<Numeral<'a>>) -> Self {
-        Self { data: numerals }
+        let mut this = Self {
+            data: Vec::with_capacity(numerals.len()),
+            index: IdentifierMap::default(),
+        };
+        for numeral in numerals {
+            this.push(numeral);
+        }
+        this
+    }
+
+    pub(crate) fn push(&mut self, numeral: Numeral<'a>) {
+        for identifier in numeral.identifiers {
+            // The first numeral with the identifier wins if there are duplicate identifiers
+            self.index.entry(identifier).or_insert(numeral.multiplier);
+        }
+        self.data.push(numeral);
     }
 
     pub(crate) fn is_empty(&self) -> bool {
@@ -83,13 +105,9 @@ impl<'a> Numerals<'a> {
 }
 
 impl<'a> NumbersLike for Numerals<'a> {
+    #[inline]
     fn get(&self, input: &str) -> Option<Multiplier> {
-        self.data.iter().find_map(|numeral| {
-            numeral
-                .identifiers
-                .iter()
-                .find_map(|id| (*id == input).then_some(numeral.multiplier))
-        })
+        self.index.get(input).copied()
     }
 }
 
@@ -152,4 +170,12 @@ mod tests {
         let numerals = Numerals::with_numerals(vec![numeral_last]);
         assert_eq!(numerals.get(input), None);
     }
+
+    #[rstest]
+    fn test_numerals_when_duplicate_identifier_then_first_numeral_wins(numeral_next: Numeral) {
+        let mut numerals = Numerals::with_numerals(vec![numeral_next]);
+        numerals.push(Numeral::new(&["next", "two"], Multiplier(2, 0)));
+        assert_eq!(numerals.get("next"), Some(Multiplier(1, 0)));
+        assert_eq!(numerals.get("two"), Some(Multiplier(2, 0)));
+    }
 }
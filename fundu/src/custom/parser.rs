@@ -4,13 +4,19 @@
 // https://opensource.org/licenses/MIT
 
 use fundu_core::config::Delimiter;
+use fundu_core::distribution::DurationDistributionParser;
+use fundu_core::expression::ExpressionParser;
 use fundu_core::parse::Parser;
+use fundu_core::range::DurationRangeParser;
+use fundu_core::relative::RelativeDuration;
+use fundu_core::scan::{Matches, Scanner};
+use fundu_core::stream::{ParseDuration, ParseDurationPrefix};
 use fundu_core::time::{Duration, Multiplier, TimeUnitsLike};
 
 use super::builder::CustomDurationParserBuilder;
 use super::time_units::{CustomTimeUnit, CustomTimeUnits, TimeKeyword};
 use super::Numerals;
-use crate::{Numeral, ParseError, TimeUnit};
+use crate::{BorrowedParseError, Numeral, ParseError, TimeUnit};
 
 /// A parser with a customizable set of [`TimeUnit`]s and customizable identifiers.
 ///
@@ -337,7 +343,7 @@ impl<'a> CustomDurationParser<'a> {
     /// assert_eq!(parser.parse("one nano"), Ok(Duration::positive(0, 1)));
     /// ```
     pub fn numeral(&mut self, numeral: Numeral<'a>) -> &mut Self {
-        self.numerals.data.push(numeral);
+        self.numerals.push(numeral);
         self
     }
 
@@ -404,6 +410,338 @@ impl<'a> CustomDurationParser<'a> {
         )
     }
 
+    /// Parse the `source` string like [`CustomDurationParser::parse`] without allocating on errors
+    ///
+    /// The [`BorrowedParseError`] borrows from the `source` and renders its message only when
+    /// displayed. See [`fundu_core::parse::Parser::parse_borrowed`] for the details.
+    ///
+    /// # Errors
+    ///
+    /// If parsing to a [`crate::Duration`] fails, a [`BorrowedParseError`] is returned
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{CustomDurationParser, CustomTimeUnit, Duration, ParseError, TimeUnit};
+    ///
+    /// let parser = CustomDurationParser::builder()
+    ///     .time_unit(CustomTimeUnit::with_default(TimeUnit::Second, &["sec"]))
+    ///     .build();
+    /// assert_eq!(parser.parse_borrowed("2sec"), Ok(Duration::positive(2, 0)));
+    ///
+    /// let error = parser.parse_borrowed("2min").unwrap_err();
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "Time unit error: Invalid time unit: 'min' at column 1"
+    /// );
+    /// assert_eq!(
+    ///     ParseError::from(error),
+    ///     ParseError::TimeUnit(1, "Invalid time unit: 'min'".to_string())
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_borrowed<'s>(&self, source: &'s str) -> Result<Duration, BorrowedParseError<'s>> {
+        self.inner.parse_borrowed(
+            source,
+            &self.time_units,
+            (!self.keywords.is_empty()).then_some(&self.keywords),
+            (!self.numerals.is_empty()).then_some(&self.numerals),
+        )
+    }
+
+    /// Parse the longest valid duration at the start of `source` and return the unconsumed rest
+    ///
+    /// This method is useful to parse durations embedded in a larger grammar. Time units and
+    /// keywords end at the first ascii character which is not alphabetic, so identifiers with
+    /// other ascii characters (like digits or whitespace) are not recognized by this method. Time
+    /// units and keywords have to match these letters as a whole, so `5 months later` is parsed as
+    /// `5` with the remainder ` months later` if `months` is no time unit. See
+    /// [`fundu_core::parse::Parser::parse_prefix`] for the details.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if there's no valid duration at the start of the `source`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{CustomDurationParser, CustomTimeUnit, Duration};
+    /// use fundu::TimeUnit::*;
+    ///
+    /// let parser = CustomDurationParser::builder()
+    ///     .time_units(&[
+    ///         CustomTimeUnit::with_default(Second, &["sec", "seconds"]),
+    ///         CustomTimeUnit::with_default(Minute, &["min", "minutes"]),
+    ///     ])
+    ///     .allow_time_unit_delimiter()
+    ///     .parse_multiple(Some(&["and"]))
+    ///     .build();
+    /// assert_eq!(
+    ///     parser.parse_prefix("2 minutes and 10 sec, then retry"),
+    ///     Ok((Duration::positive(130, 0), ", then retry"))
+    /// );
+    /// assert_eq!(
+    ///     parser.parse_prefix("1 min and more"),
+    ///     Ok((Duration::positive(60, 0), " and more"))
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_prefix<'s>(&self, source: &'s str) -> Result<(Duration, &'s str), ParseError> {
+        self.inner.parse_prefix(
+            source,
+            &self.time_units,
+            (!self.keywords.is_empty()).then_some(&self.keywords),
+            (!self.numerals.is_empty()).then_some(&self.numerals),
+        )
+    }
+
+    /// Parse all `sources` into [`crate::Duration`]s
+    ///
+    /// The results are in the same order as the `sources` and are the same as parsing each source
+    /// with [`CustomDurationParser::parse`]. Plain numbers and numbers directly followed by a time
+    /// unit take a fast path, which makes this method faster than calling
+    /// [`CustomDurationParser::parse`] for each source. See
+    /// [`fundu_core::parse::Parser::parse_many`] for the details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::TimeUnit::*;
+    /// use fundu::{CustomDurationParser, CustomTimeUnit, Duration};
+    ///
+    /// let parser = CustomDurationParser::builder()
+    ///     .time_units(&[
+    ///         CustomTimeUnit::with_default(Second, &["s", "sec"]),
+    ///         CustomTimeUnit::with_default(MilliSecond, &["ms"]),
+    ///     ])
+    ///     .build();
+    /// assert_eq!(
+    ///     parser.parse_many(&["10", "250ms", "1.5sec"]),
+    ///     vec![
+    ///         Ok(Duration::positive(10, 0)),
+    ///         Ok(Duration::positive(0, 250_000_000)),
+    ///         Ok(Duration::positive(1, 500_000_000)),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_many<S: AsRef<str>>(&self, sources: &[S]) -> Vec<Result<Duration, ParseError>> {
+        self.inner.parse_many(
+            sources,
+            &self.time_units,
+            (!self.keywords.is_empty()).then_some(&self.keywords),
+            (!self.numerals.is_empty()).then_some(&self.numerals),
+        )
+    }
+
+    /// Parse all `sources` like [`CustomDurationParser::parse_many`] and append the results to
+    /// `results`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{CustomDurationParser, Duration};
+    ///
+    /// let parser = CustomDurationParser::new();
+    /// let mut results = Vec::new();
+    /// parser.parse_many_into(&["1", "2"], &mut results);
+    /// assert_eq!(
+    ///     results,
+    ///     vec![Ok(Duration::positive(1, 0)), Ok(Duration::positive(2, 0))]
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_many_into<S: AsRef<str>>(
+        &self,
+        sources: &[S],
+        results: &mut Vec<Result<Duration, ParseError>>,
+    ) {
+        self.inner.parse_many_into(
+            sources,
+            &self.time_units,
+            (!self.keywords.is_empty()).then_some(&self.keywords),
+            (!self.numerals.is_empty()).then_some(&self.numerals),
+            results,
+        );
+    }
+
+    /// Return an iterator over all durations embedded in the `text`
+    ///
+    /// Numbers without a time unit are not matched. See [`crate::Scanner`] for the details and
+    /// more settings.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{CustomDurationParser, CustomTimeUnit, Duration};
+    /// use fundu::TimeUnit::*;
+    ///
+    /// let parser = CustomDurationParser::builder()
+    ///     .time_units(&[
+    ///         CustomTimeUnit::with_default(Second, &["sec", "seconds"]),
+    ///         CustomTimeUnit::with_default(Minute, &["min", "minutes"]),
+    ///     ])
+    ///     .allow_time_unit_delimiter()
+    ///     .parse_multiple(Some(&["and"]))
+    ///     .build();
+    /// let text = "The job ran for 2 minutes and 10 seconds. Retry in 30 sec.";
+    /// let sources: Vec<_> = parser.find_iter(text).map(|m| m.source).collect();
+    ///
+    /// assert_eq!(sources, vec!["2 minutes and 10 seconds", "30 sec"]);
+    /// ```
+    pub fn find_iter<'t>(&self, text: &'t str) -> Matches<'t, &Self> {
+        Scanner::new(self).into_find_iter(text)
+    }
+
+    /// Return an [`ExpressionParser`] evaluating arithmetic expressions like `2 * (1h + 15m)`
+    ///
+    /// The durations within the expression are parsed with this parser. Numbers without a time
+    /// unit are interpreted in the [`TimeUnit`] set with `default_unit` and negative results are
+    /// only allowed if `allow_negative` is set. See [`ExpressionParser`] for the details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::TimeUnit::*;
+    /// use fundu::{CustomDurationParser, CustomTimeUnit, Duration};
+    ///
+    /// let parser = CustomDurationParser::builder()
+    ///     .time_units(&[
+    ///         CustomTimeUnit::with_default(Second, &["sec"]),
+    ///         CustomTimeUnit::with_default(Minute, &["min"]),
+    ///     ])
+    ///     .default_unit(Minute)
+    ///     .allow_negative()
+    ///     .build();
+    /// let expression = parser.expression();
+    ///
+    /// assert_eq!(
+    ///     expression.parse("1.5 + 30sec"),
+    ///     Ok(Duration::positive(120, 0))
+    /// );
+    /// assert_eq!(expression.parse("1sec - 2sec"), Ok(Duration::negative(1, 0)));
+    /// ```
+    pub fn expression(&self) -> ExpressionParser<&Self> {
+        let expression = ExpressionParser::new(self).default_unit(self.inner.config.default_unit);
+        if self.inner.config.allow_negative {
+            expression.allow_negative()
+        } else {
+            expression
+        }
+    }
+
+    /// Return a [`DurationRangeParser`] parsing ranges like `5m..10m`, `1s to 3s` or `>2h`
+    ///
+    /// The endpoints of the range are parsed with this parser. See [`DurationRangeParser`] for the
+    /// details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::TimeUnit::*;
+    /// use fundu::{CustomDurationParser, CustomTimeUnit, Duration};
+    ///
+    /// let parser = CustomDurationParser::builder()
+    ///     .time_units(&[
+    ///         CustomTimeUnit::with_default(Second, &["sec"]),
+    ///         CustomTimeUnit::with_default(Minute, &["min"]),
+    ///     ])
+    ///     .allow_time_unit_delimiter()
+    ///     .build();
+    /// let range = parser.range().parse("30 sec to 2 min").unwrap();
+    ///
+    /// assert!(range.contains(&Duration::positive(30, 0)));
+    /// assert!(range.contains(&Duration::positive(120, 0)));
+    /// assert!(!range.contains(&Duration::positive(121, 0)));
+    /// ```
+    pub fn range(&self) -> DurationRangeParser<&Self> {
+        DurationRangeParser::new(self)
+    }
+
+    /// Return a [`DurationDistributionParser`] parsing randomized durations like `30s ± 10%`
+    ///
+    /// The durations are parsed with this parser and negative durations in the distribution are
+    /// only allowed if `allow_negative` is set. See [`DurationDistributionParser`] for the details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::TimeUnit::*;
+    /// use fundu::{CustomDurationParser, CustomTimeUnit, Duration, DurationDistribution};
+    ///
+    /// let parser = CustomDurationParser::builder()
+    ///     .time_units(&[
+    ///         CustomTimeUnit::with_default(Second, &["sec"]),
+    ///         CustomTimeUnit::with_default(Minute, &["min"]),
+    ///     ])
+    ///     .allow_time_unit_delimiter()
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     parser.distribution().parse("rand(30 sec, 2 min)"),
+    ///     Ok(DurationDistribution::Uniform {
+    ///         min: Duration::positive(30, 0),
+    ///         max: Duration::positive(120, 0)
+    ///     })
+    /// );
+    /// ```
+    pub fn distribution(&self) -> DurationDistributionParser<&Self> {
+        let distribution = DurationDistributionParser::new(self);
+        if self.inner.config.allow_negative {
+            distribution.allow_negative()
+        } else {
+            distribution
+        }
+    }
+
+    /// Parse the `source` string into a [`RelativeDuration`] keeping calendar time units symbolic
+    ///
+    /// Other than [`CustomDurationParser::parse`], time units and keywords based on
+    /// [`TimeUnit::Year`], [`TimeUnit::Month`], [`TimeUnit::Week`] and [`TimeUnit::Day`] are not
+    /// converted into seconds. Their [`crate::Multiplier`] is still applied, so a custom `quarter`
+    /// with `Month` and `Multiplier(3, 0)` counts as `3` months. See also
+    /// [`fundu_core::parse::Parser::parse_relative`].
+    ///
+    /// # Errors
+    ///
+    /// If parsing into a [`RelativeDuration`] fails, a [`ParseError`] is returned
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::TimeUnit::*;
+    /// use fundu::{CustomDurationParser, CustomTimeUnit, Duration, Multiplier, RelativeDuration};
+    ///
+    /// let mut parser = CustomDurationParser::with_time_units(&[
+    ///     CustomTimeUnit::with_default(Hour, &["hour"]),
+    ///     CustomTimeUnit::with_default(Month, &["month"]),
+    ///     CustomTimeUnit::new(Month, &["quarter"], Some(Multiplier(3, 0))),
+    /// ]);
+    /// parser
+    ///     .allow_time_unit_delimiter(true)
+    ///     .parse_multiple(true, None);
+    ///
+    /// assert_eq!(
+    ///     parser.parse_relative("1 quarter 1 month 2 hour"),
+    ///     Ok(RelativeDuration::new(
+    ///         0,
+    ///         4,
+    ///         0,
+    ///         0,
+    ///         Duration::positive(7200, 0)
+    ///     ))
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_relative(&self, source: &str) -> Result<RelativeDuration, ParseError> {
+        self.inner.parse_relative(
+            source,
+            &self.time_units,
+            (!self.keywords.is_empty()).then_some(&self.keywords),
+            (!self.numerals.is_empty()).then_some(&self.numerals),
+        )
+    }
+
     /// Set the default [`TimeUnit`] to `unit`.
     ///
     /// The default time unit is applied when no time unit was given in the input string. If the
@@ -740,6 +1078,101 @@ impl<'a> CustomDurationParser<'a> {
         self
     }
 
+    /// If true and parsing multiple durations, the time units must occur in descending order.
+    ///
+    /// Durations without a time unit count as the default time unit. The same time unit may occur
+    /// multiple times unless [`CustomDurationParser::disable_duplicate_units`] is set, too. A
+    /// violation results in a [`ParseError::TimeUnit`] pointing at the start of the offending
+    /// duration.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{CustomDurationParser, Duration, ParseError, DEFAULT_TIME_UNITS};
+    ///
+    /// let mut parser = CustomDurationParser::with_time_units(&DEFAULT_TIME_UNITS);
+    /// parser
+    ///     .parse_multiple(true, None)
+    ///     .require_descending_order(true);
+    ///
+    /// assert_eq!(parser.parse("1h 30m 10s"), Ok(Duration::positive(5410, 0)));
+    /// assert_eq!(
+    ///     parser.parse("10s 1h"),
+    ///     Err(ParseError::TimeUnit(
+    ///         4,
+    ///         "Time units must be in descending order but the time unit of '1h' is \
+    ///          greater than the previous time unit"
+    ///             .to_string()
+    ///     ))
+    /// );
+    /// ```
+    pub fn require_descending_order(&mut self, value: bool) -> &mut Self {
+        self.inner.config.require_descending_order = value;
+        self
+    }
+
+    /// If true and parsing multiple durations, each time unit may occur only once.
+    ///
+    /// A time unit which occurred already results in a [`ParseError::TimeUnit`] pointing at the
+    /// start of the offending duration.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{CustomDurationParser, Duration, ParseError, DEFAULT_TIME_UNITS};
+    ///
+    /// let mut parser = CustomDurationParser::with_time_units(&DEFAULT_TIME_UNITS);
+    /// parser
+    ///     .parse_multiple(true, None)
+    ///     .disable_duplicate_units(true);
+    ///
+    /// assert_eq!(parser.parse("1s 1h"), Ok(Duration::positive(3601, 0)));
+    /// assert_eq!(
+    ///     parser.parse("1s 1h 1s"),
+    ///     Err(ParseError::TimeUnit(
+    ///         6,
+    ///         "Duplicate time unit: The time unit of '1s' occurred already".to_string()
+    ///     ))
+    /// );
+    /// ```
+    pub fn disable_duplicate_units(&mut self, value: bool) -> &mut Self {
+        self.inner.config.disable_duplicate_units = value;
+        self
+    }
+
+    /// If true and parsing multiple durations, all durations must have the same sign.
+    ///
+    /// Durations which are zero don't have a sign. A duration with a different sign than the
+    /// durations before results in a [`ParseError::Syntax`] pointing at the start of the offending
+    /// duration.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{CustomDurationParser, Duration, ParseError, DEFAULT_TIME_UNITS};
+    ///
+    /// let mut parser = CustomDurationParser::with_time_units(&DEFAULT_TIME_UNITS);
+    /// parser
+    ///     .parse_multiple(true, None)
+    ///     .allow_negative(true)
+    ///     .disable_mixed_signs(true);
+    ///
+    /// assert_eq!(parser.parse("-1h -5m"), Ok(Duration::negative(3900, 0)));
+    /// assert_eq!(
+    ///     parser.parse("1h -5m"),
+    ///     Err(ParseError::Syntax(
+    ///         3,
+    ///         "Mixed signs are not allowed but the sign of '-5m' differs from the previous \
+    ///          durations"
+    ///             .to_string()
+    ///     ))
+    /// );
+    /// ```
+    pub fn disable_mixed_signs(&mut self, value: bool) -> &mut Self {
+        self.inner.config.disable_mixed_signs = value;
+        self
+    }
+
     /// Set the inner [`Delimiter`] to something different then the default
     /// [`u8::is_ascii_whitespace`]
     ///
@@ -858,6 +1291,25 @@ impl<'a> Default for CustomDurationParser<'a> {
     }
 }
 
+impl ParseDuration for CustomDurationParser<'_> {
+    fn parse_duration(&self, source: &str) -> Result<Duration, ParseError> {
+        self.parse(source)
+    }
+
+    fn parse_duration_ok(&self, source: &str) -> Option<Duration> {
+        self.parse_borrowed(source).ok()
+    }
+}
+
+impl ParseDurationPrefix for CustomDurationParser<'_> {
+    fn parse_duration_prefix<'s>(
+        &self,
+        source: &'s str,
+    ) -> Result<(Duration, &'s str), ParseError> {
+        self.parse_prefix(source)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use fundu_core::config::Config;
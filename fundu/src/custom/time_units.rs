@@ -5,8 +5,9 @@
 
 use std::hash::{Hash, Hasher};
 
-use fundu_core::time::TimeUnitsLike;
+use fundu_core::time::{TimeUnitConstraints, TimeUnitsLike};
 
+use super::lookup::IdentifierMap;
 use crate::TimeUnit::*;
 use crate::{
     Multiplier, TimeUnit, DEFAULT_ID_DAY, DEFAULT_ID_HOUR, DEFAULT_ID_MICRO_SECOND,
@@ -64,15 +65,21 @@ pub(super) struct LookupData {
     max_length: usize,
     time_unit: TimeUnit,
     multiplier: Multiplier,
+    constraints: TimeUnitConstraints,
 }
 
 impl LookupData {
-    const fn new(time_unit: TimeUnit, multiplier: Multiplier) -> Self {
+    const fn new(
+        time_unit: TimeUnit,
+        multiplier: Multiplier,
+        constraints: TimeUnitConstraints,
+    ) -> Self {
         Self {
             min_length: usize::MAX,
             max_length: 0,
             time_unit,
             multiplier,
+            constraints,
         }
     }
 
@@ -85,11 +92,6 @@ impl LookupData {
             self.max_length = len;
         }
     }
-
-    const fn check(&self, identifier: &str) -> bool {
-        let len = identifier.len();
-        self.min_length <= len && self.max_length >= len
-    }
 }
 
 /// A [`CustomTimeUnit`] is a completely customizable [`TimeUnit`] using an additional
@@ -179,11 +181,17 @@ impl LookupData {
 /// ```ignore
 /// base_unit == other.base_unit && multiplier == other.multiplier
 /// ```
+///
+/// # Constraints
+///
+/// The number in front of a [`CustomTimeUnit`] can be restricted with [`TimeUnitConstraints`]. See
+/// [`CustomTimeUnit::constraints`].
 #[derive(Debug, Eq, Clone, Copy)]
 pub struct CustomTimeUnit<'a> {
     pub(super) base_unit: TimeUnit,
     pub(super) multiplier: Multiplier,
     pub(super) identifiers: &'a [&'a str],
+    pub(super) constraints: TimeUnitConstraints,
 }
 
 impl<'a> CustomTimeUnit<'a> {
@@ -226,6 +234,7 @@ impl<'a> CustomTimeUnit<'a> {
                 None => Multiplier(1, 0),
             },
             identifiers,
+            constraints: TimeUnitConstraints::new(),
         }
     }
 
@@ -248,6 +257,55 @@ impl<'a> CustomTimeUnit<'a> {
     pub const fn with_default(base_unit: TimeUnit, identifiers: &'a [&'a str]) -> Self {
         Self::new(base_unit, identifiers, None)
     }
+
+    /// Restrict the number in front of this [`CustomTimeUnit`] with [`TimeUnitConstraints`]
+    ///
+    /// The constraints are enforced by the parser in addition to the configuration of the parser,
+    /// for example to allow fractions only for seconds or to restrict minutes to the range `0..60`.
+    /// A violation of the constraints is reported as [`crate::ParseError`]. Numerals (like `one`)
+    /// are not restricted. The constraints don't influence the equality of two
+    /// [`CustomTimeUnit`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::TimeUnit::*;
+    /// use fundu::{
+    ///     CustomDurationParser, CustomTimeUnit, Duration, ParseError, TimeUnitConstraints,
+    /// };
+    ///
+    /// let parser = CustomDurationParser::builder()
+    ///     .time_units(&[
+    ///         CustomTimeUnit::with_default(Second, &["s"]),
+    ///         CustomTimeUnit::with_default(Minute, &["min"])
+    ///             .constraints(TimeUnitConstraints::new().integer_only().range(0, 60)),
+    ///         CustomTimeUnit::with_default(Hour, &["h"])
+    ///             .constraints(TimeUnitConstraints::new().number_is_optional(true)),
+    ///     ])
+    ///     .build();
+    ///
+    /// assert_eq!(parser.parse("1.5s"), Ok(Duration::positive(1, 500_000_000)));
+    /// assert_eq!(parser.parse("59min"), Ok(Duration::positive(59 * 60, 0)));
+    /// assert_eq!(parser.parse("h"), Ok(Duration::positive(60 * 60, 0)));
+    /// assert_eq!(
+    ///     parser.parse("1.5min"),
+    ///     Err(ParseError::TimeUnit(
+    ///         0,
+    ///         "The number must be an integer for this time unit but was: '1.5'".to_string()
+    ///     ))
+    /// );
+    /// assert_eq!(
+    ///     parser.parse("60min"),
+    ///     Err(ParseError::TimeUnit(
+    ///         0,
+    ///         "The number is out of the range of this time unit: '60'".to_string()
+    ///     ))
+    /// );
+    /// ```
+    pub const fn constraints(mut self, constraints: TimeUnitConstraints) -> Self {
+        self.constraints = constraints;
+        self
+    }
 }
 
 /// Two [`CustomTimeUnit`]s are equal if their `base_unit`s and their `multipliers` are equal
@@ -275,6 +333,8 @@ pub(super) struct CustomTimeUnits<'a> {
     min_length: usize,
     max_length: usize,
     time_units: Vec<IdentifiersLookupData<'a>>,
+    /// The index of the `time_units` entry of each identifier
+    index: IdentifierMap<'a, usize>,
 }
 
 impl<'a> CustomTimeUnits<'a> {
@@ -303,52 +363,51 @@ impl<'a> CustomTimeUnits<'a> {
             min_length: usize::MAX,
             max_length: 0,
             time_units: Vec::with_capacity(capacity),
+            index: IdentifierMap::default(),
         }
     }
 
     pub(super) fn add_custom_time_unit(&mut self, time_unit: CustomTimeUnit<'a>) {
-        if time_unit.identifiers.is_empty() {
-            return;
-        }
         let CustomTimeUnit {
             base_unit,
             multiplier,
             identifiers,
+            constraints,
         } = time_unit;
-        let (min_length, max_length) =
-            if let Some((data, ids)) = self.lookup_mut(base_unit, multiplier) {
-                for &identifier in identifiers.iter().filter(|&&id| !id.is_empty()) {
-                    ids.push(identifier);
-                    data.update(identifier);
-                }
-                (data.min_length, data.max_length)
-            } else {
-                let mut data = LookupData::new(base_unit, multiplier);
-                let mut ids = Vec::with_capacity(identifiers.len());
-                for &identifier in identifiers.iter().filter(|&&id| !id.is_empty()) {
-                    ids.push(identifier);
-                    data.update(identifier);
-                }
-                if ids.is_empty() {
-                    return;
-                }
-                let lengths = (data.min_length, data.max_length);
-                self.time_units.push((data, ids));
-                lengths
-            };
+        if identifiers.iter().all(|id| id.is_empty()) {
+            return;
+        }
+        let position = self
+            .time_units
+            .iter()
+            .position(|(data, _)| {
+                data.time_unit == base_unit
+                    && data.multiplier == multiplier
+                    && data.constraints == constraints
+            })
+            .unwrap_or_else(|| {
+                self.time_units.push((
+                    LookupData::new(base_unit, multiplier, constraints),
+                    Vec::with_capacity(identifiers.len()),
+                ));
+                self.time_units.len() - 1
+            });
+
+        let (data, ids) = &mut self.time_units[position];
+        for &identifier in identifiers.iter().filter(|&&id| !id.is_empty()) {
+            ids.push(identifier);
+            data.update(identifier);
+            // Like with a linear search, the first entry of the `time_units` with the identifier
+            // wins if there are duplicate identifiers
+            self.index
+                .entry(identifier)
+                .and_modify(|index| *index = (*index).min(position))
+                .or_insert(position);
+        }
+        let (min_length, max_length) = (data.min_length, data.max_length);
         self.update_lengths(min_length, max_length);
     }
 
-    pub(super) fn lookup_mut(
-        &'_ mut self,
-        unit: TimeUnit,
-        multiplier: Multiplier,
-    ) -> Option<&'_ mut (LookupData, Vec<&'a str>)> {
-        self.time_units
-            .iter_mut()
-            .find(|(data, _)| data.time_unit == unit && data.multiplier == multiplier)
-    }
-
     #[allow(dead_code)]
     pub(super) fn lookup(
         &self,
@@ -360,9 +419,10 @@ impl<'a> CustomTimeUnits<'a> {
             .find(|(data, _)| data.time_unit == unit && data.multiplier == multiplier)
     }
 
-    pub(super) fn find_id(&self, id: &str) -> Option<(TimeUnit, Multiplier)> {
-        self.time_units.iter().find_map(|(data, v)| {
-            (data.check(id) && v.contains(&id)).then_some((data.time_unit, data.multiplier))
+    pub(super) fn find_id(&self, id: &str) -> Option<(TimeUnit, Multiplier, TimeUnitConstraints)> {
+        self.index.get(id).map(|&position| {
+            let (data, _) = &self.time_units[position];
+            (data.time_unit, data.multiplier, data.constraints)
         })
     }
 
@@ -384,6 +444,15 @@ impl<'a> TimeUnitsLike for CustomTimeUnits<'a> {
 
     #[inline]
     fn get(&self, identifier: &str) -> Option<(TimeUnit, Multiplier)> {
+        self.get_with_constraints(identifier)
+            .map(|(time_unit, multiplier, _)| (time_unit, multiplier))
+    }
+
+    #[inline]
+    fn get_with_constraints(
+        &self,
+        identifier: &str,
+    ) -> Option<(TimeUnit, Multiplier, TimeUnitConstraints)> {
         let len = identifier.len();
         if self.min_length > len || self.max_length < len {
             return None;
@@ -469,6 +538,7 @@ mod tests {
                 max_length,
                 time_unit,
                 multiplier,
+                constraints: TimeUnitConstraints::new(),
             },
             identifiers,
         )
@@ -720,6 +790,26 @@ mod tests {
         assert_eq!(custom.get("ss"), Some((Second, Multiplier(2, 0))));
     }
 
+    #[test]
+    fn test_custom_time_units_adding_custom_time_unit_with_constraints() {
+        let constraints = TimeUnitConstraints::new().integer_only().range(0, 60);
+        let mut custom =
+            CustomTimeUnits::with_time_units(&[CustomTimeUnit::with_default(Minute, &["m"])]);
+        custom.add_custom_time_unit(
+            CustomTimeUnit::with_default(Minute, &["min"]).constraints(constraints),
+        );
+        assert_eq!(custom.time_units.len(), 2);
+        assert_eq!(
+            custom.get_with_constraints("m"),
+            Some((Minute, Multiplier(1, 0), TimeUnitConstraints::new()))
+        );
+        assert_eq!(
+            custom.get_with_constraints("min"),
+            Some((Minute, Multiplier(1, 0), constraints))
+        );
+        assert_eq!(custom.get("min"), Some((Minute, Multiplier(1, 0))));
+    }
+
     #[test]
     fn test_custom_time_units_adding_custom_time_unit_when_normal_time_unit_with_same_id() {
         let mut custom =
@@ -773,6 +863,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_custom_time_units_find_id_when_duplicate_identifier_then_first_entry_wins() {
+        let mut custom = CustomTimeUnits::new();
+        custom.add_custom_time_unit(CustomTimeUnit::new(Second, &["s"], None));
+        custom.add_custom_time_unit(CustomTimeUnit::new(Minute, &["x"], None));
+        custom.add_custom_time_unit(CustomTimeUnit::new(Second, &["x"], None));
+        assert_eq!(
+            custom.find_id("x"),
+            Some((Second, Multiplier(1, 0), TimeUnitConstraints::new()))
+        );
+    }
+
+    #[test]
+    fn test_custom_time_units_find_id_with_many_identifiers() {
+        let identifiers: Vec<String> = (0..1000_u32).map(|i| format!("unit{i}")).collect();
+        let identifiers: Vec<&str> = identifiers.iter().map(String::as_str).collect();
+        let mut custom = CustomTimeUnits::new();
+        for (i, chunk) in identifiers.chunks(10).enumerate() {
+            let multiplier = Multiplier(i64::try_from(i).unwrap() + 1, 0);
+            custom.add_custom_time_unit(CustomTimeUnit::new(Second, chunk, Some(multiplier)));
+        }
+        assert_eq!(
+            custom.find_id("unit0"),
+            Some((Second, Multiplier(1, 0), TimeUnitConstraints::new()))
+        );
+        assert_eq!(
+            custom.find_id("unit999"),
+            Some((Second, Multiplier(100, 0), TimeUnitConstraints::new()))
+        );
+        assert_eq!(custom.find_id("unit1000"), None);
+    }
+
     #[test]
     #[should_panic = "The time unit multiplier multiplied with the multiplier parameter may not \
                       overflow"]
@@ -344,8 +344,14 @@
 
 #[cfg(feature = "custom")]
 mod custom;
+#[cfg(feature = "macros")]
+mod macros;
+#[cfg(feature = "nom")]
+pub mod nom;
 #[cfg(feature = "standard")]
 mod standard;
+#[cfg(feature = "winnow")]
+pub mod winnow;
 
 #[cfg(feature = "custom")]
 pub use custom::{
@@ -357,11 +363,23 @@ pub use custom::{
     Numeral,
 };
 pub use fundu_core::config::Delimiter;
-pub use fundu_core::error::{ParseError, TryFromDurationError};
+pub use fundu_core::distribution::{
+    DurationDistribution, DurationDistributionParser, RandomSource,
+};
+pub use fundu_core::error::{
+    BorrowedParseError, Detail, ErrorReason, ParseError, TryFromDurationError,
+};
+pub use fundu_core::expression::ExpressionParser;
+pub use fundu_core::range::{DurationRange, DurationRangeParser};
+pub use fundu_core::relative::{CheckedAddRelative, RelativeDuration};
+pub use fundu_core::scan::{Match, Matches, Scanner};
+pub use fundu_core::stream::{
+    BufReadParser, ParseDuration, ParseDurationPrefix, StreamItem, StreamIter, StreamParser,
+};
 pub use fundu_core::time::{
-    Duration, Multiplier, SaturatingInto, TimeUnit, DEFAULT_ID_DAY, DEFAULT_ID_HOUR,
-    DEFAULT_ID_MICRO_SECOND, DEFAULT_ID_MILLI_SECOND, DEFAULT_ID_MINUTE, DEFAULT_ID_MONTH,
-    DEFAULT_ID_NANO_SECOND, DEFAULT_ID_SECOND, DEFAULT_ID_WEEK, DEFAULT_ID_YEAR,
+    Duration, Multiplier, SaturatingInto, TimeUnit, TimeUnitConstraints, DEFAULT_ID_DAY,
+    DEFAULT_ID_HOUR, DEFAULT_ID_MICRO_SECOND, DEFAULT_ID_MILLI_SECOND, DEFAULT_ID_MINUTE,
+    DEFAULT_ID_MONTH, DEFAULT_ID_NANO_SECOND, DEFAULT_ID_SECOND, DEFAULT_ID_WEEK, DEFAULT_ID_YEAR,
 };
 #[cfg(test)]
 pub use rstest_reuse;
@@ -376,6 +394,14 @@ pub use {
     fundu_core::time::TimeUnitsLike,
 };
 
+/// Used by the macros of this crate. Not public api.
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub mod __private {
+    pub use fundu_core::time::Duration;
+    pub use fundu_macros::{duration, std_duration};
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
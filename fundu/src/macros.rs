@@ -0,0 +1,66 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+/// Parse a string literal into a [`Duration`](crate::Duration) at compile time
+///
+/// The literal is parsed like with [`DurationParser::new`](crate::DurationParser::new) and the
+/// macro expands to a constant expression, so it can be used to initialize a `const` without an
+/// `unwrap()` at runtime. Additionally, multiple durations separated by whitespace like `1h 30m`
+/// are added up like with
+/// [`DurationParserBuilder::parse_multiple`](crate::DurationParserBuilder::parse_multiple) without
+/// conjunctions. An invalid duration results in a compile error pointing to the literal.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu::{duration, Duration};
+///
+/// const TIMEOUT: Duration = duration!("1.5m");
+/// assert_eq!(TIMEOUT, Duration::positive(90, 0));
+/// assert_eq!(duration!("1e-3"), Duration::positive(0, 1_000_000));
+/// assert_eq!(duration!("1h 30m"), Duration::positive(90 * 60, 0));
+/// assert_eq!(duration!("inf"), Duration::MAX);
+/// ```
+///
+/// Invalid durations don't compile
+///
+/// ```rust,compile_fail
+/// // error: Invalid duration: Invalid input: years
+/// const TIMEOUT: fundu::Duration = fundu::duration!("1years");
+/// ```
+#[macro_export]
+macro_rules! duration {
+    ($source:literal) => {
+        $crate::__private::duration!($crate, $source)
+    };
+}
+
+/// Parse a string literal into a [`std::time::Duration`] at compile time
+///
+/// Like [`duration!`](crate::duration!) but the macro expands to a [`std::time::Duration`].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use fundu::std_duration;
+///
+/// const INTERVAL: Duration = std_duration!("250ms");
+/// assert_eq!(INTERVAL, Duration::from_millis(250));
+/// ```
+///
+/// Invalid durations don't compile
+///
+/// ```rust,compile_fail
+/// // error: Invalid duration: Syntax error: Unexpected end of input at column 2
+/// const INTERVAL: std::time::Duration = fundu::std_duration!("1e");
+/// ```
+#[macro_export]
+macro_rules! std_duration {
+    ($source:literal) => {
+        $crate::__private::std_duration!($crate, $source)
+    };
+}
@@ -0,0 +1,66 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Combinators for the [`nom`](https://docs.rs/nom) parser combinator library
+//!
+//! The combinators in this module make the parsers of fundu usable within `nom` parsers. They
+//! parse the longest valid duration at the start of the input with
+//! [`ParseDurationPrefix::parse_duration_prefix`] and map a [`ParseError`] of fundu into the
+//! error type of `nom` with [`FromExternalError`]. The input is expected to be complete, so
+//! [`nom::Err::Incomplete`] is never returned.
+
+use nom::error::{ErrorKind, FromExternalError, ParseError as NomParseError};
+use nom::IResult;
+
+use crate::{Duration, ParseDurationPrefix, ParseError};
+
+/// Return a `nom` parser which parses a [`Duration`] at the start of the input with the `parser`
+///
+/// A [`ParseError`] of the `parser` is returned as recoverable [`nom::Err::Error`] with the
+/// [`ErrorKind::Verify`] at the start of the input, so alternatives can be tried with
+/// [`nom::branch::alt`]. Error types like [`nom::error::Error`] discard the [`ParseError`] but
+/// custom error types can keep it by implementing [`FromExternalError`].
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu::{Duration, DurationParser};
+/// use nom::bytes::complete::tag;
+/// use nom::error::Error;
+/// use nom::sequence::preceded;
+/// use nom::IResult;
+///
+/// const PARSER: DurationParser = DurationParser::new();
+///
+/// fn timeout(input: &str) -> IResult<&str, Duration> {
+///     preceded(tag("timeout="), fundu::nom::duration(&PARSER))(input)
+/// }
+///
+/// assert_eq!(
+///     timeout("timeout=1.5m;"),
+///     Ok((";", Duration::positive(90, 0)))
+/// );
+/// assert_eq!(
+///     timeout("timeout=never"),
+///     Err(nom::Err::Error(Error::new(
+///         "never",
+///         nom::error::ErrorKind::Verify
+///     )))
+/// );
+/// ```
+pub fn duration<'s, P, E>(parser: P) -> impl FnMut(&'s str) -> IResult<&'s str, Duration, E>
+where
+    P: ParseDurationPrefix,
+    E: NomParseError<&'s str> + FromExternalError<&'s str, ParseError>,
+{
+    move |input: &'s str| match parser.parse_duration_prefix(input) {
+        Ok((duration, rest)) => Ok((rest, duration)),
+        Err(error) => Err(nom::Err::Error(E::from_external_error(
+            input,
+            ErrorKind::Verify,
+            error,
+        ))),
+    }
+}
@@ -524,6 +524,98 @@ impl<'a> DurationParserBuilder<'a> {
         self
     }
 
+    /// If parsing multiple durations, require the time units to occur in descending order.
+    ///
+    /// See also [`DurationParser::require_descending_order`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{Duration, DurationParserBuilder, ParseError};
+    ///
+    /// let parser = DurationParserBuilder::new()
+    ///     .default_time_units()
+    ///     .parse_multiple(None)
+    ///     .require_descending_order()
+    ///     .build();
+    ///
+    /// assert_eq!(parser.parse("1h 30m 10s"), Ok(Duration::positive(5410, 0)));
+    /// assert_eq!(
+    ///     parser.parse("10s 1h"),
+    ///     Err(ParseError::TimeUnit(
+    ///         4,
+    ///         "Time units must be in descending order but the time unit of '1h' is \
+    ///          greater than the previous time unit"
+    ///             .to_string()
+    ///     ))
+    /// );
+    /// ```
+    pub const fn require_descending_order(mut self) -> Self {
+        self.config.require_descending_order = true;
+        self
+    }
+
+    /// If parsing multiple durations, reject time units which occur more than once.
+    ///
+    /// See also [`DurationParser::disable_duplicate_units`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{Duration, DurationParserBuilder, ParseError};
+    ///
+    /// let parser = DurationParserBuilder::new()
+    ///     .default_time_units()
+    ///     .parse_multiple(None)
+    ///     .disable_duplicate_units()
+    ///     .build();
+    ///
+    /// assert_eq!(parser.parse("1s 1h"), Ok(Duration::positive(3601, 0)));
+    /// assert_eq!(
+    ///     parser.parse("1s 1h 1s"),
+    ///     Err(ParseError::TimeUnit(
+    ///         6,
+    ///         "Duplicate time unit: The time unit of '1s' occurred already".to_string()
+    ///     ))
+    /// );
+    /// ```
+    pub const fn disable_duplicate_units(mut self) -> Self {
+        self.config.disable_duplicate_units = true;
+        self
+    }
+
+    /// If parsing multiple durations, reject durations with different signs.
+    ///
+    /// See also [`DurationParser::disable_mixed_signs`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{Duration, DurationParserBuilder, ParseError};
+    ///
+    /// let parser = DurationParserBuilder::new()
+    ///     .default_time_units()
+    ///     .parse_multiple(None)
+    ///     .allow_negative()
+    ///     .disable_mixed_signs()
+    ///     .build();
+    ///
+    /// assert_eq!(parser.parse("-1h -5m"), Ok(Duration::negative(3900, 0)));
+    /// assert_eq!(
+    ///     parser.parse("1h -5m"),
+    ///     Err(ParseError::Syntax(
+    ///         3,
+    ///         "Mixed signs are not allowed but the sign of '-5m' differs from the previous \
+    ///          durations"
+    ///             .to_string()
+    ///     ))
+    /// );
+    /// ```
+    pub const fn disable_mixed_signs(mut self) -> Self {
+        self.config.disable_mixed_signs = true;
+        self
+    }
+
     /// Set the inner [`Delimiter`] to something different then the default
     /// [`u8::is_ascii_whitespace`]
     ///
@@ -6,11 +6,17 @@
 use std::time::Duration as StdDuration;
 
 use fundu_core::config::Delimiter;
+use fundu_core::distribution::DurationDistributionParser;
+use fundu_core::expression::ExpressionParser;
 use fundu_core::parse::Parser;
+use fundu_core::range::DurationRangeParser;
+use fundu_core::relative::RelativeDuration;
+use fundu_core::scan::{Matches, Scanner};
+use fundu_core::stream::{ParseDuration, ParseDurationPrefix};
 use fundu_core::time::Duration as FunduDuration;
 
 use super::time_units::TimeUnits;
-use crate::{DurationParserBuilder, ParseError, TimeUnit};
+use crate::{BorrowedParseError, DurationParserBuilder, ParseError, TimeUnit};
 
 /// A parser with a customizable set of [`TimeUnit`]s with default identifiers.
 ///
@@ -229,6 +235,312 @@ impl<'a> DurationParser<'a> {
         self.inner.parse(source, &self.time_units, None, None)
     }
 
+    /// Parse the `source` string like [`DurationParser::parse`] without allocating on errors
+    ///
+    /// The [`BorrowedParseError`] borrows from the `source` and renders its message only when
+    /// displayed. See [`fundu_core::parse::Parser::parse_borrowed`] for the details.
+    ///
+    /// # Errors
+    ///
+    /// If parsing into a [`crate::Duration`] fails returns a [`BorrowedParseError`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{Duration, DurationParser, ParseError};
+    ///
+    /// let parser = DurationParser::new();
+    /// assert_eq!(
+    ///     parser.parse_borrowed("1.2e-1s"),
+    ///     Ok(Duration::positive(0, 120_000_000)),
+    /// );
+    ///
+    /// let error = parser.parse_borrowed("1x").unwrap_err();
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "Time unit error: Invalid time unit: 'x' at column 1"
+    /// );
+    /// assert_eq!(
+    ///     error.into_owned(),
+    ///     ParseError::TimeUnit(1, "Invalid time unit: 'x'".to_string())
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_borrowed<'s>(
+        &self,
+        source: &'s str,
+    ) -> Result<FunduDuration, BorrowedParseError<'s>> {
+        self.inner
+            .parse_borrowed(source, &self.time_units, None, None)
+    }
+
+    /// Parse the longest valid duration at the start of `source` and return the unconsumed rest
+    ///
+    /// This method is useful to parse durations embedded in a larger grammar. Time units end at
+    /// the first ascii character which is not alphabetic and have to match these letters as a
+    /// whole, so `5 months later` is parsed as `5` with the remainder ` months later`. See
+    /// [`fundu_core::parse::Parser::parse_prefix`] for the details.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if there's no valid duration at the start of the `source`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{Duration, DurationParser};
+    ///
+    /// let parser = DurationParser::new();
+    /// assert_eq!(
+    ///     parser.parse_prefix("5m then fail"),
+    ///     Ok((Duration::positive(300, 0), " then fail"))
+    /// );
+    /// assert_eq!(
+    ///     parser.parse_prefix("3x every"),
+    ///     Ok((Duration::positive(3, 0), "x every"))
+    /// );
+    /// assert_eq!(
+    ///     parser.parse_prefix("5months"),
+    ///     Ok((Duration::positive(5, 0), "months"))
+    /// );
+    /// assert!(parser.parse_prefix("every 5m").is_err());
+    /// ```
+    #[inline]
+    pub fn parse_prefix<'s>(
+        &self,
+        source: &'s str,
+    ) -> Result<(FunduDuration, &'s str), ParseError> {
+        self.inner
+            .parse_prefix(source, &self.time_units, None, None)
+    }
+
+    /// Parse all `sources` into [`crate::Duration`]s
+    ///
+    /// The results are in the same order as the `sources` and are the same as parsing each source
+    /// with [`DurationParser::parse`]. Plain numbers and numbers directly followed by a time unit
+    /// take a fast path, which makes this method faster than calling [`DurationParser::parse`] for
+    /// each source. See [`fundu_core::parse::Parser::parse_many`] for the details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{Duration, DurationParser, ParseError};
+    ///
+    /// assert_eq!(
+    ///     DurationParser::new().parse_many(&["10", "250ms", "1.5s", "1x"]),
+    ///     vec![
+    ///         Ok(Duration::positive(10, 0)),
+    ///         Ok(Duration::positive(0, 250_000_000)),
+    ///         Ok(Duration::positive(1, 500_000_000)),
+    ///         Err(ParseError::TimeUnit(1, "Invalid time unit: 'x'".to_string())),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_many<S: AsRef<str>>(
+        &self,
+        sources: &[S],
+    ) -> Vec<Result<FunduDuration, ParseError>> {
+        self.inner.parse_many(sources, &self.time_units, None, None)
+    }
+
+    /// Parse all `sources` like [`DurationParser::parse_many`] and append the results to `results`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{Duration, DurationParser};
+    ///
+    /// let parser = DurationParser::new();
+    /// let mut results = Vec::new();
+    /// for batch in [["1", "2s"], ["3", "4s"]] {
+    ///     results.clear();
+    ///     parser.parse_many_into(&batch, &mut results);
+    /// }
+    /// assert_eq!(
+    ///     results,
+    ///     vec![Ok(Duration::positive(3, 0)), Ok(Duration::positive(4, 0))]
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_many_into<S: AsRef<str>>(
+        &self,
+        sources: &[S],
+        results: &mut Vec<Result<FunduDuration, ParseError>>,
+    ) {
+        self.inner
+            .parse_many_into(sources, &self.time_units, None, None, results);
+    }
+
+    /// Return an iterator over all durations embedded in the `text`
+    ///
+    /// Numbers without a time unit are not matched. See [`crate::Scanner`] for the details and
+    /// more settings.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{Duration, DurationParser};
+    ///
+    /// let parser = DurationParser::builder()
+    ///     .default_time_units()
+    ///     .allow_time_unit_delimiter()
+    ///     .build();
+    /// let text = "request took 350 ms after 2 retries, backoff 1.5s";
+    /// let matches: Vec<_> = parser.find_iter(text).collect();
+    ///
+    /// assert_eq!(matches.len(), 2);
+    /// assert_eq!(matches[0].span, 13..19);
+    /// assert_eq!(matches[0].duration, Duration::positive(0, 350_000_000));
+    /// assert_eq!(matches[1].source, "1.5s");
+    /// assert_eq!(matches[1].duration, Duration::positive(1, 500_000_000));
+    /// ```
+    pub fn find_iter<'t>(&self, text: &'t str) -> Matches<'t, &Self> {
+        Scanner::new(self).into_find_iter(text)
+    }
+
+    /// Return an [`ExpressionParser`] evaluating arithmetic expressions like `2 * (1h + 15m)`
+    ///
+    /// The durations within the expression are parsed with this parser. Numbers without a time
+    /// unit are interpreted in the [`TimeUnit`] set with `default_unit` and negative results are
+    /// only allowed if `allow_negative` is set. See [`ExpressionParser`] for the details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{Duration, DurationParser, ParseError};
+    ///
+    /// let parser = DurationParser::new();
+    /// let expression = parser.expression();
+    ///
+    /// assert_eq!(
+    ///     expression.parse("2 * (1h + 15m) - 30s"),
+    ///     Ok(Duration::positive(8970, 0))
+    /// );
+    /// assert_eq!(
+    ///     expression.parse("max(1s, 10 * 50ms)"),
+    ///     Ok(Duration::positive(1, 0))
+    /// );
+    /// assert_eq!(
+    ///     expression.parse("2 * 45m <= 1h"),
+    ///     Err(ParseError::InvalidInput(
+    ///         "The duration 1h 30m is not <= 1h".to_string()
+    ///     ))
+    /// );
+    /// ```
+    pub fn expression(&self) -> ExpressionParser<&Self> {
+        let expression = ExpressionParser::new(self).default_unit(self.inner.config.default_unit);
+        if self.inner.config.allow_negative {
+            expression.allow_negative()
+        } else {
+            expression
+        }
+    }
+
+    /// Return a [`DurationRangeParser`] parsing ranges like `5m..10m`, `1s to 3s` or `>2h`
+    ///
+    /// The endpoints of the range are parsed with this parser. See [`DurationRangeParser`] for the
+    /// details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{Duration, DurationParser};
+    ///
+    /// let parser = DurationParser::new();
+    /// let range = parser.range().parse("5m..10m").unwrap();
+    ///
+    /// assert!(range.contains(&Duration::positive(300, 0)));
+    /// assert!(!range.contains(&Duration::positive(600, 0)));
+    ///
+    /// let range = parser.range().parse("<=500ms").unwrap();
+    /// assert!(range.contains(&Duration::positive(0, 500_000_000)));
+    /// assert!(!range.contains(&Duration::positive(1, 0)));
+    /// ```
+    pub fn range(&self) -> DurationRangeParser<&Self> {
+        DurationRangeParser::new(self)
+    }
+
+    /// Return a [`DurationDistributionParser`] parsing randomized durations like `30s ± 10%`
+    ///
+    /// The durations are parsed with this parser and negative durations in the distribution are
+    /// only allowed if `allow_negative` is set. See [`DurationDistributionParser`] for the details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{Duration, DurationDistribution, DurationParser, Multiplier, RandomSource};
+    ///
+    /// // A deterministic source of random numbers for this example
+    /// struct Counter(u64);
+    ///
+    /// impl RandomSource for Counter {
+    ///     fn next_u64(&mut self) -> u64 {
+    ///         self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    ///         self.0
+    ///     }
+    /// }
+    ///
+    /// let parser = DurationParser::new();
+    /// let distribution = parser.distribution().parse("30s ± 10%").unwrap();
+    /// assert_eq!(
+    ///     distribution,
+    ///     DurationDistribution::Jitter {
+    ///         base: Duration::positive(30, 0),
+    ///         percent: Multiplier(1, 1)
+    ///     }
+    /// );
+    ///
+    /// let sample = distribution.sample(&mut Counter(0));
+    /// assert!(Duration::positive(27, 0) <= sample && sample <= Duration::positive(33, 0));
+    /// ```
+    pub fn distribution(&self) -> DurationDistributionParser<&Self> {
+        let distribution = DurationDistributionParser::new(self);
+        if self.inner.config.allow_negative {
+            distribution.allow_negative()
+        } else {
+            distribution
+        }
+    }
+
+    /// Parse the `source` string into a [`RelativeDuration`] keeping calendar time units symbolic
+    ///
+    /// Other than [`DurationParser::parse`], the [`TimeUnit::Year`], [`TimeUnit::Month`],
+    /// [`TimeUnit::Week`] and [`TimeUnit::Day`] time units are not converted into seconds. The
+    /// returned [`RelativeDuration`] can be applied to a date with
+    /// [`RelativeDuration::checked_add_to`]. See also
+    /// [`fundu_core::parse::Parser::parse_relative`].
+    ///
+    /// # Errors
+    ///
+    /// If parsing into a [`RelativeDuration`] fails returns a [`ParseError`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{Duration, DurationParser, RelativeDuration};
+    ///
+    /// let parser = DurationParser::builder()
+    ///     .all_time_units()
+    ///     .parse_multiple(None)
+    ///     .build();
+    /// assert_eq!(
+    ///     parser.parse_relative("1y 2M 3d 4h"),
+    ///     Ok(RelativeDuration::new(
+    ///         1,
+    ///         2,
+    ///         0,
+    ///         3,
+    ///         Duration::positive(4 * 60 * 60, 0)
+    ///     ))
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_relative(&self, source: &str) -> Result<RelativeDuration, ParseError> {
+        self.inner
+            .parse_relative(source, &self.time_units, None, None)
+    }
+
     /// Set the default [`TimeUnit`] to `unit`.
     ///
     /// The default time unit is applied when no time unit was given in the input string. If the
@@ -569,6 +881,100 @@ impl<'a> DurationParser<'a> {
         self
     }
 
+    /// If true and parsing multiple durations, the time units must occur in descending order.
+    ///
+    /// Durations without a time unit count as the default time unit. The same time unit may occur
+    /// multiple times unless [`DurationParser::disable_duplicate_units`] is set, too. A violation
+    /// results in a [`ParseError::TimeUnit`] pointing at the start of the offending duration.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{Duration, DurationParser, ParseError};
+    ///
+    /// let mut parser = DurationParser::new();
+    /// parser
+    ///     .parse_multiple(true, None)
+    ///     .require_descending_order(true);
+    ///
+    /// assert_eq!(parser.parse("1h 30m 10s"), Ok(Duration::positive(5410, 0)));
+    /// assert_eq!(
+    ///     parser.parse("10s 1h"),
+    ///     Err(ParseError::TimeUnit(
+    ///         4,
+    ///         "Time units must be in descending order but the time unit of '1h' is \
+    ///          greater than the previous time unit"
+    ///             .to_string()
+    ///     ))
+    /// );
+    /// ```
+    pub fn require_descending_order(&mut self, value: bool) -> &mut Self {
+        self.inner.config.require_descending_order = value;
+        self
+    }
+
+    /// If true and parsing multiple durations, each time unit may occur only once.
+    ///
+    /// A time unit which occurred already results in a [`ParseError::TimeUnit`] pointing at the
+    /// start of the offending duration.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{Duration, DurationParser, ParseError};
+    ///
+    /// let mut parser = DurationParser::new();
+    /// parser
+    ///     .parse_multiple(true, None)
+    ///     .disable_duplicate_units(true);
+    ///
+    /// assert_eq!(parser.parse("1s 1h"), Ok(Duration::positive(3601, 0)));
+    /// assert_eq!(
+    ///     parser.parse("1s 1h 1s"),
+    ///     Err(ParseError::TimeUnit(
+    ///         6,
+    ///         "Duplicate time unit: The time unit of '1s' occurred already".to_string()
+    ///     ))
+    /// );
+    /// ```
+    pub fn disable_duplicate_units(&mut self, value: bool) -> &mut Self {
+        self.inner.config.disable_duplicate_units = value;
+        self
+    }
+
+    /// If true and parsing multiple durations, all durations must have the same sign.
+    ///
+    /// Durations which are zero don't have a sign. A duration with a different sign than the
+    /// durations before results in a [`ParseError::Syntax`] pointing at the start of the offending
+    /// duration.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fundu::{Duration, DurationParser, ParseError};
+    ///
+    /// let mut parser = DurationParser::new();
+    /// parser
+    ///     .parse_multiple(true, None)
+    ///     .allow_negative(true)
+    ///     .disable_mixed_signs(true);
+    ///
+    /// assert_eq!(parser.parse("-1h -5m"), Ok(Duration::negative(3900, 0)));
+    /// assert_eq!(
+    ///     parser.parse("1h -5m"),
+    ///     Err(ParseError::Syntax(
+    ///         3,
+    ///         "Mixed signs are not allowed but the sign of '-5m' differs from the previous \
+    ///          durations"
+    ///             .to_string()
+    ///     ))
+    /// );
+    /// ```
+    pub fn disable_mixed_signs(&mut self, value: bool) -> &mut Self {
+        self.inner.config.disable_mixed_signs = value;
+        self
+    }
+
     /// Set the inner [`Delimiter`] to something different then the default
     /// [`u8::is_ascii_whitespace`]
     ///
@@ -666,6 +1072,25 @@ impl<'a> Default for DurationParser<'a> {
     }
 }
 
+impl ParseDuration for DurationParser<'_> {
+    fn parse_duration(&self, source: &str) -> Result<FunduDuration, ParseError> {
+        self.parse(source)
+    }
+
+    fn parse_duration_ok(&self, source: &str) -> Option<FunduDuration> {
+        self.parse_borrowed(source).ok()
+    }
+}
+
+impl ParseDurationPrefix for DurationParser<'_> {
+    fn parse_duration_prefix<'s>(
+        &self,
+        source: &'s str,
+    ) -> Result<(FunduDuration, &'s str), ParseError> {
+        self.parse_prefix(source)
+    }
+}
+
 /// Parse a string into a [`std::time::Duration`] by accepting a `string` similar to floating
 /// point with the default set of time units.
 ///
@@ -3,6 +3,7 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use fundu_core::presets::STANDARD_TIME_UNITS;
 use fundu_core::time::TimeUnitsLike;
 
 use crate::TimeUnit::*;
@@ -84,20 +85,7 @@ impl TimeUnits {
 
     /// Create [`TimeUnits`] with default [`TimeUnit`]s.
     pub(super) const fn with_default_time_units() -> Self {
-        Self {
-            data: [
-                Some(NanoSecond),
-                Some(MicroSecond),
-                Some(MilliSecond),
-                Some(Second),
-                Some(Minute),
-                Some(Hour),
-                Some(Day),
-                Some(Week),
-                None,
-                None,
-            ],
-        }
+        Self::with_time_units(&STANDARD_TIME_UNITS)
     }
 
     /// Create [`TimeUnits`] with a all available [`TimeUnit`]s.
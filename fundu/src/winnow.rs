@@ -0,0 +1,73 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Parsers for the [`winnow`](https://docs.rs/winnow) parser combinator library
+//!
+//! The parsers in this module make the parsers of fundu usable within `winnow` parsers. They
+//! parse the longest valid duration at the start of the input with
+//! [`ParseDurationPrefix::parse_duration_prefix`] and map a [`ParseError`] of fundu into the
+//! error type of `winnow` with [`FromExternalError`]. The input is expected to be complete, so
+//! [`ErrMode::Incomplete`] is never returned.
+
+use winnow::error::{ErrMode, ErrorKind, FromExternalError, ParserError};
+use winnow::Parser;
+
+use crate::{Duration, ParseDurationPrefix, ParseError};
+
+/// Return a `winnow` parser which parses a [`Duration`] at the start of the input with the
+/// `parser`
+///
+/// A [`ParseError`] of the `parser` is returned as [`ErrMode::Backtrack`] with the
+/// [`ErrorKind::Verify`] and the input is left untouched, so alternatives can be tried with
+/// [`winnow::combinator::alt`]. Error types like [`winnow::error::ContextError`] keep the
+/// [`ParseError`] as cause.
+///
+/// # Examples
+///
+/// ```rust
+/// use fundu::{Duration, DurationParser, ParseError};
+/// use winnow::error::ErrMode;
+/// use winnow::{PResult, Parser};
+///
+/// const PARSER: DurationParser = DurationParser::new();
+///
+/// fn timeout(input: &mut &str) -> PResult<Duration> {
+///     ("timeout=", fundu::winnow::duration(&PARSER))
+///         .map(|(_, duration)| duration)
+///         .parse_next(input)
+/// }
+///
+/// let mut input = "timeout=1.5m;";
+/// assert_eq!(timeout(&mut input), Ok(Duration::positive(90, 0)));
+/// assert_eq!(input, ";");
+///
+/// let mut input = "timeout=never";
+/// match timeout(&mut input) {
+///     Err(ErrMode::Backtrack(error)) => assert_eq!(
+///         error
+///             .cause()
+///             .and_then(|cause| cause.downcast_ref::<ParseError>()),
+///         Some(&ParseError::InvalidInput("never".to_string()))
+///     ),
+///     _ => unreachable!(),
+/// }
+/// ```
+pub fn duration<'s, P, E>(parser: P) -> impl Parser<&'s str, Duration, E>
+where
+    P: ParseDurationPrefix,
+    E: ParserError<&'s str> + FromExternalError<&'s str, ParseError>,
+{
+    move |input: &mut &'s str| match parser.parse_duration_prefix(input) {
+        Ok((duration, rest)) => {
+            *input = rest;
+            Ok(duration)
+        }
+        Err(error) => Err(ErrMode::Backtrack(E::from_external_error(
+            input,
+            ErrorKind::Verify,
+            error,
+        ))),
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+#![cfg(all(feature = "standard", feature = "custom"))]
+
+use fundu::TimeUnit::*;
+use fundu::{
+    CustomDurationParser, CustomTimeUnit, Duration, DurationDistribution,
+    DurationDistributionParser, DurationParser, Multiplier, ParseError, RandomSource,
+};
+use rstest::rstest;
+
+/// The `SplitMix64` generator which is deterministic for the same seed
+struct SplitMix64(u64);
+
+impl RandomSource for SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+#[rstest]
+#[case::fixed("30s", DurationDistribution::Fixed(Duration::positive(30, 0)))]
+#[case::percent(
+    "30s ± 10%",
+    DurationDistribution::Jitter {
+        base: Duration::positive(30, 0),
+        percent: Multiplier(1, 1)
+    }
+)]
+#[case::plus_minus(
+    "1.5m +- 2.5%",
+    DurationDistribution::Jitter {
+        base: Duration::positive(90, 0),
+        percent: Multiplier(25, -1)
+    }
+)]
+#[case::jitter_duration(
+    "1m~5s",
+    DurationDistribution::Uniform {
+        min: Duration::positive(55, 0),
+        max: Duration::positive(65, 0)
+    }
+)]
+#[case::jitter_with_exponent(
+    "1s ~ 1e2ms",
+    DurationDistribution::Uniform {
+        min: Duration::positive(0, 900_000_000),
+        max: Duration::positive(1, 100_000_000)
+    }
+)]
+#[case::rand(
+    "rand(1s, 5s)",
+    DurationDistribution::Uniform {
+        min: Duration::positive(1, 0),
+        max: Duration::positive(5, 0)
+    }
+)]
+#[case::rand_with_numbers(
+    "rand(0.5, 2)",
+    DurationDistribution::Uniform {
+        min: Duration::positive(0, 500_000_000),
+        max: Duration::positive(2, 0)
+    }
+)]
+fn test_duration_parser_distribution(#[case] source: &str, #[case] expected: DurationDistribution) {
+    let parser = DurationParser::new();
+    assert_eq!(parser.distribution().parse(source), Ok(expected));
+}
+
+#[rstest]
+#[case::invalid_time_unit(
+    "1m ~ 5x",
+    ParseError::Syntax(6, "Expected the end of input but found 'x'".to_string())
+)]
+#[case::rand_min_greater_than_max(
+    "rand(5s, 1s)",
+    ParseError::InvalidInput("The minimum 5s is greater than the maximum 1s".to_string())
+)]
+#[case::negative("1s ± 150%", ParseError::NegativeNumber)]
+fn test_duration_parser_distribution_when_error(
+    #[case] source: &str,
+    #[case] expected: ParseError,
+) {
+    let parser = DurationParser::new();
+    assert_eq!(parser.distribution().parse(source), Err(expected));
+}
+
+#[test]
+fn test_duration_parser_distribution_with_allow_negative() {
+    let parser = DurationParser::builder()
+        .default_time_units()
+        .allow_negative()
+        .build();
+
+    let distribution = parser.distribution().parse("1s ± 150%").unwrap();
+    assert_eq!(distribution.min(), Duration::negative(0, 500_000_000));
+    assert_eq!(distribution.max(), Duration::positive(2, 500_000_000));
+}
+
+#[test]
+fn test_custom_duration_parser_distribution() {
+    let parser = CustomDurationParser::builder()
+        .time_units(&[
+            CustomTimeUnit::with_default(Second, &["sec", "second", "seconds"]),
+            CustomTimeUnit::with_default(Minute, &["min", "minute", "minutes"]),
+        ])
+        .allow_time_unit_delimiter()
+        .build();
+
+    assert_eq!(
+        parser.distribution().parse("2 minutes ± 10 seconds"),
+        Ok(DurationDistribution::Uniform {
+            min: Duration::positive(110, 0),
+            max: Duration::positive(130, 0)
+        })
+    );
+}
+
+#[test]
+fn test_duration_distribution_sample_with_seeded_rng() {
+    let parser = DurationParser::new();
+    let distribution = parser.distribution().parse("30s ± 10%").unwrap();
+
+    let samples: Vec<Duration> = {
+        let mut rng = SplitMix64(42);
+        (0..10).map(|_| distribution.sample(&mut rng)).collect()
+    };
+    let mut rng = SplitMix64(42);
+    for sample in &samples {
+        assert_eq!(distribution.sample(&mut rng), *sample);
+        assert!(distribution.min() <= *sample && *sample <= distribution.max());
+    }
+}
+
+#[test]
+fn test_duration_distribution_parser_with_owned_parser() {
+    let parser = DurationDistributionParser::new(DurationParser::new());
+
+    assert_eq!(
+        parser.parse("1h").unwrap().sample(&mut SplitMix64(0)),
+        Duration::positive(3600, 0)
+    );
+    assert_eq!(parser.parser().parse("1h"), Ok(Duration::positive(3600, 0)));
+}
@@ -0,0 +1,123 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+#![cfg(all(feature = "standard", feature = "custom"))]
+
+use fundu::TimeUnit::*;
+use fundu::{
+    CustomDurationParser, CustomTimeUnit, Duration, DurationParser, ExpressionParser, Multiplier,
+    ParseError, TimeKeyword,
+};
+use rstest::rstest;
+
+#[rstest]
+#[case::example("2 * (1h + 15m) - 30s", Duration::positive(8970, 0))]
+#[case::exponent("1e3ms + 1s", Duration::positive(2, 0))]
+#[case::fraction("1.5h / 3", Duration::positive(1800, 0))]
+#[case::number_is_second("1m + 30", Duration::positive(90, 0))]
+#[case::number_with_exponent("1e1 * 1e1", Duration::positive(100, 0))]
+#[case::all_time_units("1w - 6d - 23h - 59m - 59s", Duration::positive(1, 0))]
+#[case::sub_second_units("1ms + 1Ms + 1ns", Duration::positive(0, 1_001_001))]
+#[case::infinity("inf + 1h", Duration::MAX)]
+#[case::function_with_number("min(1h / 8, 5m + 30)", Duration::positive(330, 0))]
+#[case::infinity_in_function("min(inf, 1y)", Duration::positive(31_557_600, 0))]
+#[case::without_whitespace("(1h+1m)*2", Duration::positive(7320, 0))]
+#[case::comparison("90s * 2 <= 3m", Duration::positive(180, 0))]
+fn test_duration_parser_expression(#[case] source: &str, #[case] expected: Duration) {
+    let parser = DurationParser::with_all_time_units();
+    assert_eq!(parser.expression().parse(source), Ok(expected));
+}
+
+#[rstest]
+#[case::invalid_time_unit(
+    "1h + 1x",
+    ParseError::Syntax(6, "Expected an operator but found 'x'".to_string())
+)]
+#[case::missing_operator(
+    "1h 1m",
+    ParseError::Syntax(3, "Expected an operator but found '1'".to_string())
+)]
+#[case::unknown_function("avg(1s)", ParseError::InvalidInput("avg(1s)".to_string()))]
+#[case::comparison(
+    "90s * 2 < 3m",
+    ParseError::InvalidInput("The duration 3m is not < 3m".to_string())
+)]
+#[case::comparison_with_display(
+    "3 * 45m <= 2h",
+    ParseError::InvalidInput("The duration 2h 15m is not <= 2h".to_string())
+)]
+#[case::negative("1s - 1m", ParseError::NegativeNumber)]
+fn test_duration_parser_expression_when_error(#[case] source: &str, #[case] expected: ParseError) {
+    let parser = DurationParser::with_all_time_units();
+    assert_eq!(parser.expression().parse(source), Err(expected));
+}
+
+#[test]
+fn test_duration_parser_expression_uses_configuration() {
+    let parser = DurationParser::builder()
+        .default_time_units()
+        .default_unit(MilliSecond)
+        .allow_time_unit_delimiter()
+        .allow_negative()
+        .build();
+    let expression = parser.expression();
+
+    assert_eq!(
+        expression.parse("1 s + 500"),
+        Ok(Duration::positive(1, 500_000_000))
+    );
+    assert_eq!(expression.parse("1 s - 2 s"), Ok(Duration::negative(1, 0)));
+}
+
+#[test]
+fn test_duration_parser_expression_with_parse_multiple() {
+    let parser = DurationParser::builder()
+        .default_time_units()
+        .parse_multiple(None)
+        .build();
+
+    assert_eq!(
+        parser.expression().parse("2 * (1h 30m) + 1s"),
+        Ok(Duration::positive(10801, 0))
+    );
+}
+
+#[test]
+fn test_custom_duration_parser_expression() {
+    let parser = CustomDurationParser::builder()
+        .time_units(&[
+            CustomTimeUnit::with_default(Second, &["sec", "second", "seconds"]),
+            CustomTimeUnit::with_default(Minute, &["min", "minute", "minutes"]),
+            CustomTimeUnit::new(Day, &["fortnight"], Some(Multiplier(14, 0))),
+        ])
+        .keyword(TimeKeyword::new(Day, &["tomorrow"], None))
+        .allow_time_unit_delimiter()
+        .build();
+    let expression = parser.expression();
+
+    assert_eq!(
+        expression.parse("2 minutes + 30 seconds"),
+        Ok(Duration::positive(150, 0))
+    );
+    assert_eq!(
+        expression.parse("tomorrow - 1 fortnight / 14"),
+        Ok(Duration::ZERO)
+    );
+    assert_eq!(
+        expression.parse("max(1 min, 2 * 45 sec)"),
+        Ok(Duration::positive(90, 0))
+    );
+}
+
+#[test]
+fn test_expression_parser_with_owned_parser() {
+    let expression = ExpressionParser::new(DurationParser::new()).default_unit(Minute);
+
+    assert_eq!(expression.parse("1.5"), Ok(Duration::positive(90, 0)));
+    assert_eq!(
+        expression.parser().parse("1.5"),
+        Ok(Duration::positive(1, 500_000_000))
+    );
+}
@@ -0,0 +1,71 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+#![cfg(all(feature = "macros", feature = "standard"))]
+
+use fundu::{duration, std_duration, Duration, DurationParser, DurationParserBuilder};
+use rstest::rstest;
+
+const TIMEOUT: Duration = duration!("1.5m");
+const INTERVAL: std::time::Duration = std_duration!("250ms");
+
+#[rstest]
+#[case::number(duration!("1"), "1")]
+#[case::nano_second(duration!("1ns"), "1ns")]
+#[case::micro_second(duration!("1Ms"), "1Ms")]
+#[case::milli_second(duration!("1ms"), "1ms")]
+#[case::minute(duration!("1m"), "1m")]
+#[case::hour(duration!("1h"), "1h")]
+#[case::day(duration!("1d"), "1d")]
+#[case::week(duration!("1w"), "1w")]
+#[case::fraction(duration!("1.123456789s"), "1.123456789s")]
+#[case::exponent(duration!("1.5e-3"), "1.5e-3")]
+#[case::only_fraction(duration!(".5"), ".5")]
+#[case::positive_sign(duration!("+1s"), "+1s")]
+#[case::infinity(duration!("inf"), "inf")]
+#[case::infinity_long(duration!("Infinity"), "Infinity")]
+#[case::overflow(duration!("1e30w"), "1e30w")]
+#[case::raw_literal(duration!(r"1s"), "1s")]
+#[case::escape(duration!("1\x73"), "1s")]
+fn test_duration_macro_is_equal_to_duration_parser(
+    #[case] duration: Duration,
+    #[case] source: &str,
+) {
+    assert_eq!(DurationParser::new().parse(source), Ok(duration));
+}
+
+#[rstest]
+#[case::two(duration!("1h 30m"), "1h 30m")]
+#[case::without_whitespace(duration!("1m30s"), "1m30s")]
+#[case::many_whitespace(duration!("1d \t 2h  1.5s"), "1d \t 2h  1.5s")]
+fn test_duration_macro_with_multiple_durations(#[case] duration: Duration, #[case] source: &str) {
+    let parser = DurationParserBuilder::new()
+        .default_time_units()
+        .parse_multiple(None)
+        .build();
+    assert_eq!(parser.parse(source), Ok(duration));
+}
+
+#[rstest]
+#[case::number(std_duration!("1"), "1")]
+#[case::fraction(std_duration!("1.123456789s"), "1.123456789s")]
+#[case::infinity(std_duration!("inf"), "inf")]
+fn test_std_duration_macro_is_equal_to_duration_parser(
+    #[case] duration: std::time::Duration,
+    #[case] source: &str,
+) {
+    assert_eq!(
+        DurationParser::new()
+            .parse(source)
+            .map(|duration| duration.try_into().unwrap()),
+        Ok(duration)
+    );
+}
+
+#[test]
+fn test_duration_macros_in_const() {
+    assert_eq!(TIMEOUT, Duration::positive(90, 0));
+    assert_eq!(INTERVAL, std::time::Duration::from_millis(250));
+}
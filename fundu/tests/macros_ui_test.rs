@@ -0,0 +1,16 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+#![cfg(all(feature = "macros", feature = "standard"))]
+
+#[test]
+fn test_duration_macros_when_invalid_then_compile_error() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
+
+#[test]
+fn test_duration_macros_when_valid_then_compile() {
+    trybuild::TestCases::new().pass("tests/ui/pass/*.rs");
+}
@@ -0,0 +1,116 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+#![cfg(all(feature = "nom", feature = "custom"))]
+
+use fundu::TimeUnit::*;
+use fundu::{CustomDurationParser, CustomTimeUnit, Duration, DurationParser, ParseError};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, space1};
+use nom::combinator::value;
+use nom::error::{ErrorKind, FromExternalError, ParseError as NomParseError};
+use nom::multi::separated_list1;
+use nom::sequence::separated_pair;
+use nom::IResult;
+use rstest::rstest;
+
+#[derive(Debug, PartialEq)]
+enum TestError<'a> {
+    Nom(&'a str, ErrorKind),
+    Fundu(&'a str, ParseError),
+}
+
+impl<'a> NomParseError<&'a str> for TestError<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        Self::Nom(input, kind)
+    }
+
+    fn append(_: &'a str, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<'a> FromExternalError<&'a str, ParseError> for TestError<'a> {
+    fn from_external_error(input: &'a str, _: ErrorKind, error: ParseError) -> Self {
+        Self::Fundu(input, error)
+    }
+}
+
+#[rstest]
+#[case::only_duration("1s", "", Duration::positive(1, 0))]
+#[case::with_remainder("2m,3s", ",3s", Duration::positive(120, 0))]
+#[case::number_without_time_unit("5 apples", " apples", Duration::positive(5, 0))]
+fn test_nom_duration(#[case] input: &str, #[case] rest: &str, #[case] expected: Duration) {
+    let parser = DurationParser::new();
+    let result: IResult<&str, Duration> = fundu::nom::duration(&parser)(input);
+    assert_eq!(result, Ok((rest, expected)));
+}
+
+#[rstest]
+#[case::empty("", ParseError::Empty)]
+#[case::word("never", ParseError::InvalidInput("never".to_owned()))]
+fn test_nom_duration_when_invalid_then_fundu_error(
+    #[case] input: &str,
+    #[case] expected: ParseError,
+) {
+    let parser = DurationParser::new();
+    let result: IResult<&str, Duration, TestError> = fundu::nom::duration(&parser)(input);
+    assert_eq!(
+        result,
+        Err(nom::Err::Error(TestError::Fundu(input, expected)))
+    );
+}
+
+#[test]
+fn test_nom_duration_in_list_of_assignments() {
+    let parser = CustomDurationParser::builder()
+        .time_units(&[
+            CustomTimeUnit::with_default(Second, &["s", "sec"]),
+            CustomTimeUnit::with_default(Minute, &["min"]),
+        ])
+        .allow_time_unit_delimiter()
+        .build();
+    let mut assignments = separated_list1(
+        tag(", "),
+        separated_pair(
+            alpha1::<&str, TestError>,
+            tag("="),
+            fundu::nom::duration(&parser),
+        ),
+    );
+    assert_eq!(
+        assignments("delay=2 min, timeout=30 sec;"),
+        Ok((
+            ";",
+            vec![
+                ("delay", Duration::positive(120, 0)),
+                ("timeout", Duration::positive(30, 0))
+            ]
+        ))
+    );
+}
+
+#[test]
+fn test_nom_duration_when_error_then_alt_tries_alternatives() {
+    let parser = DurationParser::new();
+    let mut timeout = alt((
+        value(None, tag::<&str, &str, TestError>("never")),
+        nom::combinator::map(fundu::nom::duration(&parser), Some),
+    ));
+    assert_eq!(timeout("never"), Ok(("", None)));
+    assert_eq!(timeout("1s"), Ok(("", Some(Duration::positive(1, 0)))));
+    assert_eq!(
+        separated_list1(space1, &mut timeout)("1s never 2s"),
+        Ok((
+            "",
+            vec![
+                Some(Duration::positive(1, 0)),
+                None,
+                Some(Duration::positive(2, 0))
+            ]
+        ))
+    );
+}
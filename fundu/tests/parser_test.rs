@@ -8,7 +8,8 @@ use std::time::Duration as StdDuration;
 use fundu::TimeUnit::*;
 use fundu::{
     parse_duration, CustomDurationParser, CustomDurationParserBuilder, CustomTimeUnit, Duration,
-    DurationParser, Multiplier, ParseError, TimeKeyword, TimeUnit, SYSTEMD_TIME_UNITS,
+    DurationParser, Multiplier, ParseError, TimeKeyword, TimeUnit, DEFAULT_TIME_UNITS,
+    SYSTEMD_TIME_UNITS,
 };
 use rstest::rstest;
 
@@ -1057,3 +1058,79 @@ fn test_custom_parser_with_allow_ago_when_parse_multiple(
         .build();
     assert_eq!(parser.parse(input), Ok(expected));
 }
+
+#[rstest]
+#[case::only_duration("1.5s", Duration::positive(1, 500_000_000), "")]
+#[case::embedded("5m then fail", Duration::positive(300, 0), " then fail")]
+#[case::number_without_time_unit("3x every 5m", Duration::positive(3, 0), "x every 5m")]
+#[case::punctuation("1h,2h", Duration::positive(3600, 0), ",2h")]
+#[case::longest_time_unit("1ms)", Duration::positive(0, 1_000_000), ")")]
+#[case::time_unit_followed_by_letters("5mthen", Duration::positive(5, 0), "mthen")]
+#[case::longer_time_unit_followed_by_letters("5msfoo", Duration::positive(5, 0), "msfoo")]
+#[case::word_starting_with_time_unit("5 months later", Duration::positive(5, 0), " months later")]
+#[case::not_an_exponent("2each", Duration::positive(2, 0), "each")]
+#[case::infinity("inf]", Duration::MAX, "]")]
+fn test_parser_parse_prefix(
+    #[case] source: &str,
+    #[case] expected: Duration,
+    #[case] remainder: &str,
+) {
+    assert_eq!(
+        DurationParser::new().parse_prefix(source),
+        Ok((expected, remainder))
+    );
+    assert_eq!(
+        CustomDurationParser::with_time_units(&DEFAULT_TIME_UNITS).parse_prefix(source),
+        Ok((expected, remainder))
+    );
+}
+
+#[rstest]
+#[case::empty("", ParseError::Empty)]
+#[case::leading_whitespace(" 1s", ParseError::InvalidInput(" 1s".to_owned()))]
+#[case::word("every 5m", ParseError::InvalidInput("every 5m".to_owned()))]
+#[case::negative("-1s", ParseError::NegativeNumber)]
+fn test_parser_parse_prefix_when_error(#[case] source: &str, #[case] expected: ParseError) {
+    assert_eq!(DurationParser::new().parse_prefix(source), Err(expected));
+}
+
+#[test]
+fn test_parser_parse_many() {
+    let sources = ["1", "00012", "1ms", "1.5s", "1e1s", "1x", "", "1 s"];
+    let expected: Vec<_> = sources
+        .iter()
+        .map(|source| DurationParser::new().parse(source))
+        .collect();
+    assert_eq!(DurationParser::new().parse_many(&sources), expected);
+
+    let parser = CustomDurationParser::with_time_units(&DEFAULT_TIME_UNITS);
+    let expected: Vec<_> = sources.iter().map(|source| parser.parse(source)).collect();
+    let mut results = Vec::new();
+    parser.parse_many_into(&sources, &mut results);
+    assert_eq!(results, expected);
+}
+
+#[test]
+fn test_custom_parser_parse_prefix_with_keywords_and_multiple() {
+    let parser = CustomDurationParser::builder()
+        .time_units(&DEFAULT_TIME_UNITS)
+        .keyword(TimeKeyword::new(Day, &["tomorrow"], Some(Multiplier(1, 0))))
+        .allow_time_unit_delimiter()
+        .allow_ago()
+        .allow_negative()
+        .parse_multiple(Some(&["and"]))
+        .build();
+
+    assert_eq!(
+        parser.parse_prefix("tomorrow and 2 h ago, rest"),
+        Ok((Duration::positive(22 * 60 * 60, 0), ", rest"))
+    );
+    assert_eq!(
+        parser.parse_prefix("1 h and then"),
+        Ok((Duration::positive(60 * 60, 0), " and then"))
+    );
+    assert_eq!(
+        parser.parse_prefix("5 months later"),
+        Ok((Duration::positive(5, 0), " months later"))
+    );
+}
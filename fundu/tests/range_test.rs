@@ -0,0 +1,173 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+#![cfg(all(feature = "standard", feature = "custom"))]
+
+use std::ops::Bound;
+
+use fundu::TimeUnit::*;
+use fundu::{
+    CustomDurationParser, CustomTimeUnit, Duration, DurationParser, DurationRange,
+    DurationRangeParser, Multiplier, ParseError, TimeKeyword,
+};
+use rstest::rstest;
+
+#[rstest]
+#[case::exclusive(
+    "5m..10m",
+    Bound::Included(Duration::positive(300, 0)),
+    Bound::Excluded(Duration::positive(600, 0))
+)]
+#[case::inclusive(
+    "5m..=10m",
+    Bound::Included(Duration::positive(300, 0)),
+    Bound::Included(Duration::positive(600, 0))
+)]
+#[case::to(
+    "1s to 3s",
+    Bound::Included(Duration::positive(1, 0)),
+    Bound::Included(Duration::positive(3, 0))
+)]
+#[case::hyphen(
+    "1s-3s",
+    Bound::Included(Duration::positive(1, 0)),
+    Bound::Included(Duration::positive(3, 0))
+)]
+#[case::open_start("..1h", Bound::Unbounded, Bound::Excluded(Duration::positive(3600, 0)))]
+#[case::open_end("1h..", Bound::Included(Duration::positive(3600, 0)), Bound::Unbounded)]
+#[case::less_or_equal(
+    "<=500ms",
+    Bound::Unbounded,
+    Bound::Included(Duration::positive(0, 500_000_000))
+)]
+#[case::greater(">2h", Bound::Excluded(Duration::positive(7200, 0)), Bound::Unbounded)]
+#[case::numbers(
+    "1.5..2",
+    Bound::Included(Duration::positive(1, 500_000_000)),
+    Bound::Excluded(Duration::positive(2, 0))
+)]
+#[case::number_with_point(
+    "0...5",
+    Bound::Included(Duration::ZERO),
+    Bound::Excluded(Duration::positive(0, 500_000_000))
+)]
+#[case::exponent(
+    "1e-3s..1e3ms",
+    Bound::Included(Duration::positive(0, 1_000_000)),
+    Bound::Excluded(Duration::positive(1, 0))
+)]
+#[case::infinity(
+    "1w..inf",
+    Bound::Included(Duration::positive(604_800, 0)),
+    Bound::Excluded(Duration::MAX)
+)]
+fn test_duration_parser_range(
+    #[case] source: &str,
+    #[case] start: Bound<Duration>,
+    #[case] end: Bound<Duration>,
+) {
+    let parser = DurationParser::with_all_time_units();
+    assert_eq!(
+        parser.range().parse(source),
+        Ok(DurationRange::new(start, end))
+    );
+}
+
+#[rstest]
+#[case::start_greater_than_end(
+    "1h..1m",
+    ParseError::InvalidInput("The start 1h of the range is greater than the end 1m".to_string())
+)]
+#[case::invalid_time_unit(
+    "1s..1x",
+    ParseError::Syntax(5, "Expected the end of input but found 'x'".to_string())
+)]
+#[case::single_duration(
+    "1s",
+    ParseError::Syntax(
+        2,
+        "Expected '..', '..=', 'to' or '-' but reached the end of input".to_string()
+    )
+)]
+#[case::negative("-1s..1s", ParseError::NegativeNumber)]
+fn test_duration_parser_range_when_error(#[case] source: &str, #[case] expected: ParseError) {
+    let parser = DurationParser::with_all_time_units();
+    assert_eq!(parser.range().parse(source), Err(expected));
+}
+
+#[test]
+fn test_duration_parser_range_with_allow_negative() {
+    let parser = DurationParser::builder()
+        .default_time_units()
+        .allow_negative()
+        .build();
+
+    assert_eq!(
+        parser.range().parse("-1s..1s"),
+        Ok(DurationRange::new(
+            Bound::Included(Duration::negative(1, 0)),
+            Bound::Excluded(Duration::positive(1, 0))
+        ))
+    );
+}
+
+#[test]
+fn test_duration_parser_range_with_time_unit_delimiter() {
+    let parser = DurationParser::builder()
+        .default_time_units()
+        .allow_time_unit_delimiter()
+        .build();
+
+    assert_eq!(
+        parser.range().parse("5 m .. 10 m"),
+        Ok(DurationRange::new(
+            Bound::Included(Duration::positive(300, 0)),
+            Bound::Excluded(Duration::positive(600, 0))
+        ))
+    );
+    assert_eq!(
+        parser.range().parse("> 2 h"),
+        Ok(DurationRange::new(
+            Bound::Excluded(Duration::positive(7200, 0)),
+            Bound::Unbounded
+        ))
+    );
+}
+
+#[test]
+fn test_custom_duration_parser_range() {
+    let parser = CustomDurationParser::builder()
+        .time_units(&[
+            CustomTimeUnit::with_default(Second, &["sec", "second", "seconds"]),
+            CustomTimeUnit::with_default(Minute, &["min", "minute", "minutes"]),
+        ])
+        .keyword(TimeKeyword::new(Day, &["today"], Some(Multiplier(0, 0))))
+        .allow_time_unit_delimiter()
+        .build();
+
+    let range = parser.range().parse("30 seconds to 2 minutes").unwrap();
+    assert!(range.contains(&Duration::positive(30, 0)));
+    assert!(range.contains(&Duration::positive(120, 0)));
+    assert!(!range.contains(&Duration::positive(121, 0)));
+
+    assert_eq!(
+        parser.range().parse("today..1 min"),
+        Ok(DurationRange::new(
+            Bound::Included(Duration::ZERO),
+            Bound::Excluded(Duration::positive(60, 0))
+        ))
+    );
+}
+
+#[test]
+fn test_duration_range_parser_with_owned_parser() {
+    let parser = DurationRangeParser::new(DurationParser::new());
+
+    assert!(parser
+        .parse("..=1h")
+        .unwrap()
+        .contains(&Duration::positive(3600, 0)));
+    assert_eq!(parser.parser().parse("1h"), Ok(Duration::positive(3600, 0)));
+}
@@ -0,0 +1,101 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+#![cfg(all(feature = "standard", feature = "custom"))]
+
+use fundu::TimeUnit::*;
+use fundu::{
+    CustomDurationParser, CustomTimeUnit, Duration, DurationParser, Multiplier, Scanner,
+    TimeKeyword, DEFAULT_TIME_UNITS,
+};
+use rstest::rstest;
+
+#[rstest]
+#[case::empty("", vec![])]
+#[case::no_durations("nothing to see here", vec![])]
+#[case::log_line(
+    "request took 350ms after 2 retries, backoff 1.5s",
+    vec![(13..18, Duration::positive(0, 350_000_000)), (44..48, Duration::positive(1, 500_000_000))]
+)]
+#[case::exponent("took 1e-3s", vec![(5..10, Duration::positive(0, 1_000_000))])]
+#[case::in_parentheses("(2h)", vec![(1..3, Duration::positive(7200, 0))])]
+#[case::no_unit_with_fraction("version 1.5 took 3.", vec![])]
+#[case::inside_word("mass 10ms1", vec![])]
+#[case::infinity("timeout inf", vec![(8..11, Duration::MAX)])]
+fn test_duration_parser_find_iter(
+    #[case] text: &str,
+    #[case] expected: Vec<(std::ops::Range<usize>, Duration)>,
+) {
+    let parser = DurationParser::with_all_time_units();
+    let actual = parser
+        .find_iter(text)
+        .map(|m| (m.span, m.duration))
+        .collect::<Vec<_>>();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_duration_parser_find_iter_with_parse_multiple_is_maximal() {
+    let parser = DurationParser::builder()
+        .default_time_units()
+        .allow_time_unit_delimiter()
+        .parse_multiple(Some(&["and"]))
+        .build();
+    let sources = parser
+        .find_iter("waited 1 h and 30 m and 2 tries")
+        .map(|m| m.source)
+        .collect::<Vec<_>>();
+    assert_eq!(sources, vec!["1 h and 30 m"]);
+}
+
+#[test]
+fn test_duration_parser_find_iter_with_scanner_settings() {
+    let parser = DurationParser::new();
+    let text = "1 2s 3";
+
+    let scanner = Scanner::new(&parser).allow_numbers_without_unit();
+    let sources = scanner
+        .find_iter(text)
+        .map(|m| m.source)
+        .collect::<Vec<_>>();
+    assert_eq!(sources, vec!["1", "2s", "3"]);
+
+    let parser = DurationParser::builder()
+        .default_time_units()
+        .parse_multiple(None)
+        .build();
+    let scanner = Scanner::new(&parser).max_words(2);
+    let sources = scanner
+        .find_iter("1s 2s 3s")
+        .map(|m| m.source)
+        .collect::<Vec<_>>();
+    assert_eq!(sources, vec!["1s 2s", "3s"]);
+}
+
+#[test]
+fn test_custom_duration_parser_find_iter_with_keywords() {
+    let parser = CustomDurationParser::builder()
+        .time_units(&DEFAULT_TIME_UNITS)
+        .time_unit(CustomTimeUnit::with_default(Day, &["days"]))
+        .keyword(TimeKeyword::new(
+            Day,
+            &["yesterday"],
+            Some(Multiplier(-1, 0)),
+        ))
+        .allow_time_unit_delimiter()
+        .allow_negative()
+        .build();
+    let actual = parser
+        .find_iter("yesterday, and 2 days before; 3 apples")
+        .map(|m| (m.source, m.duration))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        actual,
+        vec![
+            ("yesterday", Duration::negative(86400, 0)),
+            ("2 days", Duration::positive(2 * 86400, 0))
+        ]
+    );
+}
@@ -0,0 +1,105 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+#![cfg(all(feature = "standard", feature = "custom"))]
+
+use std::io::BufReader;
+
+use fundu::TimeUnit::*;
+use fundu::{
+    BufReadParser, CustomDurationParser, Duration, DurationParser, StreamItem, StreamParser,
+    SYSTEMD_TIME_UNITS,
+};
+use rstest::rstest;
+
+fn collect<'a>(stream: &mut StreamParser<DurationParser<'a>>, chunks: &[&str]) -> Vec<StreamItem> {
+    let mut items = vec![];
+    for chunk in chunks {
+        items.extend(stream.feed(chunk.as_bytes()));
+    }
+    items.extend(stream.finish());
+    items
+}
+
+#[rstest]
+#[case::single_chunk(&["1.25ms 2s"])]
+#[case::split_in_number(&["1.2", "5ms 2s"])]
+#[case::split_in_time_unit(&["1.25m", "s 2s"])]
+#[case::split_at_separator(&["1.25ms", " ", "2s"])]
+#[case::one_byte_chunks(&["1", ".", "2", "5", "m", "s", " ", "2", "s"])]
+fn test_stream_parser_with_duration_parser(#[case] chunks: &[&str]) {
+    let mut stream = StreamParser::new(DurationParser::with_all_time_units());
+    assert_eq!(
+        collect(&mut stream, chunks),
+        vec![
+            StreamItem {
+                span: 0..6,
+                duration: Duration::positive(0, 1_250_000)
+            },
+            StreamItem {
+                span: 7..9,
+                duration: Duration::positive(2, 0)
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_stream_parser_with_duration_parser_when_durations_inside_tokens() {
+    let mut stream = StreamParser::new(DurationParser::new());
+    assert_eq!(
+        collect(&mut stream, &["elapsed=1.", "5ms 1", "x (3s) 4"]),
+        vec![
+            StreamItem {
+                span: 8..13,
+                duration: Duration::positive(0, 1_500_000)
+            },
+            StreamItem {
+                span: 18..20,
+                duration: Duration::positive(3, 0)
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_stream_parser_with_custom_duration_parser_and_separator() {
+    let parser = CustomDurationParser::with_time_units(&SYSTEMD_TIME_UNITS);
+    let mut stream = StreamParser::with_separator(parser, |byte| byte == b',');
+
+    let mut items: Vec<_> = stream.feed(b"1min,30sec,2h").collect();
+    items.extend(stream.finish());
+    let durations: Vec<_> = items.into_iter().map(|item| item.duration).collect();
+    assert_eq!(
+        durations,
+        vec![
+            Duration::positive(60, 0),
+            Duration::positive(30, 0),
+            Duration::positive(7200, 0)
+        ]
+    );
+}
+
+#[rstest]
+#[case::capacity_one(1)]
+#[case::capacity_three(3)]
+#[case::capacity_default(8 * 1024)]
+fn test_buf_read_parser_with_duration_parser(#[case] capacity: usize) {
+    let input = "1s\n2ms\n  3.5d\n";
+    let reader = BufReader::with_capacity(capacity, input.as_bytes());
+    let parser = DurationParser::with_time_units(&[Second, MilliSecond, Day]);
+
+    let durations = BufReadParser::new(reader, parser)
+        .map(|item| item.unwrap().duration)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        durations,
+        vec![
+            Duration::positive(1, 0),
+            Duration::positive(0, 2_000_000),
+            Duration::positive(302_400, 0)
+        ]
+    );
+}
@@ -0,0 +1,8 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+const TIMEOUT: fundu::Duration = fundu::duration!("-1s");
+
+fn main() {}
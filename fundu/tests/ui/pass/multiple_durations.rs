@@ -0,0 +1,12 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+const TIMEOUT: fundu::Duration = fundu::duration!("1h 30m");
+const INTERVAL: std::time::Duration = fundu::std_duration!("1s 500ms");
+
+fn main() {
+    assert_eq!(TIMEOUT, fundu::Duration::positive(90 * 60, 0));
+    assert_eq!(INTERVAL, std::time::Duration::from_millis(1500));
+}
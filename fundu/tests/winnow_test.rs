@@ -0,0 +1,94 @@
+// Copyright (c) 2023 Joining7943 <joining@posteo.de>
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+#![cfg(all(feature = "winnow", feature = "custom"))]
+
+use fundu::TimeUnit::*;
+use fundu::{CustomDurationParser, CustomTimeUnit, Duration, DurationParser, ParseError};
+use rstest::rstest;
+use winnow::ascii::{alpha1, space1};
+use winnow::combinator::{alt, separated, separated_pair};
+use winnow::error::{ContextError, ErrMode, InputError};
+use winnow::{PResult, Parser};
+
+#[rstest]
+#[case::only_duration("1s", "", Duration::positive(1, 0))]
+#[case::with_remainder("2m,3s", ",3s", Duration::positive(120, 0))]
+#[case::number_without_time_unit("5 apples", " apples", Duration::positive(5, 0))]
+fn test_winnow_duration(#[case] input: &str, #[case] rest: &str, #[case] expected: Duration) {
+    let parser = DurationParser::new();
+    let mut input = input;
+    let result: PResult<Duration> = fundu::winnow::duration(&parser).parse_next(&mut input);
+    assert_eq!(result, Ok(expected));
+    assert_eq!(input, rest);
+}
+
+#[rstest]
+#[case::empty("", ParseError::Empty)]
+#[case::word("never", ParseError::InvalidInput("never".to_owned()))]
+fn test_winnow_duration_when_invalid_then_fundu_error_is_cause(
+    #[case] input: &str,
+    #[case] expected: ParseError,
+) {
+    let parser = DurationParser::new();
+    let mut rest = input;
+    let result: PResult<Duration, ContextError> =
+        fundu::winnow::duration(&parser).parse_next(&mut rest);
+    match result {
+        Err(ErrMode::Backtrack(error)) => assert_eq!(
+            error
+                .cause()
+                .and_then(|cause| cause.downcast_ref::<ParseError>()),
+            Some(&expected)
+        ),
+        result => panic!("Unexpected result: {result:?}"),
+    }
+    assert_eq!(rest, input);
+}
+
+#[test]
+fn test_winnow_duration_in_list_of_assignments() {
+    let parser = CustomDurationParser::builder()
+        .time_units(&[
+            CustomTimeUnit::with_default(Second, &["s", "sec"]),
+            CustomTimeUnit::with_default(Minute, &["min"]),
+        ])
+        .allow_time_unit_delimiter()
+        .build();
+    let mut input = "delay=2 min, timeout=30 sec;";
+    let assignments: PResult<Vec<(&str, Duration)>, InputError<&str>> = separated(
+        1..,
+        separated_pair(alpha1, "=", fundu::winnow::duration(&parser)),
+        ", ",
+    )
+    .parse_next(&mut input);
+    assert_eq!(
+        assignments,
+        Ok(vec![
+            ("delay", Duration::positive(120, 0)),
+            ("timeout", Duration::positive(30, 0))
+        ])
+    );
+    assert_eq!(input, ";");
+}
+
+#[test]
+fn test_winnow_duration_when_error_then_alt_tries_alternatives() {
+    let parser = DurationParser::new();
+    let timeout = alt((
+        "never".value(None),
+        fundu::winnow::duration(&parser).map(Some),
+    ));
+    let timeouts: PResult<Vec<Option<Duration>>> =
+        separated(1.., timeout, space1).parse_next(&mut "1s never 2s");
+    assert_eq!(
+        timeouts,
+        Ok(vec![
+            Some(Duration::positive(1, 0)),
+            None,
+            Some(Duration::positive(2, 0))
+        ])
+    );
+}